@@ -0,0 +1,79 @@
+//! # A* Heuristic Benchmark
+//!
+//! Compares A* searches with the Chebyshev heuristic (used by robots), the
+//! former Manhattan heuristic and no heuristic at all (Dijkstra, the optimal
+//! reference) on random 20x20 maps with 25% obstacles: nodes expanded, nodes
+//! expanded more than once, and paths longer than optimal.
+//!
+//! Run with `cargo bench --bench astar`.
+
+use std::time::Instant;
+
+use ereea::map::Map;
+use ereea::robot::Robot;
+use ereea::types::{Position, RobotType, TileType, MAP_SIZE};
+use rand::Rng;
+
+// NOTE - Number of random (map, start, target) triples measured
+const SAMPLES: usize = 2000;
+
+// NOTE - Share of obstacle tiles on each generated map
+const OBSTACLE_RATIO: f64 = 0.25;
+
+// NOTE - Distance estimate given to A*, in tiles
+type Heuristic = fn(Position, Position) -> usize;
+
+// NOTE - Previous heuristic, overestimates diagonal moves
+fn manhattan_distance(a: Position, b: Position) -> usize {
+    a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+}
+
+// NOTE - No estimate: A* degrades to Dijkstra, as in `Robot::find_path_dijkstra`
+fn no_estimate(_: Position, _: Position) -> usize {
+    0
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let mut cases = Vec::with_capacity(SAMPLES);
+
+    while cases.len() < SAMPLES {
+        let mut map = Map::new();
+        map.tiles = (0..MAP_SIZE).map(|_| (0..MAP_SIZE).map(|_| {
+            if rng.gen_bool(OBSTACLE_RATIO) { TileType::Obstacle } else { TileType::Empty }
+        }).collect()).collect();
+
+        let start = (rng.gen_range(0..MAP_SIZE), rng.gen_range(0..MAP_SIZE));
+        let target = (rng.gen_range(0..MAP_SIZE), rng.gen_range(0..MAP_SIZE));
+        if map.is_valid_position(start.0, start.1) && map.is_valid_position(target.0, target.1) {
+            cases.push((map, start, target));
+        }
+    }
+
+    let heuristics: [(&str, Heuristic); 3] = [
+        ("Chebyshev", Position::chebyshev_distance),
+        ("Manhattan", manhattan_distance),
+        ("Dijkstra", no_estimate),
+    ];
+
+    for (name, heuristic) in heuristics {
+        let started = Instant::now();
+        let (mut expanded, mut reexpanded, mut suboptimal) = (0, 0, 0);
+
+        for (map, start, target) in &cases {
+            let robot = Robot::new(start.0, start.1, RobotType::Explorer);
+            let stats = robot.path_search_stats(map, *target, heuristic);
+            let optimal = robot.path_search_stats(map, *target, no_estimate);
+
+            expanded += stats.expanded_nodes;
+            reexpanded += stats.reexpanded_nodes;
+            if let (Some(cost), Some(best)) = (stats.path_cost, optimal.path_cost)
+                && cost > best + 1e-3 {
+                suboptimal += 1;
+            }
+        }
+
+        println!("{:<10} {:>6.1} nœuds développés en moyenne, {} re-développements, {} chemins sous-optimaux ({:?})",
+                 name, expanded as f64 / SAMPLES as f64, reexpanded, suboptimal, started.elapsed());
+    }
+}
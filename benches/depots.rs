@@ -0,0 +1,60 @@
+//! # Forward Depot Benchmark
+//!
+//! Compares the average haul distance of the collectors (from the last
+//! pickup of a load to the station it is unloaded at, see
+//! `Station::average_haul_distance`) and the mission length on the same
+//! seeds: with the central station only, with one secondary station placed
+//! at generation (`Map::with_stations`), and with one forward depot the
+//! station builds for minerals once the deposits are known
+//! (`Station::try_build_depot`).
+//!
+//! Run with `cargo bench --bench depots`.
+
+use ereea::knowledge::ExplorationArea;
+use ereea::map::Map;
+use ereea::simulation::{deploy_fleet, step_simulation, StepOutcome, INITIAL_FLEET};
+use ereea::station::{Station, DEPOT_MINERAL_COST};
+
+// NOTE - Seeds of the missions averaged
+const SEEDS: std::ops::RangeInclusive<u32> = 1..=20;
+
+// NOTE - Cycles after which a mission is given up
+const MAX_TICKS: u32 = 3000;
+
+// NOTE - Average haul distance and length of the mission of a seed (stations given to `Map::with_stations`)
+fn run_mission(seed: u32, stations: usize, depot_cost: u32) -> (f32, u32) {
+    let mut map = Map::with_stations(seed, stations);
+    let mut station = Station::new();
+    station.depot_cost = depot_cost;
+    station.set_exploration_area(ExplorationArea::reachable(&map));
+    let mut robots = deploy_fleet(&map, &mut station, &INITIAL_FLEET);
+
+    let mut cycles = MAX_TICKS;
+    for iteration in 0..MAX_TICKS {
+        if step_simulation(&mut map, &mut station, &mut robots, iteration).outcome == StepOutcome::MissionComplete {
+            cycles = iteration + 1;
+            break;
+        }
+    }
+    (station.average_haul_distance().unwrap_or(0.0), cycles)
+}
+
+fn main() {
+    let scenarios = [
+        ("station centrale seule", 1, 0),
+        ("station secondaire", 2, 0),
+        ("dépôt avancé construit", 1, DEPOT_MINERAL_COST),
+    ];
+    let missions = SEEDS.count() as f32;
+
+    for (name, stations, depot_cost) in scenarios {
+        let (mut haul, mut cycles) = (0.0, 0);
+        for seed in SEEDS {
+            let (average, length) = run_mission(seed, stations, depot_cost);
+            haul += average;
+            cycles += length;
+        }
+        println!("{:<24} transport moyen {:.2} cases, mission {:.0} cycles (moyenne sur {} graines)",
+                 name, haul / missions, cycles as f32 / missions, missions);
+    }
+}
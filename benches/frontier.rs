@@ -0,0 +1,59 @@
+//! # Exploration Cost Benchmark
+//!
+//! Measures the average time of one simulation cycle for a fleet of
+//! explorers, from deployment until the map is fully explored. Exploration
+//! targets are picked from each robot's frontier (explored tiles next to
+//! unexplored ones) instead of scanning the whole memory grid.
+//!
+//! The map size is the compile-time `MAP_SIZE`, so the measure is taken on
+//! the standard map only.
+//!
+//! Run with `cargo bench --bench frontier`.
+
+use std::time::Instant;
+
+use ereea::map::Map;
+use ereea::occupancy::OccupancyGrid;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{MAP_SIZE, RobotType};
+
+// NOTE - Number of independent missions averaged
+const MISSIONS: usize = 200;
+
+// NOTE - Simulation cycles per mission
+const CYCLES: usize = 300;
+
+// NOTE - Explorers deployed from the station
+const EXPLORERS: usize = 4;
+
+fn main() {
+    let mut elapsed = 0.0;
+    let mut cycles = 0;
+
+    for _ in 0..MISSIONS {
+        let mut map = Map::new();
+        let mut station = Station::new();
+        let (station_x, station_y) = (map.station_x, map.station_y);
+        let mut robots: Vec<Robot> = (0..EXPLORERS)
+            .map(|i| Robot::new_with_memory(
+                station_x, station_y, RobotType::Explorer, i + 1,
+                station_x, station_y, station.global_memory.clone(),
+            ))
+            .collect();
+
+        let start = Instant::now();
+        for _ in 0..CYCLES {
+            station.tick();
+            let mut occupancy = OccupancyGrid::from_robots(&robots, station_x, station_y);
+            for robot in robots.iter_mut() {
+                robot.update(&mut map, &mut station, &mut occupancy);
+            }
+            cycles += 1;
+        }
+        elapsed += start.elapsed().as_secs_f64();
+    }
+
+    println!("{:.1} µs par cycle ({} explorateurs, carte {}x{}, moyenne sur {} cycles)",
+             elapsed * 1e6 / cycles as f64, EXPLORERS, MAP_SIZE, MAP_SIZE, cycles);
+}
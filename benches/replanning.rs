@@ -0,0 +1,59 @@
+//! # Path Replanning Benchmark
+//!
+//! Counts the A* searches run by a small fleet over 1000 simulation cycles.
+//! Robots keep their planned path between updates and only replan when the
+//! target changes or the path becomes unusable, and keep their way home
+//! for the energy checks while the map stays the same.
+//!
+//! Run with `cargo bench --bench replanning`.
+
+use ereea::map::Map;
+use ereea::occupancy::OccupancyGrid;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::RobotType;
+
+// NOTE - Number of independent missions averaged
+const MISSIONS: usize = 20;
+
+// NOTE - Simulation cycles per mission
+const CYCLES: usize = 1000;
+
+// NOTE - Same initial team as the simulation server, plus two explorers
+const FLEET: [RobotType; 6] = [
+    RobotType::Explorer,
+    RobotType::Explorer,
+    RobotType::Explorer,
+    RobotType::EnergyCollector,
+    RobotType::MineralCollector,
+    RobotType::ScientificCollector,
+];
+
+fn main() {
+    let mut searches = 0;
+
+    for _ in 0..MISSIONS {
+        let mut map = Map::new();
+        let mut station = Station::new();
+        let (station_x, station_y) = (map.station_x, map.station_y);
+        let mut robots: Vec<Robot> = FLEET.iter().enumerate()
+            .map(|(i, &robot_type)| Robot::new_with_memory(
+                station_x, station_y, robot_type, i + 1,
+                station_x, station_y, station.global_memory.clone(),
+            ))
+            .collect();
+
+        for _ in 0..CYCLES {
+            station.tick();
+            let mut occupancy = OccupancyGrid::from_robots(&robots, station_x, station_y);
+            for robot in robots.iter_mut() {
+                robot.update(&mut map, &mut station, &mut occupancy);
+            }
+        }
+
+        searches += robots.iter().map(Robot::path_search_count).sum::<u64>();
+    }
+
+    println!("{:.0} recherches A* pour {} cycles ({} robots, moyenne sur {} missions)",
+             searches as f64 / MISSIONS as f64, CYCLES, FLEET.len(), MISSIONS);
+}
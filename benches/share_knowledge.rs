@@ -0,0 +1,85 @@
+//! # Knowledge Sharing Benchmark
+//!
+//! Measures the average time of a robot's synchronization with the station
+//! (`Station::share_knowledge`, a merge in both directions), and of the
+//! construction of a robot from the station memory (`Robot::new_with_memory`,
+//! which copies the whole memory grid).
+//!
+//! Each round starts from a station knowing part of the map and robots each
+//! knowing another part, observed at different cycles, so both merges learn
+//! tiles and resolve conflicts.
+//!
+//! Run with `cargo bench --bench share_knowledge`.
+
+use std::time::Instant;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use ereea::robot::Robot;
+use ereea::station::{Station, TerrainData};
+use ereea::types::{MAP_SIZE, RobotType, TileType};
+
+// NOTE - Number of independent rounds averaged
+const ROUNDS: usize = 2000;
+
+// NOTE - Robots synchronized with the station in each round
+const ROBOTS: usize = 8;
+
+// NOTE - Share of the map each memory knows before the synchronization
+const KNOWN_SHARE: f64 = 0.4;
+
+// NOTE - Observation of a tile at a random cycle
+fn observation(rng: &mut StdRng, robot_id: usize) -> TerrainData {
+    TerrainData {
+        explored: true,
+        timestamp: rng.gen_range(1..500),
+        robot_id,
+        robot_type: RobotType::Explorer,
+        tile_type: if rng.gen_bool(0.2) { TileType::Mineral } else { TileType::Empty },
+    }
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let (mut build_time, mut sync_time) = (0.0, 0.0);
+
+    for _ in 0..ROUNDS {
+        let mut station = Station::new();
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                if rng.gen_bool(KNOWN_SHARE) {
+                    station.global_memory[(x, y)] = observation(&mut rng, 0);
+                }
+            }
+        }
+        station.reindex_memory();
+
+        let start = Instant::now();
+        let mut robots: Vec<Robot> = (1..=ROBOTS)
+            .map(|id| Robot::new_with_memory(0, 0, RobotType::Explorer, id, 0, 0, station.global_memory.clone()))
+            .collect();
+        build_time += start.elapsed().as_secs_f64();
+
+        // Chaque robot a exploré sa propre part de la carte depuis sa construction
+        for robot in robots.iter_mut() {
+            for y in 0..MAP_SIZE {
+                for x in 0..MAP_SIZE {
+                    if rng.gen_bool(KNOWN_SHARE) {
+                        robot.memory[(x, y)] = observation(&mut rng, robot.id);
+                    }
+                }
+            }
+            robot.reindex_memory();
+        }
+
+        let start = Instant::now();
+        for robot in robots.iter_mut() {
+            station.share_knowledge(robot);
+        }
+        sync_time += start.elapsed().as_secs_f64();
+    }
+
+    let syncs = (ROUNDS * ROBOTS) as f64;
+    println!("{:.2} µs par synchronisation, {:.2} µs par robot construit (carte {}x{}, moyenne sur {} robots)",
+             sync_time * 1e6 / syncs, build_time * 1e6 / syncs, MAP_SIZE, MAP_SIZE, syncs);
+}
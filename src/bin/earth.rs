@@ -2,20 +2,25 @@
 
 /// Module imports for the Earth control center application
 /// - TileType, MAP_SIZE, RobotType, RobotMode: Core simulation types
-/// - SimulationState, DEFAULT_PORT: Network communication structures
-use ereea::types::{TileType, MAP_SIZE, RobotType, RobotMode};
-use ereea::network::{SimulationState, DEFAULT_PORT};
+/// - SimulationState, NetworkMessage, DEFAULT_PORT: Network communication structures
+use ereea::types::{Biome, BrainKind, DayPhase, Inventory, TileType, MAP_SIZE, RobotType, RobotMode, RobotTask, MissionPhase, Upgrade};
+use ereea::network::{count_by_type, ClientCommand, LiteStateDecoder, NetworkMessage, SimulationState, StationData, DEFAULT_PORT, STALE_AFTER_CYCLES};
+use ereea::events::RobotEvent;
+use ereea::network::auth::TOKEN_ENV_VAR;
+use ereea::network::codec::{encode_frame, read_frame, FrameDecoder, WireFormat, GZIP_SUPPORTED};
+use ereea::display::{robot_color, robot_glyph, tile_glyph, TILE_WIDTH};
 
 use std::io::{stdout, Write};
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 use crossterm::{
     ExecutableCommand,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
     terminal::{enable_raw_mode, disable_raw_mode, Clear, ClearType},
     cursor::MoveTo,
-    style::{Color, SetForegroundColor},
+    style::{Attribute, Color, SetAttribute, SetBackgroundColor, SetForegroundColor},
 };
 use tokio::net::TcpStream;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::AsyncWriteExt;
 
 /// Structure to track the display state of the terminal interface
 /// 
@@ -26,6 +31,10 @@ use tokio::io::{AsyncBufReadExt, BufReader};
 /// * `initialized` - Boolean flag to track if the fixed UI layout has been drawn
 /// * `log_messages` - Rolling buffer of mission log messages (FIFO queue)
 /// * `max_log_lines` - Maximum number of log lines to display (prevents overflow)
+/// * `coverage_view` - Tint explored tiles with the color of the robot type that mapped them
+/// * `fleet_size` - Number of robots in the last received state, to detect new deployments
+/// * `broken_robots` - Robots broken down in the last received state, to log breakdowns and repairs
+/// * `transitions_robot` - Robot whose latest mode changes are shown under the legend
 struct DisplayState {
     /// Flag indicating if the static UI layout has been initialized
     initialized: bool,
@@ -33,6 +42,18 @@ struct DisplayState {
     log_messages: VecDeque<String>,
     /// Maximum number of log lines to keep in memory and display
     max_log_lines: usize,
+    /// Coverage heatmap mode, enabled with `--coverage`
+    coverage_view: bool,
+    /// Fleet size in the previous state (None before the first state)
+    fleet_size: Option<usize>,
+    /// IDs of the robots in `RobotMode::Broken` in the previous state
+    broken_robots: HashSet<usize>,
+    /// Cycle of the latest mission event already logged (None before the first one)
+    last_event_cycle: Option<u32>,
+    /// Whether the station reported low energy reserves in the previous state
+    energy_low: bool,
+    /// Robot whose latest mode changes get a detail row, chosen with `--transitions <robot_id>`
+    transitions_robot: Option<usize>,
 }
 
 impl DisplayState {
@@ -45,6 +66,12 @@ impl DisplayState {
             initialized: false,        // UI layout not yet drawn
             log_messages: VecDeque::new(), // Empty message queue
             max_log_lines: 8,          // Limit to 8 visible log lines
+            coverage_view: std::env::args().any(|arg| arg == "--coverage"),
+            fleet_size: None,          // No state received yet
+            broken_robots: HashSet::new(), // Nobody broken down yet
+            last_event_cycle: None,    // No event logged yet
+            energy_low: false,         // Reserves assumed healthy until told otherwise
+            transitions_robot: None,   // Detail row hidden unless asked for
         }
     }
     
@@ -66,9 +93,9 @@ impl DisplayState {
     }
 }
 
-/// Fixed Y-coordinate positions for the terminal user interface layout
-/// These constants define the vertical positioning of each UI section
-/// to maintain a consistent and organized display structure.
+// Fixed Y-coordinate positions for the terminal user interface layout
+// These constants define the vertical positioning of each UI section
+// to maintain a consistent and organized display structure.
 
 /// Header section at the top of the screen (title and branding)
 const HEADER_Y: u16 = 0;
@@ -81,12 +108,230 @@ const MAP_LEFT: u16 = 2;
 /// Station information section (resources, conflicts, etc.)
 const STATION_INFO_Y: u16 = MAP_START_Y + MAP_SIZE as u16 + 4;
 /// Robot status section (individual robot details)
-const ROBOTS_INFO_Y: u16 = STATION_INFO_Y + 4;
+const ROBOTS_INFO_Y: u16 = STATION_INFO_Y + 5;
 /// Mission log section (recent events and notifications)
 const LOGS_Y: u16 = ROBOTS_INFO_Y + 8;
 /// Legend section at the bottom (symbol explanations)
 const LEGEND_Y: u16 = LOGS_Y + 12;
 
+/// Reads the shared secret used to authenticate with the simulation server
+/// 
+/// The `--token <secret>` argument takes precedence over the
+/// `EREEA_TOKEN` environment variable; an empty variable counts as unset.
+/// 
+/// # Returns
+/// * `Result<Option<String>, String>` - The token, `None` when connecting without authentication, or an error when `--token` has no secret
+fn read_auth_token() -> Result<Option<String>, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--token" {
+            return match args.next() {
+                Some(token) if !token.is_empty() => Ok(Some(token)),
+                _ => Err("--token attend un secret non vide".to_string()),
+            };
+        }
+    }
+    Ok(std::env::var(TOKEN_ENV_VAR).ok().filter(|t| !t.is_empty()))
+}
+
+/// Reads the port of the simulation server (`--port <port>`)
+/// 
+/// # Returns
+/// * `Result<u16, String>` - The requested port (`DEFAULT_PORT` by default) or a parsing error
+fn read_port() -> Result<u16, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--port" {
+            return args.next().and_then(|p| p.parse().ok()).ok_or_else(|| "--port attend un numéro de port".to_string());
+        }
+    }
+    Ok(DEFAULT_PORT)
+}
+
+/// Reads the wire format requested with `--format <json|msgpack>`
+/// 
+/// # Returns
+/// * `Result<WireFormat, String>` - The requested format (JSON by default) or a parsing error
+fn read_wire_format() -> Result<WireFormat, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            return args.next().unwrap_or_default().parse();
+        }
+    }
+    Ok(WireFormat::default())
+}
+
+/// Tells whether `--gzip` asks the station for compressed frames
+/// 
+/// # Returns
+/// * `Result<bool, String>` - `true` with `--gzip`, or an error when this build cannot inflate frames
+fn read_gzip_flag() -> Result<bool, String> {
+    let requested = std::env::args().skip(1).any(|arg| arg == "--gzip");
+    if requested && !GZIP_SUPPORTED {
+        return Err("--gzip nécessite la feature gzip (cargo run --features gzip --bin earth)".to_string());
+    }
+    Ok(requested)
+}
+
+/// Tells whether `--lite` asks the station for compact robot updates (see `RobotDataLite`)
+fn read_lite_flag() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--lite")
+}
+
+/// Reads the robot whose mode changes get a detail row (`--transitions <robot_id>`)
+/// 
+/// # Returns
+/// * `Result<Option<usize>, String>` - The robot ID, `None` without the option, or an error for an invalid ID
+fn read_transitions_robot() -> Result<Option<usize>, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--transitions" {
+            let id = args.next().unwrap_or_default();
+            return id.parse().map(Some).map_err(|_| format!("identifiant de robot invalide pour --transitions: '{}'", id));
+        }
+    }
+    Ok(None)
+}
+
+/// Reads the commands to send: tasks with `--task <robot_id>:<task>` and
+/// exploration policies with `--brain <robot_id>:<brain>` (both repeatable)
+/// 
+/// `<task>` is one of `move:x,y`, `collect:x,y`, `survey:x,y,radius` or `home`,
+/// `<brain>` one of `default` or `sweep`.
+/// 
+/// # Returns
+/// * `Result<Vec<ClientCommand>, String>` - One command per option, or the first parsing error
+fn read_task_commands() -> Result<Vec<ClientCommand>, String> {
+    let mut commands = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--task" {
+            let spec = args.next().unwrap_or_default();
+            commands.push(parse_task_command(&spec).ok_or_else(|| format!("tâche invalide: '{}'", spec))?);
+        } else if arg == "--brain" {
+            let spec = args.next().unwrap_or_default();
+            commands.push(parse_brain_command(&spec).ok_or_else(|| format!("stratégie invalide: '{}' (default ou sweep)", spec))?);
+        }
+    }
+    Ok(commands)
+}
+
+/// Parses `<robot_id>:<brain>` into a `SetBrain` command
+fn parse_brain_command(spec: &str) -> Option<ClientCommand> {
+    let (robot_id, brain) = spec.split_once(':')?;
+    Some(ClientCommand::SetBrain { robot_id: robot_id.trim().parse().ok()?, brain: BrainKind::from_name(brain)? })
+}
+
+/// Parses `<robot_id>:<task>` into a `DispatchTask` command
+fn parse_task_command(spec: &str) -> Option<ClientCommand> {
+    let (robot_id, task) = spec.split_once(':')?;
+    let (kind, params) = task.split_once(':').unwrap_or((task, ""));
+    let numbers: Vec<usize> = params.split(',')
+        .filter(|param| !param.is_empty())
+        .map(|param| param.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    
+    let task = match (kind, numbers.as_slice()) {
+        ("move", &[x, y]) => RobotTask::MoveTo(x, y),
+        ("collect", &[x, y]) => RobotTask::CollectAt(x, y),
+        ("survey", &[x, y, radius]) => RobotTask::Survey { center: (x, y), radius },
+        ("home", &[]) => RobotTask::ReturnHome,
+        _ => return None,
+    };
+    Some(ClientCommand::DispatchTask { robot_id: robot_id.parse().ok()?, task })
+}
+
+/// Formats a wall-clock duration as `MM:SS` (or `HH:MM:SS` past one hour)
+/// 
+/// # Parameters
+/// * `secs` - Elapsed seconds reported by the simulation server
+fn format_elapsed(secs: f64) -> String {
+    let total = secs.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// Robot types in display order, with their plural label
+const FLEET_LABELS: [(RobotType, &str); 6] = [
+    (RobotType::Explorer, "Explorateurs"),
+    (RobotType::EnergyCollector, "Énergie"),
+    (RobotType::MineralCollector, "Minerais"),
+    (RobotType::ScientificCollector, "Science"),
+    (RobotType::Repairer, "Réparateurs"),
+    (RobotType::Relay, "Relais"),
+];
+
+/// Label of a robot type in the robot list, e.g. `🔍 Explorateur`
+fn robot_type_label(robot_type: RobotType) -> &'static str {
+    match robot_type {
+        RobotType::Explorer => "🔍 Explorateur",
+        RobotType::EnergyCollector => "⚡ Énergie",
+        RobotType::MineralCollector => "🔨 Minerais",
+        RobotType::ScientificCollector => "🧪 Science",
+        RobotType::Repairer => "🔧 Réparateur",
+        RobotType::Relay => "📡 Relais",
+    }
+}
+
+/// Describes the robot the station builds next, e.g. `🔍 Explorateur (manque 5 minerais)`
+/// 
+/// # Parameters
+/// * `station` - Station snapshot, with its reserves and recommendation
+fn next_robot_summary(station: &StationData) -> String {
+    let Some(robot_type) = station.next_robot else {
+        return "aucun".to_string();
+    };
+    
+    let missing: Vec<String> = [
+        (station.next_robot_cost.energy.saturating_sub(station.energy_reserves), "énergie"),
+        (station.next_robot_cost.minerals.saturating_sub(station.collected_minerals), "minerais"),
+    ]
+        .into_iter()
+        .filter(|&(amount, _)| amount > 0)
+        .map(|(amount, resource)| format!("{} {}", amount, resource))
+        .collect();
+    
+    if missing.is_empty() {
+        robot_type_label(robot_type).to_string()
+    } else {
+        format!("{} (manque {})", robot_type_label(robot_type), missing.join(", "))
+    }
+}
+
+/// Describes the station's upgrades and storage, e.g. `baies de recharge supplémentaires | Stockage: 340/1500`
+/// 
+/// # Parameters
+/// * `station` - Station snapshot, with its upgrades and energy capacity (0 from older servers)
+fn station_upgrades_summary(station: &StationData) -> String {
+    let upgrades = if station.upgrades.is_empty() {
+        "aucune".to_string()
+    } else {
+        station.upgrades.iter().map(|upgrade| upgrade.to_string()).collect::<Vec<_>>().join(", ")
+    };
+    
+    if station.energy_capacity == 0 {
+        upgrades
+    } else {
+        format!("{} | Stockage: {}/{}", upgrades, station.energy_reserves, station.energy_capacity)
+    }
+}
+
+/// Describes the fleet composition, e.g. `3 Explorateurs, 2 Énergie, 1 Minerais`
+/// 
+/// # Parameters
+/// * `counts` - Robots per type, as returned by `count_by_type`
+fn fleet_summary(counts: &HashMap<RobotType, usize>) -> String {
+    FLEET_LABELS.iter()
+        .filter_map(|(robot_type, label)| counts.get(robot_type).map(|count| format!("{} {}", count, label)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Main asynchronous entry point for the Earth control center application
 /// 
 /// This function establishes a TCP connection to the simulation server,
@@ -102,6 +347,14 @@ const LEGEND_Y: u16 = LOGS_Y + 12;
 /// * JSON deserialization errors from corrupted data
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let port = read_port()?;
+    let token = read_auth_token()?;
+    let format = read_wire_format()?;
+    let gzip = read_gzip_flag()?;
+    let lite = read_lite_flag();
+    let mut task_commands = read_task_commands()?;
+    let transitions_robot = read_transitions_robot()?;
+    
     // NOTE - Enable raw terminal mode for UI
     enable_raw_mode()?;
     
@@ -110,7 +363,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     stdout.execute(Clear(ClearType::All))?;
     
     // NOTE - Connect to simulation server
-    let stream = match TcpStream::connect(format!("127.0.0.1:{}", DEFAULT_PORT)).await {
+    let mut stream = match TcpStream::connect(format!("127.0.0.1:{}", port)).await {
         Ok(stream) => stream,
         Err(e) => {
             disable_raw_mode()?;
@@ -121,33 +374,68 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
     
-    // NOTE - Create buffered reader for incoming data
-    let mut reader = BufReader::new(stream);
-    let mut line = String::new();
+    // NOTE - Negotiate the wire format, then authenticate if a shared secret is configured
+    stream.write_all(&encode_frame(&NetworkMessage::Hello { format, gzip, lite })?).await?;
+    if let Some(token) = token {
+        stream.write_all(&encode_frame(&NetworkMessage::Auth { token })?).await?;
+    }
+    
+    // NOTE - Create frame decoder for incoming data, and the keyframes of lite robot updates
+    let mut decoder = FrameDecoder::new();
+    let mut lite_decoder = LiteStateDecoder::new();
     let mut display_state = DisplayState::new();
+    display_state.transitions_robot = transitions_robot;
     
     // NOTE - Add initial connection logs
     display_state.add_log("🌍 Connexion établie avec la station EREEA".to_string());
     display_state.add_log("📡 Réception des données de simulation...".to_string());
+    display_state.add_log("⌨️ Touche h : rappel général de la flotte / reprise des opérations".to_string());
     
     // NOTE - Main event loop: receive and process simulation data
     loop {
-        line.clear();
-        
-        // NOTE - Read a line of data from the simulation server
-        if let Err(_) = reader.read_line(&mut line).await {
-            display_state.add_log("❌ Connexion perdue avec la station".to_string());
-            break;
-        }
-        
-        if line.is_empty() {
-            display_state.add_log("📡 Fin de transmission".to_string());
-            break;
-        }
+        // NOTE - Read the next frame from the simulation server
+        let frame = match read_frame(&mut stream, &mut decoder).await {
+            Ok(Some(frame)) => frame,
+            Ok(None) => {
+                display_state.add_log("📡 Fin de transmission".to_string());
+                break;
+            },
+            Err(_) => {
+                display_state.add_log("❌ Connexion perdue avec la station".to_string());
+                break;
+            }
+        };
         
-        // NOTE - Deserialize JSON data into SimulationState
-        let state: SimulationState = match serde_json::from_str(&line) {
-            Ok(state) => state,
+        // NOTE - Extract the SimulationState from the decoded frame
+        let state: SimulationState = match frame {
+            Ok(NetworkMessage::State(mut state)) => {
+                lite_decoder.restore(&mut state);
+                *state
+            },
+            Ok(NetworkMessage::AuthFailed { error }) => {
+                disable_raw_mode()?;
+                eprintln!("❌ Authentification refusée par la station: {}", error);
+                eprintln!("💡 Fournissez le secret avec --token <secret> ou {}", TOKEN_ENV_VAR);
+                return Err(error.into());
+            },
+            Ok(NetworkMessage::Welcome { format, gzip, lite }) => {
+                // NOTE - Every following frame from the station uses the negotiated format and compression
+                decoder.set_format(format);
+                decoder.set_compressed(gzip);
+                display_state.add_log(format!("🔗 Format de transmission: {:?}{}{}", format,
+                                              if gzip { " (gzip)" } else { "" }, if lite { " (allégé)" } else { "" }));
+                // NOTE - Handshake over: send the tasks given on the command line
+                for command in task_commands.drain(..) {
+                    stream.write_all(&encode_frame(&NetworkMessage::Command(command))?).await?;
+                }
+                continue;
+            },
+            Ok(NetworkMessage::Ping { sent_at_us }) => {
+                // NOTE - Echo heartbeat so the station can measure latency
+                stream.write_all(&encode_frame(&NetworkMessage::Pong { sent_at_us })?).await?;
+                continue;
+            },
+            Ok(_) => continue,
             Err(_) => {
                 display_state.add_log("⚠️ Données corrompues reçues".to_string());
                 continue;
@@ -164,23 +452,72 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         
         // NOTE - Dynamic log generation based on simulation progress
-        if state.iteration % 50 == 0 {
+        if state.iteration.is_multiple_of(50) {
+            // Mêmes seuils et objectifs que ceux appliqués côté serveur
             let exploration_pct = state.station_data.exploration_percentage;
-            if exploration_pct < 30.0 {
-                display_state.add_log(format!("🔍 Exploration initiale: {:.1}% - Collecteurs en attente", exploration_pct));
-            } else if exploration_pct < 60.0 {
-                display_state.add_log(format!("⚡ Collecte d'énergie/minerais: {:.1}%", exploration_pct));
-            } else if exploration_pct < 100.0 {
-                display_state.add_log(format!("🧪 Collecte scientifique: {:.1}%", exploration_pct));
+            match state.station_data.objectives.phase(&state.station_data.collector_policy, exploration_pct) {
+                MissionPhase::InitialExploration => {
+                    display_state.add_log(format!("🔍 Exploration initiale: {:.1}% - Collecteurs en attente", exploration_pct));
+                },
+                MissionPhase::ResourceCollection => {
+                    display_state.add_log(format!("⚡ Collecte d'énergie/minerais: {:.1}%", exploration_pct));
+                },
+                MissionPhase::ScientificCollection => {
+                    display_state.add_log(format!("🧪 Collecte scientifique: {:.1}%", exploration_pct));
+                },
+                MissionPhase::Finalization => {
+                    display_state.add_log(format!("🏁 Objectif d'exploration atteint ({:.1}%) - Finalisation en cours", exploration_pct));
+                },
+            }
+        }
+        
+        // NOTE - Log when the life support brings the reserves below the cost of a robot, and when they recover
+        if state.station_data.energy_low != display_state.energy_low {
+            display_state.energy_low = state.station_data.energy_low;
+            display_state.add_log(if state.station_data.energy_low {
+                format!("⚠️ Réserves d'énergie basses ({} unités) - Plus de construction de robot", state.station_data.energy_reserves)
             } else {
-                display_state.add_log("🏁 Exploration terminée - Finalisation en cours".to_string());
+                format!("🔋 Réserves d'énergie rétablies ({} unités)", state.station_data.energy_reserves)
+            });
+        }
+        
+        // NOTE - Log new robot deployments with the actual fleet composition
+        let fleet_size = state.robots_data.len();
+        if display_state.fleet_size.is_some_and(|previous| fleet_size > previous) {
+            display_state.add_log(format!("🤖 Nouveau robot déployé - Flotte: {}",
+                                        fleet_summary(&count_by_type(&state.robots_data))));
+        }
+        display_state.fleet_size = Some(fleet_size);
+        
+        // NOTE - Log breakdowns and repairs
+        let broken_robots: HashSet<usize> = state.robots_data.iter()
+            .filter(|robot| robot.mode == RobotMode::Broken)
+            .map(|robot| robot.id)
+            .collect();
+        for robot in state.robots_data.iter().filter(|robot| broken_robots.contains(&robot.id)) {
+            if !display_state.broken_robots.contains(&robot.id) {
+                display_state.add_log(format!("💥 Robot #{} en panne en ({}, {})", robot.id, robot.x, robot.y));
             }
         }
+        for id in display_state.broken_robots.difference(&broken_robots).copied().collect::<Vec<_>>() {
+            display_state.add_log(format!("✅ Robot #{} réparé", id));
+        }
+        display_state.broken_robots = broken_robots;
         
-        // NOTE - Log new robot deployments
-        if state.robots_data.len() > 4 && state.iteration % 50 == 1 {
-            display_state.add_log(format!("🤖 Nouveau robot déployé - Flotte: {} robots", 
-                                        state.robots_data.len()));
+        // NOTE - Log the mission milestones reported since the previous state
+        for mission_event in &state.events {
+            if display_state.last_event_cycle.is_some_and(|cycle| mission_event.cycle <= cycle) {
+                continue;
+            }
+            if matches!(mission_event.event, RobotEvent::Stranded { .. } | RobotEvent::ExplorationComplete { .. } | RobotEvent::Decommissioned { .. }
+                | RobotEvent::CutOff { .. } | RobotEvent::Rescued { .. } | RobotEvent::Repatriated { .. }
+                | RobotEvent::RecallStarted | RobotEvent::FleetDocked { .. } | RobotEvent::OperationsResumed
+                | RobotEvent::StationUpgraded { .. }) {
+                display_state.add_log(mission_event.event.to_string());
+            }
+        }
+        if let Some(latest) = state.events.last() {
+            display_state.last_event_cycle = Some(latest.cycle);
         }
         
         // NOTE - Mission progress warnings
@@ -188,6 +525,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             display_state.add_log("🎯 Mission proche de l'achèvement!".to_string());
         }
         
+        // NOTE - Keys pressed since the previous state: `h` recalls the fleet, or resumes operations during a recall
+        while event::poll(std::time::Duration::ZERO)? {
+            if let Event::Key(KeyEvent { code: KeyCode::Char('h'), kind: KeyEventKind::Press, .. }) = event::read()? {
+                let command = if state.station_data.recall_active { ClientCommand::Resume } else { ClientCommand::RecallAll };
+                stream.write_all(&encode_frame(&NetworkMessage::Command(command))?).await?;
+                display_state.add_log(if state.station_data.recall_active {
+                    "📡 Demande de reprise des opérations envoyée".to_string()
+                } else {
+                    "📡 Demande de rappel général envoyée".to_string()
+                });
+            }
+        }
+        
         // NOTE - Render the complete interface
         render_interface(&state, &mut display_state)?;
     }
@@ -214,7 +564,7 @@ fn render_interface(state: &SimulationState, display_state: &mut DisplayState) -
     
     // NOTE - Initialize static layout (only once)
     if !display_state.initialized {
-        initialize_fixed_layout(&mut stdout)?;
+        initialize_fixed_layout(&mut stdout, display_state.coverage_view)?;
         display_state.initialized = true;
     }
     
@@ -232,10 +582,11 @@ fn render_interface(state: &SimulationState, display_state: &mut DisplayState) -
 /// 
 /// # Parameters
 /// * `stdout` - Mutable reference to stdout for direct terminal writing
+/// * `coverage_view` - Whether the map legend describes the coverage heatmap
 /// 
 /// # Returns
 /// * `Result<(), Box<dyn std::error::Error>>` - Success or terminal manipulation error
-fn initialize_fixed_layout(stdout: &mut std::io::Stdout) -> Result<(), Box<dyn std::error::Error>> {
+fn initialize_fixed_layout(stdout: &mut std::io::Stdout, coverage_view: bool) -> Result<(), Box<dyn std::error::Error>> {
     // NOTE - Draw header section
     stdout.execute(MoveTo(0, HEADER_Y))?;
     stdout.execute(SetForegroundColor(Color::Cyan))?;
@@ -254,8 +605,8 @@ fn initialize_fixed_layout(stdout: &mut std::io::Stdout) -> Result<(), Box<dyn s
     stdout.execute(SetForegroundColor(Color::Yellow))?;
     print!("🗺️  CARTE DE L'EXOPLANÈTE");
     
-    // Calculate map display width (each tile takes 2 characters)
-    let map_width = MAP_SIZE as u16 * 2;
+    // Calculate map display width (each tile takes TILE_WIDTH columns)
+    let map_width = (MAP_SIZE * TILE_WIDTH) as u16;
     
     // Top border of map container
     stdout.execute(MoveTo(MAP_LEFT, MAP_START_Y + 1))?;
@@ -331,7 +682,9 @@ fn initialize_fixed_layout(stdout: &mut std::io::Stdout) -> Result<(), Box<dyn s
     stdout.execute(SetForegroundColor(Color::AnsiValue(10)))?;
     print!("🔋 = Énergie     ");       // Energy collector robot
     stdout.execute(SetForegroundColor(Color::AnsiValue(13)))?;
-    print!("⛏️ = Minerais");           // Mineral collector robot
+    print!("🔨 = Minerais     ");      // Mineral collector robot
+    stdout.execute(SetForegroundColor(Color::AnsiValue(14)))?;
+    print!("🔧 = Réparateur");         // Repairer robot
     
     // LEGEND CONTENT: Additional symbols (line 2)
     stdout.execute(MoveTo(0, LEGEND_Y + 4))?;
@@ -344,16 +697,50 @@ fn initialize_fixed_layout(stdout: &mut std::io::Stdout) -> Result<(), Box<dyn s
     stdout.execute(SetForegroundColor(Color::Blue))?;
     print!("🔬 = Science     ");       // Scientific resource tile
     stdout.execute(SetForegroundColor(Color::DarkGrey))?;
-    print!("❓ = Inexploré");          // Unexplored tile
+    print!("❓ = Inexploré     ");     // Unexplored tile
+    stdout.execute(SetForegroundColor(Color::White))?;
+    stdout.execute(SetAttribute(Attribute::Underlined))?;
+    print!("⭐");
+    stdout.execute(SetAttribute(Attribute::NoUnderline))?;
+    print!(" = Gisement riche");          // Deposit holding several units
     
     // USER INSTRUCTIONS: Exit command
     stdout.execute(MoveTo(0, LEGEND_Y + 5))?;
     stdout.execute(SetForegroundColor(Color::Red))?;
-    print!("🚨 Ctrl+C pour quitter la mission");
+    print!("🚨 Ctrl+C pour quitter la mission     ");
+    stdout.execute(SetForegroundColor(Color::DarkGrey))?;
+    if coverage_view {
+        print!("Vue couverture: tuiles teintées par le type de robot qui les a explorées");
+    } else {
+        print!("Couleurs atténuées = zone non revue depuis {} cycles", STALE_AFTER_CYCLES);
+    }
+    
+    // LEGEND CONTENT: Biome backgrounds (line 4)
+    stdout.execute(MoveTo(0, LEGEND_Y + 6))?;
+    stdout.execute(SetForegroundColor(Color::White))?;
+    print!("Fond des régions : ");
+    for (biome, label) in [(Biome::EnergyRich, "énergie"), (Biome::MineralRich, "minerais"), (Biome::ScientificRich, "science")] {
+        stdout.execute(SetBackgroundColor(biome_color(biome)))?;
+        print!(" {} ", label);
+        stdout.execute(SetBackgroundColor(Color::Reset))?;
+        print!("  ");
+    }
     
     Ok(())
 }
 
+/// Background of the tiles robots are heading for (see `RobotData::current_goal`)
+const GOAL_BACKGROUND: Color = Color::AnsiValue(238);
+
+/// Dark background color of a biome, so that tile glyphs stay readable on top
+fn biome_color(biome: Biome) -> Color {
+    match biome {
+        Biome::EnergyRich => Color::AnsiValue(22),
+        Biome::MineralRich => Color::AnsiValue(53),
+        Biome::ScientificRich => Color::AnsiValue(17),
+    }
+}
+
 /// Updates all dynamic content in the interface (data that changes each frame)
 /// 
 /// This function refreshes all variable information including:
@@ -374,8 +761,11 @@ fn update_all_dynamic_content(state: &SimulationState, display_state: &mut Displ
     // NOTE - Update status bar
     stdout.execute(MoveTo(0, STATUS_Y))?;
     stdout.execute(SetForegroundColor(Color::White))?;
-    print!("📊 Cycle: {:>4} | 🌍 Exploration: {:>5.1}% | 🤖 Robots: {:>2} | 🔋 Énergie: {:>3} | ⛏️  Minerais: {:>3} | 🧪 Science: {:>3}        ",
+    let night = state.station_data.day_phase == DayPhase::Night;
+    print!("📊 Cycle: {:>4} ({}) {} | 🌍 Exploration: {:>5.1}% | 🤖 Robots: {:>2} | 🔋 Énergie: {:>3} | ⛏️  Minerais: {:>3} | 🧪 Science: {:>3}        ",
            state.iteration,
+           format_elapsed(state.elapsed_secs),
+           if night { "🌙" } else { "☀️" },
            state.station_data.exploration_percentage,
            state.station_data.robot_count,
            state.station_data.energy_reserves,
@@ -385,58 +775,60 @@ fn update_all_dynamic_content(state: &SimulationState, display_state: &mut Displ
     // NOTE - Redraw entire exploration map
     for y in 0..MAP_SIZE {
         for x in 0..MAP_SIZE {
-            stdout.execute(MoveTo(MAP_LEFT + 1 + (x as u16 * 2), MAP_START_Y + 2 + y as u16))?;
+            stdout.execute(MoveTo(MAP_LEFT + 1 + (x * TILE_WIDTH) as u16, MAP_START_Y + 2 + y as u16))?;
             let robot_here = state.robots_data.iter().find(|r| r.x == x && r.y == y);
-            if x == state.map_data.station_x && y == state.map_data.station_y {
+            let station_here = (x, y) == (state.map_data.station_x, state.map_data.station_y)
+                || state.map_data.extra_stations.contains(&(x, y));
+            if station_here {
                 // NOTE - Draw station
                 stdout.execute(SetForegroundColor(Color::Yellow))?;
-                print!("🏠");
+                print!("{}", tile_glyph("🏠"));
             }
             else if let Some(robot) = robot_here {
                 // NOTE - Draw robot
-                let robot_color = match robot.robot_type {
-                    RobotType::Explorer => Color::AnsiValue(9),
-                    RobotType::EnergyCollector => Color::AnsiValue(10),
-                    RobotType::MineralCollector => Color::AnsiValue(13),
-                    RobotType::ScientificCollector => Color::AnsiValue(12),
-                };
-                stdout.execute(SetForegroundColor(robot_color))?;
-                let display_char = match robot.robot_type {
-                    RobotType::Explorer => "🤖",
-                    RobotType::EnergyCollector => "🔋",
-                    RobotType::MineralCollector => "⛏️",
-                    RobotType::ScientificCollector => "🧪",
-                };
-                print!("{}", display_char);
+                stdout.execute(SetForegroundColor(Color::AnsiValue(robot_color(robot.robot_type))))?;
+                print!("{}", tile_glyph(robot_glyph(robot.robot_type)));
             }
             else {
                 // NOTE - Draw terrain/resource or unexplored
                 if !state.exploration_data.explored_tiles[y][x] {
                     stdout.execute(SetForegroundColor(Color::DarkGrey))?;
-                    print!("❓");
+                    print!("{}", tile_glyph("❓"));
                 } else {
-                    match &state.map_data.tiles[y][x] {
-                        TileType::Empty => {
-                            stdout.execute(SetForegroundColor(Color::DarkGrey))?;
-                            print!("·");
-                        },
-                        TileType::Obstacle => {
-                            stdout.execute(SetForegroundColor(Color::DarkGrey))?;
-                            print!("🧱");
-                        },
-                        TileType::Energy => {
-                            stdout.execute(SetForegroundColor(Color::Green))?;
-                            print!("💎");
-                        },
-                        TileType::Mineral => {
-                            stdout.execute(SetForegroundColor(Color::Magenta))?;
-                            print!("⭐");
-                        },
-                        TileType::Scientific => {
-                            stdout.execute(SetForegroundColor(Color::Blue))?;
-                            print!("🔬");
-                        },
+                    // NOTE - Dim tiles not confirmed for a long time (may need re-scouting)
+                    let stale = state.exploration_data.is_stale(x, y, state.station_data.current_time);
+                    let (fresh_color, stale_color, glyph) = match &state.map_data.tiles[y][x] {
+                        TileType::Empty => (Color::Grey, Color::DarkGrey, "·"),
+                        TileType::Obstacle => (Color::Grey, Color::DarkGrey, "🧱"),
+                        TileType::Energy => (Color::Green, Color::DarkGreen, "💎"),
+                        TileType::Mineral => (Color::Magenta, Color::DarkMagenta, "⭐"),
+                        TileType::Scientific => (Color::Blue, Color::DarkBlue, "🔬"),
+                    };
+                    // NOTE - Coverage view: tint by the robot type that mapped the tile instead
+                    // (at night, the whole map is drawn in the dim colors)
+                    let color = match state.exploration_data.explored_by[y][x] {
+                        Some(robot_type) if display_state.coverage_view => Color::AnsiValue(robot_color(robot_type)),
+                        _ if stale || night => stale_color,
+                        _ => fresh_color,
+                    };
+                    stdout.execute(SetForegroundColor(color))?;
+                    // NOTE - Background tinted by the biome of the tile (absent from older servers),
+                    // grey where a robot is heading
+                    let biome = state.map_data.biome.get(y).and_then(|row| row.get(x)).copied();
+                    let goal_here = state.robots_data.iter().any(|robot| robot.current_goal == Some((x, y)));
+                    let background = if goal_here { GOAL_BACKGROUND } else { biome.map_or(Color::Reset, biome_color) };
+                    stdout.execute(SetBackgroundColor(background))?;
+                    // NOTE - Underline deposits that can still be harvested several times
+                    let amount = state.map_data.resource_amounts.get(y).and_then(|row| row.get(x)).copied().unwrap_or(0);
+                    if amount > 1 {
+                        stdout.execute(SetAttribute(Attribute::Underlined))?;
+                        print!("{}", glyph);
+                        stdout.execute(SetAttribute(Attribute::NoUnderline))?;
+                        print!("{}", &tile_glyph(glyph)[glyph.len()..]);
+                    } else {
+                        print!("{}", tile_glyph(glyph));
                     }
+                    stdout.execute(SetBackgroundColor(Color::Reset))?;
                 }
             }
         }
@@ -445,51 +837,108 @@ fn update_all_dynamic_content(state: &SimulationState, display_state: &mut Displ
     // NOTE - Update station information
     stdout.execute(MoveTo(0, STATION_INFO_Y + 3))?;
     stdout.execute(SetForegroundColor(Color::White))?;
-    print!("📊 🔋 Énergie: {:>3} | ⛏️  Minerais: {:>3} | 🧪 Science: {:>3} | ⚔️  Conflits: {:>3}                          ",
+    print!("📊 🔋 Énergie: {:>3} | ⛏️  Minerais: {:>3} | 🧪 Science: {:>3} | ⚔️  Conflits: {:>3} | 🏭 Prochain: {:<40}",
            state.station_data.energy_reserves,
            state.station_data.collected_minerals,
            state.station_data.collected_scientific_data,
-           state.station_data.conflict_count);
+           state.station_data.conflict_count,
+           next_robot_summary(&state.station_data));
+    stdout.execute(MoveTo(0, STATION_INFO_Y + 4))?;
+    print!("🏗️  Améliorations: {:<70}", station_upgrades_summary(&state.station_data));
     
     // NOTE - Update robot status (up to 5 robots)
     for i in 0..5 {
         stdout.execute(MoveTo(0, ROBOTS_INFO_Y + 3 + i as u16))?;
         if i < state.robots_data.len() {
             let robot = &state.robots_data[i];
-            let robot_color = match robot.robot_type {
-                RobotType::Explorer => Color::AnsiValue(9),
-                RobotType::EnergyCollector => Color::AnsiValue(10),
-                RobotType::MineralCollector => Color::AnsiValue(13),
-                RobotType::ScientificCollector => Color::AnsiValue(12),
-            };
-            stdout.execute(SetForegroundColor(robot_color))?;
-            let robot_type_str = match robot.robot_type {
-                RobotType::Explorer => "🔍 Explorateur",
-                RobotType::EnergyCollector => "⚡ Énergie",
-                RobotType::MineralCollector => "⛏️  Minerais",
-                RobotType::ScientificCollector => "🧪 Science",
-            };
+            stdout.execute(SetForegroundColor(Color::AnsiValue(robot_color(robot.robot_type))))?;
+            let robot_type_str = robot_type_label(robot.robot_type);
             let mode_str = match robot.mode {
                 RobotMode::Exploring => "🚶 Exploration",
                 RobotMode::Collecting => "📦 Collecte",
                 RobotMode::ReturnToStation => "🏠 Retour",
                 RobotMode::Idle => "😴 Repos",
+                RobotMode::Stranded => "🆘 Détresse",
+                RobotMode::Rescuing => "🚑 Secours",
+                RobotMode::Broken => "💥 Panne",
+                RobotMode::Charging => "🔌 Charge",
+                RobotMode::Scanning => "🔭 Balayage",
+            };
+            // Étape en cours dans le mode (analyse d'un site scientifique)
+            let mode_str = match &robot.mode_detail {
+                Some(detail) => format!("🔬 {}", detail),
+                None => mode_str.to_string(),
+            };
+            // Chargement rapporté à la capacité de la soute ("Min: 3/5")
+            let cargo_str = match robot.robot_type {
+                RobotType::EnergyCollector => format!("Éner: {}/{}", robot.inventory.energy, robot.cargo_capacity),
+                RobotType::MineralCollector => format!("Min: {}/{}", robot.inventory.minerals, robot.cargo_capacity),
+                RobotType::ScientificCollector => format!("Sci: {}/{}", robot.inventory.science, robot.cargo_capacity),
+                _ => "-".to_string(),
             };
-            print!("Robot #{:>2}: {:<12} | 📍({:>2},{:>2}) | 🔋{:>5.1}/{:<5.1} | {} | Min:{:>2} Sci:{:>2} | 📊{:>5.1}%            ",
+            // Un cycle sur place est normal (collecte, attente derrière un autre robot), pas deux
+            let mut stuck_str = if robot.stuck_ticks >= 2 {
+                " ⚠️ bloqué"
+            } else if robot.relay_linked && robot.robot_type != RobotType::Relay {
+                " 📡 relié"
+            } else {
+                ""
+            }.to_string();
+            // Cibles abandonnées depuis le déploiement (blocage, oscillation, aucun progrès)
+            if robot.stuck_events > 0 {
+                stuck_str.push_str(&format!(" ↺{}", robot.stuck_events));
+            }
+            // Tâche assignée depuis la Terre ou la station
+            if let Some(task) = &robot.current_task {
+                stuck_str.push_str(&format!(" 📋 {}", task));
+            } else if let Some((x, y)) = robot.current_goal {
+                // Destination du chemin planifié (la tâche la donne déjà)
+                stuck_str.push_str(&format!(" 🎯({},{})", x, y));
+            }
+            // Stratégie d'exploration, signalée seulement quand ce n'est pas celle par défaut
+            if !robot.brain.is_empty() && robot.brain != "default" {
+                stuck_str.push_str(&format!(" 🧠 {}", robot.brain));
+            }
+            // Modules installés par la station
+            let modules_str: String = robot.upgrades.iter()
+                .map(|upgrade| match upgrade {
+                    Upgrade::VisionModule => "👁",
+                    Upgrade::BatteryModule => "🔋",
+                    Upgrade::EfficiencyModule => "⚙",
+                    Upgrade::PropulsionModule => "🚀",
+                })
+                .collect();
+            print!("Robot #{:>2}: {:<12} {:<2} | 📍({:>2},{:>2}) | 🔋{:>5.1}/{:<5.1} | {} | {:<11} | 📊{:>5.1}% | Dépensé:{:>6.1} Dist:{:>5.0}{:<10}            ",
                    robot.id,
                    robot_type_str,
+                   modules_str,
                    robot.x, robot.y,
                    robot.energy, robot.max_energy,
                    mode_str,
-                   robot.minerals,
-                   robot.scientific_data,
-                   robot.exploration_percentage);
+                   cargo_str,
+                   robot.exploration_percentage,
+                   robot.total_energy_spent,
+                   robot.distance_traveled,
+                   stuck_str);
         } else {
             stdout.execute(SetForegroundColor(Color::White))?;
             print!("{:<90}", "");
         }
     }
     
+    // NOTE - Latest mode changes of the robot followed with --transitions, newest first
+    if let Some(robot_id) = display_state.transitions_robot {
+        stdout.execute(MoveTo(0, LEGEND_Y + 7))?;
+        stdout.execute(SetForegroundColor(Color::DarkGrey))?;
+        let transitions = match state.robots_data.iter().find(|robot| robot.id == robot_id) {
+            Some(robot) if robot.recent_transitions.is_empty() => "aucun changement de mode".to_string(),
+            Some(robot) => robot.recent_transitions.iter().rev().cloned().collect::<Vec<_>>().join(" ← "),
+            None => "robot absent".to_string(),
+        };
+        let row = format!("🔀 Robot #{} : {}", robot_id, transitions);
+        print!("{:<160}", row.chars().take(160).collect::<String>());
+    }
+    
     // NOTE - Update mission log messages
     for (i, log_line) in display_state.log_messages.iter().enumerate() {
         stdout.execute(MoveTo(0, LOGS_Y + 3 + i as u16))?;
@@ -527,19 +976,49 @@ fn show_victory_screen(state: &SimulationState) -> Result<(), Box<dyn std::error
     // NOTE - Render main victory message box
     let center_x = 8;
     let center_y = 2;
-    let message_lines = vec![
-        "╔════════════════════════════════════════════════════════════════════════╗",
-        "║                                                                        ║",
-        "║         🎉🚀 MISSION EREEA ACCOMPLIE AVEC SUCCÈS! 🚀🎉              ║",
-        "║                                                                        ║",
-        "║              🌍 EXOPLANÈTE ENTIÈREMENT EXPLORÉE 🌍                   ║",
-        "║                                                                        ║",
-        "║                     ✅ OBJECTIFS ATTEINTS ✅                         ║",
-        "║                                                                        ║",
-        "║               🔍 Exploration complète: 100%                           ║",
-        "║               💎 Toutes les ressources collectées                     ║",
-        "║               🤖 Tous les robots rapatriés                            ║",
-        "║               🏠 Retour sécurisé à la station                         ║",
+    // NOTE - One line per configured objective
+    let objectives = &state.station_data.objectives;
+    let mut goals = Vec::new();
+    // Les serveurs antérieurs au masque d'accessibilité n'envoient pas le nombre de cases accessibles
+    let reachable_tiles = state.map_data.reachable_tiles;
+    if objectives.min_exploration_pct >= 100.0 && reachable_tiles > 0 {
+        goals.push(("🔍", format!("Exploration complète: {} cases accessibles", reachable_tiles)));
+    } else if objectives.min_exploration_pct >= 100.0 {
+        goals.push(("🔍", "Exploration complète: 100%".to_string()));
+    } else {
+        goals.push(("🔍", format!("Exploration: {:.1}% (objectif {}%)",
+                                 state.station_data.exploration_percentage, objectives.min_exploration_pct)));
+    }
+    if objectives.min_minerals > 0 {
+        goals.push(("💎", format!("Au moins {} minerais rapportés", objectives.min_minerals)));
+    }
+    if objectives.min_scientific_data > 0 {
+        goals.push(("🧪", format!("Au moins {} données scientifiques", objectives.min_scientific_data)));
+    }
+    if objectives.collect_all_resources {
+        goals.push(("💎", "Toutes les ressources collectées".to_string()));
+    }
+    if objectives.require_all_home {
+        goals.push(("🤖", "Tous les robots rapatriés".to_string()));
+        goals.push(("🏠", "Retour sécurisé à la station".to_string()));
+    }
+    
+    let mut message_lines = vec![
+        "╔════════════════════════════════════════════════════════════════════════╗".to_string(),
+        "║                                                                        ║".to_string(),
+        "║         🎉🚀 MISSION EREEA ACCOMPLIE AVEC SUCCÈS! 🚀🎉              ║".to_string(),
+        "║                                                                        ║".to_string(),
+        if objectives.min_exploration_pct >= 100.0 {
+            "║              🌍 EXOPLANÈTE ENTIÈREMENT EXPLORÉE 🌍                   ║".to_string()
+        } else {
+            "║             🌍 EXOPLANÈTE PARTIELLEMENT EXPLORÉE 🌍                  ║".to_string()
+        },
+        "║                                                                        ║".to_string(),
+        "║                     ✅ OBJECTIFS ATTEINTS ✅                         ║".to_string(),
+        "║                                                                        ║".to_string(),
+    ];
+    message_lines.extend(goals.iter().map(|(icon, goal)| format!("║               {} {:<53}║", icon, goal)));
+    message_lines.extend([
         "║                                                                        ║",
         "║                        🏆 FÉLICITATIONS! 🏆                          ║",
         "║                                                                        ║",
@@ -551,7 +1030,7 @@ fn show_victory_screen(state: &SimulationState) -> Result<(), Box<dyn std::error
         "║                🚀 Fermeture automatique dans 10s...                   ║",
         "║                                                                        ║",
         "╚════════════════════════════════════════════════════════════════════════╝",
-    ];
+    ].map(str::to_string));
     for (i, line) in message_lines.iter().enumerate() {
         stdout.execute(MoveTo(center_x, center_y + i as u16))?;
         stdout.execute(SetForegroundColor(Color::Yellow))?;
@@ -564,55 +1043,59 @@ fn show_victory_screen(state: &SimulationState) -> Result<(), Box<dyn std::error
     stdout.execute(SetForegroundColor(Color::Cyan))?;
     print!("🎯 STATISTIQUES DE LA MISSION");
     
+    // NOTE - Figures come from the server's `MissionStats`
+    let stats = &state.station_data.statistics;
+    
     stdout.execute(MoveTo(center_x + 5, stats_y + 2))?;
     stdout.execute(SetForegroundColor(Color::Green))?;
-    print!("📊 Exoplanète cartographiée à {:.1}%", state.station_data.exploration_percentage);
+    print!("📊 Exoplanète cartographiée à {:.1}%", stats.exploration_percentage);
     
     stdout.execute(MoveTo(center_x + 5, stats_y + 3))?;
-    print!("💎 Minerais collectés: {}", state.station_data.collected_minerals);
+    print!("💎 Minerais collectés: {} | 🔋 Gisements d'énergie: {}", stats.collected_minerals, stats.collected_energy);
     
     stdout.execute(MoveTo(center_x + 5, stats_y + 4))?;
-    print!("🧪 Données scientifiques: {}", state.station_data.collected_scientific_data);
+    print!("🧪 Données scientifiques: {}", stats.collected_scientific_data);
+    // Ressources encore dans les soutes (objectifs sans retour de tous les robots)
+    let on_board: Inventory = state.robots_data.iter().map(|robot| robot.inventory).sum();
+    if !on_board.is_empty() {
+        print!(" | 📦 Encore à bord: {} énergie, {} minerais, {} données",
+               on_board.energy, on_board.minerals, on_board.science);
+    }
     
     stdout.execute(MoveTo(center_x + 5, stats_y + 5))?;
-    print!("🤖 Robots déployés: {}", state.robots_data.len());
+    print!("🤖 Robots déployés: {}", stats.robots_by_type.values().sum::<usize>());
     
     stdout.execute(MoveTo(center_x + 5, stats_y + 6))?;
-    print!("⚔️  Conflits résolus: {}", state.station_data.conflict_count);
+    print!("⚔️  Conflits résolus: {} | 🔄 Synchronisations: {}", stats.conflict_count, stats.peer_exchange_count);
     
     stdout.execute(MoveTo(center_x + 5, stats_y + 7))?;
-    print!("🕒 Cycles de simulation: {}", state.iteration);
+    print!("🕒 Cycles de simulation: {} en {}", stats.total_ticks, format_elapsed(state.elapsed_secs));
     
     // ROBOT TEAM RECOGNITION SECTION: Celebrate the robotic heroes
     stdout.execute(MoveTo(center_x + 5, stats_y + 9))?;
     stdout.execute(SetForegroundColor(Color::White))?;
     print!("🛠️  ÉQUIPE DE ROBOTS HÉROÏQUE:");
     
-    // Display robot type legend with colors
+    // Actual fleet composition, one color per robot type
+    let counts = count_by_type(&state.robots_data);
     stdout.execute(MoveTo(center_x + 8, stats_y + 10))?;
-    stdout.execute(SetForegroundColor(Color::AnsiValue(9)))?;
-    print!("🔍 Explorateurs   ");
-    stdout.execute(SetForegroundColor(Color::AnsiValue(10)))?;
-    print!("⚡ Collecteurs d'énergie   ");
-    stdout.execute(SetForegroundColor(Color::AnsiValue(13)))?;
-    print!("⛏️  Collecteurs de minerais");
+    for (robot_type, label) in FLEET_LABELS {
+        if let Some(count) = counts.get(&robot_type) {
+            stdout.execute(SetForegroundColor(Color::AnsiValue(robot_color(robot_type))))?;
+            print!("{} {} {}   ", robot_glyph(robot_type), count, label);
+        }
+    }
     
     stdout.execute(MoveTo(center_x + 8, stats_y + 11))?;
-    stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
-    print!("🧪 Collecteurs scientifiques ");
     stdout.execute(SetForegroundColor(Color::Yellow))?;
     print!("- Tous revenus sains et saufs!");
     
-    // ANIMATED ROBOT DISPLAY: Visual representation of the successful team
+    // ANIMATED ROBOT DISPLAY: one glyph per robot of the final fleet
     stdout.execute(MoveTo(center_x + 25, stats_y + 13))?;
-    stdout.execute(SetForegroundColor(Color::AnsiValue(9)))?;
-    print!("🤖 ");   // Explorer
-    stdout.execute(SetForegroundColor(Color::AnsiValue(10)))?;
-    print!("🔋 ");   // Energy collector
-    stdout.execute(SetForegroundColor(Color::AnsiValue(13)))?;
-    print!("⛏️  ");   // Mineral collector
-    stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
-    print!("🧪 ");   // Scientific collector
+    for (robot_type, _) in FLEET_LABELS {
+        stdout.execute(SetForegroundColor(Color::AnsiValue(robot_color(robot_type))))?;
+        print!("{}", format!("{} ", robot_glyph(robot_type)).repeat(counts.get(&robot_type).copied().unwrap_or(0)));
+    }
     stdout.execute(SetForegroundColor(Color::Yellow))?;
     print!("← NOS HÉROS!"); // Hero label
     
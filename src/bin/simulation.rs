@@ -1,17 +1,33 @@
 // Serveur de simulation EREEA
 // Exécute la logique de simulation et diffuse l'état via TCP aux clients connectés
 
-use ereea::types::{RobotType, RobotMode, MAP_SIZE, TileType};
-use ereea::map::Map;
-use ereea::robot::Robot;
-use ereea::station::Station;
-use ereea::network::{SimulationState, DEFAULT_PORT, create_simulation_state};
+use ereea::types::{BrainKind, RobotType, MAP_SIZE, TileType, CollectorPolicy, MissionObjectives, StationUpgrade, VisionModel};
+use ereea::map::{Map, MAX_STATIONS};
+use ereea::robot::RobotConfig;
+use ereea::station::{MalfunctionConfig, RobotCost, Station, StationConfig, DAY_NIGHT_PERIOD, DEPOT_MINERAL_COST, IDLE_RESCAN_TICKS, LIFE_SUPPORT_DRAIN, RETIRE_AFTER_IDLE_TICKS, UPGRADE_FLEET_SIZE};
+use ereea::knowledge::{ExplorationArea, PEER_SYNC_RADIUS, RADIO_LATENCY, RADIO_SYNC_INTERVAL, RELAY_RANGE};
+use ereea::simulation::{deploy_fleet, step_simulation, StepOutcome, INITIAL_FLEET, REGENERATION_INTERVAL};
+use ereea::network::{ClientCommand, LiteStateEncoder, NetworkMessage, DEFAULT_PORT, HEARTBEAT_INTERVAL_SECS, RECENT_EVENTS, create_lite_keyframe_state, create_simulation_state};
+use ereea::events::{MissionEvent, RobotEvent};
+use ereea::network::broadcast::StatePublisher;
+use ereea::network::auth::{AuthError, AUTH_TIMEOUT_SECS, verify_first_frame};
+#[cfg(feature = "websocket")]
+use ereea::network::auth::tokens_match;
+use ereea::network::codec::{self, compress_frame, encode_frame, encode_frame_as, read_frame, FrameDecoder, WireFormat, GZIP_SUPPORTED};
+use ereea::network::stats::{send_frame, ConnectionStats};
 
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
-use std::{thread, time::Duration};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{thread, time::{Duration, Instant}};
 use tokio::net::{TcpListener, TcpStream};
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::{mpsc, Mutex as TokioMutex};
+#[cfg(feature = "websocket")]
+use futures_util::{SinkExt, StreamExt};
+#[cfg(feature = "websocket")]
+use tokio_tungstenite::tungstenite::{Message, http::StatusCode, handshake::server::{Callback, ErrorResponse, Request, Response}};
 
 // Macro pour les logs du serveur (vers stderr)
 macro_rules! server_log {
@@ -20,15 +36,779 @@ macro_rules! server_log {
     };
 }
 
+// NOTE - Logs the events of a cycle and keeps the latest ones for the broadcast
+fn record_events(events: Vec<RobotEvent>, cycle: u32, recent: &mut VecDeque<MissionEvent>) {
+    for event in events {
+        // Les changements de mode sont trop fréquents pour le journal, seule la Terre les reçoit
+        if !matches!(event, RobotEvent::ModeChanged { .. }) {
+            server_log!("{}", event);
+        }
+        if recent.len() == RECENT_EVENTS {
+            recent.pop_front();
+        }
+        recent.push_back(MissionEvent { cycle, event });
+    }
+}
+
+// Configuration du serveur issue de la ligne de commande
+struct ServerConfig {
+    // NOTE - Shared secret required from clients (None = open access)
+    auth_token: Option<String>,
+    // NOTE - TCP port Earth clients connect to
+    port: u16,
+    // NOTE - Delay between two simulation cycles
+    tick: Duration,
+    // NOTE - Delay between two broadcasts to Earth (latest state only)
+    broadcast: Duration,
+    // NOTE - Port of the HTTP metrics endpoint (None = disabled)
+    metrics_port: Option<u16>,
+    // NOTE - Port of the WebSocket endpoint for browser monitors (None = disabled)
+    #[cfg(feature = "websocket")]
+    ws_port: Option<u16>,
+    // NOTE - Exploration thresholds gating each collector type
+    collector_policy: CollectorPolicy,
+    // NOTE - Endless mode: resources regenerate and the mission never ends
+    endless: bool,
+    // NOTE - Exploration-only scenario: explorers only, no resource to collect
+    explorers_only: bool,
+    // NOTE - Cycles after which the simulation stops, mission complete or not (None = no limit)
+    max_iterations: Option<u32>,
+    // NOTE - Random robot breakdowns (disabled unless a failure rate is given)
+    malfunctions: MalfunctionConfig,
+    // NOTE - Map seed, drives every random choice of the run (None = random)
+    seed: Option<u32>,
+    // NOTE - Number of stations on the map, the central one included
+    stations: usize,
+    // NOTE - Length of a day/night cycle in cycles (0 = always day)
+    day_night_period: u32,
+    // NOTE - Distance at which robots exchange their knowledge in the field (0 = disabled)
+    peer_sync_radius: usize,
+    // NOTE - Reach of one relay hop towards the station (0 = relays disabled)
+    relay_range: usize,
+    // NOTE - Distance to a station within which robots sync by radio (0 = no radio)
+    radio_range: usize,
+    // NOTE - Cycles data takes to cross the radio link
+    radio_latency: u32,
+    // NOTE - Cycles between two radio synchronizations
+    radio_sync_interval: u32,
+    // NOTE - Whether obstacles hide what lies behind them from robots
+    vision: VisionModel,
+    // NOTE - Idle cycles before a collector with nothing left to collect retires (0 = never)
+    retire_after: u32,
+    // NOTE - Cycles between two searches of a collector parked for lack of resources
+    idle_rescan: u32,
+    // NOTE - Whether the station hands each explorer a sector of its own
+    sector_planning: bool,
+    // NOTE - Whether collectors unload when their route passes next to the station
+    drop_off_in_passing: bool,
+    // NOTE - Whether the station spends minerals on forward depots near the deposits
+    build_depots: bool,
+    // NOTE - Whether a corridor is carved for robots cut off from their station
+    repair_access: bool,
+    // NOTE - Whether the station buys its own upgrades once the fleet is large
+    station_upgrades: bool,
+    // NOTE - Robots the mission starts with (None = one of each type, or five explorers with --explorers-only)
+    initial_fleet: Option<Vec<RobotType>>,
+    // NOTE - Robot configurations replacing the defaults of their type (initial fleet and station builds)
+    robot_configs: BTreeMap<RobotType, RobotConfig>,
+    // NOTE - Manufacturing costs replacing the default of their type
+    robot_costs: BTreeMap<RobotType, RobotCost>,
+    // NOTE - Economy of the station (default robot cost, starting energy, refining, reserve floor, storage)
+    economy: StationConfig,
+    // NOTE - Goals that end the mission
+    objectives: MissionObjectives,
+    // NOTE - Energy the station's life support burns per active robot and cycle
+    life_support: f32,
+    // NOTE - Whether explorers and collectors stay docked while the reserves are empty
+    blackout_grounding: bool,
+}
+
+impl ServerConfig {
+    // NOTE - Parse command line arguments:
+    // [--port <port>] [--auth-token <secret>] [--tick-ms <ms>] [--broadcast-ms <ms>] [--metrics-port <port>]
+    // [--collector-thresholds <énergie>,<minerais>,<science>] [--endless] [--explorers-only] [--max-iterations <cycles>]
+    // [--malfunction-rate <probabilité>] [--self-repair-ticks <cycles>] [--seed <graine>]
+    // [--stations <nombre>] [--day-length <cycles>] [--peer-sync-radius <cases>] [--relay-range <cases>]
+    // [--radio-range <cases>] [--radio-latency <cycles>] [--radio-interval <cycles>]
+    // [--vision square|line-of-sight] [--ws-port <port>] [--retire-after <cycles>] [--idle-rescan <cycles>]
+    // [--no-sectors] [--no-drop-off] [--build-depots] [--repair-access] [--station-upgrades] [--initial <type>:<nombre>,...]
+    // [--robot-config <type>:<champ>=<valeur>,...] (répétable) [--robot-cost <type>:<ressource>=<quantité>,...] (répétable)
+    // [--objectives <objectif>=<valeur>,...] [--economy <paramètre>=<valeur>,...]
+    // [--life-support <énergie>] [--blackout-grounding]
+    fn from_args() -> Result<Self, String> {
+        let mut config = Self {
+            auth_token: None,
+            port: DEFAULT_PORT,
+            tick: Duration::from_millis(300),
+            broadcast: Duration::from_millis(300),
+            metrics_port: None,
+            #[cfg(feature = "websocket")]
+            ws_port: None,
+            collector_policy: CollectorPolicy::DEFAULT,
+            endless: false,
+            explorers_only: false,
+            max_iterations: None,
+            malfunctions: MalfunctionConfig::DEFAULT,
+            seed: None,
+            stations: 1,
+            day_night_period: DAY_NIGHT_PERIOD,
+            peer_sync_radius: PEER_SYNC_RADIUS,
+            relay_range: RELAY_RANGE,
+            radio_range: 0,
+            radio_latency: RADIO_LATENCY,
+            radio_sync_interval: RADIO_SYNC_INTERVAL,
+            vision: VisionModel::default(),
+            retire_after: RETIRE_AFTER_IDLE_TICKS,
+            idle_rescan: IDLE_RESCAN_TICKS,
+            sector_planning: true,
+            drop_off_in_passing: true,
+            build_depots: false,
+            repair_access: false,
+            station_upgrades: false,
+            initial_fleet: None,
+            robot_configs: BTreeMap::new(),
+            robot_costs: BTreeMap::new(),
+            economy: StationConfig::DEFAULT,
+            objectives: MissionObjectives::DEFAULT,
+            life_support: LIFE_SUPPORT_DRAIN,
+            blackout_grounding: false,
+        };
+        let mut args = std::env::args().skip(1);
+        
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--port" => {
+                    config.port = args.next().and_then(|p| p.parse::<u16>().ok())
+                        .ok_or("--port attend un numéro de port")?;
+                },
+                "--auth-token" => {
+                    let token = args.next().filter(|t| !t.is_empty())
+                        .ok_or("--auth-token attend un secret non vide")?;
+                    config.auth_token = Some(token);
+                },
+                "--tick-ms" => config.tick = parse_millis(&arg, args.next())?,
+                "--broadcast-ms" => config.broadcast = parse_millis(&arg, args.next())?,
+                "--metrics-port" => {
+                    let port = args.next().and_then(|p| p.parse::<u16>().ok())
+                        .ok_or("--metrics-port attend un numéro de port")?;
+                    config.metrics_port = Some(port);
+                },
+                #[cfg(feature = "websocket")]
+                "--ws-port" => {
+                    let port = args.next().and_then(|p| p.parse::<u16>().ok())
+                        .ok_or("--ws-port attend un numéro de port")?;
+                    config.ws_port = Some(port);
+                },
+                #[cfg(not(feature = "websocket"))]
+                "--ws-port" => return Err("--ws-port nécessite la feature websocket (cargo run --features websocket --bin simulation)".to_string()),
+                "--collector-thresholds" => config.collector_policy = parse_collector_policy(args.next())?,
+                "--endless" => config.endless = true,
+                "--explorers-only" => config.explorers_only = true,
+                "--max-iterations" => {
+                    let cycles = args.next().and_then(|c| c.parse::<u32>().ok()).filter(|&cycles| cycles > 0)
+                        .ok_or("--max-iterations attend un nombre de cycles non nul")?;
+                    config.max_iterations = Some(cycles);
+                },
+                "--malfunction-rate" => {
+                    let rate = args.next().and_then(|r| r.parse::<f64>().ok())
+                        .filter(|rate| (0.0..=1.0).contains(rate))
+                        .ok_or("--malfunction-rate attend une probabilité par cycle entre 0 et 1")?;
+                    config.malfunctions.failure_rate = rate;
+                },
+                "--seed" => {
+                    let seed = args.next().and_then(|s| s.parse::<u32>().ok())
+                        .ok_or("--seed attend un entier positif (32 bits)")?;
+                    config.seed = Some(seed);
+                },
+                "--stations" => {
+                    let stations = args.next().and_then(|n| n.parse::<usize>().ok())
+                        .filter(|n| (1..=MAX_STATIONS).contains(n))
+                        .ok_or(format!("--stations attend un nombre de stations entre 1 et {}", MAX_STATIONS))?;
+                    config.stations = stations;
+                },
+                "--day-length" => {
+                    let period = args.next().and_then(|n| n.parse::<u32>().ok())
+                        .ok_or("--day-length attend une durée de jour en cycles (0 = toujours jour)")?;
+                    config.day_night_period = period;
+                },
+                "--peer-sync-radius" => {
+                    let radius = args.next().and_then(|r| r.parse::<usize>().ok())
+                        .ok_or("--peer-sync-radius attend une distance en cases (0 = pas d'échange entre robots)")?;
+                    config.peer_sync_radius = radius;
+                },
+                "--relay-range" => {
+                    let range = args.next().and_then(|r| r.parse::<usize>().ok())
+                        .ok_or("--relay-range attend une portée en cases (0 = pas de relais)")?;
+                    config.relay_range = range;
+                },
+                "--radio-range" => {
+                    let range = args.next().and_then(|r| r.parse::<usize>().ok())
+                        .ok_or("--radio-range attend une distance en cases (0 = pas de radio)")?;
+                    config.radio_range = range;
+                },
+                "--radio-latency" => {
+                    let latency = args.next().and_then(|l| l.parse::<u32>().ok())
+                        .ok_or("--radio-latency attend un nombre de cycles")?;
+                    config.radio_latency = latency;
+                },
+                "--radio-interval" => {
+                    let interval = args.next().and_then(|i| i.parse::<u32>().ok()).filter(|&interval| interval > 0)
+                        .ok_or("--radio-interval attend un nombre de cycles non nul")?;
+                    config.radio_sync_interval = interval;
+                },
+                "--self-repair-ticks" => {
+                    let ticks = args.next().and_then(|t| t.parse::<u32>().ok())
+                        .ok_or("--self-repair-ticks attend un nombre de cycles")?;
+                    config.malfunctions.self_repair_ticks = ticks;
+                },
+                "--retire-after" => {
+                    let ticks = args.next().and_then(|t| t.parse::<u32>().ok())
+                        .ok_or("--retire-after attend un nombre de cycles (0 = jamais de mise hors service)")?;
+                    config.retire_after = ticks;
+                },
+                "--idle-rescan" => {
+                    let ticks = args.next().and_then(|t| t.parse::<u32>().ok()).filter(|&ticks| ticks > 0)
+                        .ok_or("--idle-rescan attend un nombre de cycles non nul")?;
+                    config.idle_rescan = ticks;
+                },
+                "--no-sectors" => config.sector_planning = false,
+                "--no-drop-off" => config.drop_off_in_passing = false,
+                "--build-depots" => config.build_depots = true,
+                "--repair-access" => config.repair_access = true,
+                "--station-upgrades" => config.station_upgrades = true,
+                "--initial" => config.initial_fleet = Some(parse_initial_fleet(args.next())?),
+                "--vision" => {
+                    config.vision = match args.next().as_deref() {
+                        Some("square") => VisionModel::Square,
+                        Some("line-of-sight") => VisionModel::LineOfSight,
+                        _ => return Err("--vision attend square (à travers les obstacles) ou line-of-sight".to_string()),
+                    };
+                },
+                "--robot-config" => {
+                    let (robot_type, robot_config) = parse_robot_config(args.next(), &config.robot_configs)?;
+                    config.robot_configs.insert(robot_type, robot_config);
+                },
+                "--robot-cost" => {
+                    let (robot_type, robot_cost) = parse_robot_cost(args.next(), &config.robot_costs)?;
+                    config.robot_costs.insert(robot_type, robot_cost);
+                },
+                "--objectives" => config.objectives = parse_objectives(args.next())?,
+                "--economy" => config.economy = parse_economy(args.next())?,
+                "--life-support" => {
+                    let drain = args.next().and_then(|d| d.parse::<f32>().ok())
+                        .filter(|drain| drain.is_finite() && *drain >= 0.0)
+                        .ok_or("--life-support attend une énergie par robot actif et par cycle (0 = aucune consommation)")?;
+                    config.life_support = drain;
+                },
+                "--blackout-grounding" => config.blackout_grounding = true,
+                other => return Err(format!("Argument inconnu: {}", other)),
+            }
+        }
+        
+        // NOTE - Without collectors, no resource goal can ever be met
+        if config.explorers_only {
+            if config.objectives.min_minerals > 0 || config.objectives.min_scientific_data > 0 {
+                return Err("--explorers-only exclut les objectifs de minerais et de données scientifiques".to_string());
+            }
+            config.objectives.collect_all_resources = false;
+            if config.initial_fleet.as_ref().is_some_and(|fleet| fleet.iter().any(|&robot_type| robot_type != RobotType::Explorer)) {
+                return Err("--explorers-only n'accepte que des explorateurs dans --initial".to_string());
+            }
+        }
+        
+        Ok(config)
+    }
+}
+
+// NOTE - Parse a strictly positive duration in milliseconds
+fn parse_millis(flag: &str, value: Option<String>) -> Result<Duration, String> {
+    value.as_deref()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|ms| *ms > 0)
+        .map(Duration::from_millis)
+        .ok_or(format!("{} attend un nombre de millisecondes > 0", flag))
+}
+
+// NOTE - Parse "energy,mineral,scientific" exploration percentages (each in 0..=100)
+fn parse_collector_policy(value: Option<String>) -> Result<CollectorPolicy, String> {
+    let error = || "--collector-thresholds attend trois pourcentages: <énergie>,<minerais>,<science>".to_string();
+    let thresholds = value.ok_or_else(error)?
+        .split(',')
+        .map(|v| v.trim().parse::<f32>().ok().filter(|pct| (0.0..=100.0).contains(pct)))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(error)?;
+    
+    match thresholds[..] {
+        [energy_start_pct, mineral_start_pct, scientific_start_pct] => Ok(CollectorPolicy {
+            energy_start_pct,
+            mineral_start_pct,
+            scientific_start_pct,
+        }),
+        _ => Err(error()),
+    }
+}
+
+// NOTE - Parse "type:field=value,..." overrides of a robot type's configuration, on top of
+// the overrides already given for this type
+fn parse_robot_config(value: Option<String>, overrides: &BTreeMap<RobotType, RobotConfig>) -> Result<(RobotType, RobotConfig), String> {
+    let error = || "--robot-config attend <type>:<champ>=<valeur>,... (types: explorer, energy, mineral, scientific, repairer, relay ; \
+                    champs: energy, vision, move-cost, cargo, metabolism, speed, analysis, instrument-cost, brain=default|sweep)".to_string();
+    let value = value.ok_or_else(error)?;
+    let (name, fields) = value.split_once(':').ok_or_else(error)?;
+    let robot_type = parse_robot_type(name).ok_or_else(error)?;
+    
+    let mut robot_config = overrides.get(&robot_type).copied().unwrap_or_else(|| RobotConfig::for_type(robot_type));
+    for field in fields.split(',') {
+        let (key, value) = field.split_once('=').ok_or_else(error)?;
+        let value = value.trim();
+        robot_config = match key.trim() {
+            "energy" => robot_config.with_max_energy(value.parse().ok().filter(|&energy: &f32| energy > 0.0).ok_or_else(error)?),
+            "vision" => robot_config.with_vision_range(value.parse().ok().filter(|&range: &isize| range >= 0).ok_or_else(error)?),
+            "move-cost" => robot_config.with_move_cost(value.parse().ok().filter(|&cost: &f32| cost >= 0.0).ok_or_else(error)?),
+            "cargo" => robot_config.with_cargo_capacity(value.parse().map_err(|_| error())?),
+            "metabolism" => robot_config.with_metabolism(value.parse().ok().filter(|&cost: &f32| cost >= 0.0).ok_or_else(error)?),
+            "speed" => robot_config.with_speed(value.parse().ok().filter(|&speed: &f32| speed > 0.0 && speed.is_finite()).ok_or_else(error)?),
+            "analysis" => robot_config.with_analysis_duration(value.parse().map_err(|_| error())?),
+            "instrument-cost" => robot_config.with_instrument_cost(value.parse().ok().filter(|&cost: &f32| cost >= 0.0).ok_or_else(error)?),
+            "brain" => robot_config.with_brain(BrainKind::from_name(value).ok_or_else(error)?),
+            _ => return Err(error()),
+        };
+    }
+    Ok((robot_type, robot_config))
+}
+
+// NOTE - Parse "type:resource=amount,..." manufacturing costs of a robot type, on top of
+// the costs already given for this type
+fn parse_robot_cost(value: Option<String>, overrides: &BTreeMap<RobotType, RobotCost>) -> Result<(RobotType, RobotCost), String> {
+    let error = || "--robot-cost attend <type>:<ressource>=<quantité>,... (types: explorer, energy, mineral, scientific, repairer, relay ; \
+                    ressources: energy, minerals)".to_string();
+    let value = value.ok_or_else(error)?;
+    let (name, fields) = value.split_once(':').ok_or_else(error)?;
+    let robot_type = parse_robot_type(name).ok_or_else(error)?;
+    
+    let mut robot_cost = overrides.get(&robot_type).copied().unwrap_or_default();
+    for field in fields.split(',') {
+        let (key, value) = field.split_once('=').ok_or_else(error)?;
+        let amount = value.trim().parse().map_err(|_| error())?;
+        match key.trim() {
+            "energy" => robot_cost.energy = amount,
+            "minerals" => robot_cost.minerals = amount,
+            _ => return Err(error()),
+        }
+    }
+    Ok((robot_type, robot_cost))
+}
+
+// NOTE - Robot type named on the command line, short ("explorer", "energy", ...) or full ("Explorer", "EnergyCollector", ...)
+fn parse_robot_type(name: &str) -> Option<RobotType> {
+    match name.trim() {
+        "explorer" | "Explorer" => Some(RobotType::Explorer),
+        "energy" | "EnergyCollector" => Some(RobotType::EnergyCollector),
+        "mineral" | "MineralCollector" => Some(RobotType::MineralCollector),
+        "scientific" | "ScientificCollector" => Some(RobotType::ScientificCollector),
+        "repairer" | "Repairer" => Some(RobotType::Repairer),
+        "relay" | "Relay" => Some(RobotType::Relay),
+        _ => None,
+    }
+}
+
+// NOTE - Parse "type:count,..." into the initial fleet, robots listed in the given order
+fn parse_initial_fleet(value: Option<String>) -> Result<Vec<RobotType>, String> {
+    let error = || "--initial attend <type>:<nombre>,... (types: explorer, energy, mineral, scientific, repairer, relay, \
+                    ou Explorer, EnergyCollector, ...)".to_string();
+    let value = value.ok_or_else(error)?;
+    
+    let mut fleet = Vec::new();
+    for entry in value.split(',') {
+        let (name, count) = entry.split_once(':').ok_or_else(error)?;
+        let robot_type = parse_robot_type(name)
+            .ok_or_else(|| format!("--initial: type de robot inconnu « {} » ({})", name.trim(), error()))?;
+        let count = count.trim().parse::<usize>().map_err(|_| error())?;
+        fleet.extend(std::iter::repeat_n(robot_type, count));
+    }
+    if fleet.is_empty() {
+        return Err("--initial attend au moins un robot".to_string());
+    }
+    Ok(fleet)
+}
+
+// NOTE - "2 Explorer, 1 EnergyCollector"-style summary of a fleet, types in order of first appearance
+fn fleet_composition(fleet: &[RobotType]) -> String {
+    let mut counts: Vec<(RobotType, usize)> = Vec::new();
+    for &robot_type in fleet {
+        match counts.iter_mut().find(|(counted, _)| *counted == robot_type) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((robot_type, 1)),
+        }
+    }
+    counts.iter().map(|(robot_type, count)| format!("{} {:?}", count, robot_type)).collect::<Vec<_>>().join(", ")
+}
+
+// NOTE - Parse "goal=value,..." mission objectives, unlisted goals keep their default
+fn parse_objectives(value: Option<String>) -> Result<MissionObjectives, String> {
+    let error = || "--objectives attend <objectif>=<valeur>,... (exploration=<%>, minerals=<n>, science=<n>, \
+                    all-resources=oui|non, all-home=oui|non)".to_string();
+    let flag = |value: &str| match value {
+        "oui" => Ok(true),
+        "non" => Ok(false),
+        _ => Err(error()),
+    };
+    
+    let mut objectives = MissionObjectives::DEFAULT;
+    for goal in value.ok_or_else(error)?.split(',') {
+        let (key, value) = goal.split_once('=').ok_or_else(error)?;
+        let value = value.trim();
+        match key.trim() {
+            "exploration" => objectives.min_exploration_pct = value.parse().ok()
+                .filter(|pct| (0.0..=100.0).contains(pct))
+                .ok_or_else(error)?,
+            "minerals" => objectives.min_minerals = value.parse().map_err(|_| error())?,
+            "science" => objectives.min_scientific_data = value.parse().map_err(|_| error())?,
+            "all-resources" => objectives.collect_all_resources = flag(value)?,
+            "all-home" => objectives.require_all_home = flag(value)?,
+            _ => return Err(error()),
+        }
+    }
+    Ok(objectives)
+}
+
+// NOTE - Parse "setting=value,..." station economy, unlisted settings keep their default
+fn parse_economy(value: Option<String>) -> Result<StationConfig, String> {
+    let error = || "--economy attend <paramètre>=<valeur>,... (robot-energy=<n>, robot-minerals=<n>, \
+                    refining=<énergie par minerai>, initial-energy=<n>, reserve-floor=<n>, storage=<n>)".to_string();
+    
+    let mut economy = StationConfig::DEFAULT;
+    for setting in value.ok_or_else(error)?.split(',') {
+        let (key, value) = setting.split_once('=').ok_or_else(error)?;
+        let value = value.trim();
+        match key.trim() {
+            "robot-energy" => economy.robot_energy_cost = value.parse().map_err(|_| error())?,
+            "robot-minerals" => economy.robot_mineral_cost = value.parse().map_err(|_| error())?,
+            "refining" => economy.mineral_to_energy_ratio = value.parse().ok()
+                .filter(|ratio: &f32| ratio.is_finite() && *ratio >= 0.0)
+                .ok_or_else(error)?,
+            "initial-energy" => economy.initial_energy = value.parse().map_err(|_| error())?,
+            "reserve-floor" => economy.reserve_floor = value.parse().map_err(|_| error())?,
+            "storage" => economy.energy_storage = value.parse().map_err(|_| error())?,
+            _ => return Err(error()),
+        }
+    }
+    Ok(economy)
+}
+
+// Délai d'attente d'un Hello quand aucun secret n'est exigé (clients sans négociation)
+const HELLO_TIMEOUT_MS: u64 = 250;
+
+// NOTE - Read one handshake frame; timeout, read error, EOF or undecodable frame give None
+async fn read_handshake_frame(stream: &mut TcpStream, decoder: &mut FrameDecoder, wait: Duration) -> Option<NetworkMessage> {
+    match tokio::time::timeout(wait, read_frame(stream, decoder)).await {
+        Ok(Ok(Some(Ok(message)))) => Some(message),
+        _ => None,
+    }
+}
+
+// NOTE - How the server writes a client's frames: negotiated format, gzip-compressed or not,
+// full or lite robot updates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct FrameEncoding {
+    format: WireFormat,
+    gzip: bool,
+    lite: bool,
+}
+
+impl FrameEncoding {
+    fn encode(self, message: &NetworkMessage) -> codec::Result<Vec<u8>> {
+        let frame = encode_frame_as(message, self.format)?;
+        if self.gzip { compress_frame(&frame, self.format) } else { Ok(frame) }
+    }
+}
+
+// NOTE - Connection handshake: optional Hello (wire format, gzip, lite), then Auth when a secret is set.
+// Refused clients receive an AuthFailed frame and their socket is closed.
+async fn handshake_client(mut stream: TcpStream, token: Option<&str>) -> Result<(TcpStream, FrameEncoding), AuthError> {
+    let mut decoder = FrameDecoder::new();
+    let auth_wait = Duration::from_secs(AUTH_TIMEOUT_SECS);
+    let first_wait = if token.is_some() { auth_wait } else { Duration::from_millis(HELLO_TIMEOUT_MS) };
+    
+    let mut received = read_handshake_frame(&mut stream, &mut decoder, first_wait).await;
+    let mut negotiated = None;
+    if let Some(NetworkMessage::Hello { format, gzip, lite }) = received {
+        negotiated = Some(FrameEncoding { format: format.negotiate(), gzip: gzip && GZIP_SUPPORTED, lite });
+        received = match token {
+            Some(_) => read_handshake_frame(&mut stream, &mut decoder, auth_wait).await,
+            None => None,
+        };
+    }
+    
+    if let Some(token) = token
+        && let Err(error) = verify_first_frame(token, received.as_ref()) {
+        if let Ok(frame) = encode_frame(&NetworkMessage::AuthFailed { error }) {
+            let _ = stream.write_all(&frame).await;
+        }
+        let _ = stream.shutdown().await;
+        return Err(error);
+    }
+    
+    // NOTE - Confirm the encoding in plain JSON; every later server frame uses it
+    if let Some(FrameEncoding { format, gzip, lite }) = negotiated
+        && let Ok(frame) = encode_frame(&NetworkMessage::Welcome { format, gzip, lite }) {
+        let _ = stream.write_all(&frame).await;
+    }
+    
+    Ok((stream, negotiated.unwrap_or_default()))
+}
+
+// Nombre de diffusions compressées entre deux rapports de compression
+const COMPRESSION_REPORT_FRAMES: u32 = 100;
+
+// NOTE - Sizes of the states broadcast in gzip, before and after compression
+#[derive(Default)]
+struct CompressionTally {
+    frames: u32,
+    plain_bytes: usize,
+    compressed_bytes: usize,
+}
+
+impl CompressionTally {
+    fn record(&mut self, plain: usize, compressed: usize) {
+        self.frames += 1;
+        self.plain_bytes += plain;
+        self.compressed_bytes += compressed;
+    }
+}
+
+impl std::fmt::Display for CompressionTally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ratio = self.plain_bytes as f64 / self.compressed_bytes.max(1) as f64;
+        write!(f, "{} trames, {} → {} octets (ratio {:.1}:1)", self.frames, self.plain_bytes, self.compressed_bytes, ratio)
+    }
+}
+
+// Nombre de trames en attente par client avant de perdre les suivantes
+const CLIENT_QUEUE_SIZE: usize = 4;
+
+// NOTE - Broadcaster-side handle of an authenticated client.
+// The socket itself is owned by the client's own task (see `serve_client`).
+struct ClientHandle {
+    id: usize,
+    address: SocketAddr,
+    encoding: FrameEncoding,
+    frames: mpsc::Sender<Arc<Vec<u8>>>,
+    stats: Arc<ConnectionStats>,
+}
+
+type ClientList = Arc<TokioMutex<Vec<ClientHandle>>>;
+
+// NOTE - What every client task shares with the rest of the server
+#[derive(Clone)]
+struct ClientContext {
+    // NOTE - Reference clock for heartbeat timestamps
+    clock: Instant,
+    // NOTE - Commands received from Earth, applied by the simulation thread at its next cycle
+    commands: mpsc::UnboundedSender<ClientCommand>,
+}
+
+// NOTE - Per-client task: writes queued frames and heartbeats, reads pongs (RTT) and commands
+async fn serve_client(id: usize, address: SocketAddr, encoding: FrameEncoding, stream: TcpStream, mut frames: mpsc::Receiver<Arc<Vec<u8>>>, stats: Arc<ConnectionStats>, context: ClientContext) {
+    let (mut reader, mut writer) = stream.into_split();
+    let clock = context.clock;
+    
+    // NOTE - Clients answer heartbeats and may send commands, anything else is ignored
+    let pong_stats = stats.clone();
+    let pong_reader = tokio::spawn(async move {
+        let mut decoder = FrameDecoder::new();
+        while let Ok(Some(frame)) = read_frame(&mut reader, &mut decoder).await {
+            match frame {
+                Ok(NetworkMessage::Pong { sent_at_us }) => {
+                    let now_us = clock.elapsed().as_micros() as u64;
+                    pong_stats.record_rtt(Duration::from_micros(now_us.saturating_sub(sent_at_us)));
+                },
+                Ok(NetworkMessage::Command(command)) => {
+                    server_log!("🛰️ Commande du client #{}: {:?}", id, command);
+                    let _ = context.commands.send(command);
+                },
+                _ => {},
+            }
+        }
+    });
+    
+    let mut heartbeat = tokio::time::interval(Duration::from_secs(HEARTBEAT_INTERVAL_SECS));
+    loop {
+        let frame = tokio::select! {
+            queued = frames.recv() => match queued {
+                Some(frame) => frame,
+                None => break,
+            },
+            _ = heartbeat.tick() => {
+                let ping = NetworkMessage::Ping { sent_at_us: clock.elapsed().as_micros() as u64 };
+                match encoding.encode(&ping) {
+                    Ok(frame) => Arc::new(frame),
+                    Err(_) => continue,
+                }
+            },
+        };
+        
+        if send_frame(&mut writer, &frame, &stats).await.is_err() {
+            break;
+        }
+    }
+    
+    pong_reader.abort();
+    server_log!("📡 Connexion Terre #{} ({}) fermée: {}", id, address, stats.snapshot());
+}
+
+// Chemin du point d'accès WebSocket (ws://127.0.0.1:<port>/state)
+#[cfg(feature = "websocket")]
+const WEBSOCKET_PATH: &str = "/state";
+
+// NOTE - WebSocket endpoint for browser monitors: the broadcast states as JSON text messages
+#[cfg(feature = "websocket")]
+async fn serve_websocket(listener: TcpListener, clients: ClientList, client_ids: Arc<AtomicUsize>,
+                         state_publisher: Arc<StatePublisher>, auth_token: Option<String>) {
+    loop {
+        let Ok((stream, address)) = listener.accept().await else { continue };
+        let id = client_ids.fetch_add(1, Ordering::Relaxed);
+        let clients = clients.clone();
+        let state_publisher = state_publisher.clone();
+        let auth_token = auth_token.clone();
+        
+        tokio::spawn(async move {
+            let route = WebSocketRoute { token: auth_token.as_deref() };
+            let socket = match tokio_tungstenite::accept_hdr_async(stream, route).await {
+                Ok(socket) => socket,
+                Err(e) => {
+                    server_log!("⛔ Moniteur web {} refusé: {}", address, e);
+                    return;
+                }
+            };
+            let (mut sink, mut incoming) = socket.split();
+            let stats = Arc::new(ConnectionStats::new());
+            
+            // NOTE - Same frames as the TCP clients, minus the trailing newline
+            let as_message = |frame: &[u8]| Message::text(String::from_utf8_lossy(frame.trim_ascii_end()).into_owned());
+            if let Some(state) = state_publisher.latest()
+                && let Ok(frame) = encode_frame_as(&NetworkMessage::State(Box::new(state)), WireFormat::Json) {
+                if sink.send(as_message(&frame)).await.is_err() {
+                    return;
+                }
+                stats.record_sent(frame.len());
+            }
+            
+            let (frames_tx, mut frames_rx) = mpsc::channel(CLIENT_QUEUE_SIZE);
+            {
+                let mut clients = clients.lock().await;
+                clients.push(ClientHandle { id, address, encoding: FrameEncoding::default(), frames: frames_tx, stats: stats.clone() });
+                server_log!("🕸️ Moniteur web #{} connecté depuis {} (clients: {})", id, address, clients.len());
+            }
+            
+            // NOTE - Browsers only ever close the connection; reading also answers their pings
+            loop {
+                tokio::select! {
+                    queued = frames_rx.recv() => {
+                        let Some(frame) = queued else { break };
+                        if sink.send(as_message(&frame)).await.is_err() {
+                            break;
+                        }
+                        stats.record_sent(frame.len());
+                    },
+                    message = incoming.next() => match message {
+                        Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                        Some(Ok(_)) => {},
+                    },
+                }
+            }
+            server_log!("🕸️ Moniteur web #{} ({}) déconnecté: {}", id, address, stats.snapshot());
+        });
+    }
+}
+
+// NOTE - Accepts the WebSocket upgrade on `WEBSOCKET_PATH` only, with `?token=<secret>` when the server requires one
+#[cfg(feature = "websocket")]
+struct WebSocketRoute<'a> {
+    token: Option<&'a str>,
+}
+
+#[cfg(feature = "websocket")]
+impl Callback for WebSocketRoute<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        let refuse = |status: StatusCode, reason: String| {
+            let mut response = ErrorResponse::new(Some(reason));
+            *response.status_mut() = status;
+            response
+        };
+        
+        if request.uri().path() != WEBSOCKET_PATH {
+            return Err(refuse(StatusCode::NOT_FOUND, "route inconnue".to_string()));
+        }
+        if let Some(expected) = self.token {
+            let provided = request.uri().query().unwrap_or_default()
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("token="));
+            if !provided.is_some_and(|provided| tokens_match(expected, provided)) {
+                return Err(refuse(StatusCode::UNAUTHORIZED, AuthError::InvalidToken.to_string()));
+            }
+        }
+        Ok(response)
+    }
+}
+
+// NOTE - Minimal HTTP endpoint: GET /clients returns per-connection statistics as JSON
+async fn serve_metrics(listener: TcpListener, clients: ClientList) {
+    loop {
+        let Ok((mut socket, _)) = listener.accept().await else { continue };
+        let clients = clients.clone();
+        
+        tokio::spawn(async move {
+            let mut request = [0u8; 1024];
+            let read = socket.read(&mut request).await.unwrap_or(0);
+            let request_line = String::from_utf8_lossy(&request[..read]);
+            
+            let (status, body) = if request_line.starts_with("GET /clients ") {
+                let report: Vec<_> = clients.lock().await.iter().map(|client| serde_json::json!({
+                    "id": client.id,
+                    "address": client.address.to_string(),
+                    "format": client.encoding.format,
+                    "gzip": client.encoding.gzip,
+                    "lite": client.encoding.lite,
+                    "stats": client.stats.snapshot(),
+                })).collect();
+                ("200 OK", serde_json::Value::from(report).to_string())
+            } else {
+                ("404 Not Found", r#"{"error":"route inconnue"}"#.to_string())
+            };
+            
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+            let _ = socket.shutdown().await;
+        });
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     server_log!("🚀 Démarrage du serveur de simulation EREEA...");
     
+    let config = ServerConfig::from_args()?;
+    server_log!("⏱️  Cycle de simulation: {:?} | Diffusion: {:?}", config.tick, config.broadcast);
+    if config.auth_token.is_some() {
+        server_log!("🔐 Authentification par secret partagé activée.");
+    }
+    
     // === PHASE 1: INITIALISATION DES COMPOSANTS ===
     
     // NOTE - Generating the exoplanet map
     server_log!("📍 Étape 1: Génération de l'exoplanète...");
-    let map = Arc::new(Mutex::new(Map::new()));
+    let map = Map::with_stations(config.seed.unwrap_or_else(rand::random), config.stations);
+    // Graine affichée pour pouvoir rejouer la mission à l'identique (--seed)
+    let seed = map.seed();
+    server_log!("🎲 Graine de la mission: {} (rejouable avec --seed {})", seed, seed);
+    if !map.extra_stations.is_empty() {
+        server_log!("🏠 Stations secondaires: {:?}", map.extra_stations);
+    }
+    let map = Arc::new(Mutex::new(map));
     
     // NOTE - Counting resources on the generated map
     {
@@ -44,68 +824,121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         server_log!("✅ Exoplanète générée avec {} ressources à la position station ({}, {})", 
                  resource_count, map_lock.station_x, map_lock.station_y);
+        server_log!("🧭 Cases accessibles depuis la station: {}/{} (les autres ne comptent pas dans l'exploration)",
+                 map_lock.reachable_tile_count(), MAP_SIZE * MAP_SIZE);
     }
     
     // NOTE - Building the space station
     server_log!("🏗️  Étape 2: Construction de la station spatiale...");
-    let station = Arc::new(Mutex::new(Station::new()));
+    let mut station = Station::new_with_config(config.economy);
+    station.collector_policy = config.collector_policy;
+    station.endless = config.endless;
+    station.explorers_only = config.explorers_only;
+    station.malfunctions = config.malfunctions;
+    station.day_night_period = config.day_night_period;
+    station.vision_model = config.vision;
+    station.robot_configs = config.robot_configs.clone();
+    station.robot_costs = config.robot_costs.clone();
+    station.objectives = config.objectives;
+    station.retire_after_idle_ticks = config.retire_after;
+    station.idle_rescan_ticks = config.idle_rescan;
+    station.sector_planning = config.sector_planning;
+    station.drop_off_in_passing = config.drop_off_in_passing;
+    if config.build_depots {
+        station.depot_cost = DEPOT_MINERAL_COST;
+    }
+    station.repair_access = config.repair_access;
+    station.auto_upgrades = config.station_upgrades;
+    station.life_support_drain = config.life_support;
+    station.blackout_grounds_robots = config.blackout_grounding;
+    station.peer_sync_radius = config.peer_sync_radius;
+    station.relay_range = config.relay_range;
+    station.radio_range = config.radio_range;
+    station.radio_latency = config.radio_latency;
+    station.radio_sync_interval = config.radio_sync_interval;
+    station.set_exploration_area(ExplorationArea::reachable(&map.lock().unwrap()));
+    let station = Arc::new(Mutex::new(station));
     server_log!("✅ Station spatiale opérationnelle.");
+    server_log!("⛏️  Seuils d'exploration des collecteurs: énergie {}%, minerais {}%, science {}%",
+             config.collector_policy.energy_start_pct,
+             config.collector_policy.mineral_start_pct,
+             config.collector_policy.scientific_start_pct);
+    if config.build_depots {
+        server_log!("🏗️  Dépôts avancés: la station en construit près des gisements pour {} minerais (jusqu'à {} stations).",
+                 DEPOT_MINERAL_COST, MAX_STATIONS);
+    }
+    if config.repair_access {
+        server_log!("⛏️  Accès à la station entretenu: un passage est dégagé pour tout robot coupé de la station.");
+    }
+    if config.station_upgrades {
+        server_log!("🏗️  Améliorations de la station: {} (dans cet ordre), une fois la flotte à {} robots.",
+                 StationUpgrade::LADDER.map(|upgrade| upgrade.to_string()).join(", "), UPGRADE_FLEET_SIZE);
+    }
+    if config.explorers_only {
+        server_log!("🔍 Scénario d'exploration seule: la station ne construit que des explorateurs.");
+    }
+    if let Some(cycles) = config.max_iterations {
+        server_log!("⏱️  Arrêt de la simulation après {} cycles au plus.", cycles);
+    }
+    if config.endless {
+        server_log!("♾️  Mode sans fin: les ressources se régénèrent tous les {} cycles.", REGENERATION_INTERVAL);
+    }
+    if config.malfunctions.failure_rate > 0.0 {
+        server_log!("💥 Pannes aléatoires: probabilité {} par cycle, autoréparation en {} cycles.",
+                 config.malfunctions.failure_rate, config.malfunctions.self_repair_ticks);
+    }
+    if config.radio_range > 0 {
+        server_log!("📻 Liaison radio: portée {} cases, latence {} cycles, synchronisation tous les {} cycles.",
+                 config.radio_range, config.radio_latency, config.radio_sync_interval);
+    }
+    server_log!("🎯 Objectifs de la mission: {}", config.objectives.summary());
+    if config.life_support > 0.0 {
+        server_log!("🫁 Support vital: {} unités d'énergie par robot actif et par cycle{}.", config.life_support,
+                 if config.blackout_grounding { ", robots consignés à la station si les réserves s'épuisent" } else { "" });
+    }
+    for (robot_type, robot_config) in &config.robot_configs {
+        server_log!("🔧 Configuration des robots {:?}: énergie {}, vision {}, déplacement {}, soute {}, métabolisme {}, vitesse {}, analyse {} cycles à {}",
+                 robot_type, robot_config.max_energy, robot_config.vision_range, robot_config.move_cost,
+                 robot_config.cargo_capacity, robot_config.metabolism, robot_config.speed,
+                 robot_config.analysis_duration, robot_config.instrument_cost);
+    }
+    if config.economy != StationConfig::DEFAULT {
+        server_log!("💰 Économie de la station: {} énergie au départ, robots à {} énergie et {} minerais, {} énergie par minerai raffiné, réserve de {} unités, stockage de {} unités.",
+                 config.economy.initial_energy, config.economy.robot_energy_cost, config.economy.robot_mineral_cost,
+                 config.economy.mineral_to_energy_ratio, config.economy.reserve_floor, config.economy.energy_storage);
+    }
+    for (robot_type, robot_cost) in &config.robot_costs {
+        server_log!("🏭 Coût de fabrication des robots {:?}: {} énergie, {} minerais",
+                 robot_type, robot_cost.energy, robot_cost.minerals);
+    }
     
     // NOTE - Extracting coordinates for robots
     server_log!("📋 Étape 3: Configuration des robots initiaux...");
-    let (station_x, station_y, global_memory_clone) = {
-        let map_lock = map.lock().unwrap();
-        let station_lock = station.lock().unwrap();
-        
-        (
-            map_lock.station_x,
-            map_lock.station_y,
-            station_lock.global_memory.clone()
-        )
+    let initial_fleet: &[RobotType] = match &config.initial_fleet {
+        Some(fleet) => fleet,
+        None if config.explorers_only => &[RobotType::Explorer; 5],
+        None => &INITIAL_FLEET,
     };
-    
-    // NOTE - Creating the initial robot team
-    let robots = Arc::new(Mutex::new(vec![
-        Robot::new_with_memory(
-            station_x, station_y, 
-            RobotType::Explorer, 1,
-            station_x, station_y,
-            global_memory_clone.clone()
-        ),
-        Robot::new_with_memory(
-            station_x, station_y, 
-            RobotType::EnergyCollector, 2,
-            station_x, station_y,
-            global_memory_clone.clone()
-        ),
-        Robot::new_with_memory(
-            station_x, station_y, 
-            RobotType::MineralCollector, 3,
-            station_x, station_y,
-            global_memory_clone.clone()
-        ),
-        Robot::new_with_memory(
-            station_x, station_y, 
-            RobotType::ScientificCollector, 4,
-            station_x, station_y,
-            global_memory_clone.clone()
-        ),
-    ]));
-    
-    // NOTE - Setting next robot ID
-    station.lock().unwrap().next_robot_id = 5;
-    
-    // NOTE - Activating robots
-    for robot in robots.lock().unwrap().iter_mut() {
-        robot.mode = RobotMode::Exploring;
+    server_log!("🤖 Équipe initiale: {} robots ({})", initial_fleet.len(), fleet_composition(initial_fleet));
+    // Chaque robot construit coûte de l'énergie et des minerais : sans leurs collecteurs, la flotte ne grandira pas
+    let self_sustaining = initial_fleet.contains(&RobotType::EnergyCollector) && initial_fleet.contains(&RobotType::MineralCollector);
+    if !config.explorers_only && !self_sustaining {
+        server_log!("⚠️  Équipe initiale sans collecteur d'énergie ou de minerais: la station risque de ne jamais pouvoir construire de robot.");
     }
+    
+    // NOTE - Creating the initial robot team, with the configuration the station would build them with
+    let robots = {
+        let map_lock = map.lock().unwrap();
+        let mut station_lock = station.lock().unwrap();
+        Arc::new(Mutex::new(deploy_fleet(&map_lock, &mut station_lock, initial_fleet)))
+    };
     server_log!("✅ Équipe de robots déployée sur l'exoplanète.");
     
     // === PHASE 2: CONFIGURATION DU SYSTÈME DE COMMUNICATION ===
     
     // NOTE - Setting up communication channel for simulation state
     server_log!("📡 Étape 4: Configuration du système de communication...");
-    let (state_tx, mut state_rx) = mpsc::channel::<SimulationState>(100);
+    let state_publisher = Arc::new(StatePublisher::new());
     server_log!("✅ Canal de communication configuré.");
     
     // === PHASE 3: DÉMARRAGE DU THREAD DE SIMULATION ===
@@ -115,76 +948,87 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let map_for_sim = map.clone();
     let station_for_sim = station.clone();
     let robots_for_sim = robots.clone();
+    let publisher_for_sim = state_publisher.clone();
+    let (commands_tx, mut commands_rx) = mpsc::unbounded_channel();
+    let (tick, broadcast_period) = (config.tick, config.broadcast);
+    let max_iterations = config.max_iterations;
+    let started_at = Instant::now();
     
     // NOTE - Main simulation loop
     let _simulation_thread = thread::spawn(move || {
         server_log!("🔄 Moteur de simulation actif.");
         let mut iteration = 0;
-        let mut last_robot_creation = 0;
         let mut last_status_log = 0;
+        let mut recent_events = VecDeque::with_capacity(RECENT_EVENTS);
         
         // NOTE - Simulation main loop
         loop {
             // NOTE - Periodic progress log
             if iteration % 100 == 0 && iteration != last_status_log {
-                let exploration_pct = if let Ok(station_lock) = station_for_sim.lock() {
-                    station_lock.get_exploration_percentage()
+                let (exploration_pct, claims) = if let Ok(station_lock) = station_for_sim.lock() {
+                    (station_lock.get_exploration_percentage(), station_lock.exploration_claim_count())
                 } else {
-                    0.0
+                    (0.0, 0)
                 };
-                server_log!("📊 Cycle: {} - Exploration: {:.1}%", iteration, exploration_pct);
+                server_log!("📊 Cycle: {} - Exploration: {:.1}% - Cibles réservées: {}", iteration, exploration_pct, claims);
                 last_status_log = iteration;
             }
             
-            // NOTE - Advance global clock
-            if let Ok(mut station_lock) = station_for_sim.lock() {
-                station_lock.tick();
-            } else {
-                server_log!("❌ Erreur de verrouillage station (tick)");
-                break;
-            }
-            
-            // NOTE - Update all robots and handle emergencies
+            // NOTE - Commands from Earth, then one simulation cycle with all locks held
             {
                 let robots_result = robots_for_sim.lock();
                 let map_result = map_for_sim.lock();
                 let station_result = station_for_sim.lock();
                 
-                // NOTE - Atomic processing with all locks
                 match (robots_result, map_result, station_result) {
                     (Ok(mut robots_lock), Ok(mut map_lock), Ok(mut station_lock)) => {
-                        // NOTE - Update each robot
-                        for robot in robots_lock.iter_mut() {
-                            robot.update(&mut map_lock, &mut station_lock);
-                            
-                            // NOTE - Emergency: robot out of energy
-                            if robot.energy <= 0.0 {
-                                server_log!("🚨 URGENCE: Robot {} en panne d'énergie, rapatriement!", robot.id);
-                                robot.x = robot.home_station_x;
-                                robot.y = robot.home_station_y;
-                                robot.energy = robot.max_energy / 2.0;
-                                robot.mode = RobotMode::Idle;
+                        // NOTE - Commands received from Earth since the last cycle
+                        while let Ok(command) = commands_rx.try_recv() {
+                            match command {
+                                ClientCommand::DispatchTask { robot_id, task } => {
+                                    if robots_lock.iter().any(|robot| robot.id == robot_id) {
+                                        station_lock.dispatch_task(robot_id, task);
+                                    } else {
+                                        server_log!("⚠️ Commande ignorée: robot #{} inconnu", robot_id);
+                                    }
+                                },
+                                ClientCommand::SetBrain { robot_id, brain } => {
+                                    match robots_lock.iter_mut().find(|robot| robot.id == robot_id) {
+                                        Some(robot) => {
+                                            robot.set_brain(brain.build());
+                                            server_log!("🧠 Robot #{} suit désormais la stratégie {}", robot_id, robot.brain_name());
+                                        },
+                                        None => {
+                                            server_log!("⚠️ Commande ignorée: robot #{} inconnu", robot_id);
+                                        },
+                                    }
+                                },
+                                ClientCommand::RecallAll => station_lock.recall_all(),
+                                ClientCommand::Resume => station_lock.resume_operations(),
                             }
                         }
                         
-                        // NOTE - Check if mission is complete BEFORE creating new robots
-                        if station_lock.is_mission_complete(&map_lock) {
-                            server_log!("🎉 MISSION TERMINÉE! Toutes les ressources collectées!");
-                            
-                            // NOTE - Wait for all robots to return to base
-                            let all_robots_home = robots_lock.iter().all(|r| {
-                                r.x == r.home_station_x && r.y == r.home_station_y && 
-                                (r.mode == RobotMode::Idle || r.mode == RobotMode::ReturnToStation)
-                            });
+                        let report = step_simulation(&mut map_lock, &mut station_lock, &mut robots_lock, iteration);
+                        for notice in &report.notices {
+                            server_log!("{}", notice);
+                        }
+                        record_events(report.events, station_lock.current_time, &mut recent_events);
+                        
+                        // NOTE - Wait for all robots to return to base (unless the objectives do not require it)
+                        if report.outcome != StepOutcome::Running {
+                            server_log!("🎉 MISSION TERMINÉE! Objectifs atteints: {}", station_lock.objectives.summary());
                             
-                            if all_robots_home {
+                            if report.outcome == StepOutcome::MissionComplete {
                                 server_log!("🏠 Tous les robots sont revenus à la base!");
+                                let stats = station_lock.statistics(&map_lock, &robots_lock);
                                 server_log!("📊 STATISTIQUES FINALES:");
-                                server_log!("   🔋 Énergie collectée: {}", station_lock.energy_reserves);
-                                server_log!("   ⛏️ Minerais collectés: {}", station_lock.collected_minerals);
-                                server_log!("   🧪 Données scientifiques: {}", station_lock.collected_scientific_data);
-                                server_log!("   🌍 Exploration: {:.1}%", station_lock.get_exploration_percentage());
-                                server_log!("   🤖 Robots déployés: {}", robots_lock.len());
+                                server_log!("   🔋 Gisements d'énergie collectés: {}", stats.collected_energy);
+                                server_log!("   ⛏️ Minerais collectés: {}", stats.collected_minerals);
+                                server_log!("   🧪 Données scientifiques: {}", stats.collected_scientific_data);
+                                server_log!("   🌍 Exploration: {:.1}%", stats.exploration_percentage);
+                                server_log!("   🤖 Robots déployés: {}", stats.robots_by_type.values().sum::<usize>());
+                                server_log!("   🔄 Synchronisations: {} ({} conflits résolus)", stats.peer_exchange_count, stats.conflict_count);
+                                server_log!("   🕒 Cycles: {}", stats.total_ticks);
                                 
                                 // NOTE - Broadcast final state for a few cycles then exit
                                 static mut FINAL_CYCLES: u32 = 0;
@@ -193,34 +1037,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     if FINAL_CYCLES >= 10 {
                                         server_log!("🚀 MISSION EREEA TERMINÉE AVEC SUCCÈS!");
                                         server_log!("🛑 Arrêt automatique de la simulation...");
+                                        // NOTE - Leave time for the final state to be broadcast
+                                        thread::sleep(broadcast_period * 2);
                                         std::process::exit(0);
                                     }
                                 }
                             }
-                            
-                            // NOTE - Continue broadcasting final state, no more robot creation
-                        } else {
-                            // NOTE - Robot creation logic (every 50 cycles)
-                            if iteration - last_robot_creation >= 50 {
-                                // NOTE - Check if more explorers are needed
-                                let exploration_percentage = station_lock.get_exploration_percentage();
-                                let explorer_count = robots_lock.iter().filter(|r| r.robot_type == RobotType::Explorer).count();
-                                
-                                // NOTE - Create more explorers if exploration is low and few explorers exist
-                                let need_more_explorers = exploration_percentage < 80.0 && explorer_count < 3;
-                                
-                                if let Some(mut new_robot) = station_lock.try_create_robot(&map_lock) {
-                                    // NOTE - Force explorer creation if needed
-                                    if need_more_explorers {
-                                        new_robot.robot_type = RobotType::Explorer;
-                                        server_log!("🔍 Création prioritaire d'un explorateur pour accélérer la découverte");
-                                    }
-                                    
-                                    robots_lock.push(new_robot);
-                                    last_robot_creation = iteration;
-                                    server_log!("🤖 Nouveau robot déployé! Flotte totale: {} robots", robots_lock.len());
-                                }
-                            }
                         }
                     },
                     _ => {
@@ -234,7 +1056,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let state_result = {
                 match (map_for_sim.lock(), station_for_sim.lock(), robots_for_sim.lock()) {
                     (Ok(map_lock), Ok(station_lock), Ok(robots_lock)) => {
-                        Ok(create_simulation_state(&map_lock, &station_lock, &robots_lock, iteration))
+                        let mut state = create_simulation_state(&map_lock, &station_lock, &robots_lock, iteration, started_at.elapsed().as_secs_f64());
+                        state.events = recent_events.iter().cloned().collect();
+                        Ok(state)
                     },
                     _ => {
                         server_log!("❌ Erreur lors de la création de l'état de simulation");
@@ -243,18 +1067,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             };
             
-            // NOTE - Broadcast state to connected clients
+            // NOTE - Publish state; the broadcaster samples it at its own rate
             if let Ok(state) = state_result {
-                if let Err(_) = state_tx.blocking_send(state) {
-                    if iteration % 1000 == 0 {
-                        server_log!("⚠️  Aucun client connecté pour recevoir les données");
-                    }
-                }
+                publisher_for_sim.publish(state);
             }
             
             // NOTE - Simulation cycle pause
-            thread::sleep(Duration::from_millis(300));
+            thread::sleep(tick);
             iteration += 1;
+            
+            // NOTE - Cycle limit reached: report how far the mission went and stop
+            if max_iterations.is_some_and(|cycles| iteration >= cycles) {
+                if let Ok(station_lock) = station_for_sim.lock() {
+                    server_log!("⏱️  Limite de {} cycles atteinte: exploration {:.1}%, mission {}",
+                             iteration, station_lock.get_exploration_percentage(),
+                             if station_lock.is_mission_complete(&map_for_sim.lock().unwrap()) { "terminée" } else { "inachevée" });
+                }
+                thread::sleep(broadcast_period * 2);
+                std::process::exit(0);
+            }
         }
         
         server_log!("🔄 Moteur de simulation arrêté.");
@@ -266,13 +1097,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // NOTE - Opening TCP listener for Earth connections
     server_log!("🌐 Étape 6: Ouverture des communications avec la Terre...");
-    let listener = match TcpListener::bind(format!("127.0.0.1:{}", DEFAULT_PORT)).await {
+    let listener = match TcpListener::bind(format!("127.0.0.1:{}", config.port)).await {
         Ok(l) => {
-            server_log!("✅ Liaison établie sur le port {}", DEFAULT_PORT);
+            server_log!("✅ Liaison établie sur le port {}", config.port);
             l
         },
         Err(e) => {
-            server_log!("❌ ERREUR: Impossible d'établir la liaison sur le port {}: {:?}", DEFAULT_PORT, e);
+            server_log!("❌ ERREUR: Impossible d'établir la liaison sur le port {}: {:?}", config.port, e);
             server_log!("💡 Vérifiez qu'aucun autre programme n'utilise ce port.");
             return Err(e.into());
         }
@@ -285,8 +1116,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // NOTE - Initializing client connection storage
     server_log!("📺 Étape 7: Initialisation du système de diffusion...");
-    let client_streams = Arc::new(TokioMutex::new(Vec::<TcpStream>::new()));
-    let client_streams_clone = client_streams.clone();
+    let clients: ClientList = Arc::new(TokioMutex::new(Vec::new()));
+    let clients_for_broadcast = clients.clone();
+    let mut state_sampler = state_publisher.sampler(config.broadcast);
     server_log!("✅ Système de diffusion initialisé.");
     
     // NOTE - Spawning async task for broadcasting simulation state
@@ -294,36 +1126,61 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tokio::spawn(async move {
         server_log!("📤 Diffuseur de données activé.");
         
-        // NOTE - Main broadcast loop
-        while let Some(state) = state_rx.recv().await {
-            // NOTE - Serialize simulation state to JSON
-            let state_json = match serde_json::to_string(&state) {
-                Ok(json) => json,
-                Err(e) => {
-                    server_log!("❌ Erreur de sérialisation: {:?}", e);
+        // NOTE - Bytes before and after gzip since the last compression report
+        let mut compression = CompressionTally::default();
+        
+        // NOTE - Keyframes already sent to the lite clients
+        let mut lite_encoder = LiteStateEncoder::new();
+        
+        // NOTE - Main broadcast loop (intermediate states are coalesced)
+        while let Some(state) = state_sampler.next().await {
+            // NOTE - Encode simulation state once per encoding, shared by every client queue
+            let mut clients = clients_for_broadcast.lock().await;
+            let lite_message = clients.iter().any(|client| client.encoding.lite)
+                .then(|| NetworkMessage::State(Box::new(lite_encoder.encode(&state))));
+            let message = NetworkMessage::State(Box::new(state));
+            let mut frames: HashMap<FrameEncoding, Option<Arc<Vec<u8>>>> = HashMap::new();
+            for client in clients.iter() {
+                let FrameEncoding { format, gzip, lite } = client.encoding;
+                if frames.contains_key(&client.encoding) {
                     continue;
                 }
-            };
-            
-            // NOTE - Broadcast to all connected clients
-            let mut disconnected_indices = Vec::new();
-            let mut streams = client_streams_clone.lock().await;
-            
-            for (i, stream) in streams.iter_mut().enumerate() {
-                if let Err(_) = stream.write_all(state_json.as_bytes()).await {
-                    disconnected_indices.push(i);
-                } else {
-                    if let Err(_) = stream.write_all(b"\n").await {
-                        disconnected_indices.push(i);
-                    }
+                // NOTE - Compressed frames are built from the plain frame, which gives the ratio for free
+                let plain = frames.entry(FrameEncoding { format, gzip: false, lite }).or_insert_with(|| {
+                    encode_frame_as(lite_message.as_ref().filter(|_| lite).unwrap_or(&message), format)
+                        .inspect_err(|e| { server_log!("❌ Erreur d'encodage: {}", e); })
+                        .ok()
+                        .map(Arc::new)
+                }).clone();
+                if gzip {
+                    let compressed = plain.and_then(|plain| {
+                        compress_frame(&plain, format)
+                            .inspect(|frame| compression.record(plain.len(), frame.len()))
+                            .inspect_err(|e| { server_log!("❌ Erreur de compression: {}", e); })
+                            .ok()
+                    });
+                    frames.insert(client.encoding, compressed.map(Arc::new));
                 }
             }
-            
-            // NOTE - Clean up closed connections
-            for i in disconnected_indices.iter().rev() {
-                server_log!("📡 Connexion Terre #{} fermée", i);
-                streams.remove(*i);
+            if compression.frames >= COMPRESSION_REPORT_FRAMES {
+                server_log!("🗜️ Compression gzip: {}", compression);
+                compression = CompressionTally::default();
             }
+            
+            // NOTE - Queue for all connected clients without waiting on slow sockets
+            clients.retain(|client| {
+                let Some(frame) = frames.get(&client.encoding).cloned().flatten() else { return true };
+                
+                match client.frames.try_send(frame.clone()) {
+                    Ok(()) => true,
+                    Err(mpsc::error::TrySendError::Full(_)) => {
+                        client.stats.record_dropped();
+                        true
+                    },
+                    // NOTE - Client task ended (connection closed)
+                    Err(mpsc::error::TrySendError::Closed(_)) => false,
+                }
+            });
         }
         
         server_log!("📤 Diffuseur de données arrêté.");
@@ -331,20 +1188,77 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     server_log!("✅ Diffusion de données activée.");
     
+    // NOTE - Optional HTTP metrics endpoint
+    if let Some(port) = config.metrics_port {
+        let metrics_listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
+        server_log!("📈 Statistiques des connexions: http://127.0.0.1:{}/clients", port);
+        tokio::spawn(serve_metrics(metrics_listener, clients.clone()));
+    }
+    
+    // NOTE - Client IDs are shared by Earth connections and web monitors
+    let client_ids = Arc::new(AtomicUsize::new(1));
+    
+    // NOTE - Optional WebSocket endpoint for browser monitors
+    #[cfg(feature = "websocket")]
+    if let Some(port) = config.ws_port {
+        let ws_listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
+        server_log!("🕸️ Moniteurs web: ws://127.0.0.1:{}{}", port, WEBSOCKET_PATH);
+        tokio::spawn(serve_websocket(ws_listener, clients.clone(), client_ids.clone(), state_publisher.clone(), config.auth_token.clone()));
+    }
+    
     // === PHASE 6: BOUCLE D'ACCEPTATION DES CONNEXIONS ===
     
     server_log!("🚀 EREEA opérationnel! En attente de connexions de la Terre...");
     
+    // NOTE - Reference clock for heartbeat timestamps, and the way back to the simulation thread
+    let context = ClientContext { clock: Instant::now(), commands: commands_tx };
+    
     // NOTE - Main loop for accepting new client connections
     loop {
         match listener.accept().await {
             Ok((stream, addr)) => {
                 server_log!("🌍 Nouvelle connexion depuis la Terre: {}", addr);
+                let id = client_ids.fetch_add(1, Ordering::Relaxed);
                 
-                // NOTE - Add new client to broadcast list
-                let mut streams = client_streams.lock().await;
-                streams.push(stream);
-                server_log!("📊 Clients connectés: {}", streams.len());
+                // NOTE - Handshake runs in its own task so a silent client never blocks accept
+                let clients = clients.clone();
+                let auth_token = config.auth_token.clone();
+                let state_publisher = state_publisher.clone();
+                let context = context.clone();
+                tokio::spawn(async move {
+                    let (mut stream, encoding) = match handshake_client(stream, auth_token.as_deref()).await {
+                        Ok(connection) => {
+                            if auth_token.is_some() {
+                                server_log!("🔐 Client {} authentifié", addr);
+                            }
+                            connection
+                        },
+                        Err(e) => {
+                            server_log!("⛔ Client {} refusé: {}", addr, e);
+                            return;
+                        }
+                    };
+                    
+                    // NOTE - Send the latest snapshot right away instead of waiting for the next broadcast
+                    let stats = Arc::new(ConnectionStats::new());
+                    if let Some(state) = state_publisher.latest()
+                        && let Ok(frame) = encoding.encode(&NetworkMessage::State(Box::new(
+                            if encoding.lite { create_lite_keyframe_state(&state) } else { state })))
+                        && send_frame(&mut stream, &frame, &stats).await.is_err() {
+                        return;
+                    }
+                    
+                    // NOTE - Add new client to broadcast list (only after authentication)
+                    let (frames_tx, frames_rx) = mpsc::channel(CLIENT_QUEUE_SIZE);
+                    {
+                        let mut clients = clients.lock().await;
+                        clients.push(ClientHandle { id, address: addr, encoding, frames: frames_tx, stats: stats.clone() });
+                        server_log!("📊 Clients connectés: {} (client #{} en {:?}{}{})", clients.len(), id, encoding.format,
+                                    if encoding.gzip { ", gzip" } else { "" }, if encoding.lite { ", allégé" } else { "" });
+                    }
+                    
+                    serve_client(id, addr, encoding, stream, frames_rx, stats, context).await;
+                });
             }
             Err(e) => {
                 server_log!("❌ Erreur lors de l'acceptation d'une connexion: {:?}", e);
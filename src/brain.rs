@@ -0,0 +1,221 @@
+//! # Brain Module
+//!
+//! Exploration policies of the robots. When a robot is free to explore (no
+//! task, no station duty, enough energy, nothing to collect nearby), it asks
+//! its brain what to do next (`RobotBrain::decide`) and `Robot::update`
+//! carries the chosen `RobotAction` out. Everything else a robot does
+//! (returning to recharge, collecting, answering the station, giving up
+//! unreachable targets) stays the same whatever the brain.
+//!
+//! - `DefaultBrain` keeps the built-in frontier planner (`RobotAction::Explore`):
+//!   targets reserved with the station, sectors, wide-area scans.
+//! - `SweepBrain` sweeps the map in horizontal lanes, alternating direction
+//!   from one lane to the next, without coordinating with the other explorers.
+//!
+//! A robot gets the brain of its configuration (`RobotConfig::brain`), which
+//! Earth can change at any time (`ClientCommand::SetBrain`). Other policies
+//! only need to implement `RobotBrain` and be given with `Robot::set_brain`.
+//!
+//! # Examples
+//!
+//! A brain that walks towards the frontier tile closest to the top-left corner:
+//!
+//! ```rust
+//! # use ereea::brain::{RobotAction, RobotBrain, RobotContext};
+//! # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+//! # use ereea::types::RobotType;
+//! struct TopLeftBrain;
+//!
+//! impl RobotBrain for TopLeftBrain {
+//!     fn name(&self) -> &str {
+//!         "top-left"
+//!     }
+//!
+//!     fn decide(&mut self, ctx: RobotContext) -> RobotAction {
+//!         ctx.frontier.iter()
+//!             .min_by_key(|&&(x, y)| (x + y, y))
+//!             .map_or(RobotAction::ReturnHome, |&target| RobotAction::Move(target))
+//!     }
+//! }
+//!
+//! let mut map = Map::open();
+//! let mut station = Station::new();
+//! let mut robot = Robot::new(10, 10, RobotType::Explorer);
+//! robot.set_brain(Box::new(TopLeftBrain));
+//! let mut occupancy = OccupancyGrid::new(10, 10);
+//!
+//! for _ in 0..6 {
+//!     station.tick();
+//!     robot.update(&mut map, &mut station, &mut occupancy);
+//! }
+//! assert_eq!(robot.brain_name(), "top-left");
+//! assert!(robot.x < 10 && robot.y < 10);
+//! ```
+
+use crate::grid::Grid;
+use crate::station::TerrainData;
+use crate::types::{BrainKind, RobotType, MAP_SIZE};
+use std::collections::HashSet;
+
+/// What the station tells the robots about the mission
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StationSummary {
+    /// Station clock, in cycles
+    pub current_time: u32,
+    /// Share of the exploration area the station knows, in percent
+    pub exploration_percentage: f32,
+    /// Energy units in store
+    pub energy_reserves: u32,
+}
+
+/// What a robot knows when its brain decides
+#[derive(Clone, Copy, Debug)]
+pub struct RobotContext<'a> {
+    /// Specialization of the robot
+    pub robot_type: RobotType,
+    /// Current position
+    pub position: (usize, usize),
+    /// Station the robot docks at
+    pub home: (usize, usize),
+    /// Energy left
+    pub energy: f32,
+    /// Battery capacity
+    pub max_energy: f32,
+    /// Radius of the square observed at each update, modules included
+    pub vision_range: isize,
+    /// The robot's map of the planet
+    pub memory: &'a Grid<TerrainData>,
+    /// Explored tiles bordering the unknown (see `Robot::frontier`)
+    pub frontier: &'a HashSet<(usize, usize)>,
+    /// Targets the robot gave up lately, with the updates left before it tries them again
+    pub avoided_targets: &'a [((usize, usize), u32)],
+    /// State of the mission
+    pub station: StationSummary,
+}
+
+impl RobotContext<'_> {
+    /// Whether the robot gave `target` up lately (unreachable, blocked, oscillating)
+    pub fn is_avoided(&self, target: (usize, usize)) -> bool {
+        self.avoided_targets.iter().any(|&(avoided, _)| avoided == target)
+    }
+}
+
+/// Next step chosen by a brain, carried out by `Robot::update`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RobotAction {
+    /// Walk one step towards a tile; an unreachable tile is avoided for a while
+    Move((usize, usize)),
+    /// Harvest the resource under the robot, if it collects this kind
+    Collect,
+    /// Head back to the home station
+    ReturnHome,
+    /// Stay in place for this update
+    Wait,
+    /// Built-in frontier planner: targets reserved with the station, sectors, scans
+    Explore,
+}
+
+/// Exploration policy of a robot
+pub trait RobotBrain: Send {
+    /// Name reported to Earth (see `RobotData::brain`)
+    fn name(&self) -> &str;
+
+    /// Chooses what the robot does during this update
+    fn decide(&mut self, ctx: RobotContext) -> RobotAction;
+}
+
+/// The robots' original behavior: the built-in frontier planner, coordinated with the station
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultBrain;
+
+impl RobotBrain for DefaultBrain {
+    fn name(&self) -> &str {
+        "default"
+    }
+
+    fn decide(&mut self, _ctx: RobotContext) -> RobotAction {
+        RobotAction::Explore
+    }
+}
+
+/// Sweeps the map in horizontal lanes as tall as the robot's field of view,
+/// left to right on even lanes and right to left on odd ones.
+///
+/// The next target is the first frontier tile in that order; once no
+/// frontier is left, the robot goes home.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::brain::{RobotAction, RobotBrain, RobotContext, StationSummary, SweepBrain};
+/// # use ereea::station::Station;
+/// # use ereea::types::RobotType;
+/// # use std::collections::HashSet;
+/// let memory = Station::new().global_memory;
+/// // Vision 2: lanes of 5 rows, the second one (rows 5 to 9) is swept right to left
+/// let frontier: HashSet<_> = [(3, 7), (12, 6), (18, 14)].into_iter().collect();
+/// let mut ctx = RobotContext {
+///     robot_type: RobotType::Explorer,
+///     position: (0, 0),
+///     home: (0, 0),
+///     energy: 80.0,
+///     max_energy: 80.0,
+///     vision_range: 2,
+///     memory: &memory,
+///     frontier: &frontier,
+///     avoided_targets: &[],
+///     station: StationSummary { current_time: 1, exploration_percentage: 10.0, energy_reserves: 100 },
+/// };
+/// assert_eq!(SweepBrain.decide(ctx), RobotAction::Move((12, 6)));
+///
+/// let avoided = [((12, 6), 50)];
+/// ctx.avoided_targets = &avoided;
+/// assert_eq!(SweepBrain.decide(ctx), RobotAction::Move((3, 7)));
+///
+/// let empty = HashSet::new();
+/// ctx.frontier = &empty;
+/// assert_eq!(SweepBrain.decide(ctx), RobotAction::ReturnHome);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SweepBrain;
+
+impl RobotBrain for SweepBrain {
+    fn name(&self) -> &str {
+        "sweep"
+    }
+
+    fn decide(&mut self, ctx: RobotContext) -> RobotAction {
+        let lane_height = (2 * ctx.vision_range.max(0) + 1) as usize;
+        // NOTE - Position of a tile along the sweep: lane, then column in the lane's direction
+        let sweep_order = |&(x, y): &(usize, usize)| {
+            let lane = y / lane_height;
+            let column = if lane.is_multiple_of(2) { x } else { MAP_SIZE - 1 - x };
+            (lane, column, y)
+        };
+
+        ctx.frontier.iter()
+            .copied()
+            .filter(|&tile| !ctx.is_avoided(tile))
+            .min_by_key(sweep_order)
+            .map_or(RobotAction::ReturnHome, RobotAction::Move)
+    }
+}
+
+impl BrainKind {
+    /// Brain kind named `"default"` or `"sweep"`, as in `RobotBrain::name`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "default" => Some(BrainKind::Default),
+            "sweep" => Some(BrainKind::Sweep),
+            _ => None,
+        }
+    }
+
+    /// A new brain of this kind
+    pub fn build(self) -> Box<dyn RobotBrain> {
+        match self {
+            BrainKind::Default => Box::new(DefaultBrain),
+            BrainKind::Sweep => Box::new(SweepBrain),
+        }
+    }
+}
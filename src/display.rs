@@ -5,15 +5,81 @@ use crossterm::{
     cursor::MoveTo,
     style::{Color, SetForegroundColor},
 };
-use crate::types::{TileType, MAP_SIZE, RobotType, RobotMode};
+use crate::types::{Inventory, TileType, MAP_SIZE, RobotType, RobotMode};
 use crate::map::Map;
 use crate::robot::Robot;
 use crate::station::Station;
+use unicode_width::UnicodeWidthStr;
+
+/// Number of terminal columns occupied by one map tile
+pub const TILE_WIDTH: usize = 2;
+
+/// Returns the map glyph of a robot type.
+/// 
+/// Every glyph is a single code point rendered 2 columns wide. Sequences
+/// with a variation selector (such as "⛏️") are avoided because terminals
+/// disagree on their width and shift the rest of the map row.
+pub fn robot_glyph(robot_type: RobotType) -> &'static str {
+    match robot_type {
+        RobotType::Explorer => "🤖",
+        RobotType::EnergyCollector => "🔋",
+        RobotType::MineralCollector => "🔨",
+        RobotType::ScientificCollector => "🧪",
+        RobotType::Repairer => "🔧",
+        RobotType::Relay => "📡",
+    }
+}
+
+/// Returns the ANSI color index of a robot type, shared by every interface.
+pub fn robot_color(robot_type: RobotType) -> u8 {
+    match robot_type {
+        RobotType::Explorer => 9,              // Rouge vif
+        RobotType::EnergyCollector => 10,      // Vert vif
+        RobotType::MineralCollector => 13,     // Magenta vif
+        RobotType::ScientificCollector => 12,  // Bleu vif
+        RobotType::Repairer => 14,             // Cyan vif
+        RobotType::Relay => 11,                // Jaune vif
+    }
+}
+
+/// Pads a glyph with spaces so it fills exactly `TILE_WIDTH` columns.
+/// 
+/// Map glyphs are either 1 column wide (like "·") or 2 columns wide (emoji);
+/// the padding is computed from the actual display width so that no row
+/// desyncs whatever glyph ends up on it.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use ereea::display::{robot_glyph, tile_glyph, TILE_WIDTH};
+/// use ereea::types::RobotType;
+/// use unicode_width::UnicodeWidthStr;
+/// 
+/// // Every theme glyph is single-cell or double-cell, never wider
+/// let terrain = ["·", "🧱", "💎", "⭐", "🔬", "❓", "🏠"];
+/// let robots = [RobotType::Explorer, RobotType::EnergyCollector,
+///               RobotType::MineralCollector, RobotType::ScientificCollector, RobotType::Repairer];
+/// 
+/// assert_eq!("·".width(), 1);
+/// for glyph in terrain.into_iter().skip(1).chain(robots.map(robot_glyph)) {
+///     assert_eq!(glyph.width(), 2, "{}", glyph);
+///     assert_eq!(glyph.chars().count(), 1, "{} must be a single code point", glyph);
+/// }
+/// 
+/// // Once padded, every tile occupies exactly TILE_WIDTH columns
+/// for glyph in terrain.into_iter().chain(robots.map(robot_glyph)) {
+///     assert_eq!(tile_glyph(glyph).width(), TILE_WIDTH);
+/// }
+/// ```
+pub fn tile_glyph(glyph: &str) -> String {
+    let padding = TILE_WIDTH.saturating_sub(glyph.width());
+    format!("{}{}", glyph, " ".repeat(padding))
+}
 
 pub struct Display;
 
 impl Display {
-    pub fn render(map: &Map, station: &Station, robots: &Vec<Robot>) -> Result<()> {
+    pub fn render(map: &Map, station: &Station, robots: &[Robot]) -> Result<()> {
         let mut stdout = stdout();
         
         // NOTE - Clear the screen
@@ -22,7 +88,7 @@ impl Display {
         // NOTE - Draw border around the map
         let map_top = 0;
         let map_left = 0;
-        let map_width = MAP_SIZE as u16 * 2;
+        let map_width = (MAP_SIZE * TILE_WIDTH) as u16;
 
         // NOTE - Draw top border
         stdout.execute(MoveTo(map_left, map_top))?;
@@ -39,14 +105,14 @@ impl Display {
                 // NOTE - Check if a robot is on this tile
                 let robot_here = robots.iter().find(|r| r.x == x && r.y == y);
                 
-                if x == map.station_x && y == map.station_y {
+                if map.is_station(x, y) {
                     // NOTE - Draw station
                     stdout.execute(SetForegroundColor(Color::Yellow))?;
-                    print!("🏠");
+                    print!("{}", tile_glyph("🏠"));
                 } else if let Some(robot) = robot_here {
                     // NOTE - Draw robot
                     stdout.execute(SetForegroundColor(Color::AnsiValue(robot.get_display_color())))?;
-                    print!("{}", robot.get_display_char());
+                    print!("{}", tile_glyph(robot.get_display_char()));
                 } else {
                     // NOTE - Draw terrain/resource or unexplored
                     let base_color = match map.get_tile(x, y) {
@@ -56,19 +122,20 @@ impl Display {
                         TileType::Mineral => Color::Magenta,
                         TileType::Scientific => Color::Blue,
                     };
-                    let is_explored_by_station = station.global_memory[y][x].explored;
+                    let is_explored_by_station = station.global_memory[(x, y)].explored;
                     if is_explored_by_station {
                         stdout.execute(SetForegroundColor(base_color))?;
-                        match map.get_tile(x, y) {
-                            TileType::Empty => print!("· "),
-                            TileType::Obstacle => print!("🧱"),
-                            TileType::Energy => print!("💎"),
-                            TileType::Mineral => print!("⭐"),
-                            TileType::Scientific => print!("🔬"),
-                        }
+                        let glyph = match map.get_tile(x, y) {
+                            TileType::Empty => "·",
+                            TileType::Obstacle => "🧱",
+                            TileType::Energy => "💎",
+                            TileType::Mineral => "⭐",
+                            TileType::Scientific => "🔬",
+                        };
+                        print!("{}", tile_glyph(glyph));
                     } else {
                         stdout.execute(SetForegroundColor(Color::DarkGrey))?;
-                        print!("❓");
+                        print!("{}", tile_glyph("❓"));
                     }
                 }
             }
@@ -95,7 +162,7 @@ impl Display {
             station.collected_scientific_data,
             station.conflict_count
         );
-        println!("Statut: {}", station.get_status());
+        println!("Statut: {}", station.get_status(map, robots));
 
         // NOTE - Display robot information
         let robots_y = info_y + 4;
@@ -108,19 +175,26 @@ impl Display {
             let robot_type = match robot.robot_type {
                 RobotType::Explorer => "🤖 Explorateur",
                 RobotType::EnergyCollector => "🔋 Collecteur d'énergie",
-                RobotType::MineralCollector => "⛏️  Collecteur de minerais",
+                RobotType::MineralCollector => "🔨 Collecteur de minerais",
                 RobotType::ScientificCollector => "🧪 Collecteur scientifique",
+                RobotType::Repairer => "🔧 Réparateur",
+                RobotType::Relay => "📡 Relais",
             };
             let mode = match robot.mode {
                 RobotMode::Exploring => "Exploration",
                 RobotMode::Collecting => "Collecte",
                 RobotMode::ReturnToStation => "Retour",
                 RobotMode::Idle => "Inactif",
+                RobotMode::Stranded => "En détresse",
+                RobotMode::Rescuing => "Secours",
+                RobotMode::Broken => "En panne",
+                RobotMode::Charging => "En charge",
+                RobotMode::Scanning => "Balayage",
             };
             println!(
-                "Robot #{}: {:<25} | Pos: ({:>2},{:>2}) | Énergie: {:>5.1}/{:<5.1} | Mode: {:<10} | Min: {:>2} | Sci: {:>2} | Exploré: {:>5.1}%",
+                "Robot #{}: {:<25} | Pos: ({:>2},{:>2}) | Énergie: {:>5.1}/{:<5.1} | Mode: {:<10} | Éner: {:>2} | Min: {:>2} | Sci: {:>2} | Exploré: {:>5.1}%",
                 robot.id, robot_type, robot.x, robot.y, robot.energy, robot.max_energy, 
-                mode, robot.minerals, robot.scientific_data, robot.get_exploration_percentage()
+                mode, robot.inventory.energy, robot.inventory.minerals, robot.inventory.science, robot.get_exploration_percentage()
             );
         }
 
@@ -136,9 +210,11 @@ impl Display {
         stdout.execute(SetForegroundColor(Color::AnsiValue(10)))?;
         print!("🔋 = Collecteur d'énergie   ");
         stdout.execute(SetForegroundColor(Color::AnsiValue(13)))?;
-        print!("⛏️ = Collecteur de minerais   ");
+        print!("🔨 = Collecteur de minerais   ");
         stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
-        println!("🧪 = Collecteur scientifique");
+        print!("🧪 = Collecteur scientifique   ");
+        stdout.execute(SetForegroundColor(Color::AnsiValue(14)))?;
+        println!("🔧 = Réparateur");
         stdout.execute(SetForegroundColor(Color::Green))?;
         print!("💎 = Énergie   ");
         stdout.execute(SetForegroundColor(Color::Magenta))?;
@@ -154,7 +230,7 @@ impl Display {
         Ok(())
     }
 
-    pub fn render_mission_complete(_map: &Map, station: &Station, robots: &Vec<Robot>) -> Result<()> {
+    pub fn render_mission_complete(_map: &Map, station: &Station, robots: &[Robot]) -> Result<()> {
         let mut stdout = stdout();
         
         // NOTE - Clear the screen for mission complete
@@ -209,7 +285,13 @@ impl Display {
         println!("💎 Minerais collectés: {}", station.collected_minerals);
         
         stdout.execute(MoveTo(center_x + 8, center_y + message_lines.len() as u16 + 6))?;
-        println!("🧪 Données scientifiques: {}", station.collected_scientific_data);
+        let on_board: Inventory = robots.iter().map(|robot| robot.inventory).sum();
+        if on_board.is_empty() {
+            println!("🧪 Données scientifiques: {}", station.collected_scientific_data);
+        } else {
+            println!("🧪 Données scientifiques: {} | 📦 Encore à bord: {} énergie, {} minerais, {} données",
+                     station.collected_scientific_data, on_board.energy, on_board.minerals, on_board.science);
+        }
         
         stdout.execute(MoveTo(center_x + 8, center_y + message_lines.len() as u16 + 7))?;
         println!("🤖 Robots déployés: {}", robots.len());
@@ -228,11 +310,13 @@ impl Display {
         stdout.execute(SetForegroundColor(Color::AnsiValue(10)))?;
         print!("🔋 Collecteurs d'énergie   ");
         stdout.execute(SetForegroundColor(Color::AnsiValue(13)))?;
-        println!("⛏️  Collecteurs de minerais");
+        println!("🔨 Collecteurs de minerais");
         
         stdout.execute(MoveTo(center_x + 10, center_y + message_lines.len() as u16 + 12))?;
         stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
         print!("🧪 Collecteurs scientifiques   ");
+        stdout.execute(SetForegroundColor(Color::AnsiValue(14)))?;
+        print!("🔧 Réparateurs   ");
         stdout.execute(SetForegroundColor(Color::White))?;
         println!("- Tous revenus sains et saufs!");
         
@@ -248,7 +332,7 @@ impl Display {
         stdout.execute(SetForegroundColor(Color::AnsiValue(10)))?;
         print!("🔋 ");
         stdout.execute(SetForegroundColor(Color::AnsiValue(13)))?;
-        print!("⛏️  ");
+        print!("🔨 ");
         stdout.execute(SetForegroundColor(Color::AnsiValue(12)))?;
         print!("🧪 ");
         stdout.execute(SetForegroundColor(Color::Yellow))?;
@@ -0,0 +1,92 @@
+//! # Energy Model Module
+//!
+//! Defines how much energy robots spend, so that experiments can swap the
+//! energy economy without touching robot logic. Every robot holds a shared
+//! `EnergyModel` (`Robot::energy_model`, `DefaultEnergyModel` unless told
+//! otherwise) and asks it, given its `RobotConfig`, for the cost of staying
+//! powered for one cycle and of each step it takes. Robot-specific modifiers (load carried, efficiency
+//! modules) are applied by the robot on top of the model's costs.
+
+use crate::robot::{movement_cost, RobotConfig};
+use crate::types::TileType;
+
+/// Energy spent by robots, per cycle and per step.
+///
+/// Costs are those of an empty robot without modules. `move_cost` is also
+/// what robots use to estimate their trip home, so a model changes both
+/// consumption and return decisions.
+///
+/// # Examples
+///
+/// A model where moving is free: a robot only pays its metabolism.
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// # use ereea::energy::{DefaultEnergyModel, EnergyModel};
+/// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+/// # use ereea::robot::RobotConfig;
+/// # use ereea::types::{RobotMode, RobotType, TileType};
+/// struct FreeMoves;
+///
+/// impl EnergyModel for FreeMoves {
+///     fn metabolism_cost(&self, config: &RobotConfig) -> f32 {
+///         DefaultEnergyModel.metabolism_cost(config)
+///     }
+///     fn move_cost(&self, _config: &RobotConfig, _distance: f32, _tile: &TileType) -> f32 {
+///         0.0
+///     }
+/// }
+///
+/// let mut map = Map::open();
+/// (map.station_x, map.station_y) = (0, 5);
+/// let mut station = Station::new();
+/// let mut occupancy = OccupancyGrid::new(0, 5);
+///
+/// let mut robot = Robot::new(0, 5, RobotType::Explorer);
+/// (robot.x, robot.mode) = (8, RobotMode::ReturnToStation);
+/// robot.energy_model = Arc::new(FreeMoves);
+/// robot.update(&mut map, &mut station, &mut occupancy);
+/// assert_eq!(robot.x, 7);
+/// assert!((robot.energy - (80.0 - 0.1)).abs() < 1e-4);
+/// ```
+pub trait EnergyModel: Send + Sync {
+    /// Energy spent by a robot with this configuration to stay powered for one cycle
+    fn metabolism_cost(&self, config: &RobotConfig) -> f32;
+
+    /// Energy spent by a robot with this configuration to move `distance` tiles onto `tile`
+    fn move_cost(&self, config: &RobotConfig, distance: f32, tile: &TileType) -> f32;
+}
+
+/// The mission's historical energy economy.
+///
+/// The metabolism of the robot's configuration, and its step cost (by
+/// default 0.3 for explorers and relays, 0.4 for energy collectors and
+/// repairers, 0.5 for mineral collectors, 0.6 for scientific collectors)
+/// scaled by the terrain multiplier `robot::movement_cost`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::energy::{DefaultEnergyModel, EnergyModel};
+/// # use ereea::robot::RobotConfig;
+/// # use ereea::types::{RobotType, TileType};
+/// let model = DefaultEnergyModel;
+/// let collector = RobotConfig::for_type(RobotType::MineralCollector);
+/// let explorer = RobotConfig::for_type(RobotType::Explorer);
+/// assert_eq!(model.metabolism_cost(&collector), 0.1);
+/// assert!((model.move_cost(&collector, 1.0, &TileType::Empty) - 0.5).abs() < 1e-6);
+/// assert!((model.move_cost(&explorer, 2.0, &TileType::Mineral) - 0.72).abs() < 1e-6);
+/// assert!(model.move_cost(&explorer, 1.0, &TileType::Obstacle).is_infinite());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DefaultEnergyModel;
+
+impl EnergyModel for DefaultEnergyModel {
+    fn metabolism_cost(&self, config: &RobotConfig) -> f32 {
+        config.metabolism
+    }
+
+    fn move_cost(&self, config: &RobotConfig, distance: f32, tile: &TileType) -> f32 {
+        config.move_cost * distance * movement_cost(tile)
+    }
+}
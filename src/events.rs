@@ -0,0 +1,164 @@
+//! # Mission Events Module
+//!
+//! What robots and the station report while the simulation runs. The library
+//! never prints: `Robot::update` returns the events of the robot's update and
+//! `Station::take_events` those of the station, and the caller decides where
+//! they go (server log, broadcast to Earth as `MissionEvent`s, local display).
+//! `Display` gives the log line of each event.
+
+use crate::types::{GiveUpReason, RobotMode, StationUpgrade, TileType};
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+/// Something a robot did or went through, as seen by the mission log.
+/// 
+/// # Examples
+/// 
+/// A mineral collector that knows no mineral parks at its station and says why:
+/// 
+/// ```rust
+/// # use ereea::events::RobotEvent;
+/// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+/// # use ereea::types::{CollectorPolicy, RobotType, TileType};
+/// let mut map = Map::open();
+/// (map.station_x, map.station_y) = (0, 0);
+/// let mut station = Station::new();
+/// station.collector_policy = CollectorPolicy::IMMEDIATE;
+/// let mut occupancy = OccupancyGrid::new(0, 0);
+/// let mut robot = Robot::new_with_memory(0, 0, RobotType::MineralCollector, 1, 0, 0, station.global_memory.clone());
+/// 
+/// let events: Vec<RobotEvent> = (0..20)
+///     .flat_map(|_| robot.update(&mut map, &mut station, &mut occupancy))
+///     .filter(|event| matches!(event, RobotEvent::Idle { .. }))
+///     .collect();
+/// // Reported once, not at each search of the station memory
+/// assert_eq!(events, vec![RobotEvent::Idle { robot_id: 1, resource: TileType::Mineral }]);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum RobotEvent {
+    /// One unit harvested from the deposit at (x, y); `left` units remain in it
+    Collected { robot_id: usize, resource: TileType, x: usize, y: usize, left: u32 },
+    /// Mode at the end of an update differs from the mode at its start
+    ModeChanged { robot_id: usize, from: RobotMode, to: RobotMode },
+    /// An explorer mapped the whole planet (reported once)
+    ExplorationComplete { robot_id: usize },
+    /// Energy only covers the trip home anymore: the robot heads back from (x, y)
+    LowEnergy { robot_id: usize, x: usize, y: usize },
+    /// The robot docked at the station at (x, y)
+    Arrived { robot_id: usize, x: usize, y: usize },
+    /// Not enough energy to make it home: waiting for a repairer at (x, y)
+    Stranded { robot_id: usize, x: usize, y: usize },
+    /// Taken out of service by the station, which recovered `refund` minerals
+    Decommissioned { robot_id: usize, refund: u32 },
+    /// A collector knows no `resource` left to collect: it stays idle at its station
+    Idle { robot_id: usize, resource: TileType },
+    /// Stood still at (x, y) for `ticks` cycles, or made no progress: the target is given up
+    Stuck { robot_id: usize, x: usize, y: usize, ticks: u32 },
+    /// A collector gave up the resource at (x, y) it was heading to
+    TargetAbandoned { robot_id: usize, x: usize, y: usize, reason: GiveUpReason },
+    /// No walkable way is left between (x, y) and the station: the robot is lost (see `Robot::is_lost`)
+    CutOff { robot_id: usize, x: usize, y: usize },
+    /// Broke down at (x, y); a severe breakdown waits for a repairer (see `Robot::roll_malfunction`)
+    BrokeDown { robot_id: usize, x: usize, y: usize, severe: bool },
+    /// Back in working order at (x, y), heading home for a check-up
+    Repaired { robot_id: usize, x: usize, y: usize },
+    /// A stranded robot received `energy` units brought by a repairer
+    Rescued { robot_id: usize, energy: f32 },
+    /// Stranded at (x, y) with no repairer left, brought back by `Robot::emergency_recover`
+    Repatriated { robot_id: usize, x: usize, y: usize },
+    /// Any other step worth a log line (task, charging, sector, scan...), without effect on the mission
+    Notice { robot_id: usize, message: String },
+    /// The station called the whole fleet home (see `Station::recall_all`)
+    RecallStarted,
+    /// The last recalled robot docked: `robots` robots wait at their station
+    FleetDocked { robots: usize },
+    /// The recall is over, robots go back to work (see `Station::resume_operations`)
+    OperationsResumed,
+    /// The station bought one of its own upgrades (see `Station::purchase_upgrade`)
+    StationUpgraded { upgrade: StationUpgrade },
+}
+
+impl RobotEvent {
+    /// Robot the event is about (0 for fleet-wide and station events)
+    pub fn robot_id(&self) -> usize {
+        match self {
+            RobotEvent::Collected { robot_id, .. }
+            | RobotEvent::ModeChanged { robot_id, .. }
+            | RobotEvent::ExplorationComplete { robot_id }
+            | RobotEvent::LowEnergy { robot_id, .. }
+            | RobotEvent::Arrived { robot_id, .. }
+            | RobotEvent::Stranded { robot_id, .. }
+            | RobotEvent::Decommissioned { robot_id, .. }
+            | RobotEvent::Idle { robot_id, .. }
+            | RobotEvent::Stuck { robot_id, .. }
+            | RobotEvent::TargetAbandoned { robot_id, .. }
+            | RobotEvent::CutOff { robot_id, .. }
+            | RobotEvent::BrokeDown { robot_id, .. }
+            | RobotEvent::Repaired { robot_id, .. }
+            | RobotEvent::Rescued { robot_id, .. }
+            | RobotEvent::Repatriated { robot_id, .. }
+            | RobotEvent::Notice { robot_id, .. } => *robot_id,
+            RobotEvent::RecallStarted | RobotEvent::FleetDocked { .. } | RobotEvent::OperationsResumed
+            | RobotEvent::StationUpgraded { .. } => 0,
+        }
+    }
+}
+
+impl fmt::Display for RobotEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RobotEvent::Collected { robot_id, resource, x, y, left } => {
+                let (icon, what) = match resource {
+                    TileType::Energy => ("🔋", "de l'énergie"),
+                    TileType::Mineral => ("⛏️", "un minerai"),
+                    _ => ("🧪", "des données scientifiques"),
+                };
+                write!(f, "{} Robot #{} a collecté {} à ({}, {})", icon, robot_id, what, x, y)?;
+                if *left > 0 {
+                    write!(f, " (reste {})", left)?;
+                }
+                Ok(())
+            },
+            RobotEvent::ModeChanged { robot_id, from, to } => write!(f, "🔄 Robot #{} : {:?} → {:?}", robot_id, from, to),
+            RobotEvent::ExplorationComplete { robot_id } => write!(f, "🌍 EXPLORATION DE L'EXOPLANÈTE TERMINÉE ! 🌍 Robot explorateur #{} a cartographié 100% de la planète.", robot_id),
+            RobotEvent::LowEnergy { robot_id, x, y } => write!(f, "🪫 Robot #{} : énergie faible en ({}, {}), retour à la station", robot_id, x, y),
+            RobotEvent::Arrived { robot_id, x, y } => write!(f, "🏠 Robot #{} à quai à la station ({}, {})", robot_id, x, y),
+            RobotEvent::Stranded { robot_id, x, y } => write!(f, "🆘 Robot #{} bloqué à ({}, {}) : énergie insuffisante pour rentrer", robot_id, x, y),
+            RobotEvent::Decommissioned { robot_id, refund } => write!(f, "♻️ Robot #{} mis hors service ({} minerais récupérés)", robot_id, refund),
+            RobotEvent::Idle { robot_id, resource } => {
+                write!(f, "🏁 Robot collecteur #{} : aucune ressource {} connue, reste au repos à la station", robot_id, match resource {
+                    TileType::Energy => "d'énergie",
+                    TileType::Mineral => "minérale",
+                    _ => "scientifique",
+                })
+            },
+            RobotEvent::Stuck { robot_id, x, y, ticks } => write!(f, "⚠️ Robot #{} bloqué en ({}, {}) depuis {} cycles : abandon de sa cible", robot_id, x, y, ticks),
+            RobotEvent::TargetAbandoned { robot_id, x, y, reason: GiveUpReason::Depleted } => {
+                write!(f, "🔀 Robot #{} : la ressource visée en ({}, {}) a été épuisée par un autre robot, changement de cible", robot_id, x, y)
+            },
+            RobotEvent::TargetAbandoned { robot_id, x, y, reason } => write!(f, "🔙 Robot #{} renonce à la ressource ({}, {}) : {}", robot_id, x, y, reason),
+            RobotEvent::CutOff { robot_id, x, y } => write!(f, "📵 Robot #{} coupé de la station en ({}, {}): plus aucun passage praticable", robot_id, x, y),
+            RobotEvent::BrokeDown { robot_id, x, y, severe } => {
+                write!(f, "💥 Robot #{} en panne en ({}, {}) : {}", robot_id, x, y,
+                       if *severe { "intervention d'un réparateur requise" } else { "autodiagnostic en cours" })
+            },
+            RobotEvent::Repaired { robot_id, x, y } => write!(f, "✅ Robot #{} réparé en ({}, {}), retour à la station pour contrôle", robot_id, x, y),
+            RobotEvent::Rescued { robot_id, energy } => write!(f, "🔧 Robot #{} ravitaillé de {:.1} unités d'énergie", robot_id, energy),
+            RobotEvent::Repatriated { robot_id, x, y } => write!(f, "🚨 URGENCE: Robot {} bloqué en ({}, {}), rapatriement!", robot_id, x, y),
+            RobotEvent::Notice { message, .. } => write!(f, "{}", message),
+            RobotEvent::RecallStarted => write!(f, "📢 Rappel général : tous les robots rentrent à la station"),
+            RobotEvent::FleetDocked { robots } => write!(f, "🏠 Rappel terminé : {} robot(s) à quai, en attente de la reprise des opérations", robots),
+            RobotEvent::OperationsResumed => write!(f, "▶️ Reprise des opérations : les robots repartent au travail"),
+            RobotEvent::StationUpgraded { upgrade } => write!(f, "🏗️ Station améliorée : {}", upgrade),
+        }
+    }
+}
+
+/// NOTE - Event stamped with the station cycle it happened at, as broadcast to Earth
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MissionEvent {
+    /// Station time (`Station::current_time`) of the update that produced the event
+    pub cycle: u32,
+    /// What happened
+    pub event: RobotEvent,
+}
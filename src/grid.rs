@@ -0,0 +1,129 @@
+//! # Grid Module
+//!
+//! `Grid<T>` stores one value per map tile in a single flat vector, row
+//! after row (`y * width + x`), instead of a vector of rows: one allocation
+//! per grid, cheap to copy, and scanned in memory order. It backs the robots'
+//! memory (`Robot::memory`), the station's (`Station::global_memory`) and the
+//! tile sets of the exploration area and the reachability floods.
+//!
+//! Cells are indexed by `(x, y)`, like positions everywhere else.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use ereea::grid::Grid;
+//! let mut grid = Grid::new(3, 2, 0);
+//! grid[(2, 1)] = 7;
+//! *grid.get_mut(0, 1).unwrap() += 1;
+//!
+//! assert_eq!(grid.get(2, 1), Some(&7));
+//! assert_eq!(grid.get(3, 0), None);
+//! assert_eq!(grid.iter().sum::<i32>(), 8);
+//! assert_eq!(grid.iter_coords().filter(|&(_, &value)| value > 0).map(|(xy, _)| xy).collect::<Vec<_>>(),
+//!            vec![(0, 1), (2, 1)]);
+//! assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[0, 0, 0][..], &[1, 0, 7][..]]);
+//! ```
+
+use crate::types::MAP_SIZE;
+use std::ops::{Index, IndexMut};
+
+/// One value per tile of a `width` × `height` map, stored row after row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid holding the same value on every tile
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Grid of the size of the map (`MAP_SIZE` × `MAP_SIZE`) holding the same value on every tile
+    pub fn filled(value: T) -> Self {
+        Self::new(MAP_SIZE, MAP_SIZE, value)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid whose value on each tile `(x, y)` is given by `cell(x, y)`
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..width * height).map(|i| cell(i % width, i / width)).collect();
+        Self { width, height, cells }
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // NOTE - Position of a tile in `cells`, None outside the grid
+    fn offset(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Value on a tile, None outside the grid
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.offset(x, y).map(|i| &self.cells[i])
+    }
+
+    /// Mutable value on a tile, None outside the grid
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.offset(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// Values row after row
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Mutable values row after row
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Coordinates `(x, y)` and value of each tile, row after row
+    pub fn iter_coords(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, value)| ((i % width, i / width), value))
+    }
+
+    /// Coordinates `(x, y)` and mutable value of each tile, row after row
+    pub fn iter_coords_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(i, value)| ((i % width, i / width), value))
+    }
+
+    /// Rows from top to bottom, each one from left to right
+    pub fn rows(&self) -> std::slice::ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    // NOTE - Panics outside the grid, like slice indexing
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.offset(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!("case ({}, {}) hors de la grille {}x{}", x, y, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.offset(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("case ({}, {}) hors de la grille {}x{}", x, y, self.width, self.height),
+        }
+    }
+}
@@ -0,0 +1,560 @@
+//! # Knowledge Sharing Module
+//!
+//! Merges exploration memories, whether a robot synchronizes with the station
+//! (`Station::share_knowledge`) or two robots meet in the field
+//! (`sync_peers`). Both use the same rule (`merge_tile`): an unknown tile is
+//! simply learned, a tile known on both sides keeps the most recent
+//! observation (highest timestamp), and each overwritten tile counts as one
+//! resolved conflict.
+//!
+//! Relay robots extend the station's reach: a robot linked to the station
+//! through a chain of relays synchronizes remotely (`sync_through_relays`)
+//! without having to come back. With a radio (`Station::radio_range`), robots
+//! near a station or linked through relays exchange what was known a few
+//! cycles ago instead (`sync_by_radio`).
+
+use crate::grid::Grid;
+use crate::map::Map;
+use crate::robot::Robot;
+use crate::station::{Station, TerrainData};
+use crate::types::{Position, RobotMode, RobotType, TileType, MAP_SIZE};
+use std::sync::Arc;
+
+/// Default distance (in tiles, Chebyshev) at which two robots exchange their knowledge
+pub const PEER_SYNC_RADIUS: usize = 3;
+
+/// Default reach (in tiles, Chebyshev) of one hop of a relay chain
+pub const RELAY_RANGE: usize = 5;
+
+/// Cycles between two remote synchronizations over a relay chain
+pub const REMOTE_SYNC_INTERVAL: u32 = 10;
+
+/// Default cycles data takes to cross the radio link (see `sync_by_radio`)
+pub const RADIO_LATENCY: u32 = 5;
+
+/// Default cycles between two radio synchronizations
+pub const RADIO_SYNC_INTERVAL: u32 = 10;
+
+/// An exploration memory that can learn tiles from another one.
+///
+/// Implemented by robots and by the station, which both keep counters in
+/// step with their memory: writes must go through `learn_tile`.
+pub trait KnowledgeBase {
+    /// What the memory holds about a tile
+    fn known_tile(&self, x: usize, y: usize) -> &TerrainData;
+
+    /// Stores a tile in the memory, keeping its counters up to date
+    fn learn_tile(&mut self, x: usize, y: usize, data: TerrainData);
+}
+
+/// Tiles that count in an exploration percentage.
+///
+/// The whole map by default. Built from a map (`ExplorationArea::reachable`),
+/// only the tiles robots can walk to from the station count
+/// (`Map::is_reachable`): obstacles and pockets sealed off by obstacles can
+/// never be visited, and would keep exploration from ever completing. Robots
+/// and the station count explored tiles of their area as they learn them and
+/// only look for unknown tiles inside it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::knowledge::ExplorationArea;
+/// # use ereea::map::Map;
+/// # use ereea::types::{TileType, MAP_SIZE};
+/// let mut map = Map::open();
+/// map.set_tile(0, 0, TileType::Obstacle);
+///
+/// let whole_map = ExplorationArea::whole_map();
+/// let area = ExplorationArea::reachable(&map);
+/// assert!(whole_map.contains(0, 0));
+/// assert!(!area.contains(0, 0));
+/// assert_eq!((whole_map.size(), area.size()), (MAP_SIZE * MAP_SIZE, MAP_SIZE * MAP_SIZE - 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExplorationArea {
+    // NOTE - Counted tiles (None = the whole map)
+    tiles: Option<Arc<Grid<bool>>>,
+    // NOTE - Number of counted tiles
+    size: usize,
+}
+
+impl ExplorationArea {
+    /// Every tile of the map counts
+    pub fn whole_map() -> Self {
+        Self { tiles: None, size: MAP_SIZE * MAP_SIZE }
+    }
+    
+    /// Only the tiles reachable from the station count (see `Map::is_reachable`)
+    pub fn reachable(map: &Map) -> Self {
+        let tiles = Grid::from_fn(MAP_SIZE, MAP_SIZE, |x, y| map.is_reachable(x, y));
+        Self { tiles: Some(Arc::new(tiles)), size: map.reachable_tile_count() }
+    }
+    
+    /// Tells whether a tile counts
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.tiles.as_ref().is_none_or(|tiles| tiles[(x, y)])
+    }
+    
+    /// Number of tiles that count
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Default for ExplorationArea {
+    fn default() -> Self {
+        Self::whole_map()
+    }
+}
+
+/// Merges what a memory already knows about a tile with an incoming observation.
+///
+/// Returns the tile to keep and whether the incoming observation overwrote a
+/// known one (a resolved conflict):
+///
+/// - nothing known yet: the incoming tile is learned, no conflict;
+/// - incoming newer (higher timestamp): it replaces the known tile, one conflict;
+/// - incoming older: it is ignored;
+/// - same cycle: deposits can be emptied during a cycle but never refill within
+///   one, so an incoming `TileType::Empty` replaces a resource (one conflict);
+///   otherwise the known tile stays.
+///
+/// An unexplored incoming tile never changes anything.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::knowledge::merge_tile;
+/// # use ereea::station::TerrainData;
+/// # use ereea::types::{RobotType, TileType};
+/// let seen = |timestamp, robot_id, tile_type| TerrainData {
+///     explored: true, timestamp, robot_id, robot_type: RobotType::Explorer, tile_type,
+/// };
+/// let unexplored = TerrainData { explored: false, ..seen(0, 0, TileType::Empty) };
+/// let known = seen(10, 1, TileType::Mineral);
+///
+/// // Previously unexplored: learned, no conflict
+/// assert_eq!(merge_tile(&unexplored, &known), (known.clone(), false));
+///
+/// // Incoming newer: overwrites, conflict
+/// let newer = seen(15, 2, TileType::Empty);
+/// assert_eq!(merge_tile(&known, &newer), (newer.clone(), true));
+///
+/// // Incoming older: ignored
+/// assert_eq!(merge_tile(&known, &seen(5, 2, TileType::Empty)), (known.clone(), false));
+/// assert_eq!(merge_tile(&known, &unexplored), (known.clone(), false));
+///
+/// // Same cycle: the emptied deposit wins, whichever side it comes from
+/// let emptied = seen(10, 2, TileType::Empty);
+/// assert_eq!(merge_tile(&known, &emptied), (emptied.clone(), true));
+/// assert_eq!(merge_tile(&emptied, &known), (emptied.clone(), false));
+/// assert_eq!(merge_tile(&known, &seen(10, 2, TileType::Mineral)), (known.clone(), false));
+/// ```
+pub fn merge_tile(existing: &TerrainData, incoming: &TerrainData) -> (TerrainData, bool) {
+    if !incoming.explored {
+        return (existing.clone(), false);
+    }
+    if !existing.explored {
+        return (incoming.clone(), false);
+    }
+    
+    let overwrite = incoming.timestamp > existing.timestamp
+        || (incoming.timestamp == existing.timestamp
+            && incoming.tile_type == TileType::Empty
+            && existing.tile_type != TileType::Empty);
+    if overwrite {
+        (incoming.clone(), true)
+    } else {
+        (existing.clone(), false)
+    }
+}
+
+/// Merges every tile of `src` into `dst` and returns the number of conflicts resolved.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::knowledge::{merge_memory, KnowledgeBase};
+/// # use ereea::{map::Map, robot::Robot, station::Station, types::RobotType};
+/// let map = Map::new();
+/// let mut station = Station::new();
+/// let mut robot = Robot::new(10, 10, RobotType::Explorer);
+/// robot.update_memory(&map, &station);
+///
+/// // First merge: everything is new to the station, no conflict
+/// assert_eq!(merge_memory(&mut station, &robot), 0);
+/// assert_eq!(station.explored_count(), robot.explored_count());
+///
+/// // A fresher look at the same tiles overrides the older data
+/// station.tick();
+/// robot.update_memory(&map, &station);
+/// assert_eq!(merge_memory(&mut station, &robot), robot.explored_count());
+/// assert_eq!(station.known_tile(10, 10).timestamp, 1);
+/// ```
+pub fn merge_memory<D, S>(dst: &mut D, src: &S) -> usize
+where
+    D: KnowledgeBase + ?Sized,
+    S: KnowledgeBase + ?Sized,
+{
+    let all_tiles = (0..MAP_SIZE).flat_map(|y| (0..MAP_SIZE).map(move |x| (x, y)));
+    merge_tiles(dst, src, all_tiles)
+}
+
+/// Merges the given tiles of `src` into `dst` (tile by tile, see `merge_tile`).
+///
+/// Lets callers that know which tiles may have changed skip the full grid.
+pub fn merge_tiles<D, S, I>(dst: &mut D, src: &S, tiles: I) -> usize
+where
+    D: KnowledgeBase + ?Sized,
+    S: KnowledgeBase + ?Sized,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let mut conflicts = 0;
+    for (x, y) in tiles {
+        let theirs = src.known_tile(x, y);
+        let ours = dst.known_tile(x, y);
+        let learned = theirs.explored && !ours.explored;
+        let (merged, conflict) = merge_tile(ours, theirs);
+        if learned || conflict {
+            dst.learn_tile(x, y, merged);
+            conflicts += usize::from(conflict);
+        }
+    }
+    conflicts
+}
+
+/// Outcome of a peer-to-peer synchronization step (see `sync_peers`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PeerSyncStats {
+    /// Pairs of robots that taught each other something
+    pub exchanges: usize,
+    /// Conflicts resolved while merging their memories
+    pub conflicts: usize,
+}
+
+/// Lets every pair of robots within `radius` tiles merge their memories.
+///
+/// Only the tiles each robot learned since its last station visit are sent
+/// (`Robot::unsynced_tiles`), not the whole grid: the rest already came
+/// from the station. A radius of 0 disables the exchange.
+///
+/// # Examples
+///
+/// Two robots map opposite halves of the map, then meet in the middle:
+///
+/// ```rust
+/// # use ereea::knowledge::sync_peers;
+/// # use ereea::{map::Map, robot::Robot, station::Station, types::{RobotType, VisionModel}};
+/// let map = Map::new();
+/// let station = Station::new();
+/// let mut robots = vec![Robot::new(10, 10, RobotType::MineralCollector), Robot::new(10, 10, RobotType::MineralCollector)];
+/// robots[1].id = 1;
+/// for robot in robots.iter_mut() {
+///     robot.vision_model = VisionModel::Square;
+/// }
+///
+/// for y in (2..20).step_by(5) {
+///     for x in (2..10).step_by(5) {
+///         (robots[0].x, robots[0].y) = (x, y);
+///         robots[0].update_memory(&map, &station);
+///         (robots[1].x, robots[1].y) = (x + 10, y);
+///         robots[1].update_memory(&map, &station);
+///     }
+/// }
+/// assert_eq!(robots[0].get_exploration_percentage(), 50.0);
+/// assert_eq!(robots[1].get_exploration_percentage(), 50.0);
+///
+/// // Still too far apart
+/// (robots[0].x, robots[0].y) = (6, 10);
+/// (robots[1].x, robots[1].y) = (13, 10);
+/// assert_eq!(sync_peers(&mut robots, 3).exchanges, 0);
+///
+/// (robots[1].x, robots[1].y) = (9, 10);
+/// assert_eq!(sync_peers(&mut robots, 3).exchanges, 1);
+/// assert_eq!(robots[0].get_exploration_percentage(), 100.0);
+/// assert_eq!(robots[1].get_exploration_percentage(), 100.0);
+///
+/// // Nothing new to tell each other the next time
+/// assert_eq!(sync_peers(&mut robots, 3).exchanges, 0);
+/// ```
+pub fn sync_peers(robots: &mut [Robot], radius: usize) -> PeerSyncStats {
+    let mut stats = PeerSyncStats::default();
+    if radius == 0 {
+        return stats;
+    }
+
+    for i in 0..robots.len() {
+        let (head, tail) = robots.split_at_mut(i + 1);
+        let first = &mut head[i];
+        for second in tail.iter_mut() {
+            if first.position().chebyshev_distance(second.position()) > radius {
+                continue;
+            }
+
+            let known_before = (first.explored_count(), second.explored_count());
+            let first_news: Vec<(usize, usize)> = first.unsynced_tiles().iter().copied().collect();
+            let second_news: Vec<(usize, usize)> = second.unsynced_tiles().iter().copied().collect();
+            let conflicts = merge_tiles(second, &*first, first_news) + merge_tiles(first, &*second, second_news);
+
+            if conflicts > 0 || known_before != (first.explored_count(), second.explored_count()) {
+                stats.exchanges += 1;
+                stats.conflicts += conflicts;
+            }
+        }
+    }
+    stats
+}
+
+/// Tiles missing for `position` to be within `radius` of the nearest anchor (0 = linked).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::knowledge::link_gap;
+/// # use ereea::types::Position;
+/// let station = Position::new(10, 10);
+/// assert_eq!(link_gap(Position::new(13, 12), &[station], 5), 0);
+/// assert_eq!(link_gap(Position::new(18, 10), &[station], 5), 3);
+/// ```
+pub fn link_gap(position: Position, anchors: &[Position], radius: usize) -> usize {
+    anchors.iter()
+        .map(|&anchor| anchor.chebyshev_distance(position).saturating_sub(radius))
+        .min()
+        .unwrap_or(MAP_SIZE)
+}
+
+// NOTE - Relays able to pass data on (a stranded or broken relay is silent)
+fn is_working_relay(robot: &Robot) -> bool {
+    robot.robot_type == RobotType::Relay && !matches!(robot.mode, RobotMode::Stranded | RobotMode::Broken)
+}
+
+// NOTE - Stations, then every working relay within `radius` of a linked point (one relay may be left out)
+fn linked_anchors(robots: &[Robot], stations: &[(usize, usize)], radius: usize, excluded: Option<usize>) -> (Vec<Position>, Vec<bool>) {
+    let mut anchors: Vec<Position> = stations.iter().map(|&station| station.into()).collect();
+    let mut linked = vec![false; robots.len()];
+    
+    // Chaque passe rattache les relais à portée d'un point déjà relié
+    let mut extended = true;
+    while extended {
+        extended = false;
+        for (i, robot) in robots.iter().enumerate() {
+            if !linked[i] && Some(i) != excluded && is_working_relay(robot)
+                && link_gap(robot.position(), &anchors, radius) == 0 {
+                linked[i] = true;
+                anchors.push(robot.position());
+                extended = true;
+            }
+        }
+    }
+    (anchors, linked)
+}
+
+/// Relays pick their post from the current fleet positions (see `Robot::choose_relay_post`).
+///
+/// The fleet is every other robot working away from the stations; each
+/// relay links to the stations and to the other relays already linked.
+pub fn assign_relay_posts(robots: &mut [Robot], map: &Map, radius: usize) {
+    let stations = map.stations();
+    let fleet: Vec<Position> = robots.iter()
+        .filter(|robot| robot.robot_type != RobotType::Relay && !map.is_station(robot.x, robot.y))
+        .map(|robot| robot.position())
+        .collect();
+    
+    for i in 0..robots.len() {
+        if robots[i].robot_type == RobotType::Relay {
+            let (anchors, _) = linked_anchors(robots, &stations, radius, Some(i));
+            robots[i].choose_relay_post(map, &fleet, &anchors, radius);
+        }
+    }
+}
+
+/// Synchronizes with the station every robot linked to it through relays.
+///
+/// A robot is linked when a chain robot → relays → station exists, each hop
+/// within `radius` tiles; working relays linked to a station are linked too.
+/// `Robot::relay_linked` is refreshed at each call, but the exchange itself
+/// (`Station::share_knowledge_remotely`) only happens every
+/// `REMOTE_SYNC_INTERVAL` cycles, and never for docked robots, which already
+/// sync at the station. With a radio (`Station::radio_range` above 0), linked
+/// robots sync through `sync_by_radio` instead, with its latency. Returns
+/// the number of robots synchronized.
+///
+/// # Examples
+///
+/// An explorer maps the far corner of the map while a relay links it to the
+/// station: the station learns what it sees without it ever coming back.
+///
+/// ```rust
+/// # use ereea::knowledge::{assign_relay_posts, sync_through_relays, REMOTE_SYNC_INTERVAL};
+/// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+/// # use ereea::types::{RobotMode, RobotType};
+/// let mut map = Map::open();
+/// let mut station = Station::new();
+/// let mut robots = vec![
+///     Robot::new_with_memory(18, 10, RobotType::Explorer, 1, 10, 10, station.global_memory.clone()),
+///     Robot::new_with_memory(10, 10, RobotType::Relay, 2, 10, 10, station.global_memory.clone()),
+/// ];
+///
+/// // Out of reach: nothing goes through
+/// station.tick();
+/// robots[0].update_memory(&map, &station);
+/// assert_eq!(sync_through_relays(&mut robots, &mut station, &map, 5), 0);
+/// assert!(!robots[0].relay_linked);
+///
+/// // The relay heads out, the explorer sweeps the far column
+/// for tick in 0..60 {
+///     station.tick();
+///     assign_relay_posts(&mut robots, &map, 5);
+///     let mut occupancy = OccupancyGrid::from_map(&robots, &map);
+///     robots[1].update(&mut map, &mut station, &mut occupancy);
+///     (robots[0].x, robots[0].y) = (18, 10 + (tick / 10) % 10);
+///     robots[0].update_memory(&map, &station);
+///     sync_through_relays(&mut robots, &mut station, &map, 5);
+///     assert_ne!((robots[0].x, robots[0].y), (10, 10));
+/// }
+///
+/// assert!(robots[0].relay_linked);
+/// assert_eq!(robots[1].mode, RobotMode::Idle);
+/// assert!(station.global_memory[(19, 19)].explored);
+/// assert!(robots[0].last_sync_time >= 60 - REMOTE_SYNC_INTERVAL);
+/// ```
+pub fn sync_through_relays(robots: &mut [Robot], station: &mut Station, map: &Map, radius: usize) -> usize {
+    if radius == 0 {
+        robots.iter_mut().for_each(|robot| robot.relay_linked = false);
+        return 0;
+    }
+    
+    let (anchors, linked_relays) = linked_anchors(robots, &map.stations(), radius, None);
+    let relay_anchors: Vec<Position> = anchors[map.stations().len()..].to_vec();
+    let due = station.current_time.is_multiple_of(REMOTE_SYNC_INTERVAL);
+    
+    let mut synced = 0;
+    for (robot, relay_linked) in robots.iter_mut().zip(linked_relays) {
+        robot.relay_linked = relay_linked
+            || (robot.robot_type != RobotType::Relay && link_gap(robot.position(), &relay_anchors, radius) == 0);
+        
+        let docked = (robot.x, robot.y) == (robot.home_station_x, robot.home_station_y);
+        if robot.relay_linked && due && !docked && station.radio_range == 0 {
+            station.share_knowledge_remotely(robot);
+            robot.last_sync_time = station.current_time;
+            synced += 1;
+        }
+    }
+    synced
+}
+
+/// Delayed synchronization over the radio, every `Station::radio_sync_interval` cycles.
+///
+/// Robots within `Station::radio_range` tiles of a station, or linked to it
+/// through relays (`Robot::relay_linked`, see `sync_through_relays`), exchange
+/// what either side knew `Station::radio_latency` cycles ago
+/// (`Station::share_knowledge_by_radio`): each robot keeps a journal of its
+/// own discoveries, sent once old enough, and receives the station tiles
+/// observed before the same cutoff. Docked robots sync in full at the
+/// station instead. A range of 0 disables the radio. Returns the number of
+/// robots synchronized.
+///
+/// # Examples
+///
+/// With a latency of 4 cycles, a discovery reaches the station 4 cycles
+/// after it was made, and other robots in range 4 cycles later still:
+///
+/// ```rust
+/// # use ereea::knowledge::sync_by_radio;
+/// # use ereea::{map::Map, robot::Robot, station::Station};
+/// # use ereea::types::{RobotType, TileType};
+/// let mut map = Map::open();
+/// let mut station = Station::new();
+/// (station.radio_range, station.radio_latency, station.radio_sync_interval) = (8, 4, 1);
+/// let mut robots = vec![
+///     Robot::new_with_memory(16, 10, RobotType::Explorer, 1, 10, 10, station.global_memory.clone()),
+///     Robot::new_with_memory(4, 10, RobotType::EnergyCollector, 2, 10, 10, station.global_memory.clone()),
+/// ];
+///
+/// // The explorer sees (18, 10) at cycle 1
+/// station.tick();
+/// robots[0].update_memory(&map, &station);
+/// let mut heard_at = None;
+/// let mut relayed_at = None;
+/// for _ in 0..12 {
+///     sync_by_radio(&mut robots, &mut station, &map);
+///     if station.global_memory[(18, 10)].explored {
+///         heard_at.get_or_insert(station.current_time);
+///     }
+///     if robots[1].memory[(18, 10)].explored {
+///         relayed_at.get_or_insert(station.current_time);
+///     }
+///     station.tick();
+/// }
+/// assert_eq!((heard_at, relayed_at), (Some(1 + 4), Some(1 + 4 + 4)));
+///
+/// // Out of range: the explorer's news wait for it to come closer (or dock)
+/// (robots[0].x, robots[0].y) = (19, 19);
+/// map.set_tile(19, 18, TileType::Mineral);
+/// robots[0].update_memory(&map, &station);
+/// for _ in 0..8 {
+///     station.tick();
+///     sync_by_radio(&mut robots, &mut station, &map);
+/// }
+/// assert!(!station.global_memory[(19, 18)].explored);
+/// (robots[0].x, robots[0].y) = (15, 15);
+/// station.tick();
+/// sync_by_radio(&mut robots, &mut station, &map);
+/// assert_eq!(station.global_memory[(19, 18)].tile_type, TileType::Mineral);
+/// ```
+///
+/// Late news do not override fresher ones: conflicts still resolve by timestamp.
+///
+/// ```rust
+/// # use ereea::knowledge::sync_by_radio;
+/// # use ereea::{map::Map, robot::Robot, station::Station};
+/// # use ereea::types::{RobotType, TileType};
+/// let mut map = Map::open();
+/// map.set_tile(12, 10, TileType::Mineral);
+/// let mut station = Station::new();
+/// (station.radio_range, station.radio_latency, station.radio_sync_interval) = (8, 5, 1);
+/// let mut far = Robot::new_with_memory(12, 10, RobotType::Explorer, 1, 10, 10, station.global_memory.clone());
+/// let mut docked = Robot::new_with_memory(12, 10, RobotType::MineralCollector, 2, 10, 10, station.global_memory.clone());
+///
+/// // The explorer sees the deposit at cycle 1, the collector sees it emptied at cycle 3
+/// station.tick();
+/// far.update_memory(&map, &station);
+/// station.tick();
+/// station.tick();
+/// map.set_tile(12, 10, TileType::Empty);
+/// docked.update_memory(&map, &station);
+/// (docked.x, docked.y) = (10, 10);
+/// station.share_knowledge(&mut docked);
+///
+/// // The explorer's older report arrives afterwards and loses
+/// let mut robots = vec![far];
+/// for _ in 0..6 {
+///     station.tick();
+///     sync_by_radio(&mut robots, &mut station, &map);
+/// }
+/// assert_eq!(station.global_memory[(12, 10)].tile_type, TileType::Empty);
+/// assert_eq!(robots[0].memory[(12, 10)].tile_type, TileType::Empty);
+/// ```
+pub fn sync_by_radio(robots: &mut [Robot], station: &mut Station, map: &Map) -> usize {
+    if station.radio_range == 0 || !station.current_time.is_multiple_of(station.radio_sync_interval.max(1)) {
+        return 0;
+    }
+    // Rien n'est encore assez ancien pour avoir traversé la liaison
+    let Some(cutoff) = station.current_time.checked_sub(station.radio_latency) else {
+        return 0;
+    };
+    
+    let stations = map.stations();
+    let mut synced = 0;
+    for robot in robots.iter_mut() {
+        let in_range = stations.iter()
+            .any(|&position| robot.position().chebyshev_distance(position.into()) <= station.radio_range);
+        let docked = (robot.x, robot.y) == (robot.home_station_x, robot.home_station_y);
+        if (in_range || robot.relay_linked) && !docked {
+            station.share_knowledge_by_radio(robot, cutoff);
+            synced += 1;
+        }
+    }
+    synced
+}
@@ -7,10 +7,19 @@ pub mod robot;         // NOTE - Logique des robots et intelligence artificielle
 pub mod display;       // NOTE - Affichage terminal pour mode local
 pub mod station;       // NOTE - Gestion de la station et coordination
 pub mod network;       // NOTE - Communication réseau et sérialisation
+pub mod occupancy;     // NOTE - Occupation des cases par les robots (anti-collision)
+pub mod knowledge;     // NOTE - Fusion des connaissances (station et échanges entre robots)
+pub mod energy;        // NOTE - Modèle de consommation d'énergie des robots
+pub mod events;        // NOTE - Événements de mission (journal serveur et diffusion vers la Terre)
+pub mod grid;          // NOTE - Grilles plates indexées par case (mémoires, zones)
+pub mod pathfinding;   // NOTE - Recherche de chemins (A*) et accessibilité des cases
+pub mod simulation;    // NOTE - Cycle de simulation (robots, station, carte) sans réseau
+pub mod brain;         // NOTE - Stratégies d'exploration des robots (cerveaux interchangeables)
 
 // NOTE - Ré-exportation des types principaux pour faciliter l'importation
 pub use types::*;
 pub use map::Map;
 pub use robot::Robot;
 pub use station::Station;
+pub use occupancy::OccupancyGrid;
 pub use network::*;
\ No newline at end of file
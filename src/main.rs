@@ -1,9 +1,3 @@
-mod types;
-mod map;  
-mod robot;
-mod display;
-mod station;
-
 use std::{thread, time::Duration};
 use crossterm::terminal::{enable_raw_mode, disable_raw_mode};
 
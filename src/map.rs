@@ -15,11 +15,43 @@
 //! - **Resource Balance**: Controlled distribution of energy, minerals, and science points
 //! - **Accessibility Guarantee**: All resources are reachable from the station
 //! - **Obstacle Placement**: Natural-looking terrain barriers and passages
+//! - **Secondary Stations**: Up to `MAX_STATIONS` bases, robots return to the nearest one
+//! - **Biomes**: A second, low-frequency noise channel splits the map into
+//!   energy-rich, mineral-rich and scientific regions (see `Map::biome`)
 
-use crate::types::{TileType, MAP_SIZE};
+use crate::types::{Biome, TileType, MAP_SIZE};
+use crate::grid::Grid;
+use crate::occupancy::OccupancyGrid;
+use crate::pathfinding::{self, Diagonals, PathOptions};
 use noise::{NoiseFn, Perlin};
 use rand::prelude::*;
-use std::collections::VecDeque;
+
+// NOTE - Cost of carving through an obstacle when linking an isolated resource, in steps on open ground
+const OBSTACLE_CARVING_COST: u32 = 4;
+
+// NOTE - Half-width of the square kept free of obstacles and resources around the station
+const STATION_CLEARING: usize = 2;
+
+/// Maximum number of stations on a map, the central one included (see `Map::with_stations`)
+pub const MAX_STATIONS: usize = 3;
+
+/// Share of the depleted resource tiles that come back at each `Map::regenerate_resources` call
+pub const REGENERATION_RATE: f64 = 0.1;
+
+// NOTE - Noise values above this are obstacles; resource bands share the range below, down to 0.0
+const RESOURCE_BAND_TOP: f64 = 0.5;
+
+// NOTE - Offset between the terrain seed and the seed of the biome noise channel
+const BIOME_SEED_OFFSET: u32 = 0x9E37_79B9;
+
+// NOTE - Frequency of the biome channel: a few regions across the map
+const BIOME_FREQUENCY: f64 = 1.5;
+
+// NOTE - Biome channel values below minus this are energy-rich, above it mineral-rich
+const BIOME_THRESHOLD: f64 = 0.15;
+
+/// Units held by the richest deposits (the poorest hold one, see `Map::resource_amount`)
+pub const MAX_DEPOSIT_AMOUNT: u32 = 3;
 
 /// Represents the exoplanet exploration map with terrain, resources, and station location.
 /// 
@@ -66,6 +98,21 @@ pub struct Map {
     /// - Resources (energy, mineral, or scientific deposits)
     pub tiles: Vec<Vec<TileType>>,
     
+    /// Units left in each resource deposit, indexed `[y][x]` like `tiles`
+    /// 
+    /// A deposit can be harvested that many times before its tile becomes
+    /// empty. Non-resource tiles hold 0; use `set_tile` rather than writing
+    /// `tiles` directly so both grids stay in step.
+    pub resource_amounts: Vec<Vec<u32>>,
+    
+    /// Region of each tile, indexed `[y][x]` like `tiles`
+    /// 
+    /// Drawn from a low-frequency noise channel independent of the terrain.
+    /// Each biome widens the noise band of its resource at the expense of
+    /// the two others; the bands always share the same total range, so a
+    /// map holds as many resource tiles as without biomes, only regrouped.
+    pub biome: Vec<Vec<Biome>>,
+    
     /// X coordinate of the central station where robots begin and return
     /// 
     /// The station serves as:
@@ -77,6 +124,34 @@ pub struct Map {
     
     /// Y coordinate of the central station
     pub station_y: usize,
+    
+    /// Secondary stations, in addition to the central one (see `stations`)
+    /// 
+    /// Robots are deployed at the central station but dock at the nearest
+    /// one. All stations share the same reserves and global memory.
+    pub extra_stations: Vec<(usize, usize)>,
+    
+    /// Seed of the Perlin field the terrain was generated from
+    /// 
+    /// Kept so that `regenerate_resources` brings back the resource type
+    /// originally found on each tile.
+    noise_seed: u32,
+    
+    /// Deposits emptied since the last `take_recent_changes` call
+    /// 
+    /// Lets the simulation tell robots heading for a deposit that another
+    /// robot took its last unit, before they walk all the way there.
+    recent_changes: Vec<(usize, usize)>,
+    
+    /// Tiles a robot can walk to from the central station, indexed `[y][x]`
+    /// 
+    /// Computed once the map is generated and kept up to date by `set_tile`
+    /// and `add_station`; call `update_reachability` after editing `tiles`
+    /// directly (see `is_reachable`).
+    reachable: Grid<bool>,
+    
+    /// Number of terrain changes so far (see `revision`)
+    revision: u64,
 }
 
 impl Map {
@@ -89,7 +164,8 @@ impl Map {
     /// # Generation Process
     /// 
     /// 1. **Noise-Based Terrain**: Uses Perlin noise for natural terrain distribution
-    /// 2. **Resource Placement**: Distributes energy, mineral, and scientific deposits
+    /// 2. **Resource Placement**: Distributes energy, mineral, and scientific deposits,
+    ///    favoring the resource of each tile's biome
     /// 3. **Station Clearing**: Ensures station area is obstacle-free
     /// 4. **Accessibility Check**: Verifies all resources can be reached
     /// 5. **Path Creation**: Creates routes to isolated resources if needed
@@ -108,6 +184,8 @@ impl Map {
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::MAP_SIZE;
     /// let map1 = Map::new();
     /// let map2 = Map::new();
     /// // map1 and map2 will have different terrain due to random seed
@@ -117,9 +195,90 @@ impl Map {
     /// ```
     pub fn new() -> Self {
         // NOTE - Generate unique random seed for procedural generation
-        let seed: u32 = rand::thread_rng().r#gen();
+        Self::with_seed(rand::thread_rng().r#gen())
+    }
+    
+    /// Creates a map of empty ground with no deposit, the station at the center.
+    /// 
+    /// Meant for examples and tests that lay out their own terrain: tiles
+    /// placed with `set_tile` keep resource amounts and reachability in step,
+    /// while edits made straight to `tiles` need `update_reachability`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// assert!(map.reachable_resources().is_empty());
+    /// assert_eq!(map.reachable_tile_count(), MAP_SIZE * MAP_SIZE);
+    /// 
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// assert_eq!(map.reachable_resources(), vec![(3, 3)]);
+    /// assert_eq!(map.resource_amount(3, 3), 1);
+    /// ```
+    pub fn open() -> Self {
+        let mut map = Self {
+            tiles: vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE],
+            resource_amounts: vec![vec![0; MAP_SIZE]; MAP_SIZE],
+            biome: vec![vec![Biome::EnergyRich; MAP_SIZE]; MAP_SIZE],
+            station_x: MAP_SIZE / 2,
+            station_y: MAP_SIZE / 2,
+            extra_stations: Vec::new(),
+            noise_seed: 0,
+            recent_changes: Vec::new(),
+            reachable: Grid::filled(false),
+            revision: 0,
+        };
+        map.update_reachability();
+        map
+    }
+    
+    /// Generates the map of a given seed, as `new` does.
+    /// 
+    /// The same seed always gives the same map (terrain, resources and the
+    /// pathways carved to them), so a whole run can be replayed from it
+    /// (see `seed` and `Robot::seed_rng`).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// let map = Map::with_seed(1234);
+    /// assert_eq!(map.seed(), 1234);
+    /// assert_eq!(Map::with_seed(1234).tiles, map.tiles);
+    /// assert_ne!(Map::with_seed(4321).tiles, map.tiles);
+    /// ```
+    /// 
+    /// Deposits are regrouped by biome (`Map::biome`): over a few maps, most
+    /// minerals lie in mineral-rich regions.
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{Biome, TileType, MAP_SIZE};
+    /// let (mut in_region, mut total) = (0, 0);
+    /// for seed in 0..50 {
+    ///     let map = Map::with_seed(seed);
+    ///     for y in 0..MAP_SIZE {
+    ///         for x in 0..MAP_SIZE {
+    ///             if map.tiles[y][x] == TileType::Mineral {
+    ///                 total += 1;
+    ///                 in_region += (map.biome[y][x] == Biome::MineralRich) as usize;
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// assert!(in_region * 2 > total, "{} / {}", in_region, total);
+    /// ```
+    pub fn with_seed(seed: u32) -> Self {
         let perlin = Perlin::new(seed);
         
+        // NOTE - Regions come from their own noise channel, so they don't follow the terrain
+        let biome_perlin = Perlin::new(seed.wrapping_add(BIOME_SEED_OFFSET));
+        let biome: Vec<Vec<Biome>> = (0..MAP_SIZE)
+            .map(|y| (0..MAP_SIZE).map(|x| Self::noise_biome(&biome_perlin, x, y)).collect())
+            .collect();
+        
         // NOTE - Initialize empty map grid
         let mut tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
         
@@ -130,31 +289,14 @@ impl Map {
         // NOTE - First pass: Generate base terrain using Perlin noise
         for y in 0..MAP_SIZE {
             for x in 0..MAP_SIZE {
-                // NOTE - Normalize coordinates to 0.0-1.0 range for noise function
-                let nx = x as f64 / MAP_SIZE as f64;
-                let ny = y as f64 / MAP_SIZE as f64;
-                
-                // NOTE - Sample Perlin noise with 4x frequency for detailed features
-                let value = perlin.get([nx * 4.0, ny * 4.0]);
-                
-                // NOTE - Convert noise value to tile type using threshold system
-                tiles[y][x] = if value > 0.5 {
-                    TileType::Obstacle       // NOTE - 25% obstacles for navigation challenge
-                } else if value > 0.3 {
-                    TileType::Energy         // NOTE - 20% energy deposits
-                } else if value > 0.1 {
-                    TileType::Mineral        // NOTE - 20% mineral deposits  
-                } else if value > 0.0 {
-                    TileType::Scientific     // NOTE - 10% scientific points
-                } else {
-                    TileType::Empty          // NOTE - 25% empty traversable space
-                };
+                tiles[y][x] = Self::noise_tile(&perlin, biome[y][x], x, y);
             }
         }
         
         // NOTE - Clear area around station to ensure robot deployment space
-        for dy in -2..=2 {
-            for dx in -2..=2 {
+        let clearing = STATION_CLEARING as isize;
+        for dy in -clearing..=clearing {
+            for dx in -clearing..=clearing {
                 // NOTE - Calculate coordinates with boundary clamping
                 let sx = (station_x as isize + dx).clamp(0, MAP_SIZE as isize - 1) as usize;
                 let sy = (station_y as isize + dy).clamp(0, MAP_SIZE as isize - 1) as usize;
@@ -164,26 +306,261 @@ impl Map {
             }
         }
         
+        // NOTE - Richer deposits lie deeper inside their noise band
+        let mut resource_amounts = vec![vec![0; MAP_SIZE]; MAP_SIZE];
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                if tiles[y][x].is_resource() {
+                    resource_amounts[y][x] = Self::noise_amount(&perlin, biome[y][x], x, y);
+                }
+            }
+        }
+        
         // NOTE - Create initial map structure
         let mut map = Self {
             tiles,
+            resource_amounts,
+            biome,
             station_x,
             station_y,
+            extra_stations: Vec::new(),
+            noise_seed: seed,
+            recent_changes: Vec::new(),
+            reachable: Grid::filled(false),     // Computed once the map is final
+            revision: 0,                        // Terrain as generated
         };
         
         // NOTE - Accessibility pass: Ensure all resources can be reached from station
         let resources = map.find_all_resources();
+        let mut reachable = pathfinding::reachable_from(&map, (station_x, station_y));
         for (res_x, res_y) in resources {
             // NOTE - Check if each resource is reachable from station
-            if !map.is_accessible(station_x, station_y, res_x, res_y) {
+            if !reachable[(res_x, res_y)] {
                 // NOTE - Create pathway if resource is isolated
-                map.create_path(station_x, station_y, res_x, res_y);
+                map.create_path((station_x, station_y), (res_x, res_y));
+                reachable = pathfinding::reachable_from(&map, (station_x, station_y));
             }
         }
         
+        map.update_reachability();
+        map
+    }
+    
+    /// Generates the map of a given seed with `count` stations (1 to [`MAX_STATIONS`]).
+    /// 
+    /// The central station stays at the map center; secondary ones are built
+    /// near two opposite corners, each with a cleared area and a way to the
+    /// central station.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::MAP_SIZE;
+    /// let map = Map::with_stations(42, 3);
+    /// let stations = map.stations();
+    /// assert_eq!(stations.len(), 3);
+    /// assert_eq!(stations[0], (MAP_SIZE / 2, MAP_SIZE / 2));
+    /// for &station in &stations {
+    ///     assert!(map.is_station(station.0, station.1));
+    ///     assert!(map.path_exists(stations[0], station));
+    /// }
+    /// 
+    /// // Robots dock at the closest one
+    /// assert_eq!(map.nearest_station(1, 2), stations[1]);
+    /// assert_eq!(map.nearest_station(11, 9), stations[0]);
+    /// ```
+    /// 
+    /// A robot deployed at the central station heads for the nearest one
+    /// when it returns:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+    /// let mut map = Map::with_stations(42, 2);
+    /// map.tiles = vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE];
+    /// let mut station = Station::new();
+    /// let (station_x, station_y) = (map.station_x, map.station_y);
+    /// let mut robot = Robot::new_with_memory(station_x, station_y, RobotType::Explorer, 1,
+    ///                                        station_x, station_y, station.global_memory.clone());
+    /// (robot.x, robot.y) = (2, 3);
+    /// robot.mode = RobotMode::ReturnToStation;
+    /// 
+    /// let mut docked_at = None;
+    /// for _ in 0..5 {
+    ///     station.tick();
+    ///     let mut occupancy = OccupancyGrid::from_map(std::slice::from_ref(&robot), &map);
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     if map.is_station(robot.x, robot.y) {
+    ///         docked_at = Some((robot.x, robot.y));
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(docked_at, Some(map.extra_stations[0]));
+    /// assert_eq!((robot.home_station_x, robot.home_station_y), map.extra_stations[0]);
+    /// ```
+    pub fn with_stations(seed: u32, count: usize) -> Self {
+        let mut map = Self::with_seed(seed);
+        let sites = [(MAP_SIZE / 4, MAP_SIZE / 4), (MAP_SIZE * 3 / 4, MAP_SIZE * 3 / 4)];
+        for &(x, y) in sites.iter().take(count.clamp(1, MAX_STATIONS) - 1) {
+            map.add_station(x, y);
+        }
         map
     }
     
+    /// Builds a secondary station: clears the area around it and links it to the central station
+    pub fn add_station(&mut self, x: usize, y: usize) {
+        let clearing = STATION_CLEARING as isize;
+        for dy in -clearing..=clearing {
+            for dx in -clearing..=clearing {
+                let sx = (x as isize + dx).clamp(0, MAP_SIZE as isize - 1) as usize;
+                let sy = (y as isize + dy).clamp(0, MAP_SIZE as isize - 1) as usize;
+                self.tiles[sy][sx] = TileType::Empty;
+                self.resource_amounts[sy][sx] = 0;
+            }
+        }
+        
+        if !self.path_exists((self.station_x, self.station_y), (x, y)) {
+            self.create_path((self.station_x, self.station_y), (x, y));
+        }
+        self.extra_stations.push((x, y));
+        self.update_reachability();
+    }
+    
+    /// Builds a forward depot on a walkable tile during the mission (see `Station::try_build_depot`).
+    /// 
+    /// Unlike `add_station`, nothing is cleared around it: robots may be at
+    /// work nearby. The depot counts as a secondary station from then on.
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, if the tile is not empty.
+    pub fn add_depot(&mut self, x: usize, y: usize) {
+        debug_assert_eq!(self.get_tile(x, y), TileType::Empty, "dépôt sur une case occupée ({}, {})", x, y);
+        self.extra_stations.push((x, y));
+    }
+    
+    // NOTE - Every station of the map, the central one first
+    pub fn stations(&self) -> Vec<(usize, usize)> {
+        std::iter::once((self.station_x, self.station_y))
+            .chain(self.extra_stations.iter().copied())
+            .collect()
+    }
+    
+    // NOTE - True if a station (central or secondary) stands on the tile
+    pub fn is_station(&self, x: usize, y: usize) -> bool {
+        (x, y) == (self.station_x, self.station_y) || self.extra_stations.contains(&(x, y))
+    }
+    
+    // NOTE - Closest station to a tile (Chebyshev distance), the central one on ties
+    pub fn nearest_station(&self, x: usize, y: usize) -> (usize, usize) {
+        self.stations()
+            .into_iter()
+            .min_by_key(|&(sx, sy)| sx.abs_diff(x).max(sy.abs_diff(y)))
+            .unwrap_or((self.station_x, self.station_y))
+    }
+    
+    // NOTE - Seed the map was generated from (`Map::with_seed` gives it back)
+    pub fn seed(&self) -> u32 {
+        self.noise_seed
+    }
+    
+    /// Counter bumped whenever the terrain may have changed.
+    /// 
+    /// Covers `set_tile`, `add_station`, deposits emptied by
+    /// `consume_resource`, `regenerate_resources` and every
+    /// `update_reachability` call (the one to make after editing `tiles`
+    /// directly). Lets robots keep a path planned on an unchanged map.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::TileType;
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// let before = map.revision();
+    /// 
+    /// map.set_resource_amount(3, 3, 2);
+    /// map.consume_resource(3, 3);
+    /// assert_eq!(map.revision(), before);
+    /// 
+    /// // The last unit leaves an empty tile
+    /// map.consume_resource(3, 3);
+    /// assert!(map.revision() > before);
+    /// ```
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+    
+    // NOTE - Perlin field value at a position
+    fn noise_value(perlin: &Perlin, x: usize, y: usize) -> f64 {
+        // NOTE - Normalize coordinates to 0.0-1.0 range for noise function
+        let nx = x as f64 / MAP_SIZE as f64;
+        let ny = y as f64 / MAP_SIZE as f64;
+        
+        // NOTE - Sample Perlin noise with 4x frequency for detailed features
+        perlin.get([nx * 4.0, ny * 4.0])
+    }
+    
+    // NOTE - Biome given by the low-frequency channel at a position
+    fn noise_biome(perlin: &Perlin, x: usize, y: usize) -> Biome {
+        let nx = x as f64 / MAP_SIZE as f64;
+        let ny = y as f64 / MAP_SIZE as f64;
+        let value = perlin.get([nx * BIOME_FREQUENCY, ny * BIOME_FREQUENCY]);
+        
+        if value < -BIOME_THRESHOLD {
+            Biome::EnergyRich
+        } else if value > BIOME_THRESHOLD {
+            Biome::MineralRich
+        } else {
+            Biome::ScientificRich
+        }
+    }
+    
+    // NOTE - Noise bands of the deposits in a biome, top down, as (tile, lower bound, width).
+    // Widths always add up to RESOURCE_BAND_TOP (0.2 / 0.2 / 0.1 before biomes).
+    fn resource_bands(biome: Biome) -> [(TileType, f64, f64); 3] {
+        let (energy, mineral) = match biome {
+            Biome::EnergyRich => (0.34, 0.1),
+            Biome::MineralRich => (0.15, 0.28),
+            Biome::ScientificRich => (0.18, 0.16),
+        };
+        let scientific = RESOURCE_BAND_TOP - energy - mineral;
+        [
+            (TileType::Energy, RESOURCE_BAND_TOP - energy, energy),
+            (TileType::Mineral, scientific, mineral),
+            (TileType::Scientific, 0.0, scientific),
+        ]
+    }
+    
+    // NOTE - Tile type given by the Perlin field at a position (before station clearing)
+    fn noise_tile(perlin: &Perlin, biome: Biome, x: usize, y: usize) -> TileType {
+        let value = Self::noise_value(perlin, x, y);
+        
+        // NOTE - Convert noise value to tile type: about 25% obstacles, 25% empty space,
+        // the rest split between deposits according to the biome
+        if value > RESOURCE_BAND_TOP {
+            return TileType::Obstacle;
+        }
+        Self::resource_bands(biome)
+            .into_iter()
+            .find(|&(_, low, _)| value > low)
+            .map_or(TileType::Empty, |(tile, _, _)| tile)
+    }
+    
+    // NOTE - Units of a deposit: 1 at the edge of its noise band, MAX_DEPOSIT_AMOUNT at its core
+    fn noise_amount(perlin: &Perlin, biome: Biome, x: usize, y: usize) -> u32 {
+        let value = Self::noise_value(perlin, x, y);
+        let Some((_, low, width)) = Self::resource_bands(biome)
+            .into_iter()
+            .find(|&(_, low, _)| value > low && value <= RESOURCE_BAND_TOP) else {
+            return 0;
+        };
+        let depth = ((value - low) / width).clamp(0.0, 1.0);
+        1 + (depth * MAX_DEPOSIT_AMOUNT as f64).min(MAX_DEPOSIT_AMOUNT as f64 - 1.0) as u32
+    }
+    
     /// Retrieves the tile type at the specified coordinates.
     /// 
     /// This method provides safe access to map tiles with bounds checking.
@@ -202,6 +579,8 @@ impl Map {
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
     /// let map = Map::new();
     /// 
     /// // Valid coordinates
@@ -221,6 +600,112 @@ impl Map {
         self.tiles[y][x].clone()
     }
     
+    /// Mutable access to a tile, `None` for out-of-bounds coordinates.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::new();
+    /// 
+    /// if let Some(tile) = map.get_tile_mut(3, 4) {
+    ///     *tile = TileType::Mineral;
+    /// }
+    /// assert_eq!(map.get_tile(3, 4), TileType::Mineral);
+    /// 
+    /// // Off-map coordinates never panic
+    /// assert!(map.get_tile_mut(MAP_SIZE, 0).is_none());
+    /// assert!(map.get_tile_mut(0, MAP_SIZE).is_none());
+    /// ```
+    pub fn get_tile_mut(&mut self, x: usize, y: usize) -> Option<&mut TileType> {
+        if x >= MAP_SIZE || y >= MAP_SIZE {
+            return None;
+        }
+        
+        Some(&mut self.tiles[y][x])
+    }
+    
+    /// Replaces a tile, e.g. to place or remove an obstacle at runtime.
+    /// 
+    /// A resource placed this way holds a single unit (see `set_resource_amount`
+    /// for richer deposits). Returns `false` (and changes nothing) for
+    /// out-of-bounds coordinates.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::new();
+    /// 
+    /// assert!(map.set_tile(2, 2, TileType::Obstacle));
+    /// assert!(!map.is_valid_position(2, 2));
+    /// assert!(map.set_tile(2, 2, TileType::Empty));
+    /// assert!(map.is_valid_position(2, 2));
+    /// 
+    /// assert!(!map.set_tile(MAP_SIZE, 2, TileType::Obstacle));
+    /// assert!(!map.set_tile(2, usize::MAX, TileType::Obstacle));
+    /// 
+    /// assert!(map.set_tile(2, 2, TileType::Mineral));
+    /// assert_eq!(map.resource_amount(2, 2), 1);
+    /// ```
+    pub fn set_tile(&mut self, x: usize, y: usize, tile: TileType) -> bool {
+        let amount = u32::from(tile.is_resource());
+        match self.get_tile_mut(x, y) {
+            Some(current) => {
+                *current = tile;
+                self.resource_amounts[y][x] = amount;
+                self.update_reachability();
+                true
+            },
+            None => false,
+        }
+    }
+    
+    /// Units left in the deposit at a position, 0 if the tile holds no resource.
+    /// 
+    /// A resource tile always holds at least one unit, even when it was
+    /// written to `tiles` directly.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::{Map, MAX_DEPOSIT_AMOUNT};
+    /// # use ereea::types::MAP_SIZE;
+    /// let map = Map::with_seed(7);
+    /// for y in 0..MAP_SIZE {
+    ///     for x in 0..MAP_SIZE {
+    ///         let amount = map.resource_amount(x, y);
+    ///         if map.get_tile(x, y).is_resource() {
+    ///             assert!((1..=MAX_DEPOSIT_AMOUNT).contains(&amount));
+    ///         } else {
+    ///             assert_eq!(amount, 0);
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(map.resource_amount(MAP_SIZE, 0), 0);
+    /// ```
+    pub fn resource_amount(&self, x: usize, y: usize) -> u32 {
+        if !self.get_tile(x, y).is_resource() {
+            return 0;
+        }
+        
+        self.resource_amounts[y][x].max(1)
+    }
+    
+    /// Sets the units left in a resource deposit (at least one).
+    /// 
+    /// Returns `false` (and changes nothing) if the tile holds no resource.
+    pub fn set_resource_amount(&mut self, x: usize, y: usize, amount: u32) -> bool {
+        if !self.get_tile(x, y).is_resource() {
+            return false;
+        }
+        
+        self.resource_amounts[y][x] = amount.max(1);
+        true
+    }
+    
     /// Validates whether a position is traversable by robots.
     /// 
     /// This method combines bounds checking with tile type validation
@@ -239,12 +724,13 @@ impl Map {
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::map::Map;
     /// let map = Map::new();
+    /// let (target_x, target_y) = (map.station_x + 1, map.station_y);
     /// 
     /// // Check if position is valid for robot movement
     /// if map.is_valid_position(target_x, target_y) {
     ///     // Robot can move to this position
-    ///     robot.move_to(target_x, target_y);
     /// }
     /// ```
     pub fn is_valid_position(&self, x: usize, y: usize) -> bool {
@@ -252,16 +738,306 @@ impl Map {
         x < MAP_SIZE && y < MAP_SIZE && self.tiles[y][x] != TileType::Obstacle
     }
     
-    // NOTE - Consume a resource at a position (only modifies resources)
-    pub fn consume_resource(&mut self, x: usize, y: usize) {
-        if x < MAP_SIZE && y < MAP_SIZE {
-            match self.tiles[y][x] {
-                TileType::Energy | TileType::Mineral | TileType::Scientific => {
-                    self.tiles[y][x] = TileType::Empty;
-                },
-                _ => {}
+    /// Checks whether a robot can step from `from` to the adjacent tile `to`.
+    /// 
+    /// The destination must be a valid position. A diagonal step additionally
+    /// requires at least one of the two orthogonal tiles it passes between to
+    /// be passable, so robots never squeeze between two touching obstacles.
+    /// 
+    /// # Examples
+    /// 
+    /// The only "path" between two open tiles is a diagonal blocked on both
+    /// sides: the step is refused and no path is found.
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::types::{RobotType, TileType, MAP_SIZE};
+    /// let mut map = Map::new();
+    /// map.tiles = vec![vec![TileType::Obstacle; MAP_SIZE]; MAP_SIZE];
+    /// map.tiles[5][5] = TileType::Empty;
+    /// map.tiles[6][6] = TileType::Empty;
+    /// 
+    /// assert!(!map.can_step((5, 5), (6, 6)));
+    /// let robot = Robot::new(5, 5, RobotType::Explorer);
+    /// assert!(robot.find_path(&map, (6, 6)).is_empty());
+    /// 
+    /// // Opening one side of the corner makes the diagonal legal
+    /// map.tiles[5][6] = TileType::Empty;
+    /// assert!(map.can_step((5, 5), (6, 6)));
+    /// assert_eq!(robot.find_path(&map, (6, 6)).len(), 1);
+    /// ```
+    pub fn can_step(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        PathOptions::new().can_step(self, from, to)
+    }
+    
+    /// True if nothing blocks the view between two tiles.
+    /// 
+    /// Walks the Bresenham line from `from` to `to`: the view is blocked by an
+    /// obstacle on any tile strictly between them. The end tiles themselves
+    /// never block, so an obstacle is seen when the ray hits it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::TileType;
+    /// let mut map = Map::open();
+    /// for y in 3..=7 {
+    ///     map.tiles[y][7] = TileType::Obstacle;
+    /// }
+    /// 
+    /// // The wall itself is seen, not what lies behind it
+    /// assert!(map.has_line_of_sight((5, 5), (7, 5)));
+    /// assert!(!map.has_line_of_sight((5, 5), (9, 5)));
+    /// assert!(!map.has_line_of_sight((5, 5), (9, 7)));
+    /// assert!(!map.has_line_of_sight((5, 5), (9, 9)));
+    /// 
+    /// // Past the end of the wall the view is clear
+    /// assert!(map.has_line_of_sight((5, 9), (9, 9)));
+    /// assert!(map.has_line_of_sight((5, 5), (5, 5)));
+    /// ```
+    pub fn has_line_of_sight(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        let (mut x, mut y) = (from.0 as isize, from.1 as isize);
+        let (to_x, to_y) = (to.0 as isize, to.1 as isize);
+        let (dx, dy) = ((to_x - x).abs(), -(to_y - y).abs());
+        let (step_x, step_y) = (if x < to_x { 1 } else { -1 }, if y < to_y { 1 } else { -1 });
+        let mut error = dx + dy;
+        
+        while (x, y) != (to_x, to_y) {
+            if (x, y) != (from.0 as isize, from.1 as isize) && self.get_tile(x as usize, y as usize) == TileType::Obstacle {
+                return false;
+            }
+            let double_error = 2 * error;
+            if double_error >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if double_error <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+        true
+    }
+    
+    /// Takes one unit from the deposit at a position and returns the units left.
+    /// 
+    /// The tile becomes empty once its last unit is taken, and its position
+    /// is recorded for `take_recent_changes`. Other tiles (and out-of-bounds
+    /// coordinates) are left untouched.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::new();
+    /// map.set_tile(3, 3, TileType::Energy);
+    /// map.set_tile(4, 3, TileType::Obstacle);
+    /// 
+    /// assert_eq!(map.consume_resource(3, 3), 0);
+    /// assert_eq!(map.consume_resource(4, 3), 0);
+    /// assert_eq!(map.consume_resource(MAP_SIZE, MAP_SIZE), 0);
+    /// assert_eq!(map.get_tile(3, 3), TileType::Empty);
+    /// assert_eq!(map.get_tile(4, 3), TileType::Obstacle);
+    /// 
+    /// // A rich deposit is harvested several times before it runs out
+    /// map.set_tile(5, 5, TileType::Mineral);
+    /// map.set_resource_amount(5, 5, 3);
+    /// assert_eq!(map.consume_resource(5, 5), 2);
+    /// assert_eq!(map.consume_resource(5, 5), 1);
+    /// assert_eq!(map.get_tile(5, 5), TileType::Mineral);
+    /// assert_eq!(map.consume_resource(5, 5), 0);
+    /// assert_eq!(map.get_tile(5, 5), TileType::Empty);
+    /// 
+    /// // Only emptied deposits are reported, once
+    /// assert_eq!(map.take_recent_changes(), vec![(3, 3), (5, 5)]);
+    /// assert!(map.take_recent_changes().is_empty());
+    /// ```
+    pub fn consume_resource(&mut self, x: usize, y: usize) -> u32 {
+        let left = self.resource_amount(x, y).saturating_sub(1);
+        if let Some(tile) = self.get_tile_mut(x, y)
+            && tile.is_resource() {
+            if left == 0 {
+                *tile = TileType::Empty;
+                self.recent_changes.push((x, y));
+                self.revision += 1;
+            }
+            self.resource_amounts[y][x] = left;
+        }
+        left
+    }
+    
+    /// Returns the deposits emptied since the previous call, oldest first.
+    /// 
+    /// Drained once per cycle by the simulation, which passes them to
+    /// `Robot::handle_map_changes`.
+    pub fn take_recent_changes(&mut self) -> Vec<(usize, usize)> {
+        std::mem::take(&mut self.recent_changes)
+    }
+    
+    /// Brings back part of the depleted resources (endless mode).
+    /// 
+    /// Each empty tile that held a resource in the original Perlin field gets
+    /// that resource back with probability `REGENERATION_RATE`. Tiles near
+    /// the station, tiles holding a robot and tiles that cannot be reached
+    /// from the station are left empty. Returns the number of regenerated tiles.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::occupancy::OccupancyGrid;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::types::{RobotType, TileType, MAP_SIZE};
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// let mut map = Map::new();
+    /// let original = map.tiles.clone();
+    /// 
+    /// // Wall off the left edge of the map, then collect everything
+    /// for y in 0..MAP_SIZE {
+    ///     map.tiles[y][3] = TileType::Obstacle;
+    /// }
+    /// for y in 0..MAP_SIZE {
+    ///     for x in 0..MAP_SIZE {
+    ///         while map.consume_resource(x, y) > 0 {}
+    ///     }
+    /// }
+    /// 
+    /// // A robot parked on a former resource tile
+    /// let parked = (0..MAP_SIZE).flat_map(|y| (4..MAP_SIZE).map(move |x| (x, y)))
+    ///     .find(|&(x, y)| original[y][x] == TileType::Mineral)
+    ///     .unwrap();
+    /// let occupancy = OccupancyGrid::from_robots(&[Robot::new(parked.0, parked.1, RobotType::Explorer)], map.station_x, map.station_y);
+    /// 
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let regenerated: usize = (0..200).map(|_| map.regenerate_resources(&mut rng, &occupancy)).sum();
+    /// assert!(regenerated > 0);
+    /// 
+    /// for y in 0..MAP_SIZE {
+    ///     for x in 0..MAP_SIZE {
+    ///         let tile = map.get_tile(x, y);
+    ///         let is_resource = matches!(tile, TileType::Energy | TileType::Mineral | TileType::Scientific);
+    ///         if x < 3 || (x, y) == parked || (x, y) == (map.station_x, map.station_y) {
+    ///             // Unreachable, occupied or station: nothing grows back
+    ///             assert!(!is_resource);
+    ///         } else if is_resource {
+    ///             // Whatever grows back is what the tile originally held
+    ///             assert_eq!(tile, original[y][x]);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn regenerate_resources<R: Rng + ?Sized>(&mut self, rng: &mut R, occupancy: &OccupancyGrid) -> usize {
+        let perlin = Perlin::new(self.noise_seed);
+        let reachable = self.reachable_from_station();
+        let stations = self.stations();
+        let mut regenerated = 0;
+        
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                // NOTE - Only depleted, reachable tiles away from the station and from robots
+                let near_station = stations.iter().any(|&(sx, sy)| {
+                    x.abs_diff(sx) <= STATION_CLEARING && y.abs_diff(sy) <= STATION_CLEARING
+                });
+                if self.tiles[y][x] != TileType::Empty || near_station
+                    || !reachable[(x, y)] || occupancy.robots_at(x, y) > 0 {
+                    continue;
+                }
+                
+                let original = Self::noise_tile(&perlin, self.biome[y][x], x, y);
+                if matches!(original, TileType::Energy | TileType::Mineral | TileType::Scientific)
+                    && rng.gen_bool(REGENERATION_RATE) {
+                    self.tiles[y][x] = original;
+                    self.resource_amounts[y][x] = Self::noise_amount(&perlin, self.biome[y][x], x, y);
+                    self.revision += 1;
+                    regenerated += 1;
+                }
             }
         }
+        
+        regenerated
+    }
+    
+    // NOTE - Tiles reachable from the station (BFS over `can_step`)
+    fn reachable_from_station(&self) -> Grid<bool> {
+        pathfinding::reachable_from(self, (self.station_x, self.station_y))
+    }
+    
+    /// Tells whether a robot can walk to a tile from the central station.
+    /// 
+    /// Obstacles are never reachable, nor are pockets sealed off by
+    /// obstacles on all sides: robots can never explore them, so they do not
+    /// count in exploration percentages (see `knowledge::ExplorationArea`).
+    /// 
+    /// # Examples
+    /// 
+    /// A 3x3 pocket walled in by obstacles:
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// for y in 3..=7 {
+    ///     map.tiles[y][13] = TileType::Obstacle;
+    ///     map.tiles[y][17] = TileType::Obstacle;
+    /// }
+    /// for x in 13..=17 {
+    ///     map.tiles[3][x] = TileType::Obstacle;
+    ///     map.tiles[7][x] = TileType::Obstacle;
+    /// }
+    /// map.update_reachability();
+    /// 
+    /// assert!(map.is_reachable(0, 0));
+    /// assert!(!map.is_reachable(13, 3));
+    /// assert!(!map.is_reachable(15, 5));
+    /// assert_eq!(map.reachable_tile_count(), MAP_SIZE * MAP_SIZE - 25);
+    /// 
+    /// // Opening the wall makes the pocket reachable again
+    /// map.set_tile(13, 5, TileType::Empty);
+    /// assert!(map.is_reachable(15, 5));
+    /// assert_eq!(map.reachable_tile_count(), MAP_SIZE * MAP_SIZE - 15);
+    /// ```
+    pub fn is_reachable(&self, x: usize, y: usize) -> bool {
+        self.reachable[(x, y)]
+    }
+    
+    /// Number of tiles a robot can walk to from the central station (see `is_reachable`)
+    pub fn reachable_tile_count(&self) -> usize {
+        self.reachable.iter().filter(|&&reachable| reachable).count()
+    }
+    
+    // NOTE - Recompute `reachable` (after editing `tiles` directly)
+    pub fn update_reachability(&mut self) {
+        self.reachable = self.reachable_from_station();
+        self.revision += 1;
+    }
+    
+    /// Resource tiles a robot can reach by walking from the station.
+    /// 
+    /// # Examples
+    /// 
+    /// Map generation carves a way to every resource it places:
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// for _ in 0..50 {
+    ///     let map = Map::new();
+    ///     let resources = (0..MAP_SIZE)
+    ///         .flat_map(|y| (0..MAP_SIZE).map(move |x| (x, y)))
+    ///         .filter(|&(x, y)| matches!(map.get_tile(x, y), TileType::Energy | TileType::Mineral | TileType::Scientific))
+    ///         .count();
+    ///     assert_eq!(map.reachable_resources().len(), resources);
+    /// }
+    /// ```
+    pub fn reachable_resources(&self) -> Vec<(usize, usize)> {
+        let reachable = pathfinding::reachable_from(self, (self.station_x, self.station_y));
+        self.find_all_resources()
+            .into_iter()
+            .filter(|&(x, y)| reachable[(x, y)])
+            .collect()
     }
     
     // NOTE - Find all resource positions on the map
@@ -280,84 +1056,137 @@ impl Map {
         resources
     }
     
-    // NOTE - Check if a position is accessible from another (BFS)
-    fn is_accessible(&self, start_x: usize, start_y: usize, target_x: usize, target_y: usize) -> bool {
-        let mut visited = vec![vec![false; MAP_SIZE]; MAP_SIZE];
-        let mut queue = VecDeque::new();
-        
-        // NOTE - Start point
-        queue.push_back((start_x, start_y));
-        visited[start_y][start_x] = true;
-        
-        while let Some((x, y)) = queue.pop_front() {
-            // NOTE - If target reached
-            if x == target_x && y == target_y {
-                return true;
-            }
-            
-            for dy in -1..=1 {
-                for dx in -1..=1 {
-                    if dx == 0 && dy == 0 {
-                        continue;
-                    }
-                    
-                    // NOTE - Explore neighbors
-                    let nx = x as isize + dx;
-                    let ny = y as isize + dy;
-                    
-                    if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize {
-                        let nx = nx as usize;
-                        let ny = ny as usize;
-                        
-                        if !visited[ny][nx] && self.tiles[ny][nx] != TileType::Obstacle {
-                            visited[ny][nx] = true;
-                            queue.push_back((nx, ny));
-                        }
-                    }
+    /// Tells whether a robot can walk from `from` to `to` (breadth-first search).
+    /// 
+    /// Follows the robots' movement rules: 8 directions, obstacles blocked and
+    /// no corner cut between two obstacles (`can_step`). A tile is always
+    /// reachable from itself.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// // Wall across the map at x = 5, with a one-tile gap
+    /// for y in 0..MAP_SIZE {
+    ///     map.tiles[y][5] = TileType::Obstacle;
+    /// }
+    /// map.tiles[12][5] = TileType::Empty;
+    /// assert!(map.path_exists((0, 0), (19, 0)));
+    /// 
+    /// // Gap closed: the two halves are cut off from each other
+    /// map.tiles[12][5] = TileType::Obstacle;
+    /// assert!(!map.path_exists((0, 0), (19, 0)));
+    /// assert!(map.path_exists((0, 0), (4, 19)));
+    /// 
+    /// // Two diagonal obstacles leave no way through their corner
+    /// map = Map::open();
+    /// map.tiles[0][1] = TileType::Obstacle;
+    /// map.tiles[1][0] = TileType::Obstacle;
+    /// assert!(!map.path_exists((0, 0), (1, 1)));
+    /// ```
+    pub fn path_exists(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        pathfinding::find_path(self, from, to, &PathOptions::new()).is_some()
+    }
+    
+    /// Surrounds a tile with obstacles, leaving the tile itself empty ground.
+    /// 
+    /// The eight neighbors become obstacles (those inside the map), as after
+    /// a rockfall: a robot standing there is cut off until a way is carved.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::TileType;
+    /// let mut map = Map::open();
+    /// map.wall_in(0, 3);
+    /// assert_eq!(map.get_tile(0, 3), TileType::Empty);
+    /// assert!((2..=4).all(|y| map.get_tile(1, y) == TileType::Obstacle));
+    /// assert!(!map.path_exists((0, 3), (map.station_x, map.station_y)));
+    /// ```
+    pub fn wall_in(&mut self, x: usize, y: usize) {
+        self.set_tile(x, y, TileType::Empty);
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if (dx, dy) != (0, 0)
+                    && let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+                    // NOTE - Out-of-map neighbors are rejected by `set_tile`
+                    self.set_tile(nx, ny, TileType::Obstacle);
                 }
             }
         }
-        
-        false
     }
     
-    // NOTE - Create a path between two points by removing obstacles
-    fn create_path(&mut self, start_x: usize, start_y: usize, target_x: usize, target_y: usize) {
-        // NOTE - Use Manhattan distance to create an approximate path
-        let mut current_x = start_x;
-        let mut current_y = start_y;
+    /// Carves a way back to the central station for every position cut off from it.
+    /// 
+    /// Each position the station can no longer be reached from (see
+    /// `path_exists`) gets the orthogonal corridor crossing the fewest
+    /// obstacles, the way map generation connects resources. Positions
+    /// outside the map are ignored. Returns the obstacle tiles turned into
+    /// empty ground, in the order they were carved.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::types::TileType;
+    /// let mut map = Map::open();
+    /// let station = (map.station_x, map.station_y);
+    /// 
+    /// // A robot walled in at (1, 1), another one free to come back
+    /// map.wall_in(1, 1);
+    /// assert!(!map.path_exists((1, 1), station));
+    /// 
+    /// // A single tile of the wall is enough
+    /// let carved = map.ensure_station_connectivity(&[(1, 1), (10, 5)]);
+    /// assert_eq!(carved.len(), 1);
+    /// assert!(map.path_exists((1, 1), station));
+    /// assert!(map.is_reachable(1, 1));
+    /// 
+    /// // Nothing left to carve
+    /// assert!(map.ensure_station_connectivity(&[(1, 1), (10, 5)]).is_empty());
+    /// ```
+    pub fn ensure_station_connectivity(&mut self, positions: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let station = (self.station_x, self.station_y);
+        let mut carved = Vec::new();
+        let mut reachable = self.reachable_from_station();
         
-        while current_x != target_x || current_y != target_y {
-            // NOTE - Decide direction to move
-            let move_horizontal = rand::thread_rng().gen_bool(0.5);
-            
-            if move_horizontal && current_x != target_x {
-                // NOTE - Move horizontally
-                if current_x < target_x {
-                    current_x += 1;
-                } else {
-                    current_x -= 1;
-                }
-            } else if current_y != target_y {
-                // NOTE - Move vertically
-                if current_y < target_y {
-                    current_y += 1;
-                } else {
-                    current_y -= 1;
-                }
-            } else if current_x != target_x {
-                // NOTE - Forced horizontal move
-                if current_x < target_x {
-                    current_x += 1;
-                } else {
-                    current_x -= 1;
-                }
+        for &(x, y) in positions {
+            if x >= MAP_SIZE || y >= MAP_SIZE || reachable[(x, y)] {
+                continue;
             }
-            
+            carved.extend(self.create_path(station, (x, y)));
+            reachable = self.reachable_from_station();
+        }
+        
+        if !carved.is_empty() {
+            self.update_reachability();
+        }
+        carved
+    }
+    
+    // NOTE - Create a path between two points by removing obstacles: the orthogonal path
+    // crossing the fewest obstacles, so that robots can walk it without cutting corners; returns the carved tiles
+    fn create_path(&mut self, start: (usize, usize), target: (usize, usize)) -> Vec<(usize, usize)> {
+        let through_rock = |tile: TileType| Some(if tile == TileType::Obstacle { OBSTACLE_CARVING_COST } else { 1 });
+        let options = PathOptions::new().with_diagonals(Diagonals::Never).with_step_cost(&through_rock);
+        
+        let mut carved = Vec::new();
+        for (x, y) in pathfinding::find_path(self, start, target, &options).unwrap_or_default() {
             // NOTE - If obstacle, convert to empty tile
-            if self.tiles[current_y][current_x] == TileType::Obstacle {
-                self.tiles[current_y][current_x] = TileType::Empty;
+            if self.tiles[y][x] == TileType::Obstacle {
+                self.tiles[y][x] = TileType::Empty;
+                carved.push((x, y));
             }
         }
+        carved
     }
-}
\ No newline at end of file
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
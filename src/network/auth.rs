@@ -0,0 +1,109 @@
+//! # Shared-Secret Authentication
+//!
+//! Optional handshake protecting the simulation port when it is reachable
+//! beyond localhost. When the server is started with `--auth-token <secret>`,
+//! the first frame sent by a client (after an optional `Hello`) must be
+//! `NetworkMessage::Auth { token }`.
+//! Any other first frame, a wrong token, or silence until the timeout ends
+//! with an `AuthFailed` frame and a closed connection.
+
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+use super::NetworkMessage;
+
+/// Environment variable read by the Earth client when `--token` is not given
+pub const TOKEN_ENV_VAR: &str = "EREEA_TOKEN";
+
+/// Delay granted to a client to send its `Auth` frame after connecting
+pub const AUTH_TIMEOUT_SECS: u64 = 5;
+
+/// NOTE - Reasons for refusing a client during the authentication handshake.
+///
+/// Transmitted to the client inside `NetworkMessage::AuthFailed` right
+/// before the server closes the connection.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum AuthError {
+    /// No `Auth` frame was received as the first message
+    MissingToken,
+    /// An `Auth` frame was received but its token does not match
+    InvalidToken,
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::MissingToken => write!(f, "jeton d'authentification manquant"),
+            AuthError::InvalidToken => write!(f, "jeton d'authentification invalide"),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// Compares two tokens in constant time with respect to their content.
+///
+/// Every byte of `expected` is examined even after a mismatch so the
+/// response time does not leak how many leading characters were correct.
+///
+/// # Examples
+///
+/// ```rust
+/// use ereea::network::auth::tokens_match;
+///
+/// assert!(tokens_match("s3cret", "s3cret"));
+/// assert!(!tokens_match("s3cret", "s3creT"));
+/// assert!(!tokens_match("s3cret", "s3cret-but-longer"));
+/// ```
+pub fn tokens_match(expected: &str, provided: &str) -> bool {
+    let expected = expected.as_bytes();
+    let provided = provided.as_bytes();
+
+    // NOTE - Length mismatch is folded into the accumulator instead of returning early
+    let mut diff = expected.len() ^ provided.len();
+    for (i, byte) in expected.iter().enumerate() {
+        let other = provided.get(i).copied().unwrap_or(0);
+        diff |= (byte ^ other) as usize;
+    }
+
+    diff == 0
+}
+
+/// Validates the first frame received from a client against the server secret.
+///
+/// # Parameters
+///
+/// * `expected` - Secret configured on the server with `--auth-token`
+/// * `first_frame` - First message decoded from the client after its optional `Hello`, `None` if the client
+///   sent an undecodable frame, closed the connection or stayed silent until the timeout
+///
+/// # Returns
+///
+/// `Ok(())` if the client may join the broadcast list, the typed reason otherwise
+///
+/// # Examples
+///
+/// ```rust
+/// use ereea::network::NetworkMessage;
+/// use ereea::network::auth::{verify_first_frame, AuthError};
+///
+/// let auth = |token: &str| NetworkMessage::Auth { token: token.to_string() };
+///
+/// // Accepted: correct token
+/// assert_eq!(verify_first_frame("s3cret", Some(&auth("s3cret"))), Ok(()));
+///
+/// // Rejected: wrong token
+/// assert_eq!(verify_first_frame("s3cret", Some(&auth("guess"))), Err(AuthError::InvalidToken));
+///
+/// // Missing: nothing sent, or something other than an Auth frame
+/// let other = NetworkMessage::AuthFailed { error: AuthError::MissingToken };
+/// assert_eq!(verify_first_frame("s3cret", None), Err(AuthError::MissingToken));
+/// assert_eq!(verify_first_frame("s3cret", Some(&other)), Err(AuthError::MissingToken));
+/// ```
+pub fn verify_first_frame(expected: &str, first_frame: Option<&NetworkMessage>) -> Result<(), AuthError> {
+    match first_frame {
+        Some(NetworkMessage::Auth { token }) if tokens_match(expected, token) => Ok(()),
+        Some(NetworkMessage::Auth { .. }) => Err(AuthError::InvalidToken),
+        _ => Err(AuthError::MissingToken),
+    }
+}
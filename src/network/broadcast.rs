@@ -0,0 +1,128 @@
+//! # Latest-State Broadcasting
+//!
+//! Decouples the simulation tick rate from the network broadcast rate.
+//! The simulation publishes every state it produces; the broadcaster samples
+//! the most recent one at its own pace, so intermediate states are coalesced
+//! instead of piling up for slow or infrequent broadcasts.
+
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time::{Interval, MissedTickBehavior};
+
+use super::SimulationState;
+
+/// NOTE - Cache of the most recent simulation state, written by the simulation thread.
+///
+/// Publishing never blocks and never fails: a new state simply replaces the
+/// previous one. The cached state is also available to send a snapshot to
+/// clients as soon as they connect.
+pub struct StatePublisher {
+    // NOTE - Watch channel holding the latest state (None until the first tick)
+    sender: watch::Sender<Option<SimulationState>>,
+}
+
+impl StatePublisher {
+    /// Creates an empty cache; samplers wait until the first state is published
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(None);
+        Self { sender }
+    }
+
+    /// Replaces the cached state with the one produced by the latest tick
+    pub fn publish(&self, state: SimulationState) {
+        self.sender.send_replace(Some(state));
+    }
+
+    /// Returns a copy of the most recent state, if any has been published
+    pub fn latest(&self) -> Option<SimulationState> {
+        self.sender.borrow().clone()
+    }
+
+    /// Creates a sampler yielding the latest state at most once per `period`.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn sampler(&self, period: Duration) -> StateSampler {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        StateSampler {
+            receiver: self.sender.subscribe(),
+            interval,
+        }
+    }
+}
+
+impl Default for StatePublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Samples a `StatePublisher` at a fixed broadcast period.
+///
+/// # Examples
+///
+/// A 10 ms simulation tick sampled every 500 ms yields 2 frames per second,
+/// each one 50 iterations ahead of the previous. The clock is paused, so
+/// time only moves when every task waits on a timer:
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use ereea::{map::Map, station::Station};
+/// use ereea::network::create_simulation_state;
+/// use ereea::network::broadcast::StatePublisher;
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+/// runtime.block_on(async {
+///     tokio::time::pause();
+///     let publisher = Arc::new(StatePublisher::new());
+///
+///     // Simulation ticking every 10 ms
+///     let simulation = publisher.clone();
+///     tokio::spawn(async move {
+///         let (map, station) = (Map::open(), Station::new());
+///         let mut tick = tokio::time::interval(Duration::from_millis(10));
+///         for iteration in 0..200 {
+///             tick.tick().await;
+///             simulation.publish(create_simulation_state(&map, &station, &vec![], iteration, 0.0));
+///         }
+///     });
+///
+///     let mut sampler = publisher.sampler(Duration::from_millis(500));
+///     let mut frames = Vec::new();
+///     for _ in 0..3 {
+///         frames.push(sampler.next().await.unwrap().iteration);
+///     }
+///     assert!(frames.windows(2).all(|w| w[1] - w[0] == 50), "{:?}", frames);
+/// });
+/// ```
+pub struct StateSampler {
+    // NOTE - Subscription to the publisher's watch channel
+    receiver: watch::Receiver<Option<SimulationState>>,
+    // NOTE - Broadcast cadence
+    interval: Interval,
+}
+
+impl StateSampler {
+    /// Waits for the next broadcast slot and returns the latest state.
+    ///
+    /// Slots where nothing new was published are skipped, so the same state
+    /// is never sent twice. Returns `None` once the publisher is gone.
+    pub async fn next(&mut self) -> Option<SimulationState> {
+        loop {
+            self.interval.tick().await;
+
+            match self.receiver.has_changed() {
+                Ok(true) => {
+                    if let Some(state) = self.receiver.borrow_and_update().clone() {
+                        return Some(state);
+                    }
+                },
+                Ok(false) => {},
+                Err(_) => return None,
+            }
+        }
+    }
+}
@@ -0,0 +1,584 @@
+//! # Wire Codec
+//!
+//! Encoding and framing of `NetworkMessage` values, independent of any socket
+//! so the protocol can be exercised in isolation.
+//!
+//! ## Framing
+//!
+//! Two wire formats exist (see `WireFormat`):
+//!
+//! - **JSON** (default): each frame is one JSON document followed by `\n`.
+//! - **MessagePack** (`msgpack` feature): each frame is a 4-byte big-endian
+//!   length followed by a MessagePack document, structs encoded as maps.
+//!
+//! Either format can additionally be gzip-compressed (`gzip` feature): each
+//! frame is then a 4-byte big-endian length followed by the gzip stream of
+//! the document, delimiter and length prefix excluded. Large states shrink
+//! several times over since most of a map is repeated tile names.
+//!
+//! The decoder is stateful: it accepts arbitrary byte chunks as they come off
+//! the network, keeps partial frames between reads, and yields every complete
+//! message in order. A frame that cannot be parsed is reported and skipped
+//! without losing the frames that follow it.
+//!
+//! ## Negotiation
+//!
+//! Connections always start in JSON. A client may send `Hello { format, gzip }`
+//! as its first frame; the server answers with `Welcome { format, gzip }`
+//! (still JSON, uncompressed) and uses that format and compression for every
+//! frame it sends afterwards. Frames sent by the client stay in plain JSON.
+//! Compression is only enabled when both sides ask for it and the server was
+//! built with it, so clients that never mention it are unaffected. With
+//! `lite: true`, the states carry compact robot updates and keyframes
+//! (`robots_lite`, `robot_keyframes`) instead of `robots_data`, which the
+//! client rebuilds with `LiteStateDecoder`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "gzip")]
+use std::io::{Read, Write};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{NetworkMessage, MAX_MESSAGE_SIZE};
+
+/// Byte terminating every JSON frame on the wire
+pub const FRAME_DELIMITER: u8 = b'\n';
+
+/// Size of the length prefix of MessagePack frames
+pub const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// Tells whether this build can compress and inflate frames (`gzip` feature)
+pub const GZIP_SUPPORTED: bool = cfg!(feature = "gzip");
+
+/// Size of the read buffer used by `read_frame`
+const READ_CHUNK_SIZE: usize = 16 * 1024;
+
+/// NOTE - Serialization formats a client can negotiate with `Hello`.
+///
+/// `MessagePack` is always part of the protocol vocabulary but only usable
+/// when the crate is built with the `msgpack` feature; otherwise the server
+/// falls back to JSON during negotiation.
+///
+/// # Examples
+///
+/// Golden reference for external decoders: a small known state must encode
+/// to the exact bytes of `tests/fixtures/small_state.msgpack`, and decode back
+/// to the same message. Enums such as `TileType` and `RobotType` are integer
+/// tags in this format.
+///
+/// ```rust
+/// # #[cfg(feature = "msgpack")] {
+/// use ereea::network::*;
+/// use ereea::network::codec::{encode_frame, encode_frame_as, FrameDecoder, WireFormat};
+/// use ereea::types::{Biome, TileType, RobotType, RobotMode, CollectorPolicy, DayPhase, Inventory, MissionObjectives};
+/// use ereea::station::{MissionStats, RobotCost};
+/// use ereea::events::{MissionEvent, RobotEvent};
+///
+/// let state = SimulationState {
+///     map_data: MapData {
+///         tiles: vec![vec![TileType::Empty, TileType::Mineral], vec![TileType::Obstacle, TileType::Energy]],
+///         station_x: 0,
+///         station_y: 0,
+///         extra_stations: vec![],
+///         resource_amounts: vec![vec![0, 2], vec![0, 1]],
+///         biome: vec![vec![Biome::EnergyRich, Biome::MineralRich], vec![Biome::ScientificRich, Biome::MineralRich]],
+///         reachable_tiles: 3,
+///     },
+///     robots_data: vec![RobotData {
+///         id: 1, x: 1, y: 0,
+///         energy: 40.0, max_energy: 80.0,
+///         minerals: 0, scientific_data: 0,
+///         robot_type: RobotType::Explorer,
+///         mode: RobotMode::Exploring,
+///         exploration_percentage: 50.0,
+///         total_energy_spent: 40.0,
+///         distance_traveled: 3.0,
+///         energy_cargo: 0,
+///         cargo_load: 0,
+///         cargo_capacity: 0,
+///         stuck_ticks: 0,
+///         upgrades: vec![],
+///         relay_linked: false,
+///         stuck_events: 0,
+///         frontier_size: 3,
+///         current_task: None,
+///         mode_detail: Some("Analyse 3/5".to_string()),
+///         inventory: Inventory::default(),
+///         brain: "default".to_string(),
+///         recent_transitions: vec![],
+///         current_goal: None,
+///     }],
+///     station_data: StationData {
+///         energy_reserves: 100, collected_minerals: 2, collected_scientific_data: 1,
+///         exploration_percentage: 50.0, conflict_count: 0, robot_count: 1,
+///         status_message: "ok".to_string(), mission_complete: false, current_time: 7,
+///         collector_policy: CollectorPolicy::DEFAULT,
+///         statistics: MissionStats::default(),
+///         day_phase: DayPhase::Day,
+///         next_robot: Some(RobotType::Explorer),
+///         next_robot_cost: RobotCost { energy: 80, minerals: 25 },
+///         objectives: MissionObjectives::DEFAULT,
+///         energy_low: false,
+///         recall_active: false,
+///         upgrades: vec![],
+///         energy_capacity: 1000,
+///     },
+///     exploration_data: ExplorationData {
+///         explored_tiles: vec![vec![true, true], vec![false, false]],
+///         last_seen: vec![vec![7, 6], vec![0, 0]],
+///         explored_by: vec![vec![Some(RobotType::Explorer), Some(RobotType::Explorer)], vec![None, None]],
+///     },
+///     iteration: 7,
+///     elapsed_secs: 2.1,
+///     events: vec![MissionEvent {
+///         cycle: 6,
+///         event: RobotEvent::Collected { robot_id: 1, resource: TileType::Mineral, x: 1, y: 0, left: 2 },
+///     }],
+///     robots_lite: vec![],
+///     robot_keyframes: vec![],
+/// };
+/// let message = NetworkMessage::State(Box::new(state));
+///
+/// let frame = encode_frame_as(&message, WireFormat::MessagePack).unwrap();
+/// let golden = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/small_state.msgpack"));
+/// assert_eq!(frame, golden);
+///
+/// // Round trip: decoding gives back the same message
+/// let mut decoder = FrameDecoder::new();
+/// decoder.set_format(WireFormat::MessagePack);
+/// let decoded = decoder.decode(&frame).pop().unwrap().unwrap();
+/// assert_eq!(encode_frame(&decoded).unwrap(), encode_frame(&message).unwrap());
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WireFormat {
+    /// Newline-delimited JSON, always available
+    #[default]
+    Json,
+    /// Length-prefixed MessagePack, requires the `msgpack` feature
+    MessagePack,
+}
+
+impl WireFormat {
+    /// Tells whether this build can encode and decode the format
+    pub fn is_supported(self) -> bool {
+        match self {
+            WireFormat::Json => true,
+            WireFormat::MessagePack => cfg!(feature = "msgpack"),
+        }
+    }
+
+    /// Returns the format the server will actually use for a client request
+    pub fn negotiate(self) -> WireFormat {
+        if self.is_supported() { self } else { WireFormat::Json }
+    }
+}
+
+impl FromStr for WireFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(WireFormat::Json),
+            "msgpack" => Ok(WireFormat::MessagePack),
+            other => Err(format!("format inconnu: {} (json ou msgpack)", other)),
+        }
+    }
+}
+
+/// NOTE - Errors produced while encoding or decoding frames.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodecError {
+    /// The message could not be serialized
+    Serialization(String),
+    /// The frame exceeds the configured maximum size (in bytes)
+    FrameTooLarge { size: usize, max: usize },
+    /// A complete frame was received but is not a valid `NetworkMessage`
+    InvalidFrame(String),
+    /// The format was not compiled into this build
+    UnsupportedFormat(WireFormat),
+    /// Compression was not compiled into this build (`gzip` feature)
+    UnsupportedCompression,
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Serialization(e) => write!(f, "erreur de sérialisation: {}", e),
+            CodecError::FrameTooLarge { size, max } => {
+                write!(f, "trame trop volumineuse: {} octets (max {})", size, max)
+            },
+            CodecError::InvalidFrame(e) => write!(f, "trame invalide: {}", e),
+            CodecError::UnsupportedFormat(format) => write!(f, "format non disponible: {:?}", format),
+            CodecError::UnsupportedCompression => write!(f, "compression gzip non disponible"),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+/// Result alias used throughout the codec
+pub type Result<T> = std::result::Result<T, CodecError>;
+
+/// Serializes a message into a complete JSON frame ready to be written.
+///
+/// # Errors
+///
+/// * `CodecError::Serialization` if the message cannot be turned into JSON
+/// * `CodecError::FrameTooLarge` if the frame exceeds `MAX_MESSAGE_SIZE`
+///
+/// # Examples
+///
+/// ```rust
+/// use ereea::network::NetworkMessage;
+/// use ereea::network::codec::encode_frame;
+///
+/// let frame = encode_frame(&NetworkMessage::Auth { token: "s3cret".to_string() }).unwrap();
+/// assert_eq!(frame, b"{\"Auth\":{\"token\":\"s3cret\"}}\n");
+/// ```
+pub fn encode_frame(message: &NetworkMessage) -> Result<Vec<u8>> {
+    encode_frame_as(message, WireFormat::Json)
+}
+
+/// Serializes a message into a complete frame in the given wire format.
+///
+/// # Errors
+///
+/// Same as `encode_frame`, plus `CodecError::UnsupportedFormat` when the
+/// format was not compiled in.
+pub fn encode_frame_as(message: &NetworkMessage, format: WireFormat) -> Result<Vec<u8>> {
+    match format {
+        WireFormat::Json => {
+            let mut frame = serde_json::to_vec(message)
+                .map_err(|e| CodecError::Serialization(e.to_string()))?;
+            check_size(frame.len(), MAX_MESSAGE_SIZE)?;
+            frame.push(FRAME_DELIMITER);
+            Ok(frame)
+        },
+        #[cfg(feature = "msgpack")]
+        WireFormat::MessagePack => {
+            let payload = rmp_serde::to_vec_named(message)
+                .map_err(|e| CodecError::Serialization(e.to_string()))?;
+            check_size(payload.len(), MAX_MESSAGE_SIZE)?;
+            let mut frame = Vec::with_capacity(LENGTH_PREFIX_SIZE + payload.len());
+            frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+            frame.extend_from_slice(&payload);
+            Ok(frame)
+        },
+        #[cfg(not(feature = "msgpack"))]
+        WireFormat::MessagePack => Err(CodecError::UnsupportedFormat(format)),
+    }
+}
+
+/// Compresses a frame produced by `encode_frame_as` in the same `format`.
+///
+/// The document is gzipped without its delimiter or length prefix, and the
+/// result is length-prefixed. Taking an already encoded frame lets a sender
+/// serialize a state once and compare both sizes.
+///
+/// # Errors
+///
+/// * `CodecError::UnsupportedCompression` without the `gzip` feature
+/// * `CodecError::FrameTooLarge` if the compressed frame exceeds `MAX_MESSAGE_SIZE`
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "gzip")] {
+/// use ereea::network::NetworkMessage;
+/// use ereea::network::codec::{compress_frame, encode_frame, FrameDecoder, WireFormat};
+///
+/// let message = NetworkMessage::Auth { token: "a".repeat(500) };
+/// let frame = encode_frame(&message).unwrap();
+/// let compressed = compress_frame(&frame, WireFormat::Json).unwrap();
+/// assert!(compressed.len() < frame.len() / 5);
+///
+/// let mut decoder = FrameDecoder::new();
+/// decoder.set_compressed(true);
+/// let decoded = decoder.decode(&compressed).pop().unwrap().unwrap();
+/// assert_eq!(encode_frame(&decoded).unwrap(), frame);
+/// # }
+/// ```
+pub fn compress_frame(frame: &[u8], format: WireFormat) -> Result<Vec<u8>> {
+    let payload = match format {
+        WireFormat::Json => frame.strip_suffix(&[FRAME_DELIMITER]).unwrap_or(frame),
+        WireFormat::MessagePack => frame.get(LENGTH_PREFIX_SIZE..).unwrap_or_default(),
+    };
+    let compressed = gzip(payload)?;
+    check_size(compressed.len(), MAX_MESSAGE_SIZE)?;
+    let mut frame = Vec::with_capacity(LENGTH_PREFIX_SIZE + compressed.len());
+    frame.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&compressed);
+    Ok(frame)
+}
+
+#[cfg(feature = "gzip")]
+fn gzip(payload: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(payload)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| CodecError::Serialization(e.to_string()))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip(_payload: &[u8]) -> Result<Vec<u8>> {
+    Err(CodecError::UnsupportedCompression)
+}
+
+// NOTE - Inflated size is bounded too, so a tiny frame cannot expand without limit
+#[cfg(feature = "gzip")]
+fn gunzip(compressed: &[u8], max_size: usize) -> Result<Vec<u8>> {
+    let mut payload = Vec::new();
+    flate2::read::GzDecoder::new(compressed)
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut payload)
+        .map_err(|e| CodecError::InvalidFrame(e.to_string()))?;
+    check_size(payload.len(), max_size)?;
+    Ok(payload)
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gunzip(_compressed: &[u8], _max_size: usize) -> Result<Vec<u8>> {
+    Err(CodecError::UnsupportedCompression)
+}
+
+// NOTE - Shared size limit check for both formats
+fn check_size(size: usize, max: usize) -> Result<()> {
+    if size > max {
+        return Err(CodecError::FrameTooLarge { size, max });
+    }
+    Ok(())
+}
+
+/// Stateful decoder turning a stream of byte chunks into messages.
+///
+/// Bytes are only interpreted when a frame is requested, so the format can
+/// be switched with `set_format` right after the `Welcome` frame without
+/// misreading the frames already buffered behind it.
+///
+/// # Examples
+///
+/// Each row feeds the chunks to a fresh decoder and lists what comes out
+/// (`true` for a decoded message, `false` for a reported error):
+///
+/// ```rust
+/// use ereea::network::NetworkMessage;
+/// use ereea::network::codec::{encode_frame, FrameDecoder};
+///
+/// let auth = encode_frame(&NetworkMessage::Auth { token: "a".to_string() }).unwrap();
+/// let (head, tail) = auth.split_at(5);
+/// let both = [auth.clone(), auth.clone()].concat();
+/// let oversized = vec![b'x'; 64];
+///
+/// let cases: Vec<(&str, Vec<&[u8]>, Vec<bool>)> = vec![
+///     ("single frame",        vec![&auth],                        vec![true]),
+///     ("split frame",         vec![head, tail],                   vec![true]),
+///     ("byte by byte",        auth.chunks(1).collect(),           vec![true]),
+///     ("concatenated frames", vec![&both],                        vec![true, true]),
+///     ("garbage then frame",  vec![b"\x00not json\n", &auth],     vec![false, true]),
+///     ("empty lines skipped", vec![b"\n\n", &auth],               vec![true]),
+///     ("incomplete frame",    vec![head],                         vec![]),
+///     ("size limit",          vec![&oversized, b"\n", &auth],     vec![false, true]),
+/// ];
+///
+/// for (name, chunks, expected) in cases {
+///     let mut decoder = FrameDecoder::with_max_size(32);
+///     let mut results = Vec::new();
+///     for chunk in chunks {
+///         results.extend(decoder.decode(chunk).iter().map(|r| r.is_ok()));
+///     }
+///     assert_eq!(results, expected, "{}", name);
+/// }
+/// ```
+pub struct FrameDecoder {
+    // NOTE - Bytes received but not yet turned into frames
+    buffer: Vec<u8>,
+    // NOTE - Format of the incoming frames
+    format: WireFormat,
+    // NOTE - Maximum accepted frame size, delimiter or length prefix excluded
+    max_size: usize,
+    // NOTE - True while skipping the rest of an oversized JSON frame
+    discarding: bool,
+    // NOTE - Bytes of an oversized length-prefixed frame still to skip
+    skip_remaining: usize,
+    // NOTE - True when incoming frames are gzip-compressed
+    compressed: bool,
+}
+
+impl FrameDecoder {
+    /// Creates a JSON decoder enforcing the protocol-wide `MAX_MESSAGE_SIZE`
+    pub fn new() -> Self {
+        Self::with_max_size(MAX_MESSAGE_SIZE)
+    }
+
+    /// Creates a JSON decoder with a custom maximum frame size (in bytes)
+    pub fn with_max_size(max_size: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            format: WireFormat::Json,
+            max_size,
+            discarding: false,
+            skip_remaining: 0,
+            compressed: false,
+        }
+    }
+
+    /// Switches the format used for the frames that follow
+    pub fn set_format(&mut self, format: WireFormat) {
+        self.format = format;
+    }
+
+    /// Format currently expected from the peer
+    pub fn format(&self) -> WireFormat {
+        self.format
+    }
+
+    /// Switches gzip compression on or off for the frames that follow
+    pub fn set_compressed(&mut self, compressed: bool) {
+        self.compressed = compressed;
+    }
+
+    /// Tells whether incoming frames are expected to be gzip-compressed
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+
+    /// Feeds a chunk of bytes received from the network.
+    ///
+    /// Complete frames become available through `next_frame`; trailing bytes
+    /// are kept until the rest of their frame arrives.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Returns the next decoded frame, or `None` if no complete frame is buffered
+    pub fn next_frame(&mut self) -> Option<Result<NetworkMessage>> {
+        match (self.compressed, self.format) {
+            (true, format) => self.next_compressed_frame(format),
+            (false, WireFormat::Json) => self.next_json_frame(),
+            (false, WireFormat::MessagePack) => self.next_prefixed_frame().map(|frame| frame.and_then(|frame| decode_msgpack(&frame))),
+        }
+    }
+
+    /// Feeds a chunk and returns every frame it completed, in order
+    pub fn decode(&mut self, chunk: &[u8]) -> Vec<Result<NetworkMessage>> {
+        self.push(chunk);
+        std::iter::from_fn(|| self.next_frame()).collect()
+    }
+
+    // NOTE - Newline-delimited JSON frames
+    fn next_json_frame(&mut self) -> Option<Result<NetworkMessage>> {
+        loop {
+            let Some(end) = self.buffer.iter().position(|b| *b == FRAME_DELIMITER) else {
+                if self.discarding {
+                    self.buffer.clear();
+                } else if self.buffer.len() > self.max_size {
+                    // NOTE - Report an oversized frame once, then skip until its delimiter
+                    let size = self.buffer.len();
+                    self.buffer.clear();
+                    self.discarding = true;
+                    return Some(Err(CodecError::FrameTooLarge { size, max: self.max_size }));
+                }
+                return None;
+            };
+
+            let frame: Vec<u8> = self.buffer.drain(..=end).collect();
+            if std::mem::take(&mut self.discarding) {
+                continue;
+            }
+
+            let content = &frame[..end];
+            let content = content.strip_suffix(b"\r").unwrap_or(content);
+            if let Err(e) = check_size(content.len(), self.max_size) {
+                return Some(Err(e));
+            }
+
+            // NOTE - Blank lines carry no message
+            if content.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            return Some(serde_json::from_slice(content).map_err(|e| CodecError::InvalidFrame(e.to_string())));
+        }
+    }
+
+    // NOTE - Length-prefixed gzip frames, inflated then parsed in the negotiated format
+    fn next_compressed_frame(&mut self, format: WireFormat) -> Option<Result<NetworkMessage>> {
+        let frame = self.next_prefixed_frame()?;
+        Some(frame.and_then(|frame| gunzip(&frame, self.max_size)).and_then(|payload| match format {
+            WireFormat::Json => serde_json::from_slice(&payload).map_err(|e| CodecError::InvalidFrame(e.to_string())),
+            WireFormat::MessagePack => decode_msgpack(&payload),
+        }))
+    }
+
+    // NOTE - Length-prefixed frames (MessagePack or gzip), returned without their prefix
+    fn next_prefixed_frame(&mut self) -> Option<Result<Vec<u8>>> {
+        if self.skip_remaining > 0 {
+            let skipped = self.skip_remaining.min(self.buffer.len());
+            self.buffer.drain(..skipped);
+            self.skip_remaining -= skipped;
+            if self.skip_remaining > 0 {
+                return None;
+            }
+        }
+
+        let prefix: [u8; LENGTH_PREFIX_SIZE] = self.buffer.get(..LENGTH_PREFIX_SIZE)?.try_into().ok()?;
+        let size = u32::from_be_bytes(prefix) as usize;
+
+        if let Err(e) = check_size(size, self.max_size) {
+            self.buffer.drain(..LENGTH_PREFIX_SIZE);
+            self.skip_remaining = size;
+            return Some(Err(e));
+        }
+
+        if self.buffer.len() < LENGTH_PREFIX_SIZE + size {
+            return None;
+        }
+
+        Some(Ok(self.buffer.drain(..LENGTH_PREFIX_SIZE + size).skip(LENGTH_PREFIX_SIZE).collect()))
+    }
+}
+
+#[cfg(feature = "msgpack")]
+fn decode_msgpack(payload: &[u8]) -> Result<NetworkMessage> {
+    rmp_serde::from_slice(payload).map_err(|e| CodecError::InvalidFrame(e.to_string()))
+}
+
+#[cfg(not(feature = "msgpack"))]
+fn decode_msgpack(_payload: &[u8]) -> Result<NetworkMessage> {
+    Err(CodecError::UnsupportedFormat(WireFormat::MessagePack))
+}
+
+impl Default for FrameDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads from `reader` until `decoder` yields the next frame.
+///
+/// # Returns
+///
+/// * `Ok(Some(frame))` - The next decoded frame (or its decoding error)
+/// * `Ok(None)` - The peer closed the connection
+/// * `Err(e)` - The underlying read failed
+pub async fn read_frame<R>(reader: &mut R, decoder: &mut FrameDecoder) -> std::io::Result<Option<Result<NetworkMessage>>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut chunk = [0u8; READ_CHUNK_SIZE];
+
+    loop {
+        if let Some(frame) = decoder.next_frame() {
+            return Ok(Some(frame));
+        }
+
+        let read = reader.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(None);
+        }
+        decoder.push(&chunk[..read]);
+    }
+}
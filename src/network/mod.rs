@@ -22,7 +22,18 @@
 
 // NOTE - Module imports for internal types and serialization
 use serde::{Serialize, Deserialize};
-use crate::types::{MAP_SIZE, TileType, RobotType, RobotMode};
+use crate::types::{MAP_SIZE, Biome, BrainKind, TileType, RobotType, RobotMode, RobotTask, CollectorPolicy, DayPhase, Inventory, MissionObjectives, StationUpgrade, Upgrade};
+use crate::station::{MissionStats, RobotCost};
+use crate::events::MissionEvent;
+use std::collections::{HashMap, HashSet};
+
+pub mod auth;          // NOTE - Optional shared-secret handshake
+pub mod codec;         // NOTE - Frame encoding/decoding independent of sockets
+pub mod broadcast;     // NOTE - Latest-state cache sampled at the broadcast rate
+pub mod stats;         // NOTE - Per-connection bandwidth and latency counters
+
+use auth::AuthError;
+use codec::WireFormat;
 
 /// NOTE - Network-serializable representation of the exploration map data.
 /// 
@@ -40,16 +51,23 @@ use crate::types::{MAP_SIZE, TileType, RobotType, RobotMode};
 /// 
 /// ```rust
 /// use ereea::network::MapData;
-/// use ereea::types::TileType;
+/// use ereea::types::{Biome, TileType, MAP_SIZE};
+/// # fn main() -> Result<(), serde_json::Error> {
 /// 
 /// let map_data = MapData {
 ///     tiles: vec![vec![TileType::Empty; MAP_SIZE]; MAP_SIZE],
 ///     station_x: 10,
 ///     station_y: 10,
+///     extra_stations: vec![],
+///     resource_amounts: vec![vec![0; MAP_SIZE]; MAP_SIZE],
+///     biome: vec![vec![Biome::MineralRich; MAP_SIZE]; MAP_SIZE],
+///     reachable_tiles: MAP_SIZE * MAP_SIZE,
 /// };
 /// 
 /// // Serialize for network transmission
 /// let json = serde_json::to_string(&map_data)?;
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MapData {
@@ -69,6 +87,29 @@ pub struct MapData {
     
     /// Y coordinate of the central station facility
     pub station_y: usize,
+    
+    /// Secondary stations, drawn like the central one (see `Map::extra_stations`)
+    #[serde(default)]
+    pub extra_stations: Vec<(usize, usize)>,
+    
+    /// Units left in each deposit, indexed `[y][x]` (see `Map::resource_amount`)
+    /// 
+    /// Empty for senders predating rich deposits: every resource then counts as one unit.
+    #[serde(default)]
+    pub resource_amounts: Vec<Vec<u32>>,
+    
+    /// Region of each tile, indexed `[y][x]` (see `Map::biome`)
+    /// 
+    /// Fixed for the whole mission; empty for senders predating biomes.
+    #[serde(default)]
+    pub biome: Vec<Vec<Biome>>,
+    
+    /// Tiles a robot can walk to from the central station (see `Map::reachable_tile_count`)
+    /// 
+    /// The denominator of the exploration percentages; 0 for senders
+    /// predating the reachability mask.
+    #[serde(default)]
+    pub reachable_tiles: usize,
 }
 
 /// NOTE - Network-serializable representation of individual robot status and performance.
@@ -89,7 +130,7 @@ pub struct MapData {
 /// 
 /// ```rust
 /// use ereea::network::RobotData;
-/// use ereea::types::{RobotType, RobotMode};
+/// use ereea::types::{Inventory, RobotType, RobotMode};
 /// 
 /// let robot_status = RobotData {
 ///     id: 3,
@@ -99,8 +140,37 @@ pub struct MapData {
 ///     robot_type: RobotType::Explorer,
 ///     mode: RobotMode::Exploring,
 ///     exploration_percentage: 25.3,
+///     total_energy_spent: 112.4,
+///     distance_traveled: 310.0,
+///     energy_cargo: 0, cargo_load: 0, cargo_capacity: 0,
+///     stuck_ticks: 0, upgrades: vec![], relay_linked: false, stuck_events: 0,
+///     frontier_size: 12, current_task: None, mode_detail: None,
+///     inventory: Inventory { energy: 0, minerals: 2, science: 1 },
+///     brain: "default".to_string(),
+///     recent_transitions: vec!["cycle 9 : Idle → Exploring (prêt à repartir)".to_string()],
+///     current_goal: Some((17, 6)),
 /// };
 /// ```
+/// 
+/// The carried resources travel both as `inventory` and as the flat fields
+/// of the previous release, so that clients of either release read them:
+/// 
+/// ```rust
+/// # use ereea::network::{create_robot_data, RobotData};
+/// # use ereea::robot::Robot;
+/// # use ereea::types::{Inventory, RobotType, TileType};
+/// let mut robot = Robot::new(0, 0, RobotType::MineralCollector);
+/// robot.load(TileType::Mineral, 3);
+/// let json = serde_json::to_value(create_robot_data(&robot)).unwrap();
+/// assert_eq!(json["minerals"], 3);
+/// assert_eq!(json["inventory"]["minerals"], 3);
+/// 
+/// // A frame from the previous release, without `inventory`, still decodes
+/// let mut old_frame = json.clone();
+/// old_frame.as_object_mut().unwrap().remove("inventory");
+/// let decoded: RobotData = serde_json::from_value(old_frame).unwrap();
+/// assert_eq!((decoded.minerals, decoded.inventory), (3, Inventory::default()));
+/// ```
 #[derive(Serialize, Deserialize, Clone)]
 pub struct RobotData {
     /// Unique identifier for this robot across the entire mission
@@ -131,16 +201,14 @@ pub struct RobotData {
     
     /// Number of mineral units currently carried by the robot
     /// 
-    /// Only meaningful for MineralCollector robots. High values indicate
-    /// successful mining operations but may slow robot movement speed.
-    /// Zero for non-mining robot types.
+    /// Same as `inventory.minerals`, kept for the clients of the previous
+    /// release; will be removed in the next one.
     pub minerals: u32,
     
     /// Number of scientific data units currently stored by the robot
     /// 
-    /// Only meaningful for ScientificCollector robots. Represents
-    /// completed analysis of points of scientific interest and contributes
-    /// to overall mission scientific objectives.
+    /// Same as `inventory.science`, kept for the clients of the previous
+    /// release; will be removed in the next one.
     pub scientific_data: u32,
     
     /// Robot specialization type determining capabilities and behavior
@@ -166,6 +234,166 @@ pub struct RobotData {
     /// contribution to overall mission progress. High values indicate
     /// effective exploration patterns and pathfinding algorithms.
     pub exploration_percentage: f32,
+    
+    /// Total energy consumed since deployment (movement and metabolism)
+    /// 
+    /// Compared with `distance_traveled` and collected resources, it helps
+    /// spot robots that waste energy. Defaults to 0 when absent from the frame.
+    #[serde(default)]
+    pub total_energy_spent: f32,
+    
+    /// Total distance covered since deployment, in tiles
+    #[serde(default)]
+    pub distance_traveled: f32,
+    
+    /// Energy units hauled to the station
+    /// 
+    /// Same as `inventory.energy`, kept for the clients of the previous
+    /// release; will be removed in the next one.
+    #[serde(default)]
+    pub energy_cargo: u32,
+    
+    /// Resource units currently carried (energy, minerals and scientific data)
+    #[serde(default)]
+    pub cargo_load: u32,
+    
+    /// Resource units the robot carries before heading home (0 = carries nothing)
+    #[serde(default)]
+    pub cargo_capacity: u32,
+    
+    /// Consecutive ticks spent standing still with a goal (see `Robot::stuck_ticks`)
+    #[serde(default)]
+    pub stuck_ticks: u32,
+    
+    /// Modules installed by the station (see `Station::try_install_upgrade`)
+    #[serde(default)]
+    pub upgrades: Vec<Upgrade>,
+    
+    /// Linked to the station through a relay chain (see `knowledge::sync_through_relays`)
+    #[serde(default)]
+    pub relay_linked: bool,
+    
+    /// Targets given up since deployment after getting stuck (see `Robot::stuck_events`)
+    #[serde(default)]
+    pub stuck_events: u32,
+    
+    /// Explored tiles bordering the unknown in the robot's memory (see `Robot::frontier`)
+    #[serde(default)]
+    pub frontier_size: usize,
+    
+    /// Task assigned from outside the robot is working on (see `Robot::current_task`)
+    #[serde(default)]
+    pub current_task: Option<String>,
+    
+    /// What the robot is doing within its mode, e.g. `"Analyse 3/5"` (see `Robot::mode_detail`)
+    #[serde(default)]
+    pub mode_detail: Option<String>,
+    
+    /// Resource units currently carried, by kind (see `Robot::inventory`)
+    /// 
+    /// Empty in frames from servers of the previous release, which only
+    /// send the flat `minerals`, `scientific_data` and `energy_cargo`.
+    #[serde(default)]
+    pub inventory: Inventory,
+    
+    /// Name of the exploration policy the robot follows (see `Robot::brain_name`)
+    /// 
+    /// Empty in frames from servers of the previous release.
+    #[serde(default)]
+    pub brain: String,
+    
+    /// Latest mode changes, oldest first, e.g. `"cycle 42 : Collecting → ReturnToStation (soute pleine)"`
+    /// 
+    /// At most `RECENT_TRANSITIONS` of them (see `Robot::mode_history`).
+    #[serde(default)]
+    pub recent_transitions: Vec<String>,
+    
+    /// Tile the robot is heading for (see `Robot::current_goal`)
+    #[serde(default)]
+    pub current_goal: Option<(usize, usize)>,
+}
+
+/// NOTE - Fields of a robot that seldom change, sent in the keyframes of lite links.
+/// 
+/// Clients keep the last keyframe of each robot and rebuild its full
+/// `RobotData` from it and every `RobotDataLite` update (see `LiteStateDecoder`).
+/// The server sends one again whenever a field changes (a new robot, a
+/// battery module, another exploration policy, a mode change), and for the
+/// whole fleet every `LITE_KEYFRAME_INTERVAL` broadcasts.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RobotKeyframe {
+    /// Robot the fields belong to (see `RobotData::id`)
+    pub id: usize,
+    /// Robot specialization type
+    pub robot_type: RobotType,
+    /// Maximum energy capacity, the scale of `RobotDataLite::energy_level`
+    pub max_energy: f32,
+    /// Resource units the robot carries before heading home
+    pub cargo_capacity: u32,
+    /// Modules installed by the station
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub upgrades: Vec<Upgrade>,
+    /// Name of the exploration policy the robot follows
+    #[serde(default)]
+    pub brain: String,
+    /// Latest mode changes, oldest first (see `RobotData::recent_transitions`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_transitions: Vec<String>,
+}
+
+/// NOTE - Compact robot status for bandwidth-sensitive links (`Hello { lite: true }`).
+/// 
+/// Leaves out the fields of the robot's `RobotKeyframe` and the flat copies of
+/// the inventory kept for older clients, quantizes the energy and the
+/// exploration percentage, and omits empty, zero and `false` fields.
+/// Positions fit in a byte since `MAP_SIZE` is well below 256.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RobotDataLite {
+    /// Robot identifier (see `RobotData::id`)
+    pub id: u32,
+    /// Current X coordinate
+    pub x: u8,
+    /// Current Y coordinate
+    pub y: u8,
+    /// Energy as a fraction of `RobotKeyframe::max_energy`, in 255ths
+    pub energy_level: u8,
+    /// Current operational mode
+    pub mode: RobotMode,
+    /// Share of the map the robot explored, in tenths of a percent
+    pub exploration_permille: u16,
+    /// Energy consumed since deployment, rounded to the unit
+    pub total_energy_spent: u32,
+    /// Distance covered since deployment, rounded to the tile
+    pub distance_traveled: u32,
+    /// Resource units currently carried, by kind
+    #[serde(default, skip_serializing_if = "Inventory::is_empty")]
+    pub inventory: Inventory,
+    /// Consecutive ticks spent standing still with a goal
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub stuck_ticks: u16,
+    /// Targets given up since deployment
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub stuck_events: u16,
+    /// Explored tiles bordering the unknown in the robot's memory
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub frontier_size: u16,
+    /// Linked to the station through a relay chain
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub relay_linked: bool,
+    /// Task the robot is working on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_task: Option<String>,
+    /// What the robot is doing within its mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode_detail: Option<String>,
+    /// Tile the robot is heading for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_goal: Option<(u8, u8)>,
+}
+
+// NOTE - Counters left out of lite updates while zero
+fn is_zero(value: &u16) -> bool {
+    *value == 0
 }
 
 /// NOTE - Network-serializable representation of central station status and operations.
@@ -186,6 +414,8 @@ pub struct RobotData {
 /// 
 /// ```rust
 /// use ereea::network::StationData;
+/// use ereea::station::{MissionStats, RobotCost};
+/// use ereea::types::{CollectorPolicy, DayPhase, MissionObjectives, RobotType, StationUpgrade};
 /// 
 /// let station_status = StationData {
 ///     energy_reserves: 150,
@@ -196,6 +426,17 @@ pub struct RobotData {
 ///     robot_count: 6,
 ///     status_message: "Phase 2: Resource Collection".to_string(),
 ///     mission_complete: false,
+///     current_time: 420,
+///     collector_policy: CollectorPolicy::DEFAULT,
+///     statistics: MissionStats::default(),
+///     day_phase: DayPhase::Day,
+///     next_robot: Some(RobotType::MineralCollector),
+///     next_robot_cost: RobotCost::DEFAULT,
+///     objectives: MissionObjectives::DEFAULT,
+///     energy_low: false,
+///     recall_active: false,
+///     upgrades: vec![StationUpgrade::ChargingBays],
+///     energy_capacity: 1000,
 /// };
 /// ```
 #[derive(Serialize, Deserialize, Clone)]
@@ -256,29 +497,135 @@ pub struct StationData {
     
     /// Human-readable status message describing current mission phase
     /// 
-    /// Provides contextual information about current operations:
-    /// - "Phase 1: Initial Exploration" (0-30% exploration)
-    /// - "Phase 2: Resource Collection" (30-80% exploration)  
-    /// - "Phase 3: Scientific Analysis" (80-100% exploration)
-    /// - "Mission Complete" (all objectives achieved)
+    /// Provides contextual information about current operations, following
+    /// the phases of `collector_policy`:
+    /// - Initial exploration (no collector working yet)
+    /// - Energy and mineral collection
+    /// - Scientific collection (every collector working)
+    /// - Mission complete (all objectives achieved)
     pub status_message: String,
     
     /// Boolean flag indicating whether all mission objectives are complete
     /// 
-    /// True when:
+    /// True once `objectives` are met (see `Station::is_mission_complete`),
+    /// by default when:
     /// - 100% exploration has been achieved
     /// - All available resources have been collected
-    /// - All robots have returned safely to the station
-    /// - Mission is ready for termination and data analysis
+    /// 
+    /// Robots then head home and the mission is ready for termination.
     pub mission_complete: bool,
+    
+    /// Station clock (simulation cycles) at the time of this snapshot
+    /// 
+    /// Reference point for the per-tile `last_seen` timestamps, used by
+    /// monitoring clients to judge how outdated each tile's data is.
+    pub current_time: u32,
+    
+    /// Collector thresholds in use on the server
+    /// 
+    /// Lets monitoring clients name mission phases with the same boundaries
+    /// the robots actually follow.
+    #[serde(default)]
+    pub collector_policy: CollectorPolicy,
+    
+    /// Structured mission metrics computed by `Station::statistics`
+    /// 
+    /// Authoritative figures for monitoring clients (victory screen, reports),
+    /// so they do not have to recompute them from the rest of the snapshot.
+    #[serde(default)]
+    pub statistics: MissionStats,
+    
+    /// Day or night at the time of this snapshot (see `Station::day_phase`)
+    #[serde(default)]
+    pub day_phase: DayPhase,
+    
+    /// Robot the station would build next (see `Station::next_robot_recommendation`)
+    #[serde(default)]
+    pub next_robot: Option<RobotType>,
+    
+    /// Resources needed to build `next_robot` (see `Station::robot_cost`)
+    #[serde(default)]
+    pub next_robot_cost: RobotCost,
+    
+    /// Goals that end the mission (see `Station::objectives`)
+    /// 
+    /// Lets monitoring clients name the phases and the victory conditions
+    /// the server actually applies.
+    #[serde(default)]
+    pub objectives: MissionObjectives,
+    
+    /// Reserves too low to build a robot (see `Station::is_energy_low`)
+    /// 
+    /// Lets monitoring clients warn that the life support is eating into
+    /// the reserves faster than the energy collectors refill them.
+    #[serde(default)]
+    pub energy_low: bool,
+    
+    /// Fleet recall in progress (see `Station::recall_all`)
+    /// 
+    /// Lets monitoring clients offer to resume operations instead of
+    /// recalling the fleet again.
+    #[serde(default)]
+    pub recall_active: bool,
+    
+    /// Upgrades installed on the station, in purchase order (see `Station::purchase_upgrade`)
+    #[serde(default)]
+    pub upgrades: Vec<StationUpgrade>,
+    
+    /// Energy units the reserves hold at most (see `Station::energy_capacity`)
+    /// 
+    /// 0 from servers older than the storage cap.
+    #[serde(default)]
+    pub energy_capacity: u32,
 }
 
+/// Number of cycles after which an explored tile is considered stale
+pub const STALE_AFTER_CYCLES: u32 = 100;
+
 /// NOTE - Network-serializable representation of explored tiles.
 /// Used to transmit which tiles have been explored by the station.
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ExplorationData {
     /// 2D grid: true if tile has been explored, false otherwise.
     pub explored_tiles: Vec<Vec<bool>>,
+    /// 2D grid: station time at which each tile was last confirmed by a robot.
+    pub last_seen: Vec<Vec<u32>>,
+    /// 2D grid: type of the robot that reported each explored tile (None if unexplored).
+    pub explored_by: Vec<Vec<Option<RobotType>>>,
+}
+
+impl ExplorationData {
+    /// Tells whether an explored tile has not been confirmed for a long time.
+    ///
+    /// Stale tiles may no longer match reality (e.g. a resource consumed since
+    /// it was last seen) and are good candidates for re-scouting. Unexplored
+    /// tiles are never stale.
+    ///
+    /// # Parameters
+    ///
+    /// * `x`, `y` - Tile coordinates
+    /// * `current_time` - Station clock, see `StationData::current_time`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ereea::network::{ExplorationData, STALE_AFTER_CYCLES};
+    /// use ereea::types::RobotType;
+    ///
+    /// let data = ExplorationData {
+    ///     explored_tiles: vec![vec![true, false]],
+    ///     last_seen: vec![vec![10, 0]],
+    ///     explored_by: vec![vec![Some(RobotType::Explorer), None]],
+    /// };
+    ///
+    /// assert!(!data.is_stale(0, 0, 10 + STALE_AFTER_CYCLES));
+    /// assert!(data.is_stale(0, 0, 11 + STALE_AFTER_CYCLES));
+    /// assert!(!data.is_stale(1, 0, 1000));
+    /// ```
+    pub fn is_stale(&self, x: usize, y: usize, current_time: u32) -> bool {
+        self.explored_tiles[y][x]
+            && current_time.saturating_sub(self.last_seen[y][x]) > STALE_AFTER_CYCLES
+    }
 }
 
 /// NOTE - Complete simulation state for network transmission.
@@ -290,13 +637,74 @@ pub struct SimulationState {
     pub station_data: StationData,
     pub exploration_data: ExplorationData,
     pub iteration: u32,
+    /// Wall-clock seconds since the simulation started (0 when absent from the frame)
+    #[serde(default)]
+    pub elapsed_secs: f64,
+    /// Latest robot and station events, oldest first (at most `RECENT_EVENTS`)
+    #[serde(default)]
+    pub events: Vec<MissionEvent>,
+    /// Compact robot updates replacing `robots_data` on lite links (see `RobotDataLite`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub robots_lite: Vec<RobotDataLite>,
+    /// Static robot fields sent on lite links when they change (see `RobotKeyframe`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub robot_keyframes: Vec<RobotKeyframe>,
 }
 
-/// NOTE - Global network configuration constants for reliable communication.
-/// 
-/// These constants define the communication parameters used throughout
-/// the EREEA network protocol to ensure consistent and reliable data
-/// transmission between simulation and monitoring systems.
+/// NOTE - Envelope for every newline-delimited frame exchanged over TCP.
+/// Clients and server only ever read and write this type on the wire.
+#[derive(Serialize, Deserialize, Clone)]
+pub enum NetworkMessage {
+    /// Client → server: optional first frame requesting a wire format (and gzip compression) for server frames,
+    /// and compact robot updates when `lite` (see `RobotDataLite`)
+    Hello {
+        format: WireFormat,
+        #[serde(default)]
+        gzip: bool,
+        #[serde(default)]
+        lite: bool,
+    },
+    /// Server → client: answer to `Hello`, every following server frame uses `format`, compressed when `gzip`,
+    /// with `robots_lite` in place of `robots_data` when `lite`
+    Welcome {
+        format: WireFormat,
+        #[serde(default)]
+        gzip: bool,
+        #[serde(default)]
+        lite: bool,
+    },
+    /// Client → server: shared secret, must be the first frame (after `Hello`) when the server requires it
+    Auth { token: String },
+    /// Server → client: authentication refused, the connection is closed right after
+    AuthFailed { error: AuthError },
+    /// Server → client: complete simulation state for one tick
+    State(Box<SimulationState>),
+    /// Server → client: heartbeat, `sent_at_us` is read on the server clock only
+    Ping { sent_at_us: u64 },
+    /// Client → server: heartbeat answer echoing the `Ping` timestamp unchanged
+    Pong { sent_at_us: u64 },
+    /// Client → server: order for the simulation, applied at its next cycle
+    Command(ClientCommand),
+}
+
+/// NOTE - Orders Earth can send to the simulation (see `NetworkMessage::Command`)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ClientCommand {
+    /// Queue a task for a robot (see `Station::dispatch_task`)
+    DispatchTask { robot_id: usize, task: RobotTask },
+    /// Switch a robot to another exploration policy (see `Robot::set_brain`)
+    SetBrain { robot_id: usize, brain: BrainKind },
+    /// Call the whole fleet home (see `Station::recall_all`)
+    RecallAll,
+    /// End a fleet recall (see `Station::resume_operations`)
+    Resume,
+}
+
+// NOTE - Global network configuration constants for reliable communication.
+// 
+// These constants define the communication parameters used throughout
+// the EREEA network protocol to ensure consistent and reliable data
+// transmission between simulation and monitoring systems.
 
 /// Default TCP port for EREEA simulation server communication
 /// 
@@ -320,15 +728,69 @@ pub const DEFAULT_PORT: u16 = 8080;
 /// Current simulation data typically uses 10-50KB per transmission
 pub const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
 
+/// Delay between two heartbeat `Ping` frames sent to each client
+pub const HEARTBEAT_INTERVAL_SECS: u64 = 2;
+
+/// Number of latest mission events carried by each `SimulationState`
+/// 
+/// A client missing a few frames still sees every event as long as fewer
+/// than this many happened in between.
+pub const RECENT_EVENTS: usize = 64;
+
+/// Number of latest mode changes carried for each robot (see `RobotData::recent_transitions`)
+pub const RECENT_TRANSITIONS: usize = 4;
+
+/// Broadcasts between two keyframes of the whole fleet on lite links (see `LiteStateEncoder`)
+/// 
+/// Clients that missed a keyframe (a new robot's, or one lost in a full
+/// queue) rebuild the robots concerned within this many states.
+pub const LITE_KEYFRAME_INTERVAL: u32 = 20;
+
 // NOTE - Utility: Convert Map to MapData for network
 pub fn create_map_data(map: &crate::map::Map) -> MapData {
     MapData {
         tiles: map.tiles.clone(),           // Copie de la grille des tuiles
         station_x: map.station_x,
         station_y: map.station_y,
+        extra_stations: map.extra_stations.clone(),
+        resource_amounts: (0..MAP_SIZE)
+            .map(|y| (0..MAP_SIZE).map(|x| map.resource_amount(x, y)).collect())
+            .collect(),
+        biome: map.biome.clone(),
+        reachable_tiles: map.reachable_tile_count(),
     }
 }
 
+/// NOTE - Utility: Number of robots of each type in a fleet snapshot.
+/// 
+/// Types without any robot are absent from the map. Lets clients describe the
+/// actual fleet, which changes as the station builds new robots.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use ereea::network::{count_by_type, create_robot_data};
+/// use ereea::robot::Robot;
+/// use ereea::types::RobotType;
+/// 
+/// let robots: Vec<_> = [RobotType::Explorer, RobotType::Explorer, RobotType::ScientificCollector]
+///     .into_iter()
+///     .map(|robot_type| create_robot_data(&Robot::new(0, 0, robot_type)))
+///     .collect();
+/// 
+/// let counts = count_by_type(&robots);
+/// assert_eq!(counts[&RobotType::Explorer], 2);
+/// assert_eq!(counts[&RobotType::ScientificCollector], 1);
+/// assert_eq!(counts.get(&RobotType::MineralCollector), None);
+/// ```
+pub fn count_by_type(robots: &[RobotData]) -> HashMap<RobotType, usize> {
+    let mut counts = HashMap::new();
+    for robot in robots {
+        *counts.entry(robot.robot_type).or_insert(0) += 1;
+    }
+    counts
+}
+
 // NOTE - Utility: Convert Robot to RobotData for network
 pub fn create_robot_data(robot: &crate::robot::Robot) -> RobotData {
     RobotData {
@@ -337,50 +799,155 @@ pub fn create_robot_data(robot: &crate::robot::Robot) -> RobotData {
         y: robot.y,
         energy: robot.energy,
         max_energy: robot.max_energy,
-        minerals: robot.minerals,
-        scientific_data: robot.scientific_data,
+        minerals: robot.minerals(),
+        scientific_data: robot.scientific_data(),
         robot_type: robot.robot_type,
         mode: robot.mode,
         exploration_percentage: robot.get_exploration_percentage(),
+        total_energy_spent: robot.total_energy_spent,
+        distance_traveled: robot.distance_traveled,
+        energy_cargo: robot.energy_cargo(),
+        cargo_load: robot.cargo_load(),
+        cargo_capacity: robot.cargo_capacity,
+        stuck_ticks: robot.stuck_ticks(),
+        upgrades: robot.upgrades.clone(),
+        relay_linked: robot.relay_linked,
+        stuck_events: robot.stuck_events(),
+        frontier_size: robot.frontier().len(),
+        current_task: robot.current_task().map(|task| task.to_string()),
+        mode_detail: robot.mode_detail(),
+        inventory: robot.inventory,
+        brain: robot.brain_name().to_string(),
+        recent_transitions: robot.mode_history().iter()
+            .skip(robot.mode_history().len().saturating_sub(RECENT_TRANSITIONS))
+            .map(|transition| transition.to_string())
+            .collect(),
+        current_goal: robot.current_goal(),
     }
 }
 
-// NOTE - Utility: Convert Station to StationData for network
-pub fn create_station_data(station: &crate::station::Station, map: &crate::map::Map) -> StationData {
+/// NOTE - Utility: Convert Station to StationData for network.
+/// 
+/// `robots` is the fleet actually deployed; robot IDs only ever grow, so
+/// they cannot be used to count robots.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use ereea::{map::Map, robot::Robot, station::Station};
+/// use ereea::network::create_simulation_state;
+/// use ereea::types::RobotType;
+/// 
+/// let (map, mut station) = (Map::new(), Station::new());
+/// station.next_robot_id = 10;
+/// let robots = vec![Robot::new(0, 0, RobotType::Explorer), Robot::new(0, 0, RobotType::MineralCollector)];
+/// 
+/// let state = create_simulation_state(&map, &station, &robots, 0, 0.0);
+/// assert_eq!(state.station_data.robot_count, 2);
+/// assert_eq!(state.station_data.statistics.robots_by_type[&RobotType::Explorer], 1);
+/// ```
+pub fn create_station_data(station: &crate::station::Station, map: &crate::map::Map, robots: &[crate::robot::Robot]) -> StationData {
+    let next_robot = station.next_robot_recommendation(map, robots).map(|(robot_type, _)| robot_type);
     StationData {
         energy_reserves: station.energy_reserves,
         collected_minerals: station.collected_minerals,
         collected_scientific_data: station.collected_scientific_data,
         exploration_percentage: station.get_exploration_percentage(),
         conflict_count: station.conflict_count,
-        robot_count: station.next_robot_id - 1,    // Estimation du nombre de robots
-        status_message: station.get_status(),
+        robot_count: robots.len(),
+        status_message: station.get_status(map, robots),
         mission_complete: station.is_mission_complete(map),
+        current_time: station.current_time,
+        collector_policy: station.collector_policy,
+        statistics: station.statistics(map, robots),
+        day_phase: station.day_phase(),
+        next_robot,
+        next_robot_cost: next_robot.map(|robot_type| station.robot_cost(robot_type)).unwrap_or_default(),
+        objectives: station.objectives,
+        energy_low: station.is_energy_low(),
+        recall_active: station.is_recall_active(),
+        upgrades: station.upgrades.clone(),
+        energy_capacity: station.energy_capacity(),
     }
 }
 
 // NOTE - Utility: Create exploration data for network
 pub fn create_exploration_data(station: &crate::station::Station) -> ExplorationData {
     let mut explored_tiles = vec![vec![false; MAP_SIZE]; MAP_SIZE];
+    let mut last_seen = vec![vec![0; MAP_SIZE]; MAP_SIZE];
+    let mut explored_by = vec![vec![None; MAP_SIZE]; MAP_SIZE];
     
-    // Convertir la mémoire complexe de la station en simple grille booléenne
+    // Convertir la mémoire complexe de la station en grilles simples
     for y in 0..MAP_SIZE {
         for x in 0..MAP_SIZE {
-            explored_tiles[y][x] = station.global_memory[y][x].explored;
+            let tile = &station.global_memory[(x, y)];
+            explored_tiles[y][x] = tile.explored;
+            last_seen[y][x] = tile.timestamp;
+            explored_by[y][x] = tile.explored.then_some(tile.robot_type);
         }
     }
     
     ExplorationData {
         explored_tiles,
+        last_seen,
+        explored_by,
     }
 }
 
-// NOTE - Utility: Create complete simulation state for network
+/// NOTE - Utility: Create complete simulation state for network.
+/// 
+/// Everything in the state derives from the map, station and robots, so a
+/// run seeded with `Map::with_seed` and `Robot::seed_rng` produces the same
+/// states tick after tick.
+/// 
+/// # Examples
+/// 
+/// Two 500-cycle runs of the same mission broadcast identical frames:
+/// 
+/// ```rust
+/// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+/// # use ereea::knowledge::{sync_peers, PEER_SYNC_RADIUS};
+/// # use ereea::network::{create_simulation_state, NetworkMessage};
+/// # use ereea::network::codec::encode_frame;
+/// # use ereea::types::RobotType;
+/// fn run(seed: u32) -> Vec<u8> {
+///     let mut map = Map::with_seed(seed);
+///     let mut station = Station::new();
+///     let mut robots: Vec<Robot> = [RobotType::Explorer, RobotType::Explorer, RobotType::EnergyCollector]
+///         .into_iter()
+///         .zip(1..)
+///         .map(|(robot_type, id)| {
+///             let mut robot = Robot::new_with_memory(map.station_x, map.station_y, robot_type, id,
+///                                                    map.station_x, map.station_y, station.global_memory.clone());
+///             robot.seed_rng(map.seed());
+///             robot
+///         })
+///         .collect();
+///     station.next_robot_id = 4;
+///     
+///     for _ in 0..500 {
+///         station.tick();
+///         let mut occupancy = OccupancyGrid::from_map(&robots, &map);
+///         for robot in robots.iter_mut() {
+///             robot.update(&mut map, &mut station, &mut occupancy);
+///         }
+///         sync_peers(&mut robots, PEER_SYNC_RADIUS);
+///         if let Some(robot) = station.try_create_robot(&map, &robots) {
+///             robots.push(robot);
+///         }
+///     }
+///     let state = create_simulation_state(&map, &station, &robots, 500, 0.0);
+///     encode_frame(&NetworkMessage::State(Box::new(state))).unwrap()
+/// }
+/// 
+/// assert_eq!(run(7), run(7));
+/// ```
 pub fn create_simulation_state(
     map: &crate::map::Map, 
     station: &crate::station::Station, 
     robots: &Vec<crate::robot::Robot>, 
-    iteration: u32
+    iteration: u32,
+    elapsed_secs: f64
 ) -> SimulationState {
     // Convertir les données de la carte
     let map_data = create_map_data(map);
@@ -392,7 +959,7 @@ pub fn create_simulation_state(
     }
     
     // Convertir les données de la station (avec la référence à map)
-    let station_data = create_station_data(station, map);
+    let station_data = create_station_data(station, map, robots);
     
     // Convertir les données d'exploration
     let exploration_data = create_exploration_data(station);
@@ -404,5 +971,295 @@ pub fn create_simulation_state(
         station_data,
         exploration_data,
         iteration,
+        elapsed_secs,
+        events: Vec::new(),                 // Ajoutés par la simulation, qui les collecte
+        robots_lite: Vec::new(),            // Seulement sur les liaisons allégées
+        robot_keyframes: Vec::new(),
+    }
+}
+// NOTE - Utility: Static fields of a robot status, sent in lite keyframes
+pub fn create_robot_keyframe(robot: &RobotData) -> RobotKeyframe {
+    RobotKeyframe {
+        id: robot.id,
+        robot_type: robot.robot_type,
+        max_energy: robot.max_energy,
+        cargo_capacity: robot.cargo_capacity,
+        upgrades: robot.upgrades.clone(),
+        brain: robot.brain.clone(),
+        recent_transitions: robot.recent_transitions.clone(),
+    }
+}
+
+// NOTE - Utility: Compact update of a robot status (energy and exploration quantized)
+pub fn create_robot_data_lite(robot: &RobotData) -> RobotDataLite {
+    let energy_share = if robot.max_energy > 0.0 { (robot.energy / robot.max_energy).clamp(0.0, 1.0) } else { 0.0 };
+    RobotDataLite {
+        id: robot.id as u32,
+        x: robot.x as u8,
+        y: robot.y as u8,
+        energy_level: (energy_share * u8::MAX as f32).round() as u8,
+        mode: robot.mode,
+        exploration_permille: (robot.exploration_percentage * 10.0).round().clamp(0.0, 1000.0) as u16,
+        total_energy_spent: robot.total_energy_spent.round() as u32,
+        distance_traveled: robot.distance_traveled.round() as u32,
+        inventory: robot.inventory,
+        stuck_ticks: robot.stuck_ticks.min(u16::MAX as u32) as u16,
+        stuck_events: robot.stuck_events.min(u16::MAX as u32) as u16,
+        frontier_size: robot.frontier_size.min(u16::MAX as usize) as u16,
+        relay_linked: robot.relay_linked,
+        current_task: robot.current_task.clone(),
+        mode_detail: robot.mode_detail.clone(),
+        current_goal: robot.current_goal.map(|(x, y)| (x as u8, y as u8)),
+    }
+}
+
+/// NOTE - Utility: Rebuilds a full robot status from a compact update and the robot's keyframe.
+/// 
+/// Quantized fields come back rounded: the energy to within
+/// `max_energy / 255`, the exploration percentage to a tenth, the energy
+/// spent and the distance to the unit. The flat inventory copies
+/// (`minerals`, `scientific_data`, `energy_cargo`) and `cargo_load` are
+/// derived from `inventory`.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use ereea::network::{create_robot_data, create_robot_data_lite, create_robot_keyframe, restore_robot_data};
+/// use ereea::robot::Robot;
+/// use ereea::types::{RobotType, TileType};
+/// 
+/// let mut robot = Robot::new(3, 4, RobotType::MineralCollector);
+/// robot.energy = 37.3;
+/// robot.load(TileType::Mineral, 2);
+/// let full = create_robot_data(&robot);
+/// 
+/// let restored = restore_robot_data(&create_robot_data_lite(&full), &create_robot_keyframe(&full));
+/// assert_eq!((restored.id, restored.x, restored.y, restored.mode), (full.id, 3, 4, full.mode));
+/// assert_eq!((restored.robot_type, restored.max_energy), (RobotType::MineralCollector, full.max_energy));
+/// assert!((restored.energy - full.energy).abs() <= full.max_energy / 255.0);
+/// assert_eq!((restored.minerals, restored.cargo_load, restored.inventory), (2, 2, full.inventory));
+/// ```
+pub fn restore_robot_data(lite: &RobotDataLite, keyframe: &RobotKeyframe) -> RobotData {
+    RobotData {
+        id: lite.id as usize,
+        x: lite.x as usize,
+        y: lite.y as usize,
+        energy: lite.energy_level as f32 / u8::MAX as f32 * keyframe.max_energy,
+        max_energy: keyframe.max_energy,
+        minerals: lite.inventory.minerals,
+        scientific_data: lite.inventory.science,
+        robot_type: keyframe.robot_type,
+        mode: lite.mode,
+        exploration_percentage: lite.exploration_permille as f32 / 10.0,
+        total_energy_spent: lite.total_energy_spent as f32,
+        distance_traveled: lite.distance_traveled as f32,
+        energy_cargo: lite.inventory.energy,
+        cargo_load: lite.inventory.total_weight(),
+        cargo_capacity: keyframe.cargo_capacity,
+        stuck_ticks: lite.stuck_ticks as u32,
+        upgrades: keyframe.upgrades.clone(),
+        relay_linked: lite.relay_linked,
+        stuck_events: lite.stuck_events as u32,
+        frontier_size: lite.frontier_size as usize,
+        current_task: lite.current_task.clone(),
+        mode_detail: lite.mode_detail.clone(),
+        inventory: lite.inventory,
+        brain: keyframe.brain.clone(),
+        recent_transitions: keyframe.recent_transitions.clone(),
+        current_goal: lite.current_goal.map(|(x, y)| (x as usize, y as usize)),
+    }
+}
+
+// NOTE - Copy of a full state for lite links: compact robot updates and the given keyframes
+fn lite_state(state: &SimulationState, robot_keyframes: Vec<RobotKeyframe>) -> SimulationState {
+    SimulationState {
+        robots_data: Vec::new(),
+        robots_lite: state.robots_data.iter().map(create_robot_data_lite).collect(),
+        robot_keyframes,
+        ..state.clone()
     }
-}
\ No newline at end of file
+}
+
+/// NOTE - Utility: Lite copy of a state with the keyframes of the whole fleet.
+/// 
+/// Sent to a client joining a lite link, which knows no robot yet.
+pub fn create_lite_keyframe_state(state: &SimulationState) -> SimulationState {
+    lite_state(state, state.robots_data.iter().map(create_robot_keyframe).collect())
+}
+
+/// NOTE - Utility: Checks that a state survives a JSON round trip unchanged.
+/// 
+/// Serializes the state, reads it back and compares both, field by field,
+/// as JSON documents. Catches the serde mistakes that clients would only
+/// report as "Données corrompues" or silently lost data: a field skipped on
+/// one side only, a value JSON cannot carry (a NaN energy travels as `null`,
+/// which no client reads back as a number)... The error names the first
+/// mismatching field and both of its values.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use ereea::{map::Map, simulation, station::Station};
+/// use ereea::network::{create_simulation_state, roundtrip_check};
+/// 
+/// let mut map = Map::with_seed(3);
+/// let mut station = Station::new();
+/// let mut robots = simulation::deploy_fleet(&map, &mut station, &simulation::INITIAL_FLEET);
+/// for iteration in 0..50 {
+///     simulation::step_simulation(&mut map, &mut station, &mut robots, iteration);
+/// }
+/// assert_eq!(roundtrip_check(&create_simulation_state(&map, &station, &robots, 50, 1.5)), Ok(()));
+/// 
+/// // An undefined energy cannot be sent
+/// robots[0].energy = f32::NAN;
+/// let error = roundtrip_check(&create_simulation_state(&map, &station, &robots, 50, 1.5)).unwrap_err();
+/// assert!(error.contains("null"), "{}", error);
+/// ```
+pub fn roundtrip_check(state: &SimulationState) -> Result<(), String> {
+    let sent = serde_json::to_value(state).map_err(|e| format!("sérialisation impossible : {}", e))?;
+    let json = serde_json::to_string(state).map_err(|e| format!("sérialisation impossible : {}", e))?;
+    let decoded: SimulationState = serde_json::from_str(&json).map_err(|e| format!("désérialisation impossible : {}", e))?;
+    let received = serde_json::to_value(&decoded).map_err(|e| format!("sérialisation impossible après relecture : {}", e))?;
+    match first_difference("état", &sent, &received) {
+        Some(difference) => Err(difference),
+        None => Ok(()),
+    }
+}
+
+// NOTE - Path and values of the first difference between two JSON documents, None if they are equal
+fn first_difference(path: &str, sent: &serde_json::Value, received: &serde_json::Value) -> Option<String> {
+    use serde_json::Value;
+    match (sent, received) {
+        (Value::Object(sent), Value::Object(received)) => {
+            let missing = received.keys().filter(|key| !sent.contains_key(*key));
+            sent.keys().chain(missing).find_map(|key| {
+                let field = format!("{}.{}", path, key);
+                match (sent.get(key), received.get(key)) {
+                    (Some(sent), Some(received)) => first_difference(&field, sent, received),
+                    (Some(sent), None) => Some(format!("{} : {} envoyé, absent à la réception", field, sent)),
+                    (None, received) => Some(format!("{} : absent à l'envoi, {} reçu", field, received.unwrap_or(&Value::Null))),
+                }
+            })
+        },
+        (Value::Array(sent), Value::Array(received)) if sent.len() == received.len() => {
+            sent.iter().zip(received).enumerate()
+                .find_map(|(index, (sent, received))| first_difference(&format!("{}[{}]", path, index), sent, received))
+        },
+        (Value::Array(sent), Value::Array(received)) => {
+            Some(format!("{} : {} élément(s) envoyé(s), {} reçu(s)", path, sent.len(), received.len()))
+        },
+        _ if sent == received => None,
+        _ => Some(format!("{} : {} envoyé, {} reçu", path, sent, received)),
+    }
+}
+
+/// Server side of lite links: turns each broadcast state into its lite copy.
+/// 
+/// A robot's keyframe goes along when it differs from the one sent with the
+/// previous state, and the whole fleet's every `LITE_KEYFRAME_INTERVAL`
+/// states (the first one included), so that clients which dropped a frame
+/// catch up. One encoder serves every lite client: they all receive the same
+/// states.
+/// 
+/// # Examples
+/// 
+/// Over a mission, the robots of the lite states take less than a third of
+/// the bytes of the full ones, keyframes included, and `LiteStateDecoder`
+/// rebuilds them on the client:
+/// 
+/// ```rust
+/// use ereea::{map::Map, simulation, station::Station};
+/// use ereea::network::{create_simulation_state, LiteStateDecoder, LiteStateEncoder, LITE_KEYFRAME_INTERVAL};
+/// 
+/// let mut map = Map::with_seed(3);
+/// let mut station = Station::new();
+/// let mut robots = simulation::deploy_fleet(&map, &mut station, &simulation::INITIAL_FLEET);
+/// let mut encoder = LiteStateEncoder::new();
+/// let mut decoder = LiteStateDecoder::new();
+/// let (mut full_size, mut lite_size) = (0, 0);
+/// for iteration in 0..300 {
+///     simulation::step_simulation(&mut map, &mut station, &mut robots, iteration);
+///     let state = create_simulation_state(&map, &station, &robots, iteration, 0.0);
+///     let mut lite = encoder.encode(&state);
+///     if iteration % LITE_KEYFRAME_INTERVAL == 0 {
+///         assert_eq!(lite.robot_keyframes.len(), state.robots_data.len());
+///     }
+///     
+///     full_size += serde_json::to_vec(&state.robots_data).unwrap().len();
+///     lite_size += serde_json::to_vec(&lite.robots_lite).unwrap().len()
+///         + serde_json::to_vec(&lite.robot_keyframes).unwrap().len();
+///     
+///     decoder.restore(&mut lite);
+///     assert_eq!(lite.robots_data.len(), state.robots_data.len());
+///     for (restored, full) in lite.robots_data.iter().zip(&state.robots_data) {
+///         assert_eq!((restored.id, restored.x, restored.y, restored.mode), (full.id, full.x, full.y, full.mode));
+///         assert!((restored.energy - full.energy).abs() <= full.max_energy / 255.0);
+///         assert_eq!(restored.recent_transitions, full.recent_transitions);
+///     }
+/// }
+/// assert!(lite_size * 3 < full_size, "{} octets contre {}", lite_size, full_size);
+/// ```
+#[derive(Default)]
+pub struct LiteStateEncoder {
+    /// States encoded so far
+    broadcasts: u32,
+    /// Keyframe of each robot as of the previous state
+    sent: HashMap<usize, RobotKeyframe>,
+}
+
+impl LiteStateEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Lite copy of the next broadcast state (see `LiteStateEncoder`)
+    pub fn encode(&mut self, state: &SimulationState) -> SimulationState {
+        let whole_fleet = self.broadcasts.is_multiple_of(LITE_KEYFRAME_INTERVAL);
+        self.broadcasts += 1;
+        
+        let current: HashMap<usize, RobotKeyframe> = state.robots_data.iter()
+            .map(|robot| (robot.id, create_robot_keyframe(robot)))
+            .collect();
+        let keyframes = state.robots_data.iter()
+            .filter(|robot| whole_fleet || self.sent.get(&robot.id) != current.get(&robot.id))
+            .map(|robot| current[&robot.id].clone())
+            .collect();
+        self.sent = current;
+        lite_state(state, keyframes)
+    }
+}
+
+/// Client side of lite links: rebuilds `robots_data` from the compact updates.
+/// 
+/// Keeps the latest keyframe of each robot. A robot whose keyframe has not
+/// arrived yet is left out of `robots_data` until the next one.
+#[derive(Default)]
+pub struct LiteStateDecoder {
+    /// Latest keyframe of each robot
+    keyframes: HashMap<usize, RobotKeyframe>,
+}
+
+impl LiteStateDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Fills `state.robots_data` from `state.robots_lite` and the keyframes received so far.
+    /// 
+    /// Full states (without `robots_lite`) are left as they are.
+    pub fn restore(&mut self, state: &mut SimulationState) {
+        for keyframe in state.robot_keyframes.drain(..) {
+            self.keyframes.insert(keyframe.id, keyframe);
+        }
+        if state.robots_lite.is_empty() {
+            return;
+        }
+        
+        // NOTE - Robots no longer in the fleet (retired) are forgotten
+        let present: HashSet<usize> = state.robots_lite.iter().map(|lite| lite.id as usize).collect();
+        self.keyframes.retain(|id, _| present.contains(id));
+        state.robots_data = state.robots_lite.drain(..)
+            .filter_map(|lite| self.keyframes.get(&(lite.id as usize)).map(|keyframe| restore_robot_data(&lite, keyframe)))
+            .collect();
+    }
+}
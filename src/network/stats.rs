@@ -0,0 +1,130 @@
+//! # Per-Connection Statistics
+//!
+//! Counters describing what each Earth client actually receives: bytes and
+//! frames sent, frames dropped because the client could not keep up, and the
+//! round-trip time measured from heartbeat `Ping`/`Pong` frames.
+//!
+//! Counters are atomics so the writer task, the heartbeat reader and the
+//! metrics endpoint can share them without any lock on the send path.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use serde::{Serialize, Deserialize};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// NOTE - Live counters for one client connection.
+#[derive(Debug, Default)]
+pub struct ConnectionStats {
+    // NOTE - Total bytes written to the socket (delimiters included)
+    bytes_sent: AtomicU64,
+    // NOTE - Frames fully written to the socket
+    frames_sent: AtomicU64,
+    // NOTE - Frames skipped because the client queue was full
+    frames_dropped: AtomicU64,
+    // NOTE - Last measured round-trip time in microseconds (0 = not measured yet)
+    last_rtt_micros: AtomicU64,
+}
+
+/// Point-in-time copy of `ConnectionStats`, serializable for the metrics endpoint
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ConnectionStatsSnapshot {
+    pub bytes_sent: u64,
+    pub frames_sent: u64,
+    pub frames_dropped: u64,
+    /// Last heartbeat round-trip time in milliseconds, `None` before the first pong
+    pub last_rtt_ms: Option<f64>,
+}
+
+impl ConnectionStats {
+    /// Creates zeroed counters for a new connection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a frame of `bytes` bytes successfully written to the client
+    pub fn record_sent(&self, bytes: usize) {
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.frames_sent.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a frame that was not delivered to the client
+    pub fn record_dropped(&self) {
+        self.frames_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records the round-trip time of the latest heartbeat
+    pub fn record_rtt(&self, rtt: Duration) {
+        // NOTE - Clamp to 1µs so a measured RTT is never confused with "unknown"
+        let micros = u64::try_from(rtt.as_micros()).unwrap_or(u64::MAX).max(1);
+        self.last_rtt_micros.store(micros, Ordering::Relaxed);
+    }
+
+    /// Returns a copy of the current counters
+    pub fn snapshot(&self) -> ConnectionStatsSnapshot {
+        let rtt = self.last_rtt_micros.load(Ordering::Relaxed);
+
+        ConnectionStatsSnapshot {
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            frames_sent: self.frames_sent.load(Ordering::Relaxed),
+            frames_dropped: self.frames_dropped.load(Ordering::Relaxed),
+            last_rtt_ms: (rtt > 0).then(|| rtt as f64 / 1000.0),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionStatsSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} trames / {} octets envoyés, {} trames perdues, RTT: ",
+               self.frames_sent, self.bytes_sent, self.frames_dropped)?;
+        match self.last_rtt_ms {
+            Some(rtt) => write!(f, "{:.2} ms", rtt),
+            None => write!(f, "inconnu"),
+        }
+    }
+}
+
+/// Writes one encoded frame to a client and updates its counters on success.
+///
+/// # Examples
+///
+/// Sending a known number of frames to a mock client: the byte counter
+/// matches exactly what the client received.
+///
+/// ```rust
+/// use ereea::network::NetworkMessage;
+/// use ereea::network::codec::{encode_frame, FrameDecoder};
+/// use ereea::network::stats::{send_frame, ConnectionStats};
+/// use tokio::io::AsyncReadExt;
+///
+/// tokio::runtime::Runtime::new().unwrap().block_on(async {
+///     let (mut server, mut client) = tokio::io::duplex(64 * 1024);
+///     let stats = ConnectionStats::new();
+///
+///     for sent_at_us in 0..5 {
+///         let frame = encode_frame(&NetworkMessage::Ping { sent_at_us }).unwrap();
+///         send_frame(&mut server, &frame, &stats).await.unwrap();
+///     }
+///     stats.record_dropped();
+///     drop(server);
+///
+///     let mut received = Vec::new();
+///     client.read_to_end(&mut received).await.unwrap();
+///
+///     let snapshot = stats.snapshot();
+///     assert_eq!(snapshot.bytes_sent, received.len() as u64);
+///     assert_eq!(snapshot.frames_sent, 5);
+///     assert_eq!(snapshot.frames_dropped, 1);
+///     assert_eq!(snapshot.last_rtt_ms, None);
+///     assert_eq!(FrameDecoder::new().decode(&received).len(), 5);
+/// });
+/// ```
+pub async fn send_frame<W>(writer: &mut W, frame: &[u8], stats: &ConnectionStats) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    writer.write_all(frame).await?;
+    stats.record_sent(frame.len());
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! # Robot Occupancy Module
+//!
+//! Tracks which map tiles are currently held by a robot so that robots do not
+//! stack on the same tile. The simulation loop builds one grid per tick from
+//! all robot positions; each `Robot::update` keeps it up to date as the robot
+//! moves, so robots updated later in the tick see the new positions.
+//! Station tiles (every station of the map) never count as occupied.
+
+use crate::types::MAP_SIZE;
+use crate::map::Map;
+use crate::robot::Robot;
+
+/// Number of robots standing on each tile of the map.
+///
+/// Station tiles are never reported as occupied: any number of robots can
+/// dock there at the same time.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::occupancy::OccupancyGrid;
+/// # use ereea::robot::Robot;
+/// # use ereea::types::RobotType;
+/// let robots = vec![
+///     Robot::new(10, 10, RobotType::Explorer),
+///     Robot::new(10, 10, RobotType::MineralCollector),
+///     Robot::new(4, 7, RobotType::EnergyCollector),
+/// ];
+/// let occupancy = OccupancyGrid::from_robots(&robots, 10, 10);
+///
+/// assert!(occupancy.is_occupied(4, 7));
+/// assert!(!occupancy.is_occupied(5, 7));
+///
+/// // Two robots are docked, but the station tile stays free for the others
+/// assert_eq!(occupancy.robots_at(10, 10), 2);
+/// assert!(!occupancy.is_occupied(10, 10));
+/// ```
+///
+/// Two robots heading home through a one-tile-wide corridor go through it
+/// one after the other, never sharing a tile:
+///
+/// ```rust
+/// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+/// # use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+/// // Open ground on both sides of a corridor along row 5, station at its far end
+/// let mut map = Map::open();
+/// for y in 0..MAP_SIZE {
+///     for x in 5..15 {
+///         if y != 5 {
+///             map.tiles[y][x] = TileType::Obstacle;
+///         }
+///     }
+/// }
+/// (map.station_x, map.station_y) = (18, 5);
+/// let mut station = Station::new();
+///
+/// let mut robots: Vec<Robot> = [(3, 4), (3, 6)].iter().enumerate()
+///     .map(|(i, &(x, y))| {
+///         let mut robot = Robot::new_with_memory(x, y, RobotType::Explorer, i + 1, 18, 5, station.global_memory.clone());
+///         robot.mode = RobotMode::ReturnToStation;
+///         robot
+///     })
+///     .collect();
+///
+/// let mut arrivals = [None, None];
+/// for tick in 1..=40_u32 {
+///     station.tick();
+///     let mut occupancy = OccupancyGrid::from_robots(&robots, 18, 5);
+///     for (robot, arrival) in robots.iter_mut().zip(arrivals.iter_mut()) {
+///         if arrival.is_none() {
+///             robot.update(&mut map, &mut station, &mut occupancy);
+///             if (robot.x, robot.y) == (18, 5) {
+///                 *arrival = Some(tick);
+///             }
+///         }
+///     }
+///     let [first, second] = [&robots[0], &robots[1]].map(|robot| (robot.x, robot.y));
+///     assert!(first != second || first == (18, 5), "both robots on {:?} at tick {}", first, tick);
+/// }
+///
+/// // Both got through, on successive ticks
+/// let [Some(first), Some(second)] = arrivals else { panic!("stuck: {:?}", arrivals) };
+/// assert_eq!(first.abs_diff(second), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct OccupancyGrid {
+    // NOTE - Robots per tile, indexed [y][x]
+    counts: [[u16; MAP_SIZE]; MAP_SIZE],
+    // NOTE - Station tiles, exempt from occupancy checks
+    stations: Vec<(usize, usize)>,
+}
+
+impl OccupancyGrid {
+    // NOTE - Empty grid (no robot on the map)
+    pub fn new(station_x: usize, station_y: usize) -> Self {
+        Self {
+            counts: [[0; MAP_SIZE]; MAP_SIZE],
+            stations: vec![(station_x, station_y)],
+        }
+    }
+
+    // NOTE - Grid holding the current position of every robot
+    pub fn from_robots(robots: &[Robot], station_x: usize, station_y: usize) -> Self {
+        let mut grid = Self::new(station_x, station_y);
+        for robot in robots {
+            grid.counts[robot.y][robot.x] += 1;
+        }
+        grid
+    }
+    
+    // NOTE - Grid holding the current position of every robot, all map stations exempt
+    pub fn from_map(robots: &[Robot], map: &Map) -> Self {
+        let mut grid = Self::from_robots(robots, map.station_x, map.station_y);
+        grid.stations.extend(map.extra_stations.iter().copied());
+        grid
+    }
+
+    // NOTE - Number of robots on a tile (station included)
+    pub fn robots_at(&self, x: usize, y: usize) -> usize {
+        self.counts[y][x] as usize
+    }
+
+    // NOTE - True if a robot stands on the tile and it is not a station
+    pub fn is_occupied(&self, x: usize, y: usize) -> bool {
+        self.counts[y][x] > 0 && !self.stations.contains(&(x, y))
+    }
+
+    // NOTE - Record a robot move from one tile to another
+    pub fn move_robot(&mut self, from: (usize, usize), to: (usize, usize)) {
+        self.counts[from.1][from.0] = self.counts[from.1][from.0].saturating_sub(1);
+        self.counts[to.1][to.0] += 1;
+    }
+}
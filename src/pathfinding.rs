@@ -0,0 +1,392 @@
+//! # Pathfinding Module
+//!
+//! Path searches on the map, shared by the robots (`Robot::find_path` and
+//! its variants), the map generation (`Map::path_exists`, the corridors
+//! carved to isolated resources) and anyone who needs to plan on the map.
+//!
+//! `find_path` runs an A* search whose rules come from `PathOptions`: which
+//! diagonal steps are allowed, what each step costs (and which tiles cannot
+//! be entered at all), the distance estimate and any extra tiles to avoid.
+//! The defaults are the robots' movement rules. `reachable_from` floods the
+//! map from a tile under the same default rules.
+//!
+//! # Examples
+//!
+//! A table of small maps drawn in ASCII (`#` obstacle, `.` bare ground,
+//! `M` mineral, `S` start, `G` goal, `X` goal on an obstacle); everything
+//! outside the drawing is rock.
+//!
+//! ```rust
+//! # use ereea::map::Map;
+//! # use ereea::pathfinding::{find_path, Diagonals, PathOptions};
+//! # use ereea::types::{TileType, MAP_SIZE};
+//! // Map, start and goal of an ASCII drawing
+//! fn parse(rows: &[&str]) -> (Map, (usize, usize), (usize, usize)) {
+//!     let mut map = Map::new();
+//!     map.tiles = vec![vec![TileType::Obstacle; MAP_SIZE]; MAP_SIZE];
+//!     let (mut start, mut goal) = ((0, 0), (0, 0));
+//!     for (y, row) in rows.iter().enumerate() {
+//!         for (x, c) in row.chars().enumerate() {
+//!             map.tiles[y][x] = match c {
+//!                 '#' | 'X' => TileType::Obstacle,
+//!                 'M' => TileType::Mineral,
+//!                 _ => TileType::Empty,
+//!             };
+//!             match c {
+//!                 'S' => start = (x, y),
+//!                 'G' | 'X' => goal = (x, y),
+//!                 _ => {}
+//!             }
+//!         }
+//!     }
+//!     (map, start, goal)
+//! }
+//!
+//! let orthogonal = PathOptions::new().with_diagonals(Diagonals::Never);
+//! let cutting = PathOptions::new().with_diagonals(Diagonals::Always);
+//! // Drawing, options, expected number of steps (`None`: no path)
+//! let cases: [(&[&str], &PathOptions, Option<usize>); 9] = [
+//!     // Open ground: straight ahead
+//!     (&["S...G"], &PathOptions::new(), Some(4)),
+//!     // Already there
+//!     (&["S"], &PathOptions::new(), Some(0)),
+//!     // Walled off
+//!     (&["S.#.G"], &PathOptions::new(), None),
+//!     // The goal is rock
+//!     (&["S..X."], &PathOptions::new(), None),
+//!     // Diagonal steps shorten the way...
+//!     (&["S..",
+//!        "...",
+//!        "..G"], &PathOptions::new(), Some(2)),
+//!     // ...unless only orthogonal steps are allowed
+//!     (&["S..",
+//!        "...",
+//!        "..G"], &orthogonal, Some(4)),
+//!     // No squeezing between two touching obstacles...
+//!     (&["S#",
+//!        "#G"], &PathOptions::new(), None),
+//!     // ...unless corner cutting is allowed
+//!     (&["S#",
+//!        "#G"], &cutting, Some(1)),
+//!     // Minerals are walked around when a detour costs less
+//!     (&["....",
+//!        "SMMG",
+//!        "...."], &PathOptions::new(), Some(3)),
+//! ];
+//!
+//! for (rows, options, expected) in cases {
+//!     let (map, start, goal) = parse(rows);
+//!     let path = find_path(&map, start, goal, options);
+//!     assert_eq!(path.as_ref().map(Vec::len), expected, "{:?}", rows);
+//!     if let Some(path) = path {
+//!         // Every step is legal under the options and the path ends on the goal
+//!         let mut from = start;
+//!         for &to in &path {
+//!             assert!(from.0.abs_diff(to.0) <= 1 && from.1.abs_diff(to.1) <= 1, "{:?}", rows);
+//!             assert!(cutting.can_step(&map, from, to) && options.can_step(&map, from, to), "{:?}", rows);
+//!             from = to;
+//!         }
+//!         assert_eq!(from, goal);
+//!     }
+//! }
+//!
+//! // Around the minerals rather than through them
+//! let (map, start, goal) = parse(&["....", "SMMG", "...."]);
+//! let path = find_path(&map, start, goal, &PathOptions::new()).unwrap();
+//! assert!(path.iter().all(|&(x, y)| map.tiles[y][x] != TileType::Mineral), "{:?}", path);
+//! ```
+
+use crate::grid::Grid;
+use crate::map::Map;
+use crate::robot::movement_cost;
+use crate::types::{Position, TileType, MAP_SIZE};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// A* works on integer costs: tenths of a movement cost unit (see `default_step_cost`)
+pub const PATH_COST_SCALE: f32 = 10.0;
+
+// NOTE - Tiles a path may step on, used to bound the A* estimate by the cheapest step
+const PASSABLE_TILES: [TileType; 4] = [TileType::Empty, TileType::Energy, TileType::Mineral, TileType::Scientific];
+
+/// Step cost matching `robot::movement_cost`, in `PATH_COST_SCALE` units
+/// (`None` for obstacles): the default cost table of `PathOptions`.
+pub fn default_step_cost(tile: TileType) -> Option<u32> {
+    let cost = movement_cost(&tile);
+    cost.is_finite().then(|| (cost * PATH_COST_SCALE).round() as u32)
+}
+
+/// Which diagonal steps a path may take
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Diagonals {
+    /// Orthogonal steps only
+    Never,
+    /// Diagonal steps, except between two impassable tiles (the robots' rule, see `Map::can_step`)
+    #[default]
+    NoCornerCutting,
+    /// Any diagonal step onto a passable tile
+    Always,
+}
+
+/// Rules of a path search (see `find_path`).
+///
+/// `PathOptions::new()` gives the robots' movement rules: diagonal steps
+/// without corner cutting, `default_step_cost` and the Chebyshev estimate.
+#[derive(Clone, Copy)]
+pub struct PathOptions<'a> {
+    /// Which diagonal steps are allowed
+    pub diagonals: Diagonals,
+    /// Cost of stepping onto a tile, `None` when it cannot be entered
+    pub step_cost: &'a dyn Fn(TileType) -> Option<u32>,
+    /// Distance estimate, in steps, scaled by the cheapest step of `step_cost`
+    pub heuristic: fn(Position, Position) -> usize,
+    /// Tiles treated as impassable on top of `step_cost` (robots in the way, ...)
+    pub avoid: Option<&'a dyn Fn((usize, usize)) -> bool>,
+}
+
+impl<'a> PathOptions<'a> {
+    // NOTE - Robots' movement rules
+    pub fn new() -> Self {
+        Self {
+            diagonals: Diagonals::default(),
+            step_cost: &default_step_cost,
+            heuristic: Position::chebyshev_distance,
+            avoid: None,
+        }
+    }
+
+    // NOTE - Builder method to choose the diagonal policy
+    pub fn with_diagonals(mut self, diagonals: Diagonals) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
+    // NOTE - Builder method to replace the cost table
+    pub fn with_step_cost(mut self, step_cost: &'a dyn Fn(TileType) -> Option<u32>) -> Self {
+        self.step_cost = step_cost;
+        self
+    }
+
+    // NOTE - Builder method to replace the distance estimate (a zero estimate gives Dijkstra)
+    pub fn with_heuristic(mut self, heuristic: fn(Position, Position) -> usize) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    // NOTE - Builder method to block extra tiles
+    pub fn avoiding(mut self, avoid: &'a dyn Fn((usize, usize)) -> bool) -> Self {
+        self.avoid = Some(avoid);
+        self
+    }
+
+    // NOTE - Whether a tile inside the map may be entered
+    fn is_passable(&self, map: &Map, (x, y): (usize, usize)) -> bool {
+        (self.step_cost)(map.get_tile(x, y)).is_some()
+    }
+
+    /// Whether a path may step from `from` to the adjacent tile `to` under these rules
+    pub fn can_step(&self, map: &Map, from: (usize, usize), to: (usize, usize)) -> bool {
+        if to.0 >= MAP_SIZE || to.1 >= MAP_SIZE || !self.is_passable(map, to) || self.avoid.is_some_and(|avoid| avoid(to)) {
+            return false;
+        }
+
+        // NOTE - Orthogonal steps only need a free destination
+        if from.0 == to.0 || from.1 == to.1 {
+            return true;
+        }
+        match self.diagonals {
+            Diagonals::Never => false,
+            Diagonals::NoCornerCutting => self.is_passable(map, (to.0, from.1)) || self.is_passable(map, (from.0, to.1)),
+            Diagonals::Always => true,
+        }
+    }
+}
+
+impl Default for PathOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A path (start excluded) and its total cost under `PathOptions::step_cost`
+pub type CostedPath = (Vec<(usize, usize)>, u32);
+
+/// Work done by one A* search (see `find_path_with_stats`)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PathSearchStats {
+    /// Nodes taken out of the open set and expanded
+    pub expanded_nodes: usize,
+    /// Expansions of a node that had already been expanded (inconsistent heuristic)
+    pub reexpanded_nodes: usize,
+    /// Total movement cost of the path found, `None` if the target is unreachable
+    pub path_cost: Option<f32>,
+}
+
+// NOTE - Node structure for A* pathfinding algorithm
+#[derive(Clone, Eq, PartialEq)]
+struct Node {
+    // NOTE - Node position on the map
+    position: (usize, usize),
+    // NOTE - Cost from start to this node
+    g_cost: usize,
+    // NOTE - Estimated total cost (g_cost + heuristic)
+    f_cost: usize,
+}
+
+// NOTE - Implement ordering for priority queue (min-heap for A*)
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // NOTE - Reverse order for min-heap; on equal estimates, prefer the node closest to the goal
+        other.f_cost.cmp(&self.f_cost)
+            .then_with(|| self.g_cost.cmp(&other.g_cost))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cheapest path from `start` to `goal` under `options`, start excluded.
+///
+/// Returns an empty path when `start == goal`, and `None` when `goal`
+/// cannot be reached (including when it cannot be entered at all).
+pub fn find_path(map: &Map, start: (usize, usize), goal: (usize, usize), options: &PathOptions) -> Option<Vec<(usize, usize)>> {
+    find_path_with_stats(map, start, goal, options).0.map(|(path, _)| path)
+}
+
+/// Same as `find_path`, with the total cost of the path (sum of the
+/// `step_cost` of its tiles) and the work the search did.
+pub fn find_path_with_stats(
+    map: &Map,
+    start: (usize, usize),
+    goal: (usize, usize),
+    options: &PathOptions,
+) -> (Option<CostedPath>, PathSearchStats) {
+    // Si déjà à destination
+    if start == goal {
+        let stats = PathSearchStats { path_cost: Some(0.0), ..PathSearchStats::default() };
+        return (Some((Vec::new(), 0)), stats);
+    }
+
+    // Estimation en unités de coût A* : chaque pas coûte au moins le terrain le moins cher
+    let min_step_cost = PASSABLE_TILES.iter()
+        .filter_map(|tile| (options.step_cost)(tile.clone()))
+        .min()
+        .unwrap_or(0) as usize;
+    let estimate = |pos: (usize, usize)| (options.heuristic)(pos.into(), goal.into()) * min_step_cost;
+    let mut stats = PathSearchStats::default();
+    let mut closed = HashSet::new();
+
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut g_score: HashMap<(usize, usize), usize> = HashMap::new();
+
+    // Initialiser les valeurs de départ
+    g_score.insert(start, 0);
+    open_set.push(Node {
+        position: start,
+        g_cost: 0,
+        f_cost: estimate(start),
+    });
+
+    while let Some(current) = open_set.pop() {
+        let current_pos = current.position;
+
+        // Ignorer les entrées périmées (un meilleur chemin vers ce nœud a déjà été traité)
+        if current.g_cost > g_score[&current_pos] {
+            continue;
+        }
+        stats.expanded_nodes += 1;
+        if !closed.insert(current_pos) {
+            stats.reexpanded_nodes += 1;
+        }
+
+        // Si on est arrivé à destination : reconstruire le chemin
+        if current_pos == goal {
+            let mut path = Vec::new();
+            let mut current = goal;
+            while current != start {
+                path.push(current);
+                current = came_from[&current];
+            }
+            path.reverse();
+
+            let cost = g_score[&goal] as u32;
+            stats.path_cost = Some(cost as f32 / PATH_COST_SCALE);
+            return (Some((path, cost)), stats);
+        }
+
+        // Examiner tous les voisins autorisés
+        for neighbor in neighbors(current_pos) {
+            if !options.can_step(map, current_pos, neighbor) {
+                continue;
+            }
+
+            // Calculer le nouveau coût (selon le terrain de la case d'arrivée)
+            let Some(step_cost) = (options.step_cost)(map.get_tile(neighbor.0, neighbor.1)) else {
+                continue;
+            };
+            let tentative_g_score = g_score[&current_pos] + step_cost as usize;
+
+            // Si on a trouvé un meilleur chemin
+            if g_score.get(&neighbor).is_none_or(|&score| tentative_g_score < score) {
+                came_from.insert(neighbor, current_pos);
+                g_score.insert(neighbor, tentative_g_score);
+                open_set.push(Node {
+                    position: neighbor,
+                    g_cost: tentative_g_score,
+                    f_cost: tentative_g_score + estimate(neighbor),
+                });
+            }
+        }
+    }
+
+    (None, stats)
+}
+
+/// Tiles a robot can walk to from `start`, indexed `[y][x]` (breadth-first
+/// search under the robots' movement rules, see `Map::can_step`).
+///
+/// `start` itself is always reachable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ereea::map::Map;
+/// # use ereea::pathfinding::reachable_from;
+/// # use ereea::types::{TileType, MAP_SIZE};
+/// let mut map = Map::open();
+/// // A wall across the map at x = 5
+/// for y in 0..MAP_SIZE {
+///     map.tiles[y][5] = TileType::Obstacle;
+/// }
+///
+/// let reachable = reachable_from(&map, (0, 0));
+/// assert!(reachable[(4, 19)] && !reachable[(5, 0)] && !reachable[(6, 0)]);
+/// assert_eq!(reachable.iter().filter(|&&tile| tile).count(), 5 * MAP_SIZE);
+/// ```
+pub fn reachable_from(map: &Map, start: (usize, usize)) -> Grid<bool> {
+    let mut reachable = Grid::filled(false);
+    let mut queue = VecDeque::from([start]);
+    reachable[start] = true;
+
+    while let Some(current) = queue.pop_front() {
+        for (nx, ny) in neighbors(current) {
+            if !reachable[(nx, ny)] && map.can_step(current, (nx, ny)) {
+                reachable[(nx, ny)] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    reachable
+}
+
+// NOTE - The (up to 8) tiles around a tile, inside the map
+fn neighbors((x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    (y.saturating_sub(1)..=(y + 1).min(MAP_SIZE - 1))
+        .flat_map(move |ny| (x.saturating_sub(1)..=(x + 1).min(MAP_SIZE - 1)).map(move |nx| (nx, ny)))
+        .filter(move |&neighbor| neighbor != (x, y))
+}
@@ -18,35 +18,438 @@
 //! - **Collectors**: Resource-focused behavior with efficiency optimization
 //! - **Hybrid Modes**: Dynamic switching between exploration and collection
 
-use crate::types::{MAP_SIZE, TileType, RobotType, RobotMode};
+use crate::brain::{RobotAction, RobotBrain, RobotContext, StationSummary};
+use crate::types::{BrainKind, GiveUpReason, MAP_SIZE, Inventory, Position, TileType, RobotType, RobotMode, RobotTask, Upgrade, VisionModel};
 use crate::map::Map;
-use crate::station::{Station, TerrainData};
+use crate::occupancy::OccupancyGrid;
+use crate::knowledge::{ExplorationArea, KnowledgeBase};
+use crate::station::{MalfunctionConfig, Station, TerrainData, SECTOR_SIZE};
+use crate::energy::{DefaultEnergyModel, EnergyModel};
+use crate::events::RobotEvent;
+use crate::grid::Grid;
+use crate::pathfinding::{self, PathOptions};
+// NOTE - Statistics of `Robot::path_search_stats`, now defined with the search itself
+pub use crate::pathfinding::PathSearchStats;
 use rand::prelude::*;
-use std::collections::{VecDeque, BinaryHeap, HashMap};
-use std::cmp::Ordering;
-
-// NOTE - Node structure for A* pathfinding algorithm
-#[derive(Clone, Eq, PartialEq)]
-struct Node {
-    // NOTE - Node position on the map
-    position: (usize, usize),
-    // NOTE - Cost from start to this node
-    g_cost: usize,
-    // NOTE - Estimated total cost (g_cost + heuristic)
-    f_cost: usize,
+use rand::distributions::WeightedIndex;
+use std::collections::{VecDeque, HashSet};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::sync::Arc;
+
+// NOTE - Tiles a scan reveals beyond the robot's normal vision range
+const SCAN_RANGE_BONUS: isize = 3;
+
+// NOTE - Energy consumed by one scan (see `Robot::scan`)
+const SCAN_ENERGY_COST: f32 = 4.0;
+
+// NOTE - Unknown tiles a scan must be able to reveal for an explorer to stop and scan
+const SCAN_MIN_UNKNOWN_TILES: usize = 12;
+
+// NOTE - Extra steps of energy kept in reserve when deciding to head home
+const RETURN_MARGIN_STEPS: f32 = 2.0;
+
+// NOTE - Share of the battery a robot is left with after an emergency recovery
+const EMERGENCY_RECOVERY_ENERGY_SHARE: f32 = 0.5;
+
+// NOTE - Extra movement cost of a full cargo hold (0.5 = +50% per step)
+const CARGO_WEIGHT_PENALTY: f32 = 0.5;
+
+// NOTE - Speed lost with a full cargo hold (0.5 = half speed)
+const CARGO_SPEED_PENALTY: f32 = 0.5;
+
+// NOTE - Tolerance on energy comparisons (f32 accumulation errors)
+const ENERGY_EPSILON: f32 = 1e-3;
+
+// NOTE - Number of past positions kept to detect 2- and 3-cycles
+const POSITION_HISTORY_LEN: usize = 6;
+
+// NOTE - Updates during which a target abandoned after an oscillation is ignored
+const AVOID_TARGET_TICKS: u32 = 20;
+
+// NOTE - Updates spent standing still with a goal before giving the goal up
+const MAX_STUCK_TICKS: u32 = 5;
+
+// NOTE - Updates during which a target given up as unreachable is ignored
+const UNREACHABLE_TARGET_TICKS: u32 = 100;
+
+// NOTE - Updates without getting any closer to the current target before giving it up
+const MAX_NO_PROGRESS_TICKS: u32 = 15;
+
+// NOTE - Nearest frontier tiles an explorer draws its next target from
+const FRONTIER_CANDIDATES: usize = 8;
+
+// NOTE - Radius around a frontier tile counted by `frontier_cluster_size`
+const CLUSTER_RADIUS: usize = 2;
+
+// NOTE - Updates after which a visit weighs half as much in `visit_penalty`
+const VISIT_HALF_LIFE_TICKS: f32 = 40.0;
+
+/// Default weight of recent visits when an explorer picks where to go (see `Robot::revisit_weight`)
+pub const REVISIT_PENALTY_WEIGHT: f32 = 10.0;
+
+// NOTE - A closer resource replaces the current target only if it saves at least this many steps
+const BETTER_TARGET_MARGIN: usize = 3;
+
+// NOTE - Extra vision range given by a `VisionModule`
+const VISION_MODULE_RANGE: isize = 2;
+
+// NOTE - Extra maximum energy given by a `BatteryModule`
+const BATTERY_MODULE_ENERGY: f32 = 40.0;
+
+// NOTE - Movement cost multiplier of an `EfficiencyModule` (-20%)
+const EFFICIENCY_MODULE_FACTOR: f32 = 0.8;
+
+// NOTE - Speed multiplier of a `PropulsionModule` (+25%)
+const PROPULSION_MODULE_FACTOR: f32 = 1.25;
+
+// NOTE - Path steps during which tiles held by other robots count as obstacles
+const OCCUPANCY_HORIZON: usize = 3;
+
+// NOTE - A detour around a blocking robot is taken only if it adds at most this many steps
+const DETOUR_MARGIN: usize = 2;
+
+// NOTE - Updates spent waiting behind another robot before giving up the target
+const MAX_BLOCKED_TICKS: u32 = 3;
+
+// NOTE - Latest mode changes kept by each robot (see `Robot::mode_history`)
+const MODE_HISTORY_LEN: usize = 16;
+
+// NOTE - Extra steps a collector accepts to unload at its station on the way (see `Station::drop_off_in_passing`)
+const DROP_OFF_MAX_DETOUR: usize = 1;
+
+// NOTE - Path search from the robot to a target (`Robot::find_path` or `Robot::find_path_dijkstra`)
+type PathSearch = fn(&Robot, &Map, (usize, usize)) -> VecDeque<(usize, usize)>;
+
+/// Terrain multiplier applied to a step onto `tile`.
+///
+/// Resource tiles are harder to cross than bare ground; obstacles cannot be
+/// entered at all. Never lower than 1.0, the cost of an empty tile.
+pub fn movement_cost(tile: &TileType) -> f32 {
+    match tile {
+        TileType::Empty => 1.0,
+        TileType::Energy | TileType::Mineral | TileType::Scientific => 1.2,
+        TileType::Obstacle => f32::INFINITY,
+    }
+}
+
+/// Default A* step cost of a robot type onto `tile`, in tenths of a step
+/// on bare ground (`None` for impassable tiles).
+///
+/// Explorers and relays cross resource tiles almost as easily as bare
+/// ground, repairers pay the terrain multiplier of `movement_cost`, and
+/// collectors, slowed down by their cargo bay, prefer clear lanes. Meant as
+/// the `cost_fn` of `Robot::find_path_weighted`.
+pub fn terrain_cost(robot_type: RobotType, tile: TileType) -> Option<u32> {
+    let rough_ground = match robot_type {
+        RobotType::Explorer | RobotType::Relay => 11,
+        RobotType::Repairer => 12,
+        RobotType::EnergyCollector | RobotType::MineralCollector | RobotType::ScientificCollector => 15,
+    };
+    match tile {
+        TileType::Empty => Some(10),
+        TileType::Energy | TileType::Mineral | TileType::Scientific => Some(rough_ground),
+        TileType::Obstacle => None,
+    }
+}
+
+// NOTE - Chebyshev distance from a tile to the nearest tile of a (column, row) sector
+fn sector_distance((column, row): (usize, usize), (x, y): (usize, usize)) -> usize {
+    let axis = |v: usize, cell: usize| {
+        let (start, end) = (cell * SECTOR_SIZE, (cell + 1) * SECTOR_SIZE - 1);
+        start.saturating_sub(v).max(v.saturating_sub(end))
+    };
+    axis(x, column).max(axis(y, row))
+}
+
+// NOTE - Way home planned by `energy_to_return_home`, followed as the robot walks along it
+#[derive(Debug)]
+struct HomeRoute {
+    // NOTE - Station the route leads to
+    home: (usize, usize),
+    // NOTE - `Map::revision` the route was planned on
+    revision: u64,
+    // NOTE - Tile the remaining steps start from
+    from: (usize, usize),
+    // NOTE - Remaining steps, `from` excluded
+    steps: VecDeque<(usize, usize)>,
+    // NOTE - False once steps away from the route were added in front (see `home_cost_bound`)
+    shortest: bool,
+}
+
+/// Breakdown of a robot in `RobotMode::Broken` (see `Robot::roll_malfunction`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Malfunction {
+    /// Fixed by the robot's own diagnostics after `ticks_left` more updates
+    Minor { ticks_left: u32 },
+    /// Waits for a repairer (or a repatriation by the station)
+    Severe,
+}
+
+/// Change of a robot's mode (see `Robot::mode_history`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ModeTransition {
+    /// Robot age (`Robot::age_ticks`) when the mode changed
+    pub tick: u32,
+    /// Mode left
+    pub from: RobotMode,
+    /// Mode entered
+    pub to: RobotMode,
+    /// Why the robot changed mode
+    pub reason: TransitionReason,
+}
+
+impl fmt::Display for ModeTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle {} : {:?} → {:?} ({})", self.tick, self.from, self.to, self.reason)
+    }
 }
 
-// NOTE - Implement ordering for priority queue (min-heap for A*)
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // NOTE - Reverse order for min-heap
-        other.f_cost.cmp(&self.f_cost)
+/// Why a robot changed mode (see `ModeTransition`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionReason {
+    /// The energy left only covers the trip home
+    LowEnergy,
+    /// The hold is full
+    CargoFull,
+    /// Nothing left to collect, no post to hold or no distress call to answer
+    NoTargets,
+    /// A resource to collect or a post to reach was chosen
+    TargetFound,
+    /// The destination cannot be reached
+    Unreachable,
+    /// Back at the station
+    Arrived,
+    /// Docked at the station, waiting for (or drawing from) a charging bay
+    Recharging,
+    /// Done at the station: battery full, or nothing left in the reserves
+    Recharged,
+    /// Nothing keeps an idle explorer at the station any more
+    Ready,
+    /// The explorer knows the whole exploration area
+    ExplorationComplete,
+    /// The explorer's sector is mapped, the station will give it another
+    SectorMapped,
+    /// The explorer sweeps the unknown area around it
+    Scan,
+    /// The scan is over
+    ScanDone,
+    /// The brain sent the robot home (see `brain::RobotAction::ReturnHome`)
+    BrainDecision,
+    /// Earth assigned a task, or the robot resumed one
+    TaskAssigned,
+    /// The current task is over
+    TaskDone,
+    /// Held at the station by the collector policy or an energy blackout
+    OnHold,
+    /// Mission objectives met: everyone goes home
+    MissionComplete,
+    /// Too little energy left to reach the station
+    Stranded,
+    /// A stranded robot can reach the station again
+    Rescued,
+    /// A repairer answers a distress call
+    DistressCall,
+    /// The rescue is delivered, or no longer needed
+    RescueOver,
+    /// Random breakdown (see `Robot::roll_malfunction`)
+    Breakdown,
+    /// Fixed by self-repair or by a repairer
+    Repaired,
+    /// Brought back to the station by `Robot::emergency_recover`
+    Repatriated,
+    /// No walkable way back to the home station is left
+    CutOff,
+    /// The station called the whole fleet home (see `Station::recall_all`)
+    Recalled,
+}
+
+impl fmt::Display for TransitionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TransitionReason::LowEnergy => "énergie faible",
+            TransitionReason::CargoFull => "soute pleine",
+            TransitionReason::NoTargets => "plus de cible",
+            TransitionReason::TargetFound => "cible trouvée",
+            TransitionReason::Unreachable => "cible inaccessible",
+            TransitionReason::Arrived => "arrivé",
+            TransitionReason::Recharging => "en charge",
+            TransitionReason::Recharged => "rechargé",
+            TransitionReason::Ready => "prêt à repartir",
+            TransitionReason::ExplorationComplete => "exploration terminée",
+            TransitionReason::SectorMapped => "secteur cartographié",
+            TransitionReason::Scan => "balayage",
+            TransitionReason::ScanDone => "balayage terminé",
+            TransitionReason::BrainDecision => "décision du cerveau",
+            TransitionReason::TaskAssigned => "tâche assignée",
+            TransitionReason::TaskDone => "tâche terminée",
+            TransitionReason::OnHold => "consigné à la station",
+            TransitionReason::MissionComplete => "mission accomplie",
+            TransitionReason::Stranded => "en détresse",
+            TransitionReason::Rescued => "secouru",
+            TransitionReason::DistressCall => "appel de détresse",
+            TransitionReason::RescueOver => "secours terminé",
+            TransitionReason::Breakdown => "panne",
+            TransitionReason::Repaired => "réparé",
+            TransitionReason::Repatriated => "rapatrié",
+            TransitionReason::CutOff => "coupé de la station",
+            TransitionReason::Recalled => "rappel général",
+        })
     }
 }
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+/// Factory characteristics of a robot, before any module is installed.
+/// 
+/// `RobotConfig::for_type` gives the historical values of each robot type;
+/// the `with_*` methods override them one by one. A robot keeps its
+/// configuration in `Robot::config`: its energy model reads the movement
+/// and metabolism costs from it, its vision and cargo bay are sized by it.
+/// 
+/// # Examples
+/// 
+/// The default configuration of every robot type:
+/// 
+/// ```rust
+/// # use ereea::{robot::RobotConfig, types::RobotType};
+/// let defaults = [
+///     (RobotType::Explorer, 80.0, 4, 0.3, 0),
+///     (RobotType::EnergyCollector, 120.0, 2, 0.4, 50),
+///     (RobotType::MineralCollector, 100.0, 2, 0.5, 5),
+///     (RobotType::ScientificCollector, 60.0, 2, 0.6, 3),
+///     (RobotType::Repairer, 100.0, 2, 0.4, 0),
+///     (RobotType::Relay, 100.0, 2, 0.3, 0),
+/// ];
+/// for (robot_type, max_energy, vision_range, move_cost, cargo_capacity) in defaults {
+///     let config = RobotConfig::for_type(robot_type);
+///     assert_eq!((config.max_energy, config.vision_range, config.move_cost, config.cargo_capacity, config.metabolism),
+///                (max_energy, vision_range, move_cost, cargo_capacity, 0.1));
+/// }
+/// ```
+/// 
+/// An explorer built with a larger battery and a longer sight:
+/// 
+/// ```rust
+/// # use ereea::{map::Map, robot::{Robot, RobotConfig}, station::Station};
+/// # use ereea::types::RobotType;
+/// let mut map = Map::open();
+/// let station = Station::new();
+/// 
+/// let config = RobotConfig::for_type(RobotType::Explorer)
+///     .with_max_energy(150.0)
+///     .with_vision_range(6);
+/// let mut robot = Robot::new(10, 10, RobotType::Explorer).with_config(config);
+/// assert_eq!((robot.max_energy, robot.energy), (150.0, 150.0));
+/// 
+/// // Vision range 6: a 13x13 square
+/// robot.update_memory(&map, &station);
+/// assert_eq!(robot.explored_count(), 169);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RobotConfig {
+    /// Battery capacity, the robot leaves the factory fully charged
+    pub max_energy: f32,
+    /// Radius of the square observed around the robot at each update
+    pub vision_range: isize,
+    /// Energy spent per step on bare ground (see `movement_cost` for other tiles)
+    pub move_cost: f32,
+    /// Resource units carried before heading home (0 = carries nothing)
+    pub cargo_capacity: u32,
+    /// Energy spent at each update just to stay powered
+    pub metabolism: f32,
+    /// Tiles walked per update on an empty hold; fractions add up from one update to the next
+    pub speed: f32,
+    /// Updates spent on a scientific site before its data is granted (0 or 1 = instantaneous)
+    pub analysis_duration: u32,
+    /// Energy the instruments draw per update of analysis, on top of the metabolism
+    pub instrument_cost: f32,
+    /// Exploration policy (see `brain::RobotBrain`)
+    pub brain: BrainKind,
+}
+
+impl RobotConfig {
+    /// Default configuration of a robot type
+    pub fn for_type(robot_type: RobotType) -> Self {
+        let (max_energy, move_cost) = match robot_type {
+            RobotType::Explorer => (80.0, 0.3),             // Balanced capacity for exploration
+            RobotType::EnergyCollector => (120.0, 0.4),     // High capacity for extended missions
+            RobotType::MineralCollector => (100.0, 0.5),    // Good endurance for mining work
+            RobotType::ScientificCollector => (60.0, 0.6),  // Limited by instrument power needs
+            RobotType::Repairer => (100.0, 0.4),            // Long trips to reach stranded robots
+            RobotType::Relay => (100.0, 0.3),               // Holds its post for long periods
+        };
+        let vision_range = match robot_type {
+            RobotType::Explorer => 4, // Vision étendue pour l'explorateur
+            _ => 2,                   // Vision standard pour les autres
+        };
+        let cargo_capacity = match robot_type {
+            RobotType::EnergyCollector => 50,
+            RobotType::MineralCollector => 5,
+            RobotType::ScientificCollector => 3,
+            // Les explorateurs, réparateurs et relais ne transportent rien
+            RobotType::Explorer | RobotType::Repairer | RobotType::Relay => 0,
+        };
+        Self {
+            max_energy, vision_range, move_cost, cargo_capacity,
+            metabolism: 0.1,
+            speed: 1.0,
+            analysis_duration: 5,
+            instrument_cost: 0.3,
+            brain: BrainKind::Default,
+        }
+    }
+    
+    /// Overrides the battery capacity
+    pub fn with_max_energy(mut self, max_energy: f32) -> Self {
+        self.max_energy = max_energy;
+        self
+    }
+    
+    /// Overrides the vision range
+    pub fn with_vision_range(mut self, vision_range: isize) -> Self {
+        self.vision_range = vision_range;
+        self
+    }
+    
+    /// Overrides the energy spent per step on bare ground
+    pub fn with_move_cost(mut self, move_cost: f32) -> Self {
+        self.move_cost = move_cost;
+        self
+    }
+    
+    /// Overrides the cargo capacity
+    pub fn with_cargo_capacity(mut self, cargo_capacity: u32) -> Self {
+        self.cargo_capacity = cargo_capacity;
+        self
+    }
+    
+    /// Overrides the energy spent per update
+    pub fn with_metabolism(mut self, metabolism: f32) -> Self {
+        self.metabolism = metabolism;
+        self
+    }
+    
+    /// Overrides the tiles walked per update (strictly positive)
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+    
+    /// Overrides the updates spent analyzing a scientific site
+    pub fn with_analysis_duration(mut self, analysis_duration: u32) -> Self {
+        self.analysis_duration = analysis_duration;
+        self
+    }
+    
+    /// Overrides the energy the instruments draw per update of analysis
+    pub fn with_instrument_cost(mut self, instrument_cost: f32) -> Self {
+        self.instrument_cost = instrument_cost;
+        self
+    }
+    
+    /// Overrides the exploration policy
+    pub fn with_brain(mut self, brain: BrainKind) -> Self {
+        self.brain = brain;
+        self
     }
 }
 
@@ -60,18 +463,46 @@ pub struct Robot {
     pub energy: f32,
     // NOTE - Maximum energy capacity
     pub max_energy: f32,
-    // NOTE - Minerals carried (for MineralCollector)
-    pub minerals: u32,
-    // NOTE - Scientific data carried (for ScientificCollector)
-    pub scientific_data: u32,
+    // NOTE - Resource units carried (see `minerals`, `scientific_data`, `energy_cargo`)
+    pub inventory: Inventory,
+    // NOTE - Energy carried for a stranded robot (for Repairer)
+    pub rescue_cargo: f32,
+    // NOTE - Resource units carried before heading home (0 = carries nothing)
+    pub cargo_capacity: u32,
     // NOTE - Robot specialization type
     pub robot_type: RobotType,
+    // NOTE - Modules installed by the station (see `install_upgrade`)
+    pub upgrades: Vec<Upgrade>,
+    // NOTE - Factory characteristics of the robot, before modules (see `RobotConfig`)
+    pub config: RobotConfig,
+    // NOTE - Energy spent per cycle and per step (see `energy::EnergyModel`)
+    pub energy_model: Arc<dyn EnergyModel>,
+    // NOTE - Exploration policy (see `brain::RobotBrain`)
+    brain: Box<dyn RobotBrain>,
+    // NOTE - Whether obstacles hide what lies behind them (see `update_memory`)
+    pub vision_model: VisionModel,
     // NOTE - Current operational mode
     pub mode: RobotMode,
-    // NOTE - Local exploration memory (per robot)
-    pub memory: Vec<Vec<TerrainData>>,
-    // NOTE - Planned path to station (A* waypoints)
-    pub path_to_station: VecDeque<(usize, usize)>,
+    // NOTE - Local exploration memory (per robot); call `reindex_memory` after editing it directly
+    pub memory: Grid<TerrainData>,
+    // NOTE - Explored tiles of `memory` adjacent to at least one unexplored tile
+    frontier: HashSet<(usize, usize)>,
+    // NOTE - Number of explored tiles in `memory`
+    explored_count: usize,
+    // NOTE - Tiles that count in the exploration percentage (see `set_exploration_area`)
+    exploration_area: ExplorationArea,
+    // NOTE - Number of explored tiles of `memory` inside `exploration_area`
+    explored_in_area: usize,
+    // NOTE - Tiles written since the last station synchronization (see `unsynced_tiles`)
+    unsynced_tiles: HashSet<(usize, usize)>,
+    // NOTE - Own discoveries not yet sent by radio, oldest first (see `take_radio_journal`)
+    radio_journal: VecDeque<(usize, usize, TerrainData)>,
+    // NOTE - Planned path (A* waypoints) towards `current_target`, kept across updates
+    pub current_path: VecDeque<(usize, usize)>,
+    // NOTE - Destination of `current_path` (station, resource or unexplored tile)
+    pub current_target: Option<(usize, usize)>,
+    // NOTE - Tasks assigned from outside, run in order before autonomous behavior (see `assign_task`)
+    task_queue: VecDeque<RobotTask>,
     // NOTE - Unique robot identifier
     pub id: usize,
     // NOTE - Home station X coordinate
@@ -80,56 +511,113 @@ pub struct Robot {
     pub home_station_y: usize,
     // NOTE - Last time data was synchronized with station
     pub last_sync_time: u32,
+    // NOTE - Cycles before a collector parked for lack of resources searches again (None = not parked)
+    idle_rescan_in: Option<u32>,
+    // NOTE - Linked to the station through relays at the last check (see `knowledge::sync_through_relays`)
+    pub relay_linked: bool,
+    // NOTE - Tile a relay holds between the fleet and the station (see `choose_relay_post`)
+    pub relay_post: Option<(usize, usize)>,
+    // NOTE - Sector the station handed this explorer, as (column, row) (see `Station::assign_sector`)
+    pub assigned_sector: Option<(usize, usize)>,
     // NOTE - Prevents duplicate exploration completion logs
     pub exploration_complete_announced: bool,
+    // NOTE - Cumulative energy consumed since deployment (movement + metabolism)
+    pub total_energy_spent: f32,
+    // NOTE - Cumulative distance covered by `move_to` steps
+    pub distance_traveled: f32,
+    // NOTE - Number of A* searches run by this robot (see `path_search_count`)
+    path_searches: Cell<u64>,
+    // NOTE - Way home last planned by `energy_to_return_home`, reused while the map is unchanged
+    home_route: RefCell<Option<HomeRoute>>,
+    // NOTE - Positions at the start of the last updates (oldest first)
+    pub recent_positions: VecDeque<(usize, usize)>,
+    // NOTE - Targets temporarily ignored after an oscillation, with remaining updates
+    pub avoided_targets: Vec<((usize, usize), u32)>,
+    // NOTE - Times the robot entered each tile (see `visit_penalty`)
+    pub visit_count: Grid<u16>,
+    // NOTE - `age_ticks` of the last visit of each tile
+    last_visit: Grid<u32>,
+    // NOTE - Priority an explorer loses per recent visit of a tile (0 = ignore past visits)
+    pub revisit_weight: f32,
+    // NOTE - Consecutive updates spent waiting behind another robot
+    blocked_ticks: u32,
+    // NOTE - Fraction of a step left over from previous updates (see `speed`)
+    movement_budget: f32,
+    // NOTE - Whether the last update left the robot short of a whole step on its path
+    short_of_step: bool,
+    // NOTE - Scientific site being analyzed and updates already spent on it (see `harvest`)
+    pub analysis_progress: Option<(usize, usize, u32)>,
+    // NOTE - Consecutive updates without moving while having somewhere to go (see `stuck_ticks`)
+    stuck_ticks: u32,
+    // NOTE - Current target, closest remaining distance to it and updates since it last shrank
+    target_progress: Option<((usize, usize), usize, u32)>,
+    // NOTE - Targets given up since deployment (see `stuck_events`)
+    stuck_events: u32,
+    // NOTE - Set when the last collection plan found no path to or from its resource
+    goal_unreachable: bool,
+    // NOTE - No walkable way back to the home station is left (see `is_lost`)
+    lost: bool,
+    // NOTE - Tile of the last collection since the cargo was unloaded (see `Station::average_haul_distance`)
+    last_pickup: Option<(usize, usize)>,
+    // NOTE - Updates since deployment, wears the robot out (see `MalfunctionConfig`)
+    pub age_ticks: u32,
+    // NOTE - Current breakdown, set while in `RobotMode::Broken`
+    pub malfunction: Option<Malfunction>,
+    // NOTE - Source of all the robot's random choices (see `seed_rng`)
+    rng: StdRng,
+    // NOTE - Events not yet handed to the caller (see `update`)
+    events: Vec<RobotEvent>,
+    // NOTE - Latest mode changes, oldest first (see `mode_history`)
+    mode_history: VecDeque<ModeTransition>,
+    // NOTE - Whether `update` is running: its mode changes are merged into one transition
+    updating: bool,
+    // NOTE - Updates that left their starting mode and came back to it (see `wasted_mode_changes`)
+    wasted_mode_changes: u32,
+    // NOTE - Unloaded at the station on the way during the last update: resumes its route without docking
+    passing_through: bool,
 }
 
 impl Robot {
-    /// NOTE - Create a new robot with default configuration
+    /// NOTE - Create a new robot with default configuration, a blank memory and its own tile as home station
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, if `(x, y)` lies outside the map.
     pub fn new(x: usize, y: usize, robot_type: RobotType) -> Self {
-        // NOTE - Set energy based on robot type
-        let (max_energy, energy) = match robot_type {
-            RobotType::Explorer => (80.0, 80.0),           // Balanced capacity for exploration
-            RobotType::EnergyCollector => (120.0, 120.0),  // High capacity for extended missions
-            RobotType::MineralCollector => (100.0, 100.0), // Good endurance for mining work
-            RobotType::ScientificCollector => (60.0, 60.0), // Limited by instrument power needs
-        };
-        
         // NOTE - Initialize empty exploration memory
-        let mut memory = Vec::with_capacity(MAP_SIZE);
-        for _ in 0..MAP_SIZE {
-            let row = vec![
-                TerrainData {
-                    explored: false,                    // No tiles explored yet
-                    timestamp: 0,                       // No exploration time recorded
-                    robot_id: 0,                        // Placeholder robot ID
-                    robot_type: RobotType::Explorer,    // Default type for unexplored tiles
-                }; 
-                MAP_SIZE
-            ];
-            memory.push(row);
-        }
+        let memory = Grid::filled(TerrainData {
+            explored: false,                    // No tiles explored yet
+            timestamp: 0,                       // No exploration time recorded
+            robot_id: 0,                        // Placeholder robot ID
+            robot_type: RobotType::Explorer,    // Default type for unexplored tiles
+            tile_type: TileType::Empty,         // Contents unknown until observed
+        });
         
-        Self {
-            x,
-            y,
-            energy,
-            max_energy,
-            minerals: 0,                            // Start with empty mineral storage
-            scientific_data: 0,                     // Start with no scientific data
-            robot_type,
-            mode: RobotMode::Exploring,             // Begin mission in exploration mode
-            memory,
-            path_to_station: VecDeque::new(),       // No planned path initially
-            id: 0,                                  // ID will be assigned by station
-            home_station_x: x,                      // Remember starting position as home
-            home_station_y: y,
-            last_sync_time: 0,                      // No synchronization performed yet
-            exploration_complete_announced: false,  // Haven't announced completion
-        }
+        Self::new_with_memory(x, y, robot_type, 0, x, y, memory)
     }
     
-    // NOTE - Create robot with preloaded memory (for station deployment)
+    /// Creates a robot with preloaded memory (for station deployment).
+    /// 
+    /// # Panics
+    /// 
+    /// In debug builds, if the robot or its station lies outside the map, or
+    /// if `memory` is not a `MAP_SIZE` × `MAP_SIZE` grid: the robot would
+    /// otherwise only fail on its first update.
+    /// 
+    /// ```rust,should_panic
+    /// # use ereea::{robot::Robot, station::Station, types::{RobotType, MAP_SIZE}};
+    /// let station = Station::new();
+    /// // Station outside the map
+    /// Robot::new_with_memory(10, 10, RobotType::Explorer, 1, MAP_SIZE, 10, station.global_memory.clone());
+    /// ```
+    /// 
+    /// ```rust,should_panic
+    /// # use ereea::{grid::Grid, robot::Robot, station::Station, types::{RobotType, MAP_SIZE}};
+    /// let station = Station::new();
+    /// // Memory missing a row
+    /// let memory = Grid::new(MAP_SIZE, MAP_SIZE - 1, station.global_memory[(0, 0)].clone());
+    /// Robot::new_with_memory(10, 10, RobotType::Explorer, 1, 10, 10, memory);
+    /// ```
     pub fn new_with_memory(
         x: usize, 
         y: usize, 
@@ -137,73 +625,506 @@ impl Robot {
         id: usize,
         station_x: usize,
         station_y: usize,
-        memory: Vec<Vec<TerrainData>>
+        memory: Grid<TerrainData>
     ) -> Self {
-        let (max_energy, energy) = match robot_type {
-            RobotType::Explorer => (80.0, 80.0),
-            RobotType::EnergyCollector => (120.0, 120.0),
-            RobotType::MineralCollector => (100.0, 100.0),
-            RobotType::ScientificCollector => (60.0, 60.0),
-        };
+        Self::check_placement(x, y, station_x, station_y, &memory);
         
-        Self {
+        // NOTE - Set energy and cargo bay based on robot type
+        let config = RobotConfig::for_type(robot_type);
+        
+        let mut robot = Self {
             x,
             y,
-            energy,
-            max_energy,
-            minerals: 0,
-            scientific_data: 0,
+            energy: config.max_energy,              // Leaves the factory fully charged
+            max_energy: config.max_energy,
+            inventory: Inventory::default(),        // Start with nothing to haul
+            rescue_cargo: 0.0,                      // Nothing to deliver yet
+            cargo_capacity: config.cargo_capacity,
             robot_type,
-            mode: RobotMode::Exploring,
+            upgrades: Vec::new(),                   // Factory configuration
+            config,
+            energy_model: Arc::new(DefaultEnergyModel), // Historical energy economy
+            brain: config.brain.build(),
+            vision_model: VisionModel::default(),   // Obstacles block the view
+            mode: RobotMode::Exploring,             // Begin mission in exploration mode
             memory,
-            path_to_station: VecDeque::new(),
-            id,
-            home_station_x: station_x,
+            frontier: HashSet::new(),               // Nothing explored, nothing to border
+            unsynced_tiles: HashSet::new(),         // Nothing learned yet
+            radio_journal: VecDeque::new(),         // Nothing discovered yet
+            explored_count: 0,                      // Memory starts blank
+            exploration_area: ExplorationArea::whole_map(), // Every tile counts until told otherwise
+            explored_in_area: 0,
+            current_path: VecDeque::new(),          // No planned path initially
+            current_target: None,                   // No destination yet
+            task_queue: VecDeque::new(),            // Autonomous until told otherwise
+            id,                                     // 0 until the station assigns one
+            home_station_x: station_x,              // Station it docks at
             home_station_y: station_y,
-            last_sync_time: 0,
-            exploration_complete_announced: false,
+            last_sync_time: 0,                      // No synchronization performed yet
+            idle_rescan_in: None,                   // Not parked
+            relay_linked: false,                    // Out of reach until the first relay check
+            relay_post: None,                       // Relays pick their post once deployed
+            assigned_sector: None,                  // Explorers get one from the station
+            exploration_complete_announced: false,  // Haven't announced completion
+            total_energy_spent: 0.0,                // Nothing consumed yet
+            distance_traveled: 0.0,                 // No movement yet
+            recent_positions: VecDeque::with_capacity(POSITION_HISTORY_LEN),
+            avoided_targets: Vec::new(),            // No abandoned target yet
+            visit_count: Grid::filled(0),           // Nowhere yet
+            last_visit: Grid::filled(0),
+            revisit_weight: REVISIT_PENALTY_WEIGHT,
+            blocked_ticks: 0,                       // Nothing in the way yet
+            movement_budget: 0.0,                   // No step saved up yet
+            short_of_step: false,
+            analysis_progress: None,                // No analysis under way
+            stuck_ticks: 0,                         // Not stuck yet
+            target_progress: None,                  // No target followed yet
+            stuck_events: 0,                        // Never gave up a target
+            goal_unreachable: false,                // No plan failed yet
+            lost: false,                            // Starts at its station
+            last_pickup: None,                      // Nothing carried yet
+            path_searches: Cell::new(0),            // No path planned yet
+            home_route: RefCell::new(None),         // Planned on the first energy check
+            age_ticks: 0,                           // Brand new
+            malfunction: None,                      // In working order
+            rng: StdRng::seed_from_u64(id as u64),  // Reseeded by `seed_rng` once deployed
+            events: Vec::new(),                     // Nothing happened yet
+            mode_history: VecDeque::with_capacity(MODE_HISTORY_LEN),
+            updating: false,
+            wasted_mode_changes: 0,
+            passing_through: false,
+        };
+        robot.reindex_memory();
+        robot
+    }
+    
+    /// Replaces the robot's factory configuration (see `RobotConfig`).
+    /// 
+    /// Meant right after construction: the battery is resized and refilled,
+    /// the cargo bay resized, and modules already installed are lost.
+    /// 
+    /// # Examples
+    /// 
+    /// A mineral collector with a smaller cargo bay heads home sooner, and
+    /// one with a cheaper step spends less on the same trip:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::{Robot, RobotConfig}};
+    /// # use ereea::types::{RobotType, TileType};
+    /// let mut map = Map::open();
+    /// let default = RobotConfig::for_type(RobotType::MineralCollector);
+    /// 
+    /// let mut small = Robot::new(0, 0, RobotType::MineralCollector).with_config(default.with_cargo_capacity(2));
+    /// small.load(TileType::Mineral, 2);
+    /// assert!(small.is_cargo_full());
+    /// 
+    /// let mut plain = Robot::new(0, 0, RobotType::MineralCollector);
+    /// let mut frugal = Robot::new(0, 0, RobotType::MineralCollector)
+    ///     .with_config(default.with_move_cost(0.25).with_metabolism(0.05));
+    /// (plain.x, frugal.x) = (6, 6);
+    /// let plain_cost = plain.energy_to_return_home(&map).unwrap();
+    /// let frugal_cost = frugal.energy_to_return_home(&map).unwrap();
+    /// assert!((frugal_cost - plain_cost / 2.0).abs() < 1e-4);
+    /// ```
+    pub fn with_config(mut self, config: RobotConfig) -> Self {
+        self.max_energy = config.max_energy;
+        self.energy = config.max_energy;
+        self.cargo_capacity = config.cargo_capacity;
+        self.upgrades.clear();
+        self.brain = config.brain.build();
+        self.config = config;
+        self
+    }
+    
+    /// Replaces the robot's exploration policy (see `brain::RobotBrain`).
+    /// 
+    /// Takes effect at the next update; the configuration (`RobotConfig::brain`)
+    /// is left as is, robots built later still get the configured brain.
+    pub fn set_brain(&mut self, brain: Box<dyn RobotBrain>) {
+        self.brain = brain;
+    }
+    
+    /// Name of the robot's exploration policy, e.g. `"default"` or `"sweep"`
+    pub fn brain_name(&self) -> &str {
+        self.brain.name()
+    }
+    
+    /// Resource units currently carried (energy, minerals and scientific data).
+    /// 
+    /// A loaded robot moves slower and at a higher energy cost, in proportion
+    /// to its load (half speed and +50% per step with a full hold), and heads
+    /// home as soon as the load reaches `cargo_capacity`.
+    /// 
+    /// # Examples
+    /// 
+    /// A collector with room for 3 minerals heads home exactly when its hold is full:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (0, 0);
+    /// for x in 3..=8 {
+    ///     map.set_tile(x, 0, TileType::Mineral);
+    /// }
+    /// let mut station = Station::new();
+    /// station.reveal_map(&map);
+    /// 
+    /// let mut robot = Robot::new_with_memory(0, 0, RobotType::MineralCollector, 1, 0, 0, station.global_memory.clone());
+    /// robot.cargo_capacity = 3;
+    /// let mut occupancy = OccupancyGrid::new(0, 0);
+    /// let mut load_when_leaving = None;
+    /// for _ in 0..40 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     assert!(robot.cargo_load() <= robot.cargo_capacity);
+    ///     if robot.mode == RobotMode::ReturnToStation {
+    ///         load_when_leaving = Some(robot.cargo_load());
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(load_when_leaving, Some(3));
+    /// ```
+    pub fn cargo_load(&self) -> u32 {
+        self.inventory.total_weight()
+    }
+    
+    /// Units of `resource` currently carried (0 when the robot carries none).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{robot::Robot, types::{RobotType, TileType}};
+    /// let mut robot = Robot::new(0, 0, RobotType::MineralCollector);
+    /// robot.load(TileType::Mineral, 2);
+    /// robot.load(TileType::Scientific, 1);
+    /// robot.load(TileType::Mineral, 1);
+    /// assert_eq!(robot.carried(&TileType::Mineral), 3);
+    /// assert_eq!((robot.minerals(), robot.scientific_data(), robot.energy_cargo()), (3, 1, 0));
+    /// assert_eq!(robot.cargo_load(), 4);
+    /// ```
+    pub fn carried(&self, resource: &TileType) -> u32 {
+        self.inventory.get(resource)
+    }
+    
+    // NOTE - Add resource units to the inventory
+    pub fn load(&mut self, resource: TileType, amount: u32) {
+        self.inventory.add(resource, amount);
+    }
+    
+    // NOTE - Minerals carried (for MineralCollector)
+    pub fn minerals(&self) -> u32 {
+        self.carried(&TileType::Mineral)
+    }
+    
+    // NOTE - Scientific data carried (for ScientificCollector)
+    pub fn scientific_data(&self) -> u32 {
+        self.carried(&TileType::Scientific)
+    }
+    
+    // NOTE - Energy units hauled to the station (for EnergyCollector)
+    pub fn energy_cargo(&self) -> u32 {
+        self.carried(&TileType::Energy)
+    }
+    
+    // NOTE - True once the robot carries as much as it can (never for robots carrying nothing)
+    pub fn is_cargo_full(&self) -> bool {
+        self.inventory.is_full(self.cargo_capacity)
+    }
+    
+    // NOTE - True if the module is installed on the robot
+    pub fn has_upgrade(&self, upgrade: Upgrade) -> bool {
+        self.upgrades.contains(&upgrade)
+    }
+    
+    /// Installs a module on the robot and applies its effect.
+    /// 
+    /// The station pays for it through `Station::try_install_upgrade`, which
+    /// should be preferred; this method only changes the robot. A
+    /// `BatteryModule` comes charged, the other modules take effect in
+    /// `update_memory` (vision) and `move_to` (movement cost).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotType, Upgrade};
+    /// let mut map = Map::open();
+    /// let station = Station::new();
+    /// 
+    /// let mut plain = Robot::new(10, 10, RobotType::MineralCollector);
+    /// let mut upgraded = Robot::new(10, 10, RobotType::MineralCollector);
+    /// upgraded.install_upgrade(Upgrade::VisionModule);
+    /// upgraded.install_upgrade(Upgrade::BatteryModule);
+    /// assert_eq!((upgraded.max_energy, upgraded.energy), (140.0, 140.0));
+    /// 
+    /// // Vision range 2 + 2: a 9x9 square instead of 5x5
+    /// plain.update_memory(&map, &station);
+    /// upgraded.update_memory(&map, &station);
+    /// assert_eq!(plain.explored_count(), 25);
+    /// assert_eq!(upgraded.explored_count(), 81);
+    /// 
+    /// // Moving costs 20% less energy with an efficiency module
+    /// let mut efficient = Robot::new(10, 10, RobotType::MineralCollector);
+    /// efficient.install_upgrade(Upgrade::EfficiencyModule);
+    /// plain.x = 4;
+    /// efficient.x = 4;
+    /// let plain_cost = plain.energy_to_return_home(&map).unwrap();
+    /// let efficient_cost = efficient.energy_to_return_home(&map).unwrap();
+    /// assert!(efficient_cost < plain_cost);
+    /// ```
+    pub fn install_upgrade(&mut self, upgrade: Upgrade) {
+        if upgrade == Upgrade::BatteryModule {
+            self.max_energy += BATTERY_MODULE_ENERGY;
+            self.energy += BATTERY_MODULE_ENERGY;
         }
+        self.upgrades.push(upgrade);
+    }
+    
+    /// Seeds the robot's random choices from the map seed and its ID.
+    /// 
+    /// Every random decision of a robot (exploration target among the
+    /// closest ones, random moves) comes from its own generator. Seeded from
+    /// `Map::seed`, a whole run replays identically on the same map.
+    /// 
+    /// # Examples
+    /// 
+    /// Two runs with the same seeds end in exactly the same station state:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, network::create_station_data, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType};
+    /// fn run(seed: u32) -> serde_json::Value {
+    ///     let mut map = Map::with_seed(seed);
+    ///     let mut station = Station::new();
+    ///     let mut robots: Vec<Robot> = [RobotType::Explorer, RobotType::Explorer, RobotType::MineralCollector]
+    ///         .into_iter()
+    ///         .zip(1..)
+    ///         .map(|(robot_type, id)| {
+    ///             let mut robot = Robot::new_with_memory(map.station_x, map.station_y, robot_type, id,
+    ///                                                    map.station_x, map.station_y, station.global_memory.clone());
+    ///             robot.seed_rng(map.seed());
+    ///             robot
+    ///         })
+    ///         .collect();
+    ///     station.next_robot_id = 4;
+    ///     
+    ///     for _ in 0..150 {
+    ///         station.tick();
+    ///         let mut occupancy = OccupancyGrid::from_robots(&robots, map.station_x, map.station_y);
+    ///         for robot in robots.iter_mut() {
+    ///             robot.update(&mut map, &mut station, &mut occupancy);
+    ///         }
+    ///         if let Some(robot) = station.try_create_robot(&map, &robots) {
+    ///             robots.push(robot);
+    ///         }
+    ///     }
+    ///     assert!(robots.iter().any(|robot| robot.mode != RobotMode::Idle));
+    ///     serde_json::to_value(create_station_data(&station, &map, &robots)).unwrap()
+    /// }
+    /// 
+    /// assert_eq!(run(2024), run(2024));
+    /// ```
+    pub fn seed_rng(&mut self, map_seed: u32) {
+        self.rng = StdRng::seed_from_u64(((map_seed as u64) << 32) | self.id as u64);
+    }
+    
+    // NOTE - Current position as a `Position`
+    pub fn position(&self) -> Position {
+        Position::new(self.x, self.y)
     }
     
     // NOTE - Get display character for robot type (for UI)
     pub fn get_display_char(&self) -> &str {
-        match self.robot_type {
-            RobotType::Explorer => "🤖",
-            RobotType::EnergyCollector => "🔋",
-            RobotType::MineralCollector => "⛏️",
-            RobotType::ScientificCollector => "🧪",
-        }
+        crate::display::robot_glyph(self.robot_type)
     }
     
     // NOTE - Get display color for robot type (for UI)
     pub fn get_display_color(&self) -> u8 {
-        match self.robot_type {
-            RobotType::Explorer => 9,          // Rouge vif
-            RobotType::EnergyCollector => 10,  // Vert vif
-            RobotType::MineralCollector => 13, // Magenta vif
-            RobotType::ScientificCollector => 12, // Bleu vif
-        }
+        crate::display::robot_color(self.robot_type)
     }
     
-    // NOTE - Update robot's local exploration memory (improved version)
+    /// Writes what the robot sees around it to its memory, with the current timestamp.
+    /// 
+    /// The robot sees the tiles within its vision range (4 for explorers, 2
+    /// for the others, plus vision modules). With `VisionModel::LineOfSight`
+    /// (the default), a tile is only seen if no obstacle stands between it and
+    /// the robot (`Map::has_line_of_sight`); `VisionModel::Square` sees the
+    /// whole square, straight through obstacles.
+    /// 
+    /// # Examples
+    /// 
+    /// An explorer standing in front of a wall only learns what lies behind
+    /// it once it has gone around.
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotType, TileType, VisionModel};
+    /// let mut map = Map::open();
+    /// for y in 0..=12 {
+    ///     map.tiles[y][10] = TileType::Obstacle;
+    /// }
+    /// map.tiles[5][12] = TileType::Scientific;
+    /// let station = Station::new();
+    /// 
+    /// let mut robot = Robot::new(8, 5, RobotType::Explorer);
+    /// robot.update_memory(&map, &station);
+    /// assert!(robot.memory[(10, 5)].explored);
+    /// assert!(!robot.memory[(12, 5)].explored);
+    /// 
+    /// // Around the end of the wall, then back up behind it
+    /// for (x, y) in [(9, 13), (11, 13), (11, 9)] {
+    ///     (robot.x, robot.y) = (x, y);
+    ///     robot.update_memory(&map, &station);
+    /// }
+    /// assert_eq!(robot.memory[(12, 5)].tile_type, TileType::Scientific);
+    /// 
+    /// // The historical vision sees through the wall right away
+    /// let mut robot = Robot::new(8, 5, RobotType::Explorer);
+    /// robot.vision_model = VisionModel::Square;
+    /// robot.update_memory(&map, &station);
+    /// assert!(robot.memory[(12, 5)].explored);
+    /// ```
     pub fn update_memory(&mut self, map: &Map, station: &Station) {
-        let _ = map;
-        // NOTE - Mark current tile as explored with timestamp
-        self.memory[self.y][self.x] = TerrainData {
+        // NOTE - Mark current tile as explored with timestamp and observed contents
+        self.observe_tile(self.x, self.y, TerrainData {
             explored: true,
             timestamp: station.current_time,
             robot_id: self.id,
             robot_type: self.robot_type,
-        };
+            tile_type: map.get_tile(self.x, self.y),
+        });
         
-        // NOTE - Set vision range based on robot type
-        let vision_range = match self.robot_type {
-            RobotType::Explorer => 4, // Vision étendue pour l'explorateur
-            _ => 2,                   // Vision standard pour les autres
-        };
+        self.observe_area(map, station, self.vision_range());
+    }
+    
+    /// Actively scans a wider area than the robot's normal vision, in a single update.
+    /// 
+    /// Reveals every tile within the vision range plus `SCAN_RANGE_BONUS`, written
+    /// to `memory` with the current timestamp like normal exploration, at the cost
+    /// of `SCAN_ENERGY_COST` energy. Returns the number of newly explored tiles.
+    /// Explorers scan on their own (`RobotMode::Scanning`, for one update) when
+    /// they reach their frontier target with enough unknown tiles around and
+    /// energy to spare.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::Station};
+    /// # use ereea::types::RobotType;
+    /// let map = Map::new();
+    /// let station = Station::new();
+    /// let mut looking = Robot::new(10, 10, RobotType::Explorer);
+    /// let mut scanning = Robot::new(10, 10, RobotType::Explorer);
+    /// 
+    /// looking.update_memory(&map, &station);
+    /// let revealed = scanning.scan(&map, &station);
+    /// 
+    /// assert_eq!(revealed, scanning.explored_count());
+    /// assert!(scanning.explored_count() > looking.explored_count());
+    /// assert!(scanning.energy < looking.energy);
+    /// ```
+    pub fn scan(&mut self, map: &Map, station: &Station) -> usize {
+        let known_before = self.explored_count;
+        self.consume_energy(SCAN_ENERGY_COST);
+        self.observe_area(map, station, self.vision_range() + SCAN_RANGE_BONUS);
+        self.explored_count - known_before
+    }
+    
+    // NOTE - Vision range based on robot type and vision modules
+    fn vision_range(&self) -> isize {
+        self.config.vision_range + VISION_MODULE_RANGE * self.upgrades.iter().filter(|&&upgrade| upgrade == Upgrade::VisionModule).count() as isize
+    }
+    
+    // NOTE - Unexplored tiles of the robot's memory within `range` of its position
+    fn unknown_tiles_within(&self, range: isize) -> usize {
+        self.unknown_tiles_around(self.x, self.y, range as usize)
+    }
+    
+    // NOTE - Unexplored tiles of the robot's memory within `range` of a tile
+    fn unknown_tiles_around(&self, x: usize, y: usize, range: usize) -> usize {
+        (y.saturating_sub(range)..=(y + range).min(MAP_SIZE - 1))
+            .flat_map(|ny| (x.saturating_sub(range)..=(x + range).min(MAP_SIZE - 1)).map(move |nx| (nx, ny)))
+            .filter(|&(nx, ny)| !self.memory[(nx, ny)].explored && self.exploration_area.contains(nx, ny))
+            .count()
+    }
+    
+    /// Unknown tiles within `CLUSTER_RADIUS` (2) of a tile, according to the robot's memory.
+    /// 
+    /// Explorers draw their next target among the nearest frontier tiles
+    /// with a probability proportional to this size, so a wide unknown area
+    /// is preferred over an isolated unknown tile at the same distance.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::RobotType;
+    /// // Everything is known but one tile at (10, 3) and the rows from y = 17 down
+    /// let mut map = Map::open();
+    /// let mut station = Station::new();
+    /// for ((x, y), tile) in station.global_memory.iter_coords_mut() {
+    ///     tile.explored = (x, y) != (10, 3) && y < 17;
+    /// }
+    /// station.reindex_memory();
+    /// 
+    /// let robot = Robot::new_with_memory(10, 10, RobotType::Explorer, 1, 10, 10, station.global_memory.clone());
+    /// assert_eq!(robot.frontier_cluster_size(10, 4), 1);
+    /// assert_eq!(robot.frontier_cluster_size(8, 16), 10);
+    /// assert_eq!(robot.frontier_cluster_size(10, 10), 0);
+    /// 
+    /// // Both areas are 6 tiles away: explorers mostly head for the wide one
+    /// let mut wide = 0;
+    /// for seed in 0..20 {
+    ///     let mut station = Station::new();
+    ///     station.global_memory = robot.memory.clone();
+    ///     station.reindex_memory();
+    ///     let mut robot = Robot::new_with_memory(10, 10, RobotType::Explorer, 1, 10, 10, station.global_memory.clone());
+    ///     robot.seed_rng(seed);
+    ///     let mut occupancy = OccupancyGrid::new(10, 10);
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     let target = station.exploration_target_of(1).unwrap();
+    ///     if target.1 == 16 {
+    ///         wide += 1;
+    ///     }
+    /// }
+    /// assert!(wide >= 15, "only {} explorers out of 20 chose the wide area", wide);
+    /// ```
+    pub fn frontier_cluster_size(&self, x: usize, y: usize) -> usize {
+        self.unknown_tiles_around(x, y, CLUSTER_RADIUS)
+    }
+    
+    // NOTE - Write every tile within `range` to memory with the current timestamp
+    fn observe_area(&mut self, map: &Map, station: &Station, range: isize) {
+        self.observe_tiles(map, station, range, |robot, x, y| {
+            robot.vision_model == VisionModel::Square || map.has_line_of_sight((robot.x, robot.y), (x, y))
+        });
         
-        for dy in -vision_range..=vision_range {
-            for dx in -vision_range..=vision_range {
+        // NOTE - No ray ever reaches a tile walled in on all sides: it is sensed through its known walls
+        if self.vision_model == VisionModel::LineOfSight {
+            self.observe_tiles(map, station, range, |robot, x, y| !robot.memory[(x, y)].explored && robot.is_walled_in(x, y));
+        }
+    }
+    
+    // NOTE - True if every neighbour of a tile is a known obstacle (or off the map)
+    fn is_walled_in(&self, x: usize, y: usize) -> bool {
+        (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (x as isize + dx, y as isize + dy)))
+            .filter(|&(nx, ny)| (nx, ny) != (x as isize, y as isize)
+                && nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize)
+            .all(|(nx, ny)| {
+                let tile = &self.memory[(nx as usize, ny as usize)];
+                tile.explored && tile.tile_type == TileType::Obstacle
+            })
+    }
+    
+    // NOTE - Write the tiles within `range` that the robot can see to memory
+    fn observe_tiles<F>(&mut self, map: &Map, station: &Station, range: isize, visible: F)
+    where
+        F: Fn(&Robot, usize, usize) -> bool,
+    {
+        for dy in -range..=range {
+            for dx in -range..=range {
                 let nx = self.x as isize + dx;
                 let ny = self.y as isize + dy;
                 
@@ -211,214 +1132,852 @@ impl Robot {
                     let nx = nx as usize;
                     let ny = ny as usize;
                     
+                    // Les obstacles cachent ce qui se trouve derrière eux
+                    if !visible(self, nx, ny) {
+                        continue;
+                    }
+                    
                     // Si la case n'est pas encore explorée ou si notre info est plus récente
-                    if !self.memory[ny][nx].explored || 
-                       self.memory[ny][nx].timestamp < station.current_time {
+                    if !self.memory[(nx, ny)].explored || 
+                       self.memory[(nx, ny)].timestamp < station.current_time {
                         
-                        self.memory[ny][nx] = TerrainData {
+                        self.observe_tile(nx, ny, TerrainData {
                             explored: true,
                             timestamp: station.current_time,
                             robot_id: self.id,
                             robot_type: self.robot_type,
-                        };
+                            tile_type: map.get_tile(nx, ny),
+                        });
                     }
                 }
             }
         }
     }
     
-    // NOTE - Main update method for robot behavior
-    pub fn update(&mut self, map: &mut Map, station: &mut Station) {
-        // NOTE - Consume base metabolism energy
-        self.energy -= 0.1;
+    /// Explored tiles of the robot's memory that border at least one unexplored tile.
+    ///
+    /// Kept up to date incrementally by `update_memory`. Exploration targets are
+    /// picked from it, and an empty frontier on an explored memory means the
+    /// whole map is known.
+    ///
+    /// # Examples
+    ///
+    /// The frontier stays equal to a full recomputation from the memory grid
+    /// under random exploration and station synchronizations:
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use rand::Rng;
+    /// use ereea::{map::Map, robot::Robot, station::Station};
+    /// use ereea::types::{MAP_SIZE, RobotType, VisionModel};
+    ///
+    /// fn expected(robot: &Robot) -> HashSet<(usize, usize)> {
+    ///     let mut frontier = HashSet::new();
+    ///     for y in 0..MAP_SIZE {
+    ///         for x in 0..MAP_SIZE {
+    ///             let borders_unknown = (y.saturating_sub(1)..=(y + 1).min(MAP_SIZE - 1))
+    ///                 .flat_map(|ny| (x.saturating_sub(1)..=(x + 1).min(MAP_SIZE - 1)).map(move |nx| (nx, ny)))
+    ///                 .any(|(nx, ny)| !robot.memory[(nx, ny)].explored);
+    ///             if robot.memory[(x, y)].explored && borders_unknown {
+    ///                 frontier.insert((x, y));
+    ///             }
+    ///         }
+    ///     }
+    ///     frontier
+    /// }
+    ///
+    /// let map = Map::new();
+    /// let mut rng = rand::thread_rng();
+    /// for _ in 0..20 {
+    ///     let mut station = Station::new();
+    ///     let mut explorer = Robot::new(map.station_x, map.station_y, RobotType::Explorer);
+    ///     let mut collector = Robot::new(map.station_x, map.station_y, RobotType::MineralCollector);
+    ///     assert!(explorer.frontier().is_empty());
+    ///
+    ///     for step in 0..30 {
+    ///         station.tick();
+    ///         for robot in [&mut explorer, &mut collector] {
+    ///             robot.x = rng.gen_range(0..MAP_SIZE);
+    ///             robot.y = rng.gen_range(0..MAP_SIZE);
+    ///             robot.update_memory(&map, &station);
+    ///             assert_eq!(robot.frontier(), &expected(robot));
+    ///
+    ///             // Regular visits to the station merge the other robot's knowledge
+    ///             if step % 7 == 0 {
+    ///                 (robot.x, robot.y) = (robot.home_station_x, robot.home_station_y);
+    ///                 station.share_knowledge(robot);
+    ///                 assert_eq!(robot.frontier(), &expected(robot));
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // Exploring every tile empties the frontier
+    /// let station = Station::new();
+    /// let mut explorer = Robot::new(0, 0, RobotType::Explorer);
+    /// explorer.vision_model = VisionModel::Square;
+    /// for y in (0..MAP_SIZE).step_by(4) {
+    ///     for x in (0..MAP_SIZE).step_by(4) {
+    ///         (explorer.x, explorer.y) = (x, y);
+    ///         explorer.update_memory(&map, &station);
+    ///     }
+    /// }
+    /// assert!(explorer.frontier().is_empty());
+    /// assert_eq!(explorer.get_exploration_percentage(), 100.0);
+    /// ```
+    pub fn frontier(&self) -> &HashSet<(usize, usize)> {
+        &self.frontier
+    }
+    
+    /// Unexplored tiles of the exploration area the robot can still hope to see.
+    /// 
+    /// Those linked, through unexplored tiles, to a frontier tile the robot
+    /// may stand on. Pockets walled in by known obstacles are left out: no
+    /// frontier target will ever reveal them.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{robot::Robot, station::Station, types::{RobotType, TileType, MAP_SIZE}};
+    /// let mut station = Station::new();
+    /// for ((x, y), tile) in station.global_memory.iter_coords_mut() {
+    ///     // Known everywhere but the top-left corner and a pocket at (10, 10)...
+    ///     tile.explored = (x > 1 || y > 1) && (x, y) != (10, 10);
+    ///     // ...walled in by rock
+    ///     if x.abs_diff(10) <= 1 && y.abs_diff(10) <= 1 && tile.explored {
+    ///         tile.tile_type = TileType::Obstacle;
+    ///     }
+    /// }
+    /// let robot = Robot::new_with_memory(15, 15, RobotType::Explorer, 1, 15, 15, station.global_memory.clone());
+    /// 
+    /// let mut open: Vec<_> = robot.reachable_unknown_tiles().into_iter().collect();
+    /// open.sort();
+    /// assert_eq!(open, [(0, 0), (0, 1), (1, 0), (1, 1)]);
+    /// # assert_eq!(MAP_SIZE * MAP_SIZE - robot.explored_count(), 5);
+    /// ```
+    pub fn reachable_unknown_tiles(&self) -> HashSet<(usize, usize)> {
+        let mut reachable = HashSet::new();
+        let mut queue: VecDeque<(usize, usize)> = self.frontier.iter()
+            .filter(|&&(x, y)| self.memory[(x, y)].tile_type != TileType::Obstacle)
+            .flat_map(|&(x, y)| Self::neighborhood(x, y))
+            .collect();
         
-        // NOTE - Check if exploration is complete (explorers only)
-        if self.robot_type == RobotType::Explorer {
-            if self.is_exploration_complete() && !self.exploration_complete_announced {
-                println!("🌍 EXPLORATION DE L'EXOPLANÈTE TERMINÉE ! 🌍");
-                println!("Robot explorateur #{} a cartographié 100% de la planète.", self.id);
-                self.exploration_complete_announced = true;
+        // Parcourir les cases inconnues de proche en proche depuis la frontière praticable
+        while let Some((x, y)) = queue.pop_front() {
+            if self.memory[(x, y)].explored || !self.exploration_area.contains(x, y) || !reachable.insert((x, y)) {
+                continue;
+            }
+            queue.extend(Self::neighborhood(x, y));
+        }
+        reachable
+    }
+    
+    // NOTE - Number of explored tiles in the robot's memory
+    pub fn explored_count(&self) -> usize {
+        self.explored_count
+    }
+    
+    /// Restricts the tiles that count in the robot's exploration.
+    ///
+    /// Only the tiles of `area` count in `get_exploration_percentage` and in the
+    /// frontier, so the robot stops looking for tiles it can never reach and
+    /// completes its exploration once the area is known. Robots built by a
+    /// station receive its area (see `Station::set_exploration_area`).
+    pub fn set_exploration_area(&mut self, area: ExplorationArea) {
+        self.exploration_area = area;
+        self.reindex_memory();
+    }
+    
+    /// Tiles of the robot's memory written since its last station synchronization.
+    ///
+    /// What a peer may not know yet: `knowledge::sync_peers` only sends these
+    /// tiles instead of the whole grid. Emptied by `Station::share_knowledge`.
+    pub fn unsynced_tiles(&self) -> &HashSet<(usize, usize)> {
+        &self.unsynced_tiles
+    }
+    
+    // NOTE - Forget the unsynced tiles once the station holds everything the robot knows
+    pub(crate) fn clear_unsynced_tiles(&mut self) {
+        self.unsynced_tiles.clear();
+        // Plus rien à transmettre par radio non plus
+        self.radio_journal.clear();
+    }
+    
+    // NOTE - Recompute the frontier and explored count from `memory` (after editing it directly)
+    pub fn reindex_memory(&mut self) {
+        self.frontier.clear();
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                self.refresh_frontier_tile(x, y);
             }
         }
+        self.explored_count = self.count_explored_tiles();
+        self.explored_in_area = self.count_explored_in_area();
+    }
+    
+    // NOTE - Store a tile the robot sees for itself; discoveries (new tile, changed contents) go to the radio journal
+    fn observe_tile(&mut self, x: usize, y: usize, data: TerrainData) {
+        let known = &self.memory[(x, y)];
+        if !known.explored || known.tile_type != data.tile_type {
+            self.radio_journal.push_back((x, y, data.clone()));
+        }
+        self.mark_explored(x, y, data);
+    }
+    
+    // NOTE - Discoveries observed at or before `cutoff` and not sent yet, removed from the journal
+    pub(crate) fn take_radio_journal(&mut self, cutoff: u32) -> Vec<(usize, usize, TerrainData)> {
+        let due = self.radio_journal.iter().take_while(|(_, _, data)| data.timestamp <= cutoff).count();
+        self.radio_journal.drain(..due).collect()
+    }
+    
+    // NOTE - Store a memory tile, keeping the frontier and explored count up to date
+    pub(crate) fn mark_explored(&mut self, x: usize, y: usize, data: TerrainData) {
+        let discovered = data.explored && !self.memory[(x, y)].explored;
+        self.memory[(x, y)] = data;
+        self.unsynced_tiles.insert((x, y));
         
-        // NOUVELLE LOGIQUE: Les collecteurs attendent que l'exploration atteigne un seuil minimum
-        if self.robot_type != RobotType::Explorer {
-            let exploration_percentage = station.get_exploration_percentage();
-            
-            // Les collecteurs attendent au moins 30% d'exploration avant de commencer
-            if exploration_percentage < 30.0 {
-                // Rester à la station en mode Idle
-                if self.x != self.home_station_x || self.y != self.home_station_y {
-                    self.mode = RobotMode::ReturnToStation;
-                    self.plan_path_to_station(map);
-                } else {
-                    self.mode = RobotMode::Idle;
-                }
-                return;
+        // Seule la découverte d'une case peut modifier la frontière (elle et ses voisines)
+        if discovered {
+            self.explored_count += 1;
+            if self.exploration_area.contains(x, y) {
+                self.explored_in_area += 1;
             }
-            
-            // À 30-60% d'exploration, seuls les collecteurs d'énergie et de minerais travaillent
-            if exploration_percentage < 60.0 && self.robot_type == RobotType::ScientificCollector {
-                if self.x != self.home_station_x || self.y != self.home_station_y {
-                    self.mode = RobotMode::ReturnToStation;
-                    self.plan_path_to_station(map);
-                } else {
-                    self.mode = RobotMode::Idle;
-                }
-                return;
+            for (nx, ny) in Self::neighborhood(x, y) {
+                self.refresh_frontier_tile(nx, ny);
             }
         }
+    }
+    
+    // NOTE - Insert or remove a tile from the frontier according to its memory neighbourhood
+    fn refresh_frontier_tile(&mut self, x: usize, y: usize) {
+        let borders_unexplored = Self::neighborhood(x, y)
+            .any(|(nx, ny)| !self.memory[(nx, ny)].explored && self.exploration_area.contains(nx, ny));
         
-        // NOTE - Check if robot should return to station
-        if self.should_return_to_station(map) {
-            self.mode = RobotMode::ReturnToStation;
-            self.plan_path_to_station(map);
+        if self.memory[(x, y)].explored && borders_unexplored {
+            self.frontier.insert((x, y));
+        } else {
+            self.frontier.remove(&(x, y));
         }
+    }
+    
+    // NOTE - Tile and its (up to 8) neighbours inside the map
+    fn neighborhood(x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        (y.saturating_sub(1)..=(y + 1).min(MAP_SIZE - 1))
+            .flat_map(move |ny| (x.saturating_sub(1)..=(x + 1).min(MAP_SIZE - 1)).map(move |nx| (nx, ny)))
+    }
+    
+    /// Runs one cycle of the robot's behavior and returns what happened during it.
+    ///
+    /// The robot never prints: harvests, mode changes, arrivals at a station
+    /// and other noteworthy steps come back as `RobotEvent`s, in the order they
+    /// happened, for the caller to log or broadcast. A breakdown rolled by
+    /// `roll_malfunction` is reported by the next update.
+    ///
+    /// # Examples
+    ///
+    /// A mineral collector with room for a single unit harvests it and heads home.
+    ///
+    /// ```rust
+    /// # use ereea::events::RobotEvent;
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (0, 5);
+    /// map.set_tile(3, 5, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// station.reveal_map(&map);
+    /// let mut occupancy = OccupancyGrid::new(0, 5);
+    ///
+    /// let mut robot = Robot::new_with_memory(3, 5, RobotType::MineralCollector, 1, 0, 5, station.global_memory.clone());
+    /// robot.cargo_capacity = 1;
+    ///
+    /// let mut events = Vec::new();
+    /// while robot.mode != RobotMode::Idle {
+    ///     events.extend(robot.update(&mut map, &mut station, &mut occupancy)
+    ///         .into_iter()
+    ///         .filter(|event| !matches!(event, RobotEvent::Notice { .. })));
+    /// }
+    /// assert_eq!(events, vec![
+    ///     RobotEvent::ModeChanged { robot_id: 1, from: RobotMode::Exploring, to: RobotMode::Collecting },
+    ///     RobotEvent::Collected { robot_id: 1, resource: TileType::Mineral, x: 3, y: 5, left: 0 },
+    ///     RobotEvent::ModeChanged { robot_id: 1, from: RobotMode::Collecting, to: RobotMode::ReturnToStation },
+    ///     RobotEvent::Arrived { robot_id: 1, x: 0, y: 5 },
+    ///     RobotEvent::ModeChanged { robot_id: 1, from: RobotMode::ReturnToStation, to: RobotMode::Idle },
+    /// ]);
+    /// assert_eq!(station.collected_minerals, 1);
+    /// ```
+    ///
+    /// A collector parked at the station for lack of resources searches the
+    /// station memory again every `Station::idle_rescan_ticks` cycles, and
+    /// goes back to work once an explorer reports a deposit.
+    ///
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (0, 5);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// let mut occupancy = OccupancyGrid::new(0, 5);
+    /// let mut robot = Robot::new_with_memory(0, 5, RobotType::MineralCollector, 1, 0, 5, station.global_memory.clone());
+    /// 
+    /// // Nothing known to collect: parked at the station by tick 100
+    /// for _ in 0..100 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!(robot.mode, RobotMode::Idle);
+    /// for _ in 100..200 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!((robot.mode, robot.x, robot.y), (RobotMode::Idle, 0, 5));
+    /// 
+    /// // Tick 200: an explorer reports a mineral to the station
+    /// map.set_tile(4, 5, TileType::Mineral);
+    /// station.global_memory[(4, 5)].explored = true;
+    /// station.global_memory[(4, 5)].tile_type = TileType::Mineral;
+    /// station.reindex_memory();
+    /// 
+    /// let mut ticks = 0;
+    /// while robot.mode == RobotMode::Idle {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     ticks += 1;
+    /// }
+    /// assert!(ticks <= station.idle_rescan_ticks);
+    /// assert_eq!(robot.mode, RobotMode::Collecting);
+    /// 
+    /// while station.collected_minerals == 0 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     ticks += 1;
+    ///     assert!(ticks < 100, "mineral never brought home");
+    /// }
+    /// assert_eq!(map.get_tile(4, 5), TileType::Empty);
+    /// ```
+    pub fn update(&mut self, map: &mut Map, station: &mut Station, occupancy: &mut OccupancyGrid) -> Vec<RobotEvent> {
+        let mode = self.mode;
+        let was_docked = map.is_station(self.x, self.y);
+        let analysis = self.analysis_progress;
         
-        // NOTE - For collectors, check if resources remain to collect
-        if self.robot_type != RobotType::Explorer && self.mode == RobotMode::Exploring {
-            // Vérifier d'abord si on peut voir des ressources (exploration suffisante)
-            if let Some(_resource_pos) = self.find_nearest_known_resource(map, station) {
-                // Il y a des ressources connues, continuer la collecte
-            } else {
-                // Pas de ressources connues dans les zones explorées
-                if self.x != self.home_station_x || self.y != self.home_station_y {
-                    self.mode = RobotMode::ReturnToStation;
-                    self.plan_path_to_station(map);
-                } else {
-                    self.mode = RobotMode::Idle;
-                    println!("🏁 Robot collecteur #{} : Aucune ressource connue, passage en mode Idle", self.id);
-                }
-            }
+        self.updating = true;
+        self.step(map, station, occupancy);
+        self.updating = false;
+        
+        // NOTE - An analysis needs continuous presence: an update without progress starts it over
+        if analysis.is_some() && self.analysis_progress == analysis {
+            self.interrupt_analysis();
         }
         
-        // NOTE - If at station, recharge, sync, and change mode
-        if self.x == self.home_station_x && self.y == self.home_station_y {
-            // Recharger et décharger
-            self.energy = self.max_energy;
-            station.deposit_resources(self.minerals, self.scientific_data);
-            self.minerals = 0;
-            self.scientific_data = 0;
-            
-            // Synchroniser les connaissances avec la station
-            if station.current_time > self.last_sync_time {
-                station.share_knowledge(self);
-                self.last_sync_time = station.current_time;
-            }
-            
-            // Changer de mode après avoir rechargé
-            match self.robot_type {
-                RobotType::Explorer => {
-                    // Si l'exploration est terminée, rester à la station en mode Idle
-                    if self.is_exploration_complete() {
-                        self.mode = RobotMode::Idle;
-                        if !self.exploration_complete_announced {
-                            println!("🏠 Robot explorateur #{} : Mission terminée, retour définitif à la base.", self.id);
-                        }
-                    } else {
-                        // Sinon, retourner explorer
-                        self.mode = RobotMode::Exploring;
-                    }
-                },
-                _ => {
-                    // Les collecteurs cherchent des ressources
-                    if let Some(resource_pos) = self.find_nearest_resource(map) {
-                        self.path_to_station = self.find_path(map, resource_pos);
-                        self.mode = RobotMode::Collecting;
-                    } else {
-                        // Si pas de ressource trouvée, rester à la station en mode Idle
-                        self.mode = RobotMode::Idle;
-                        println!("🏁 Robot collecteur #{} : Aucune ressource trouvée, reste en mode Idle", self.id);
-                    }
-                }
-            }
+        if map.is_station(self.x, self.y) && !was_docked {
+            self.events.push(RobotEvent::Arrived { robot_id: self.id, x: self.x, y: self.y });
+        }
+        if self.mode != mode {
+            self.events.push(RobotEvent::ModeChanged { robot_id: self.id, from: mode, to: self.mode });
         }
+        std::mem::take(&mut self.events)
+    }
+    
+    
+    // NOTE - Single entry point for mode changes: checks the transition and records it in `mode_history`
+    fn set_mode(&mut self, new: RobotMode, reason: TransitionReason) {
+        if new == self.mode {
+            return;
+        }
+        debug_assert!(self.can_enter(new), "robot #{} ({:?}) : passage {:?} -> {:?} interdit", self.id, self.robot_type, self.mode, new);
         
-        // NOTE - Logique de déplacement selon le mode
-        match self.mode {
-            RobotMode::Idle => {
-                // Pour les explorateurs : si l'exploration est terminée, rester à la station
-                if self.robot_type == RobotType::Explorer && self.is_exploration_complete() {
-                    // Ne rien faire, rester à la station
-                    return;
+        // Plusieurs changements pendant une même mise à jour ne comptent que pour une transition
+        let tick = self.age_ticks;
+        match self.mode_history.back_mut() {
+            Some(last) if self.updating && last.tick == tick => {
+                last.to = new;
+                last.reason = reason;
+                if last.from == new {
+                    // Revenu au mode de départ : la mise à jour a changé de mode pour rien
+                    self.mode_history.pop_back();
+                    self.wasted_mode_changes += 1;
                 }
-                
-                // Pour les autres ou si exploration pas terminée, retourner en mode exploration
-                if self.robot_type == RobotType::Explorer {
-                    self.mode = RobotMode::Exploring;
+            },
+            _ => {
+                if self.mode_history.len() == MODE_HISTORY_LEN {
+                    self.mode_history.pop_front();
+                }
+                self.mode_history.push_back(ModeTransition { tick, from: self.mode, to: new, reason });
+            },
+        }
+        self.mode = new;
+    }
+    
+    // NOTE - Whether the robot may enter `mode`: collecting needs a target, rescues and scans belong to one type
+    fn can_enter(&self, mode: RobotMode) -> bool {
+        match mode {
+            RobotMode::Collecting => self.current_target.is_some()
+                || matches!(self.task_queue.front(), Some(RobotTask::CollectAt(..))),
+            RobotMode::Rescuing => self.robot_type == RobotType::Repairer,
+            RobotMode::Scanning => self.robot_type == RobotType::Explorer,
+            _ => true,
+        }
+    }
+    
+    /// Latest mode changes, oldest first (at most 16).
+    /// 
+    /// The changes made during one `update` count as a single transition,
+    /// from the mode the update started in to the one it ended in; an update
+    /// that comes back to its starting mode leaves no transition (see
+    /// [`wasted_mode_changes`](Self::wasted_mode_changes)). Changes outside
+    /// `update` (breakdowns, emergency recoveries) are recorded on their own.
+    /// 
+    /// # Examples
+    /// 
+    /// A collector with nothing to collect heads home, then parks:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::{ModeTransition, Robot, TransitionReason}, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (5, 5);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// let mut robot = Robot::new_with_memory(6, 5, RobotType::MineralCollector, 1, 5, 5, station.global_memory.clone());
+    /// let mut occupancy = OccupancyGrid::new(5, 5);
+    /// 
+    /// for _ in 0..3 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// let modes: Vec<_> = robot.mode_history().iter().map(|t| (t.from, t.to, t.reason)).collect();
+    /// assert_eq!(modes, vec![
+    ///     (RobotMode::Exploring, RobotMode::ReturnToStation, TransitionReason::NoTargets),
+    ///     (RobotMode::ReturnToStation, RobotMode::Idle, TransitionReason::NoTargets),
+    /// ]);
+    /// assert!(robot.mode_history().iter().all(|&ModeTransition { tick, .. }| tick <= robot.age_ticks));
+    /// assert_eq!(robot.wasted_mode_changes(), 0);
+    /// ```
+    /// 
+    /// A full collection round: fill the hold, bring it home, recharge, go back
+    /// to the deposit and empty it:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::{Robot, TransitionReason}, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (5, 5);
+    /// map.tiles[5][8] = TileType::Mineral;
+    /// map.set_resource_amount(8, 5, 8);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// station.reveal_map(&map);
+    /// let mut robot = Robot::new_with_memory(5, 5, RobotType::MineralCollector, 1, 5, 5, station.global_memory.clone());
+    /// let mut occupancy = OccupancyGrid::new(5, 5);
+    /// 
+    /// while robot.mode_history().len() < 5 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     if robot.age_ticks == 1 {
+    ///         robot.energy = 30.0; // de quoi rentrer, pas de quoi repartir sans recharger
+    ///     }
+    ///     assert!(robot.age_ticks < 100);
+    /// }
+    /// 
+    /// let steps: Vec<_> = robot.mode_history().iter().map(|t| (t.to, t.reason)).collect();
+    /// assert_eq!(steps, vec![
+    ///     (RobotMode::Collecting, TransitionReason::TargetFound),
+    ///     (RobotMode::ReturnToStation, TransitionReason::CargoFull),
+    ///     (RobotMode::Charging, TransitionReason::Recharging),
+    ///     (RobotMode::Collecting, TransitionReason::TargetFound),
+    ///     (RobotMode::ReturnToStation, TransitionReason::NoTargets),
+    /// ]);
+    /// assert_eq!(robot.mode_history()[1].to_string(), format!("cycle {} : Collecting → ReturnToStation (soute pleine)", robot.mode_history()[1].tick));
+    /// ```
+    pub fn mode_history(&self) -> &VecDeque<ModeTransition> {
+        &self.mode_history
+    }
+    
+    /// Updates since deployment that left their starting mode and came back to it within the same update
+    pub fn wasted_mode_changes(&self) -> u32 {
+        self.wasted_mode_changes
+    }
+    
+    // NOTE - Log line of a step that has no dedicated event
+    fn notice(&mut self, message: String) {
+        self.events.push(RobotEvent::Notice { robot_id: self.id, message });
+    }
+    
+    // NOTE - Main update method for robot behavior
+    fn step(&mut self, map: &mut Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
+        self.age_ticks += 1;
+        
+        // NOTE - Tasks dispatched by the station since the last update join the queue
+        for task in station.take_tasks(self.id) {
+            self.assign_task(task);
+        }
+        
+        // NOTE - With several stations, the robot docks at the nearest one
+        if !map.extra_stations.is_empty() {
+            self.select_home_station(map);
+        }
+        
+        // NOTE - Broken robots neither move nor collect until repaired
+        if self.mode == RobotMode::Broken {
+            self.update_malfunction(station);
+            return;
+        }
+        
+        // NOTE - Stranded robots pick up the energy a repairer brought them
+        if self.mode == RobotMode::Stranded
+            && let Some(energy) = station.take_rescue_energy(self.id) {
+            self.energy = (self.energy + energy).min(self.max_energy);
+            self.events.push(RobotEvent::Rescued { robot_id: self.id, energy });
+        }
+        
+        // NOTE - Energy checks use the energy available before this tick's consumption; far from the
+        // return threshold, a longer way home leads to the same decisions as the shortest one
+        let slack = 2.0 * RETURN_MARGIN_STEPS * (self.step_energy_cost() + self.metabolism_cost());
+        let return_cost = match self.home_cost_bound(map) {
+            Some(bound) if self.energy >= bound + slack => Some(bound),
+            _ => self.energy_to_return_home(map),
+        };
+        
+        // NOTE - Sealed off from its station: neither the robot nor a repairer can make the trip, wait in place
+        if return_cost.is_none() && !map.path_exists((self.x, self.y), (self.home_station_x, self.home_station_y)) {
+            station.cancel_rescue(self.id);
+            self.declare_lost();
+            self.update_memory(map, station);
+            return;
+        }
+        self.lost = false;
+        let can_reach_station = return_cost.is_some_and(|needed| self.energy + ENERGY_EPSILON >= needed);
+        if !can_reach_station {
+            // Appel de détresse renouvelé à chaque cycle, en attendant un réparateur
+            station.report_stranded(self.id, self.x, self.y);
+            if self.mode != RobotMode::Stranded {
+                self.set_mode(RobotMode::Stranded, TransitionReason::Stranded);
+                self.invalidate_path();
+                self.events.push(RobotEvent::Stranded { robot_id: self.id, x: self.x, y: self.y });
+            }
+            return;
+        }
+        
+        // NOTE - Back on its feet (rescued, or enough energy again on its own): head home
+        if self.mode == RobotMode::Stranded {
+            station.cancel_rescue(self.id);
+            self.set_mode(RobotMode::ReturnToStation, TransitionReason::Rescued);
+        }
+        let must_return = self.should_return_to_station(return_cost);
+        if self.low_on_energy(return_cost) && self.mode != RobotMode::ReturnToStation {
+            self.events.push(RobotEvent::LowEnergy { robot_id: self.id, x: self.x, y: self.y });
+        }
+        // NOTE - Low energy or a full hold interrupt a task; it resumes once the robot is back in shape
+        let task_preempted = self.low_on_energy(return_cost) || self.is_cargo_full();
+        
+        // NOTE - Look around before deciding: nearby resources may have been taken since the last tick
+        self.update_memory(map, station);
+        
+        // NOTE - Consume base metabolism energy (a robot idling at a station is on standby)
+        if !(self.mode == RobotMode::Idle && map.is_station(self.x, self.y)) {
+            self.consume_energy(self.metabolism_cost());
+        }
+        
+        // NOTE - Standing still for too long with somewhere to go: give the goal up
+        self.update_stuck_count();
+        if self.stuck_ticks >= MAX_STUCK_TICKS {
+            self.give_up_unreachable_target(station);
+        }
+        
+        // NOTE - Moving around without getting any closer to the target: give it up as well
+        if self.update_target_progress() >= MAX_NO_PROGRESS_TICKS {
+            self.give_up_unreachable_target(station);
+        }
+        
+        // NOTE - Break out of ping-pong movements before deciding anything else
+        self.record_position();
+        if self.is_oscillating() {
+            self.break_oscillation(station);
+        }
+        
+        // NOTE - Repairers only answer distress calls
+        if self.robot_type == RobotType::Repairer {
+            self.repairer_update(map, station, occupancy, must_return);
+            self.update_memory(map, station);
+            return;
+        }
+        
+        // NOTE - A scan lasts a single update
+        if self.mode == RobotMode::Scanning {
+            self.set_mode(RobotMode::Exploring, TransitionReason::ScanDone);
+        }
+        
+        // NOTE - Fleet recall: everyone but the repairers heads home and stays docked, tasks kept for later
+        if station.is_recall_active() {
+            self.recall_update(map, station, occupancy);
+            self.update_memory(map, station);
+            return;
+        }
+        
+        // NOTE - Relays hold a post between the fleet and the station
+        if self.robot_type == RobotType::Relay {
+            self.relay_update(map, station, occupancy, must_return);
+            self.update_memory(map, station);
+            return;
+        }
+        
+        // NOTE - Once the mission objectives are met, explorers and collectors head home for good
+        if self.task_queue.is_empty() && station.is_mission_complete(map) {
+            if self.x == self.home_station_x && self.y == self.home_station_y {
+                self.unload(station);
+                station.release_target(self.id);
+                self.set_mode(RobotMode::Idle, TransitionReason::MissionComplete);
+                self.invalidate_path();
+                return;
+            }
+            if self.mode != RobotMode::ReturnToStation {
+                station.release_target(self.id);
+                self.set_mode(RobotMode::ReturnToStation, TransitionReason::MissionComplete);
+                self.plan_path_to_station(map);
+            }
+        }
+        
+        // NOTE - Check if exploration is complete (explorers only)
+        if self.robot_type == RobotType::Explorer
+            && self.is_exploration_complete() && !self.exploration_complete_announced {
+            self.events.push(RobotEvent::ExplorationComplete { robot_id: self.id });
+            self.exploration_complete_announced = true;
+        }
+        
+        // NOTE - Collectors wait at the station until exploration reaches their policy threshold,
+        // and grounded robots until the energy collectors refill the reserves
+        let waits_for_policy = self.robot_type != RobotType::Explorer
+            && !station.collector_policy.allows(self.robot_type, station.get_exploration_percentage());
+        if self.task_queue.is_empty() && (waits_for_policy || station.grounds(self.robot_type)) {
+            if self.x != self.home_station_x || self.y != self.home_station_y {
+                self.set_mode(RobotMode::ReturnToStation, TransitionReason::OnHold);
+                self.plan_path_to_station(map);
+            } else {
+                self.set_mode(RobotMode::Idle, TransitionReason::OnHold);
+            }
+            return;
+        }
+        
+        // NOTE - Check if robot should return to station (already docked: the station branch below decides)
+        if must_return && (self.x, self.y) != (self.home_station_x, self.home_station_y) {
+            self.set_mode(RobotMode::ReturnToStation, self.return_reason());
+            if self.robot_type == RobotType::Explorer && self.is_exploration_complete() {
+                self.plan_final_return(map);
+            } else {
+                self.plan_path_to_station(map);
+            }
+        }
+        
+        // NOTE - For collectors, check if resources remain to collect
+        if self.robot_type != RobotType::Explorer && self.mode == RobotMode::Exploring && self.task_queue.is_empty() {
+            // Vérifier d'abord si on peut voir des ressources (exploration suffisante)
+            if let Some(_resource_pos) = self.find_nearest_known_resource(station) {
+                // Il y a des ressources connues, continuer la collecte
+            } else {
+                // Pas de ressources connues dans les zones explorées
+                if self.x != self.home_station_x || self.y != self.home_station_y {
+                    self.set_mode(RobotMode::ReturnToStation, TransitionReason::NoTargets);
+                    self.plan_path_to_station(map);
+                } else {
+                    self.set_mode(RobotMode::Idle, TransitionReason::NoTargets);
+                }
+            }
+        }
+        
+        // NOTE - A collector parked for lack of resources is only parked at its station
+        if self.x != self.home_station_x || self.y != self.home_station_y || self.mode != RobotMode::Idle {
+            self.idle_rescan_in = None;
+        }
+        
+        // NOTE - If at station, unload, sync, recharge, and change mode (unless only passing by)
+        let passing_through = std::mem::take(&mut self.passing_through) && !must_return;
+        if self.x == self.home_station_x && self.y == self.home_station_y && !passing_through {
+            // Décharger
+            self.unload(station);
+            
+            // Synchroniser les connaissances avec la station
+            if station.current_time > self.last_sync_time {
+                station.share_knowledge(self);
+                self.last_sync_time = station.current_time;
+            }
+            
+            // Recharger progressivement : rester à quai tant que la batterie n'est pas pleine
+            if !self.charge_at_station(station) {
+                return;
+            }
+            
+            // Changer de mode après avoir rechargé
+            match self.robot_type {
+                RobotType::Explorer => {
+                    // Si l'exploration est terminée, rester à la station en mode Idle
+                    if self.is_exploration_complete() {
+                        self.set_mode(RobotMode::Idle, TransitionReason::ExplorationComplete);
+                        if !self.exploration_complete_announced {
+                            self.notice(format!("🏠 Robot explorateur #{} : Mission terminée, retour définitif à la base.", self.id));
+                        }
+                    } else {
+                        // Sinon, retourner explorer
+                        self.set_mode(RobotMode::Exploring, TransitionReason::Recharged);
+                    }
+                },
+                _ => {
+                    // Au repos faute de ressource : ne rechercher que tous les `idle_rescan_ticks` cycles
+                    if let Some(ticks) = self.idle_rescan_in
+                        && ticks > 1 {
+                        self.idle_rescan_in = Some(ticks - 1);
+                        return;
+                    }
+                    
+                    // Les collecteurs cherchent des ressources (si l'aller-retour est faisable)
+                    if let Some(resource_pos) = self.find_nearest_resource(station) {
+                        if self.plan_collection_path(map, station, resource_pos) {
+                            self.set_mode(RobotMode::Collecting, TransitionReason::TargetFound);
+                            if self.idle_rescan_in.take().is_some() {
+                                self.notice(format!("🔁 Robot collecteur #{} : ressource découverte en ({}, {}), reprise de la collecte",
+                                                    self.id, resource_pos.0, resource_pos.1));
+                            }
+                        }
+                    } else {
+                        // Si pas de ressource trouvée, rester à la station en mode Idle
+                        self.set_mode(RobotMode::Idle, TransitionReason::NoTargets);
+                        if self.idle_rescan_in.is_none()
+                            && let Some(resource) = self.collected_resource() {
+                            self.events.push(RobotEvent::Idle { robot_id: self.id, resource });
+                        }
+                        self.idle_rescan_in = Some(station.idle_rescan_ticks.max(1));
+                    }
+                }
+            }
+        }
+        
+        // NOTE - Assigned tasks come before the robot's own decisions
+        if !task_preempted && !self.task_queue.is_empty() {
+            station.release_charging_bay(self.id);
+            station.release_exploration_target(self.id);
+            station.release_target(self.id);
+            self.run_task(map, station, occupancy);
+            self.update_memory(map, station);
+            return;
+        }
+        
+        // NOTE - Only charging robots keep a charging bay (or their place in the queue)
+        if self.mode != RobotMode::Charging {
+            station.release_charging_bay(self.id);
+        }
+        
+        // NOTE - Only exploring robots keep an exploration target reserved
+        if self.mode != RobotMode::Exploring {
+            station.release_exploration_target(self.id);
+        }
+        
+        // NOTE - Only collecting robots keep a resource reserved (e.g. released when heading home on low energy)
+        if self.mode != RobotMode::Collecting {
+            station.release_target(self.id);
+        }
+        
+        // NOTE - Logique de déplacement selon le mode
+        match self.mode {
+            RobotMode::Idle => {
+                // Pour les explorateurs : si l'exploration est terminée, rester à la station
+                if self.robot_type == RobotType::Explorer && self.is_exploration_complete() {
+                    // Ne rien faire, rester à la station
+                    return;
+                }
+                
+                // Pour les autres ou si exploration pas terminée, retourner en mode exploration
+                if self.robot_type == RobotType::Explorer {
+                    self.set_mode(RobotMode::Exploring, TransitionReason::Ready);
                 }
             },
             RobotMode::Exploring => {
                 // Pour les explorateurs : vérifier si l'exploration est terminée
                 if self.robot_type == RobotType::Explorer && self.is_exploration_complete() {
                     // Si l'exploration est terminée, retourner à la station et y rester
-                    self.mode = RobotMode::ReturnToStation;
+                    self.set_mode(RobotMode::ReturnToStation, TransitionReason::ExplorationComplete);
                     self.plan_path_to_station(map);
                     return;
                 }
                 
                 // Si c'est un collecteur, vérifier s'il y a des ressources à proximité
-                if self.robot_type != RobotType::Explorer {
-                    if let Some(resource_pos) = self.find_nearest_resource(map) {
-                        let distance = self.heuristic((self.x, self.y), resource_pos);
-                        if distance <= 5 {  // Distance de détection
-                            self.path_to_station = self.find_path(map, resource_pos);
-                            self.mode = RobotMode::Collecting;
-                            return;
+                if self.robot_type != RobotType::Explorer
+                    && let Some(resource_pos) = self.find_nearest_resource(station) {
+                    let distance = self.position().chebyshev_distance(resource_pos.into());
+                    if distance <= 5 {  // Distance de détection
+                        if self.plan_collection_path(map, station, resource_pos) {
+                            self.set_mode(RobotMode::Collecting, TransitionReason::TargetFound);
                         }
+                        return;
                     }
                 }
                 
-                // Sinon, explorer normalement
-                self.explore_move(map);
+                // Sinon, laisser le cerveau choisir la suite de l'exploration
+                self.follow_brain(map, station, occupancy);
             },
             RobotMode::Collecting => {
-                // Si on est sur la ressource cible, la collecter
-                let tile = map.get_tile(self.x, self.y);
-                let can_collect = match (self.robot_type, tile) {
-                    (RobotType::EnergyCollector, TileType::Energy) => true,
-                    (RobotType::MineralCollector, TileType::Mineral) => true,
-                    (RobotType::ScientificCollector, TileType::Scientific) => true,
-                    _ => false,
-                };
+                // La ressource visée a disparu de la mémoire (vue consommée par un autre robot) : abandonner le chemin
+                if let Some(target) = self.current_target
+                    && !self.collects(&self.memory[(target.0, target.1)].tile_type) {
+                    self.invalidate_path();
+                    station.release_target(self.id);
+                }
+                
+                // Renouveler la réservation ; si elle a expiré et qu'un autre robot l'a prise, changer de cible
+                if let Some(target) = self.current_target
+                    && !station.reserve_target(self.id, target.0, target.1) {
+                    self.invalidate_path();
+                }
                 
-                if can_collect {
-                    self.collect_resources(map);
-                } else if !self.path_to_station.is_empty() {
-                    // Suivre le chemin vers la ressource
-                    let next = self.path_to_station.pop_front().unwrap();
-                    self.move_to(next.0, next.1);
+                // Une ressource nettement plus proche est connue : changer de cible
+                if let Some(target) = self.current_target
+                    && let Some(resource_pos) = self.find_nearest_resource(station)
+                    && resource_pos != target
+                    && self.position().chebyshev_distance(resource_pos.into()) + BETTER_TARGET_MARGIN
+                        <= self.current_path.len() {
+                    self.plan_collection_path(map, station, resource_pos);
+                }
+                
+                // Si on est sur la ressource cible, la collecter
+                if self.mode != RobotMode::Collecting {
+                    // Retour à la station décidé en replanifiant : le chemin est suivi au prochain cycle
+                } else if self.collects(&map.get_tile(self.x, self.y)) {
+                    self.collect_resources(map, station, occupancy);
+                } else if !self.current_path.is_empty() {
+                    // Suivre le chemin vers la ressource, en passant par la station si elle est sur la route
+                    if station.drop_off_in_passing {
+                        self.plan_drop_off(map);
+                    }
+                    self.step_along_path(map, occupancy);
+                    if station.drop_off_in_passing && (self.x, self.y) == (self.home_station_x, self.home_station_y)
+                        && !self.current_path.is_empty() && self.cargo_load() > 0 {
+                        self.drop_off(station);
+                    }
                 } else {
                     // Si le chemin est vide mais qu'on n'est pas sur la ressource, chercher une autre ressource
-                    if let Some(resource_pos) = self.find_nearest_resource(map) {
-                        self.path_to_station = self.find_path(map, resource_pos);
+                    if let Some(resource_pos) = self.find_nearest_resource(station) {
+                        self.plan_collection_path(map, station, resource_pos);
                     } else {
                         // Si plus de ressources, retourner à la station
-                        self.mode = RobotMode::ReturnToStation;
+                        self.set_mode(RobotMode::ReturnToStation, TransitionReason::NoTargets);
                         self.plan_path_to_station(map);
                     }
                 }
             },
+            RobotMode::Stranded | RobotMode::Rescuing | RobotMode::Broken | RobotMode::Charging | RobotMode::Scanning => {},
             RobotMode::ReturnToStation => {
-                if !self.path_to_station.is_empty() {
-                    // Suivre le chemin vers la station
-                    let next = self.path_to_station.pop_front().unwrap();
-                    self.move_to(next.0, next.1);
-                } else {
-                    // Si le chemin est vide mais qu'on n'est pas à la station, replanifier
-                    if self.x != self.home_station_x || self.y != self.home_station_y {
-                        self.plan_path_to_station(map);
-                        if !self.path_to_station.is_empty() {
-                            let next = self.path_to_station.pop_front().unwrap();
-                            self.move_to(next.0, next.1);
-                        } else {
-                            // Si on ne peut pas générer de chemin, revenir en mode exploration
-                            self.mode = RobotMode::Exploring;
-                        }
+                if self.x != self.home_station_x || self.y != self.home_station_y {
+                    // Suivre le chemin vers la station (replanifié seulement s'il n'est plus valide)
+                    self.plan_path_to_station(map);
+                    if !self.current_path.is_empty() {
+                        self.step_along_path(map, occupancy);
                     } else {
-                        // Si on est à la station, passer en mode idle
-                        self.mode = RobotMode::Idle;
+                        // Si on ne peut pas générer de chemin, revenir en mode exploration
+                        self.set_mode(RobotMode::Exploring, TransitionReason::Unreachable);
                     }
+                } else {
+                    // Si on est à la station, passer en mode idle
+                    self.set_mode(RobotMode::Idle, TransitionReason::Arrived);
                 }
             }
         }
@@ -427,60 +1986,795 @@ impl Robot {
         self.update_memory(map, station);
     }
     
+    // NOTE - Recalled robot: drop its targets and head home, then unload, sync and charge,
+    // and stay idle at the station until operations resume
+    fn recall_update(&mut self, map: &Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
+        station.release_exploration_target(self.id);
+        station.release_target(self.id);
+        
+        if self.x == self.home_station_x && self.y == self.home_station_y {
+            self.unload(station);
+            if station.current_time > self.last_sync_time {
+                station.share_knowledge(self);
+                self.last_sync_time = station.current_time;
+            }
+            if self.charge_at_station(station) {
+                self.set_mode(RobotMode::Idle, TransitionReason::Recalled);
+                self.invalidate_path();
+            }
+            return;
+        }
+        
+        station.release_charging_bay(self.id);
+        self.set_mode(RobotMode::ReturnToStation, TransitionReason::Recalled);
+        self.plan_path_to_station(map);
+        if !self.current_path.is_empty() {
+            self.step_along_path(map, occupancy);
+        }
+    }
+    
+    // NOTE - Repairer behavior: wait at the station for a distress call, bring energy to
+    // the stranded robot (following it if it moves), then come back
+    fn repairer_update(&mut self, map: &Map, station: &mut Station, occupancy: &mut OccupancyGrid, must_return: bool) {
+        if self.x == self.home_station_x && self.y == self.home_station_y {
+            if station.current_time > self.last_sync_time {
+                station.share_knowledge(self);
+                self.last_sync_time = station.current_time;
+            }
+            
+            // Partir secourir avec la batterie pleine
+            if !self.charge_at_station(station) {
+                return;
+            }
+            
+            // Prendre en charge le plus ancien appel de détresse, avec une cargaison d'énergie
+            if let Some(request) = station.assign_rescue(self.id) {
+                if self.mode != RobotMode::Rescuing {
+                    self.rescue_cargo = station.rescue_energy;
+                    self.set_mode(RobotMode::Rescuing, TransitionReason::DistressCall);
+                    self.notice(format!("🔧 Robot réparateur #{} part secourir le robot #{} en ({}, {})",
+                                        self.id, request.robot_id, request.position.0, request.position.1));
+                }
+            } else {
+                self.rescue_cargo = 0.0;
+                self.set_mode(RobotMode::Idle, TransitionReason::NoTargets);
+            }
+        }
+        
+        if self.mode == RobotMode::Rescuing {
+            match station.rescue_assigned_to(self.id) {
+                // Le robot en détresse est reparti seul ou a été rapatrié : rentrer
+                None => self.set_mode(RobotMode::ReturnToStation, TransitionReason::RescueOver),
+                Some(_) if must_return => {
+                    station.release_rescue(self.id);
+                    self.set_mode(RobotMode::ReturnToStation, TransitionReason::LowEnergy);
+                },
+                Some(request) if self.position().chebyshev_distance(request.position.into()) <= 1 => {
+                    station.deliver_rescue(self.id, self.rescue_cargo);
+                    self.notice(format!("🔧 Robot réparateur #{} a transféré {:.1} unités d'énergie au robot #{}",
+                                        self.id, self.rescue_cargo, request.robot_id));
+                    self.rescue_cargo = 0.0;
+                    self.set_mode(RobotMode::ReturnToStation, TransitionReason::RescueOver);
+                },
+                Some(request) => {
+                    // Replanifié si le robot en détresse a bougé depuis
+                    self.plan_path(map, request.position);
+                    if self.current_path.is_empty() {
+                        station.release_rescue(self.id);
+                        self.set_mode(RobotMode::ReturnToStation, TransitionReason::Unreachable);
+                    } else {
+                        self.step_along_path(map, occupancy);
+                    }
+                },
+            }
+        }
+        
+        if self.mode == RobotMode::ReturnToStation {
+            self.plan_path_to_station(map);
+            if !self.current_path.is_empty() {
+                self.step_along_path(map, occupancy);
+            }
+        }
+    }
+    
+    /// Picks the tile where this relay best links the fleet to the station.
+    /// 
+    /// `fleet` holds the robots working away from the stations, `anchors` the
+    /// points already linked to the station (stations and linked relays). The
+    /// post must lie within `radius` of an anchor and minimizes the fleet's
+    /// average distance to connectivity (`knowledge::link_gap`); on ties the
+    /// closest tile wins, so a relay at its post stays there. Without robots
+    /// in the field the relay has no post and goes home.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot};
+    /// # use ereea::types::{Position, RobotType};
+    /// let mut map = Map::open();
+    /// let station = Position::new(10, 10);
+    /// let mut relay = Robot::new(10, 10, RobotType::Relay);
+    /// 
+    /// // An explorer 8 tiles east of the station: the relay stands halfway
+    /// relay.choose_relay_post(&map, &[Position::new(18, 10)], &[station], 4);
+    /// let (x, _) = relay.relay_post.unwrap();
+    /// assert_eq!(x, 14);
+    /// 
+    /// // Nobody in the field, nothing to relay
+    /// relay.choose_relay_post(&map, &[], &[station], 4);
+    /// assert_eq!(relay.relay_post, None);
+    /// ```
+    pub fn choose_relay_post(&mut self, map: &Map, fleet: &[Position], anchors: &[Position], radius: usize) {
+        if fleet.is_empty() {
+            self.relay_post = None;
+            return;
+        }
+        
+        // (poste, écart total de la flotte, distance au relais)
+        let mut best: Option<((usize, usize), usize, usize)> = None;
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                let post = Position::new(x, y);
+                let linked = anchors.iter().any(|&anchor| anchor.chebyshev_distance(post) <= radius);
+                let avoided = self.avoided_targets.iter().any(|&(target, _)| target == (x, y));
+                if !linked || avoided || !map.is_valid_position(x, y) {
+                    continue;
+                }
+                
+                let gap = fleet.iter()
+                    .map(|&robot| crate::knowledge::link_gap(robot, anchors, radius)
+                        .min(robot.chebyshev_distance(post).saturating_sub(radius)))
+                    .sum::<usize>();
+                let distance = self.position().chebyshev_distance(post);
+                if best.is_none_or(|(_, best_gap, best_distance)| (gap, distance) < (best_gap, best_distance)) {
+                    best = Some(((x, y), gap, distance));
+                }
+            }
+        }
+        self.relay_post = best.map(|(post, _, _)| post);
+    }
+    
+    // NOTE - One update of a relay: charge at the station, then reach and hold its post
+    fn relay_update(&mut self, map: &Map, station: &mut Station, occupancy: &mut OccupancyGrid, must_return: bool) {
+        let at_home = (self.x, self.y) == (self.home_station_x, self.home_station_y);
+        if at_home {
+            if station.current_time > self.last_sync_time {
+                station.share_knowledge(self);
+                self.last_sync_time = station.current_time;
+            }
+            
+            // Repartir vers son poste avec la batterie pleine
+            if !self.charge_at_station(station) {
+                return;
+            }
+        }
+        
+        if must_return && !at_home {
+            self.set_mode(RobotMode::ReturnToStation, TransitionReason::LowEnergy);
+        } else {
+            match self.relay_post {
+                Some(post) if post == (self.x, self.y) => self.set_mode(RobotMode::Idle, TransitionReason::Arrived),
+                Some(post) => {
+                    self.plan_path(map, post);
+                    if self.current_path.is_empty() {
+                        // Poste inaccessible : l'écarter, un autre sera choisi au prochain cycle
+                        self.avoided_targets.push((post, UNREACHABLE_TARGET_TICKS));
+                        self.relay_post = None;
+                        self.set_mode(if at_home { RobotMode::Idle } else { RobotMode::ReturnToStation }, TransitionReason::Unreachable);
+                    } else {
+                        self.set_mode(RobotMode::Exploring, TransitionReason::TargetFound);
+                        self.step_along_path(map, occupancy);
+                    }
+                },
+                None if at_home => self.set_mode(RobotMode::Idle, TransitionReason::NoTargets),
+                None => self.set_mode(RobotMode::ReturnToStation, TransitionReason::NoTargets),
+            }
+        }
+        
+        if self.mode == RobotMode::ReturnToStation {
+            self.plan_path_to_station(map);
+            if !self.current_path.is_empty() {
+                self.step_along_path(map, occupancy);
+            }
+        }
+    }
+    
+    // NOTE - Remember the current position and age the avoided targets
+    fn record_position(&mut self) {
+        if self.recent_positions.len() == POSITION_HISTORY_LEN {
+            self.recent_positions.pop_front();
+        }
+        self.recent_positions.push_back((self.x, self.y));
+        
+        self.avoided_targets.retain_mut(|(_, ticks)| {
+            *ticks -= 1;
+            *ticks > 0
+        });
+    }
+    
+    /// Checks whether the last positions form a 2- or 3-cycle.
+    /// 
+    /// A robot standing still is not oscillating: the positions of a cycle
+    /// must all differ from each other.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::robot::Robot;
+    /// # use ereea::types::RobotType;
+    /// let mut robot = Robot::new(5, 5, RobotType::MineralCollector);
+    /// robot.recent_positions = [(5, 5), (6, 5), (5, 5), (6, 5)].into();
+    /// assert!(robot.is_oscillating());
+    /// 
+    /// robot.recent_positions = [(5, 5), (6, 5), (6, 6), (5, 5), (6, 5), (6, 6)].into();
+    /// assert!(robot.is_oscillating());
+    /// 
+    /// robot.recent_positions = [(5, 5); 6].into();
+    /// assert!(!robot.is_oscillating());
+    /// 
+    /// robot.recent_positions = [(5, 5), (6, 5), (7, 5), (6, 5)].into();
+    /// assert!(!robot.is_oscillating());
+    /// ```
+    pub fn is_oscillating(&self) -> bool {
+        let history: Vec<_> = self.recent_positions.iter().collect();
+        
+        [2, 3].into_iter().any(|period| {
+            if history.len() < period * 2 {
+                return false;
+            }
+            let window = &history[history.len() - period * 2..];
+            let repeats = (0..period).all(|i| window[i] == window[i + period]);
+            let distinct = (0..period).all(|i| (i + 1..period).all(|j| window[i] != window[j]));
+            repeats && distinct
+        })
+    }
+    
+    // NOTE - True if the target was recently abandoned after an oscillation
+    fn is_avoided(&self, pos: (usize, usize)) -> bool {
+        self.avoided_targets.iter().any(|&(target, _)| target == pos)
+    }
+    
+    // NOTE - Drop the current target for a while so the next decision picks another one
+    fn break_oscillation(&mut self, station: &mut Station) {
+        let target = match self.mode {
+            RobotMode::Collecting => self.current_target
+                .or_else(|| self.find_nearest_resource(station)),
+            RobotMode::Exploring => station.exploration_target_of(self.id),
+            _ => None,
+        };
+        
+        self.notice(format!("🔁 Robot #{} oscille autour de ({}, {}) : abandon de sa cible actuelle", self.id, self.x, self.y));
+        self.stuck_events += 1;
+        if let Some(target) = target
+            && target != (self.home_station_x, self.home_station_y) {
+            self.avoided_targets.push((target, AVOID_TARGET_TICKS));
+        }
+        
+        station.release_exploration_target(self.id);
+        self.invalidate_path();
+        self.recent_positions.clear();
+    }
+    
+    /// Consecutive updates the robot has stood still while having a goal.
+    /// 
+    /// Any mode but `Idle`, `Stranded` and `Broken` counts as having a goal.
+    /// After a few such updates (a target walled off, a resource out of
+    /// reach of every path...) the robot gives its target up: it is ignored
+    /// for a while and the robot re-plans with another one, or goes `Idle` at
+    /// the station if nothing else is left.
+    /// 
+    /// # Examples
+    /// 
+    /// The only mineral of the map is enclosed by obstacles: the collector
+    /// does not hang trying to reach it, it gives up and rests at the station.
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// for y in 2..=4 {
+    ///     for x in 2..=4 {
+    ///         if (x, y) != (3, 3) {
+    ///             map.tiles[y][x] = TileType::Obstacle;
+    ///         }
+    ///     }
+    /// }
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// station.reveal_map(&map);
+    /// 
+    /// let (station_x, station_y) = (map.station_x, map.station_y);
+    /// let mut robot = Robot::new_with_memory(station_x, station_y, RobotType::MineralCollector, 1,
+    ///                                        station_x, station_y, station.global_memory.clone());
+    /// let mut occupancy = OccupancyGrid::new(station_x, station_y);
+    /// let mut most_stuck = 0;
+    /// for _ in 0..60 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     most_stuck = most_stuck.max(robot.stuck_ticks());
+    /// }
+    /// 
+    /// assert!(most_stuck > 0, "the robot never noticed it was stuck");
+    /// assert_eq!(robot.mode, RobotMode::Idle);
+    /// assert_eq!((robot.x, robot.y), (station_x, station_y));
+    /// assert_eq!(robot.stuck_ticks(), 0);
+    /// assert!(robot.avoided_targets.iter().any(|&(target, _)| target == (3, 3)));
+    /// assert_eq!(map.get_tile(3, 3), TileType::Mineral);
+    /// ```
+    pub fn stuck_ticks(&self) -> u32 {
+        self.stuck_ticks
+    }
+    
+    /// Number of times the robot gave up a target since its deployment.
+    /// 
+    /// Counts every recovery: standing still too long (`stuck_ticks`),
+    /// bouncing between tiles (`is_oscillating`) or walking for a while
+    /// without getting any closer to its target. The abandoned target is
+    /// ignored for a while and the robot re-plans.
+    /// 
+    /// # Examples
+    /// 
+    /// A mineral gets walled in while the collector is on its way: the robot
+    /// notices, gives it up and collects the other one instead.
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(16, 10, TileType::Mineral);
+    /// map.set_tile(10, 3, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// station.reveal_map(&map);
+    /// 
+    /// let mut robot = Robot::new_with_memory(10, 10, RobotType::MineralCollector, 1, 10, 10, station.global_memory.clone());
+    /// let mut occupancy = OccupancyGrid::new(10, 10);
+    /// for _ in 0..2 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!(robot.current_target, Some((16, 10)));
+    /// assert_eq!(robot.stuck_events(), 0);
+    /// 
+    /// // Rocks fall all around the mineral
+    /// for y in 9..=11 {
+    ///     for x in 15..=17 {
+    ///         if (x, y) != (16, 10) {
+    ///             map.set_tile(x, y, TileType::Obstacle);
+    ///         }
+    ///     }
+    /// }
+    /// 
+    /// let mut ticks = 0;
+    /// while robot.minerals() == 0 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     ticks += 1;
+    ///     assert!(ticks <= 40, "still looping at ({}, {})", robot.x, robot.y);
+    /// }
+    /// assert!(robot.stuck_events() >= 1);
+    /// assert_eq!(map.get_tile(10, 3), TileType::Empty);
+    /// assert_eq!(map.get_tile(16, 10), TileType::Mineral);
+    /// ```
+    pub fn stuck_events(&self) -> u32 {
+        self.stuck_events
+    }
+    
+    // NOTE - Count updates started on the same tile as the previous one while having a goal
+    fn update_stuck_count(&mut self) {
+        // NOTE - A robot retrying a walled-in resource from the station ends its update Idle, but still had a goal
+        let has_goal = !matches!(self.mode, RobotMode::Idle | RobotMode::Stranded | RobotMode::Broken | RobotMode::Charging | RobotMode::Scanning)
+            || std::mem::take(&mut self.goal_unreachable);
+        let stood_still = self.recent_positions.back() == Some(&(self.x, self.y));
+        // NOTE - A slow robot saving up for its next step is not stuck, nor getting going again
+        if std::mem::take(&mut self.short_of_step) && stood_still {
+            return;
+        }
+        if has_goal && stood_still {
+            self.stuck_ticks += 1;
+        } else {
+            self.stuck_ticks = 0;
+        }
+    }
+    
+    // NOTE - Ignore the target the robot cannot reach for a while and let the next decision re-plan
+    fn give_up_unreachable_target(&mut self, station: &mut Station) {
+        let target = self.current_target
+            .or_else(|| station.exploration_target_of(self.id))
+            .or_else(|| self.find_nearest_resource(station));
+        
+        self.events.push(RobotEvent::Stuck { robot_id: self.id, x: self.x, y: self.y, ticks: self.stuck_ticks });
+        self.stuck_events += 1;
+        if let Some(target) = target
+            && target != (self.home_station_x, self.home_station_y) {
+            self.avoided_targets.push((target, UNREACHABLE_TARGET_TICKS));
+        }
+        
+        // NOTE - Not on its way home: the robot was carrying out its current task
+        if self.current_target != Some((self.home_station_x, self.home_station_y))
+            && let Some(task) = self.task_queue.pop_front() {
+            self.notice(format!("📋 Robot #{} : tâche « {} » abandonnée", self.id, task));
+        }
+        
+        station.release_exploration_target(self.id);
+        station.release_target(self.id);
+        self.invalidate_path();
+        self.stuck_ticks = 0;
+        self.target_progress = None;
+    }
+    
+    // NOTE - Track the remaining distance to the current target, returns the updates since it last shrank
+    fn update_target_progress(&mut self) -> u32 {
+        let following = matches!(self.mode, RobotMode::Exploring | RobotMode::Collecting | RobotMode::ReturnToStation | RobotMode::Rescuing);
+        let Some(target) = self.current_target.filter(|_| following) else {
+            self.target_progress = None;
+            return 0;
+        };
+        
+        // NOTE - Steps left on the planned path, straight-line distance when there is none
+        let distance = if self.current_path.is_empty() {
+            self.position().chebyshev_distance(target.into())
+        } else {
+            self.current_path.len()
+        };
+        
+        match &mut self.target_progress {
+            Some((tracked, best, ticks)) if *tracked == target && distance > 0 => {
+                if distance < *best {
+                    (*best, *ticks) = (distance, 0);
+                } else {
+                    *ticks += 1;
+                }
+                *ticks
+            },
+            // NOTE - New target, or standing on it (harvesting a rich deposit is progress)
+            progress => {
+                *progress = Some((target, distance, 0));
+                0
+            },
+        }
+    }
+    
+    /// Adds a task at the end of the robot's queue; returns `false` if the
+    /// robot cannot carry it out.
+    /// 
+    /// Queued tasks run one after the other, before the robot's own
+    /// decisions: a `MoveTo` or `ReturnHome` ends on arrival, a `CollectAt`
+    /// once the deposit is empty (or turns out not to be one the robot
+    /// collects), a `Survey` once every tile of the area is explored. A task
+    /// the robot cannot reach is dropped. Low energy or a full hold still send
+    /// the robot home first; the task resumes once it has recharged. Repairers
+    /// and relays keep their own duties and refuse every task, robots carrying
+    /// nothing refuse collections.
+    /// 
+    /// # Examples
+    /// 
+    /// Three tasks for an explorer short on energy: it recharges on the way
+    /// and still completes them in order.
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotTask, RobotType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (0, 0);
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(0, 0);
+    /// 
+    /// let mut robot = Robot::new_with_memory(5, 5, RobotType::Explorer, 1, 0, 0, station.global_memory.clone());
+    /// robot.energy = 10.0;
+    /// let tasks = [
+    ///     RobotTask::MoveTo(19, 19),
+    ///     RobotTask::Survey { center: (19, 0), radius: 2 },
+    ///     RobotTask::ReturnHome,
+    /// ];
+    /// for task in tasks {
+    ///     assert!(robot.assign_task(task));
+    /// }
+    /// assert!(!Robot::new(0, 0, RobotType::Relay).assign_task(RobotTask::ReturnHome));
+    /// 
+    /// let mut completed = Vec::new();
+    /// let mut recharged_mid_task = false;
+    /// for _ in 0..300 {
+    ///     let before = robot.current_task();
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     if robot.current_task() != before {
+    ///         completed.push(before.unwrap());
+    ///     }
+    ///     recharged_mid_task |= robot.mode == RobotMode::Charging && robot.current_task().is_some();
+    ///     if robot.current_task().is_none() {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(completed, tasks);
+    /// assert!(recharged_mid_task);
+    /// assert_eq!((robot.x, robot.y), (0, 0));
+    /// assert!((17..MAP_SIZE).all(|x| (0..=2).all(|y| robot.memory[(x, y)].explored)));
+    /// ```
+    pub fn assign_task(&mut self, task: RobotTask) -> bool {
+        let refused = matches!(self.robot_type, RobotType::Repairer | RobotType::Relay)
+            || matches!(task, RobotTask::CollectAt(..)) && self.cargo_capacity == 0;
+        if refused {
+            self.notice(format!("📋 Robot #{} : tâche « {} » refusée", self.id, task));
+            return false;
+        }
+        
+        self.task_queue.push_back(task);
+        true
+    }
+    
+    // NOTE - Task the robot is working on (front of the queue), if any
+    pub fn current_task(&self) -> Option<RobotTask> {
+        self.task_queue.front().copied()
+    }
+    
+    // NOTE - Assigned tasks not completed yet, current one first
+    pub fn task_queue(&self) -> &VecDeque<RobotTask> {
+        &self.task_queue
+    }
+    
+    // NOTE - Work on the current task for this update; it is removed once done or out of reach
+    fn run_task(&mut self, map: &mut Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
+        let Some(task) = self.current_task() else {
+            return;
+        };
+        
+        let here = (self.x, self.y);
+        let goal = match task {
+            RobotTask::MoveTo(x, y) => Some((x, y)).filter(|&goal| goal != here),
+            RobotTask::ReturnHome => Some((self.home_station_x, self.home_station_y)).filter(|&goal| goal != here),
+            RobotTask::CollectAt(x, y) if here == (x, y) => {
+                // Récolter une unité par cycle tant que le gisement n'est pas vide
+                if self.harvest(map, station) && self.collects(&map.get_tile(x, y)) {
+                    self.set_mode(RobotMode::Collecting, TransitionReason::TaskAssigned);
+                    return;
+                }
+                None
+            },
+            RobotTask::CollectAt(x, y) => Some((x, y)),
+            RobotTask::Survey { center, radius } => self.survey_target(map, center, radius),
+        };
+        
+        let Some(goal) = goal else {
+            self.finish_task();
+            return;
+        };
+        
+        self.set_mode(match task {
+            RobotTask::CollectAt(..) => RobotMode::Collecting,
+            RobotTask::ReturnHome => RobotMode::ReturnToStation,
+            RobotTask::MoveTo(..) | RobotTask::Survey { .. } => RobotMode::Exploring,
+        }, TransitionReason::TaskAssigned);
+        self.plan_path(map, goal);
+        if self.current_path.is_empty() {
+            self.notice(format!("📋 Robot #{} : tâche « {} » abandonnée, ({}, {}) est inaccessible", self.id, task, goal.0, goal.1));
+            self.task_queue.pop_front();
+            return;
+        }
+        self.step_along_path(map, occupancy);
+        
+        // NOTE - Moves end on arrival; a collection starts at the next update, a survey looks around first
+        if (self.x, self.y) == goal && matches!(task, RobotTask::MoveTo(..) | RobotTask::ReturnHome) {
+            self.finish_task();
+        }
+    }
+    
+    // NOTE - Nearest reachable tile of the survey area still unexplored in memory
+    fn survey_target(&self, map: &Map, center: (usize, usize), radius: usize) -> Option<(usize, usize)> {
+        let in_area = |(x, y): (usize, usize)| x.abs_diff(center.0) <= radius && y.abs_diff(center.1) <= radius;
+        
+        // Garder la case visée tant qu'elle reste inconnue
+        if let Some(target) = self.current_target
+            && in_area(target) && !self.memory[(target.0, target.1)].explored {
+            return Some(target);
+        }
+        
+        let mut unknown: Vec<(usize, usize)> = (center.1.saturating_sub(radius)..=(center.1 + radius).min(MAP_SIZE - 1))
+            .flat_map(|y| (center.0.saturating_sub(radius)..=(center.0 + radius).min(MAP_SIZE - 1)).map(move |x| (x, y)))
+            .filter(|&(x, y)| !self.memory[(x, y)].explored)
+            .collect();
+        unknown.sort_by_key(|&pos| self.position().chebyshev_distance(pos.into()));
+        unknown.into_iter().find(|&pos| !self.find_path(map, pos).is_empty())
+    }
+    
+    // NOTE - Drop the completed task; the robot makes its own decisions again until the next one
+    fn finish_task(&mut self) {
+        if let Some(task) = self.task_queue.pop_front() {
+            self.notice(format!("✅ Robot #{} : tâche « {} » terminée", self.id, task));
+        }
+        self.invalidate_path();
+        if self.x == self.home_station_x && self.y == self.home_station_y {
+            self.set_mode(RobotMode::Idle, TransitionReason::TaskDone);
+        } else if self.task_queue.is_empty() {
+            self.set_mode(RobotMode::Exploring, TransitionReason::TaskDone);
+        }
+    }
+    
+    // NOTE - Asks the brain for the next exploration step and carries it out
+    fn follow_brain(&mut self, map: &mut Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
+        let ctx = RobotContext {
+            robot_type: self.robot_type,
+            position: (self.x, self.y),
+            home: (self.home_station_x, self.home_station_y),
+            energy: self.energy,
+            max_energy: self.max_energy,
+            vision_range: self.vision_range(),
+            memory: &self.memory,
+            frontier: &self.frontier,
+            avoided_targets: &self.avoided_targets,
+            station: StationSummary {
+                current_time: station.current_time,
+                exploration_percentage: station.get_exploration_percentage(),
+                energy_reserves: station.energy_reserves,
+            },
+        };
+        
+        match self.brain.decide(ctx) {
+            RobotAction::Explore => self.explore_move(map, station, occupancy),
+            RobotAction::Move(target) => {
+                if (self.x, self.y) == target {
+                    return;
+                }
+                self.plan_path(map, target);
+                if self.current_path.is_empty() {
+                    // Cible inaccessible : l'écarter un moment, le cerveau en choisira une autre
+                    self.invalidate_path();
+                    self.avoided_targets.push((target, UNREACHABLE_TARGET_TICKS));
+                } else {
+                    self.step_along_path(map, occupancy);
+                }
+            },
+            RobotAction::Collect => {
+                if self.collects(&map.get_tile(self.x, self.y)) {
+                    self.collect_resources(map, station, occupancy);
+                }
+            },
+            RobotAction::ReturnHome => {
+                if (self.x, self.y) != (self.home_station_x, self.home_station_y) {
+                    self.set_mode(RobotMode::ReturnToStation, TransitionReason::BrainDecision);
+                    self.plan_path_to_station(map);
+                    self.step_along_path(map, occupancy);
+                }
+            },
+            RobotAction::Wait => {},
+        }
+    }
+    
     // NOTE - Smart exploration movement (improved version)
-    fn explore_move(&mut self, map: &Map) {
+    fn explore_move(&mut self, map: &Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
         // Pour l'explorateur, utiliser une stratégie plus agressive de recherche de cases non explorées
         if self.robot_type == RobotType::Explorer {
-            self.explorer_specific_move(map);
+            self.explorer_specific_move(map, station, occupancy);
         } else {
             // Logique normale pour les autres types de robots
-            self.standard_explore_move(map);
+            self.standard_explore_move(map, occupancy);
         }
     }
     
     // NOTE - Explorer-specific movement logic
-    fn explorer_specific_move(&mut self, map: &Map) {
-        // Chercher les cases non explorées sur TOUTE la carte (pas juste à proximité)
+    fn explorer_specific_move(&mut self, map: &Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
+        // Libérer la cible réservée une fois atteinte ou sortie de la frontière
+        if let Some(target) = station.exploration_target_of(self.id)
+            && ((self.x, self.y) == target || !self.frontier.contains(&target)) {
+            station.release_exploration_target(self.id);
+            
+            // Arrivé sur la frontière avec de l'énergie à revendre : balayer la zone inconnue alentour
+            if self.energy >= self.max_energy / 2.0
+                && self.unknown_tiles_within(self.vision_range() + SCAN_RANGE_BONUS) >= SCAN_MIN_UNKNOWN_TILES {
+                let revealed = self.scan(map, station);
+                self.notice(format!("🔭 Robot #{} balaye les environs de ({}, {}) : {} cases découvertes", self.id, self.x, self.y, revealed));
+                self.set_mode(RobotMode::Scanning, TransitionReason::Scan);
+                return;
+            }
+        }
+        
+        // Poursuivre vers la cible déjà réservée, en réutilisant le chemin planifié
+        if let Some(target) = station.exploration_target_of(self.id) {
+            self.plan_path(map, target);
+            if !self.current_path.is_empty() {
+                self.step_along_path(map, occupancy);
+                return;
+            }
+            station.release_exploration_target(self.id);
+        }
+        
+        // Secteur entièrement cartographié : rapporter la carte à la station, qui en attribuera un autre
+        if station.sector_planning {
+            let exhausted = self.assigned_sector.is_some_and(|sector| !self.sector_has_unexplored(sector));
+            if exhausted && (self.x, self.y) != (self.home_station_x, self.home_station_y) {
+                self.assigned_sector = None;
+                station.release_sector(self.id);
+                self.set_mode(RobotMode::ReturnToStation, TransitionReason::SectorMapped);
+                self.plan_path_to_station(map);
+                self.step_along_path(map, occupancy);
+                return;
+            }
+            if exhausted || self.assigned_sector.is_none() {
+                self.assigned_sector = station.assign_sector(self);
+                if let Some((column, row)) = self.assigned_sector {
+                    self.notice(format!("🗺️ Robot #{} prend en charge le secteur ({}, {})", self.id, column, row));
+                }
+            }
+        } else if self.assigned_sector.take().is_some() {
+            station.release_sector(self.id);
+        }
+        
+        // Chercher les cibles sur la frontière : cases connues et accessibles bordant des "?"
         let mut unexplored_tiles = Vec::new();
+        let mut claimed_tiles = Vec::new();
         
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                // Si la case n'est pas explorée (case "?")
-                if !self.memory[y][x].explored {
-                    let distance = self.heuristic((self.x, self.y), (x, y));
+        for &(x, y) in &self.frontier {
+            // Ignorer les obstacles et les cibles abandonnées récemment
+            if map.is_valid_position(x, y) && !self.is_avoided((x, y)) {
+                let distance = self.position().chebyshev_distance((x, y).into());
+                // Éviter les zones déjà réservées par un autre explorateur
+                if station.is_claimed_by_other(self.id, (x, y)) {
+                    claimed_tiles.push((x, y, distance));
+                } else {
                     unexplored_tiles.push((x, y, distance));
                 }
             }
         }
         
+        // Si tout ce qui reste est réservé, partager plutôt que rester sans cible
+        if unexplored_tiles.is_empty() {
+            unexplored_tiles = claimed_tiles;
+        }
+        
         // Si des cases non explorées sont trouvées
         if !unexplored_tiles.is_empty() {
-            // Trier par distance pour aller vers la plus proche
-            unexplored_tiles.sort_by_key(|&(_, _, dist)| dist);
+            // Trier par distance, allongée pour les zones parcourues récemment, pour aller vers la plus
+            // proche (à distance égale, par position, l'ordre de la frontière n'étant pas reproductible)
+            unexplored_tiles.sort_by_key(|&(x, y, dist)| (dist + self.revisit_cost(x, y), y, x));
+            
+            // Avec un secteur attribué, s'en tenir à sa frontière ou, à défaut, s'en rapprocher
+            if let Some(sector) = self.assigned_sector {
+                let in_sector: Vec<_> = unexplored_tiles.iter()
+                    .copied()
+                    .filter(|&(x, y, _)| sector_distance(sector, (x, y)) <= 1)
+                    .collect();
+                if in_sector.is_empty() {
+                    unexplored_tiles.sort_by_key(|&(x, y, _)| sector_distance(sector, (x, y)));
+                } else {
+                    unexplored_tiles = in_sector;
+                }
+            }
             
-            // Prendre les 3 plus proches et choisir aléatoirement parmi elles
-            // (pour éviter que tous les explorateurs aillent au même endroit)
-            let candidates = unexplored_tiles.iter().take(3).collect::<Vec<_>>();
-            let mut rng = rand::thread_rng();
-            let target_idx = rng.gen_range(0..candidates.len());
+            // Parmi les plus proches, tirer au sort en favorisant les grandes zones inconnues
+            let candidates = unexplored_tiles.iter().take(FRONTIER_CANDIDATES).collect::<Vec<_>>();
+            let weights: Vec<usize> = candidates.iter()
+                .map(|&&(x, y, _)| self.frontier_cluster_size(x, y).max(1))
+                .collect();
+            let target_idx = WeightedIndex::new(&weights)
+                .map(|distribution| distribution.sample(&mut self.rng))
+                .unwrap_or(0);
             let target = (candidates[target_idx].0, candidates[target_idx].1);
             
-            // Utiliser A* pour trouver le chemin optimal vers la case "?"
+            // Utiliser A* pour trouver le chemin optimal vers la case de frontière
             let path = self.find_path(map, target);
             
             if !path.is_empty() {
-                let next = path[0];
-                self.move_to(next.0, next.1);
+                // Réserver la cible auprès de la station pour écarter les autres explorateurs
+                station.reserve_exploration_target(self.id, target);
+                self.current_target = Some(target);
+                self.current_path = path;
+                self.step_along_path(map, occupancy);
                 return;
             }
         }
         
         // Si aucune case non explorée ou impossible d'y aller, mouvement aléatoire intelligent
-        self.intelligent_random_move(map);
+        self.intelligent_random_move(map, occupancy);
+    }
+    
+    // NOTE - Whether the sector still holds tiles this robot can hope to explore
+    fn sector_has_unexplored(&self, sector: (usize, usize)) -> bool {
+        self.reachable_unknown_tiles().into_iter().any(|tile| sector_distance(sector, tile) == 0)
     }
     
     // NOTE - Intelligent random move for explorer
-    fn intelligent_random_move(&mut self, map: &Map) {
+    fn intelligent_random_move(&mut self, map: &Map, occupancy: &mut OccupancyGrid) {
         let mut possible_moves = Vec::new();
         
         for dy in -1..=1 {
@@ -493,18 +2787,21 @@ impl Robot {
                 let ny = self.y as isize + dy;
                 
                 if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize 
-                   && map.is_valid_position(nx as usize, ny as usize) {
+                   && map.can_step((self.x, self.y), (nx as usize, ny as usize))
+                   && !occupancy.is_occupied(nx as usize, ny as usize) {
                     
                     let new_pos = (nx as usize, ny as usize);
                     
                     // Priorité : cases non visitées récemment ou jamais visitées
-                    let priority = if !self.memory[new_pos.1][new_pos.0].explored {
+                    let priority = if !self.memory[(new_pos.0, new_pos.1)].explored {
                         100 // Très haute priorité pour les cases "?"
                     } else {
                         // Priorité inversement proportionnelle au timestamp (cases anciennes = priorité plus haute)
-                        let age = self.last_sync_time.saturating_sub(self.memory[new_pos.1][new_pos.0].timestamp);
+                        let age = self.last_sync_time.saturating_sub(self.memory[(new_pos.0, new_pos.1)].timestamp);
                         age.min(50) // Limiter la priorité
                     };
+                    // Les cases où le robot est passé récemment perdent de leur intérêt
+                    let priority = priority as i64 - self.revisit_cost(new_pos.0, new_pos.1) as i64;
                     
                     possible_moves.push((new_pos.0, new_pos.1, priority));
                 }
@@ -516,7 +2813,7 @@ impl Robot {
             possible_moves.sort_by_key(|&(_, _, priority)| std::cmp::Reverse(priority));
             
             // Prendre une des 3 meilleures options avec une probabilité décroissante
-            let mut rng = rand::thread_rng();
+            let rng = &mut self.rng;
             let choice = if rng.gen_bool(0.6) && !possible_moves.is_empty() {
                 0 // 60% de chance de prendre la meilleure option
             } else if rng.gen_bool(0.3) && possible_moves.len() > 1 {
@@ -528,186 +2825,1057 @@ impl Robot {
             };
             
             let (nx, ny, _) = possible_moves[choice];
-            self.move_to(nx, ny);
+            self.move_to(map, occupancy, nx, ny);
         }
     }
     
+    /// Recent visits of a tile, each weighing half as much every
+    /// `VISIT_HALF_LIFE_TICKS` (40) updates.
+    /// 
+    /// Explorers looking for a frontier target or wandering once nothing is
+    /// left to reach (`intelligent_random_move`) prefer tiles with a low
+    /// penalty, scaled by [`revisit_weight`](Self::revisit_weight).
+    pub fn visit_penalty(&self, x: usize, y: usize) -> f32 {
+        let visits = self.visit_count[(x, y)] as f32;
+        if visits == 0.0 {
+            return 0.0;
+        }
+        let elapsed = self.age_ticks.saturating_sub(self.last_visit[(x, y)]) as f32;
+        visits * 0.5_f32.powf(elapsed / VISIT_HALF_LIFE_TICKS)
+    }
+    
+    // NOTE - `visit_penalty` scaled by `revisit_weight`, in priority (or distance) units
+    fn revisit_cost(&self, x: usize, y: usize) -> usize {
+        (self.visit_penalty(x, y) * self.revisit_weight).round() as usize
+    }
+    
     // NOTE - Standard explore move for other robots
-    fn standard_explore_move(&mut self, map: &Map) {
+    fn standard_explore_move(&mut self, map: &Map, occupancy: &mut OccupancyGrid) {
+        // Poursuivre vers la case déjà visée tant qu'elle borde des cases "?"
+        if let Some(target) = self.current_target
+            && self.frontier.contains(&target)
+            && self.next_step_is_valid(map) {
+            self.step_along_path(map, occupancy);
+            return;
+        }
+        
         // Logique originale mais avec une portée réduite pour les non-explorateurs
         let mut unexplored_tiles = Vec::new();
         let vision_range = 3; // Portée réduite pour les collecteurs
         
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                if !self.memory[y][x].explored {
-                    let distance = self.heuristic((self.x, self.y), (x, y));
-                    if distance <= vision_range {
-                        unexplored_tiles.push((x, y, distance));
-                    }
-                }
-            }
+        for &(x, y) in &self.frontier {
+            if map.is_valid_position(x, y) && !self.is_avoided((x, y)) {
+                let distance = self.position().chebyshev_distance((x, y).into());
+                if distance <= vision_range {
+                    unexplored_tiles.push((x, y, distance));
+                }
+            }
+        }
+        
+        if !unexplored_tiles.is_empty() {
+            unexplored_tiles.sort_by_key(|&(x, y, dist)| (dist, y, x));
+            let target = (unexplored_tiles[0].0, unexplored_tiles[0].1);
+            let path = self.find_path(map, target);
+            
+            if !path.is_empty() {
+                self.current_target = Some(target);
+                self.current_path = path;
+                self.step_along_path(map, occupancy);
+                return;
+            }
+        }
+        
+        // Mouvement aléatoire simple pour les collecteurs
+        let mut possible_moves = Vec::new();
+        
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                
+                let nx = self.x as isize + dx;
+                let ny = self.y as isize + dy;
+                
+                if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize 
+                   && map.can_step((self.x, self.y), (nx as usize, ny as usize))
+                   && !occupancy.is_occupied(nx as usize, ny as usize) {
+                    possible_moves.push((nx as usize, ny as usize));
+                }
+            }
+        }
+        
+        if !possible_moves.is_empty() {
+            let (nx, ny) = possible_moves[self.rng.gen_range(0..possible_moves.len())];
+            self.move_to(map, occupancy, nx, ny);
+        }
+    }
+    
+    // NOTE - Find nearest resource recorded in the station's global memory
+    fn find_nearest_known_resource(&self, station: &Station) -> Option<(usize, usize)> {
+        let target_resource = match self.robot_type {
+            RobotType::Explorer => return None,
+            RobotType::EnergyCollector => TileType::Energy,
+            RobotType::MineralCollector => TileType::Mineral,
+            RobotType::ScientificCollector => TileType::Scientific,
+            RobotType::Repairer | RobotType::Relay => return None,
+        };
+        
+        let mut nearest = None;
+        let mut min_distance = usize::MAX;
+        
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                // Vérifier que la case est explorée ET contenait la ressource lors de la dernière observation
+                let tile = &station.global_memory[(x, y)];
+                if tile.explored && tile.tile_type == target_resource {
+                    let distance = self.position().chebyshev_distance((x, y).into());
+                    if distance < min_distance {
+                        min_distance = distance;
+                        nearest = Some((x, y));
+                    }
+                }
+            }
+        }
+        
+        nearest
+    }
+    
+    // NOTE - Collect resources based on robot type
+    fn collect_resources(&mut self, map: &mut Map, station: &mut Station, occupancy: &mut OccupancyGrid) {
+        if !self.harvest(map, station) {
+            // Si pas de ressource à collecter, explorer
+            self.explore_move(map, station, occupancy);
+        }
+        
+        // Analyse en cours : rester sur le site, toujours réservé
+        if self.analysis_progress.is_some() {
+            return;
+        }
+        
+        // La ressource visée est prise (ou entamée : elle reste la plus proche) : libérer sa réservation
+        station.release_target(self.id);
+        
+        // Après avoir collecté, vérifier s'il reste de la place et des ressources
+        if !self.is_cargo_full()
+            && let Some(resource_pos) = self.find_nearest_resource(station) {
+            self.plan_collection_path(map, station, resource_pos);
+        } else {
+            // Si soute pleine ou plus de ressources, retourner à la station
+            self.set_mode(RobotMode::ReturnToStation, if self.is_cargo_full() { TransitionReason::CargoFull } else { TransitionReason::NoTargets });
+            self.plan_path_to_station(map);
+        }
+    }
+    
+    // NOTE - Take one unit of the deposit the robot stands on, if it collects this kind; false if nothing
+    // was taken. Scientific sites are analyzed first: true while the analysis goes on (see `analysis_progress`)
+    fn harvest(&mut self, map: &mut Map, station: &mut Station) -> bool {
+        let tile = map.get_tile(self.x, self.y);
+        
+        if self.robot_type == RobotType::ScientificCollector && tile == TileType::Scientific {
+            let here = (self.x, self.y);
+            let spent = match self.analysis_progress {
+                Some((x, y, spent)) if (x, y) == here => spent + 1,
+                _ => 1,
+            };
+            self.consume_energy(self.config.instrument_cost);
+            if spent < self.config.analysis_duration {
+                self.analysis_progress = Some((here.0, here.1, spent));
+                return true;
+            }
+            self.analysis_progress = None;
+        }
+        
+        match (self.robot_type, &tile) {
+            (RobotType::EnergyCollector, TileType::Energy) => {
+                // Extraite dans la soute pour la station, pas dans la batterie du robot (plus de jour que de nuit)
+                let room = self.cargo_capacity.saturating_sub(self.cargo_load());
+                let extracted = station.energy_yield().min(room);
+                self.load(TileType::Energy, extracted);
+                station.collected_energy += 1;
+                station.harvested_energy += extracted;
+            },
+            (RobotType::MineralCollector, TileType::Mineral) | (RobotType::ScientificCollector, TileType::Scientific) => {
+                self.load(tile.clone(), 1);
+            },
+            _ => return false,
+        }
+        
+        let left = map.consume_resource(self.x, self.y);
+        self.last_pickup = Some((self.x, self.y));
+        self.events.push(RobotEvent::Collected { robot_id: self.id, resource: tile, x: self.x, y: self.y, left });
+        
+        // Gisement entamé ou épuisé : mettre à jour la mémoire
+        self.memory[(self.x, self.y)].tile_type = map.get_tile(self.x, self.y);
+        true
+    }
+    
+    /// What the robot is doing within its mode, for display (e.g. `"Analyse 3/5"`).
+    /// 
+    /// `None` when the mode says it all. A scientific collector on a site
+    /// reports its analysis: the data is granted after
+    /// `RobotConfig::analysis_duration` consecutive updates on the site,
+    /// each drawing `RobotConfig::instrument_cost` on top of the metabolism.
+    /// Leaving the site early (low energy, new orders) starts the analysis over.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(4, 0, TileType::Scientific);
+    /// (map.station_x, map.station_y) = (0, 0);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// let mut occupancy = OccupancyGrid::new(0, 0);
+    /// 
+    /// let mut robot = Robot::new_with_memory(4, 0, RobotType::ScientificCollector, 1, 0, 0, station.global_memory.clone());
+    /// robot.mode = RobotMode::Collecting;
+    /// let duration = robot.config.analysis_duration;
+    /// let energy_rate = robot.config.instrument_cost + robot.config.metabolism;
+    /// 
+    /// // The data is granted once, after `analysis_duration` updates on the site
+    /// for spent in 1..duration {
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     assert_eq!(robot.mode_detail(), Some(format!("Analyse {}/{}", spent, duration)));
+    ///     assert_eq!(robot.scientific_data(), 0);
+    /// }
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!((robot.mode_detail(), robot.scientific_data()), (None, 1));
+    /// assert_eq!(map.get_tile(4, 0), TileType::Empty);
+    /// assert!((robot.total_energy_spent - duration as f32 * energy_rate).abs() < 1e-4);
+    /// 
+    /// // Ordered away mid-analysis, the robot drops it and will start over
+    /// map.set_tile(4, 0, TileType::Scientific);
+    /// robot.mode = RobotMode::Collecting;
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert!(robot.analysis_progress.is_some());
+    /// robot.mode = RobotMode::ReturnToStation;
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!((robot.mode_detail(), robot.analysis_progress), (None, None));
+    /// ```
+    pub fn mode_detail(&self) -> Option<String> {
+        self.analysis_progress
+            .map(|(_, _, spent)| format!("Analyse {}/{}", spent, self.config.analysis_duration))
+    }
+    
+    /// Tile the robot is heading for, for display: the destination of its planned path.
+    /// 
+    /// `None` once the robot stands on it, and while it is idle, charging,
+    /// broken down or stranded: it is not going anywhere then, whatever
+    /// target it kept from before.
+    /// 
+    /// # Examples
+    /// 
+    /// A collector heads for a deposit, empties it, then goes back to park:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (5, 5);
+    /// map.tiles[5][8] = TileType::Mineral;
+    /// map.set_resource_amount(8, 5, 2);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// station.reveal_map(&map);
+    /// let mut robot = Robot::new_with_memory(5, 5, RobotType::MineralCollector, 1, 5, 5, station.global_memory.clone());
+    /// let mut occupancy = OccupancyGrid::new(5, 5);
+    /// 
+    /// station.tick();
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!(robot.current_goal(), Some((8, 5)));
+    /// 
+    /// while (robot.x, robot.y) != (8, 5) {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!(robot.current_goal(), None);
+    /// 
+    /// while robot.mode != RobotMode::Idle {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     if robot.mode == RobotMode::ReturnToStation && (robot.x, robot.y) != (5, 5) {
+    ///         assert_eq!(robot.current_goal(), Some((5, 5)));
+    ///     }
+    ///     assert!(robot.age_ticks < 100);
+    /// }
+    /// assert_eq!(robot.current_goal(), None);
+    /// ```
+    pub fn current_goal(&self) -> Option<(usize, usize)> {
+        match self.mode {
+            RobotMode::Idle | RobotMode::Charging | RobotMode::Broken | RobotMode::Stranded => None,
+            _ => self.current_target.filter(|&target| target != (self.x, self.y)),
+        }
+    }
+    
+    // NOTE - Drop the analysis under way; the site has to be analyzed from the start again
+    fn interrupt_analysis(&mut self) {
+        if let Some((x, y, spent)) = self.analysis_progress.take() {
+            self.notice(format!("🔬 Robot #{} : analyse du site ({}, {}) interrompue après {}/{} cycles",
+                                self.id, x, y, spent, self.config.analysis_duration));
+        }
+    }
+    
+    // NOTE - Energy needed to analyze `tile` on arrival (0 if the robot does not analyze it)
+    fn analysis_energy(&self, tile: &TileType) -> f32 {
+        if self.robot_type == RobotType::ScientificCollector && *tile == TileType::Scientific {
+            self.config.analysis_duration as f32 * (self.config.instrument_cost + self.metabolism_cost())
+        } else {
+            0.0
+        }
+    }
+    
+    // NOTE - Check if robot should return to station
+    fn should_return_to_station(&self, return_cost: Option<f32>) -> bool {
+        // Pour les explorateurs : retourner si exploration terminée OU énergie faible
+        if self.robot_type == RobotType::Explorer && self.is_exploration_complete() {
+            return true;
+        }
+        
+        // Retourner si l'énergie ne couvre plus que le trajet, ou si l'inventaire est plein
+        self.low_on_energy(return_cost) || self.is_cargo_full()
+    }
+    
+    // NOTE - Why `should_return_to_station` sends the robot home
+    fn return_reason(&self) -> TransitionReason {
+        if self.robot_type == RobotType::Explorer && self.is_exploration_complete() {
+            TransitionReason::ExplorationComplete
+        } else if self.is_cargo_full() {
+            TransitionReason::CargoFull
+        } else {
+            TransitionReason::LowEnergy
+        }
+    }
+    
+    // NOTE - True once the energy only covers the trip home, with a margin of a few steps
+    fn low_on_energy(&self, return_cost: Option<f32>) -> bool {
+        return_cost.is_some_and(|needed| {
+            let margin = RETURN_MARGIN_STEPS * (self.step_energy_cost() + self.metabolism_cost());
+            needed > 0.0 && self.energy < needed + margin
+        })
+    }
+    
+    /// Energy needed to walk back to the home station from the current position.
+    /// 
+    /// Counts the movement cost and the metabolism of every remaining step,
+    /// measured at the start of an update (before that tick's consumption).
+    /// Returns `None` when no path to the station exists.
+    /// 
+    /// # Examples
+    /// 
+    /// A robot 10 tiles away with exactly the energy it needs makes it home,
+    /// one with less is stranded and waits for a rescue:
+    /// 
+    /// ```rust
+    /// use ereea::map::Map;
+    /// use ereea::occupancy::OccupancyGrid;
+    /// use ereea::robot::Robot;
+    /// use ereea::station::Station;
+    /// use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+    /// 
+    /// let mut map = Map::open();
+    /// map.station_x = 10;
+    /// map.station_y = 0;
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// 
+    /// let far_robot = |energy: f32| {
+    ///     let mut robot = Robot::new(0, 0, RobotType::Explorer);
+    ///     robot.home_station_x = 10;
+    ///     robot.energy = energy;
+    ///     robot
+    /// };
+    /// 
+    /// let needed = far_robot(0.0).energy_to_return_home(&map).unwrap();
+    /// assert!((needed - 10.0 * (0.3 + 0.1)).abs() < 1e-4);
+    /// 
+    /// // Exactly enough energy: heads home right away and arrives
+    /// let mut robot = far_robot(needed);
+    /// for _ in 0..10 {
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     assert_ne!(robot.mode, RobotMode::Stranded);
+    /// }
+    /// assert_eq!((robot.x, robot.y), (10, 0));
+    /// assert!(robot.energy >= 0.0);
+    /// 
+    /// // Too little energy: stranded where it stands, energy never negative
+    /// let mut robot = far_robot(needed - 0.5);
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!(robot.mode, RobotMode::Stranded);
+    /// assert_eq!((robot.x, robot.y), (0, 0));
+    /// ```
+    /// 
+    /// The decision to head home flips exactly when the energy drops below this
+    /// cost plus a margin of two steps, whatever the distance and step cost:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType, Upgrade};
+    /// let mut map = Map::open();
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// 
+    /// // Explorer steps cost 0.3, or 0.24 with an efficiency module
+    /// for (modules, step_cost) in [(vec![], 0.3), (vec![Upgrade::EfficiencyModule], 0.24)] {
+    ///     for distance in [2, 5, 9] {
+    ///         let margin = 2.0 * (step_cost + 0.1);
+    ///         let mut decide = |energy: f32| {
+    ///             let mut robot = Robot::new(10 - distance, 10, RobotType::Explorer);
+    ///             modules.iter().for_each(|&module| robot.install_upgrade(module));
+    ///             (robot.home_station_x, robot.home_station_y) = (10, 10);
+    ///             robot.energy = energy;
+    ///             let needed = robot.energy_to_return_home(&map).unwrap();
+    ///             assert!((needed - distance as f32 * (step_cost + 0.1)).abs() < 1e-4);
+    ///             robot.update(&mut map, &mut station, &mut occupancy);
+    ///             robot.mode == RobotMode::ReturnToStation
+    ///         };
+    ///         let boundary = distance as f32 * (step_cost + 0.1) + margin;
+    ///         assert!(decide(boundary - 0.05), "{:?} à {} cases", modules, distance);
+    ///         assert!(!decide(boundary + 0.05), "{:?} à {} cases", modules, distance);
+    ///     }
+    /// }
+    /// ```
+    pub fn energy_to_return_home(&self, map: &Map) -> Option<f32> {
+        if self.x == self.home_station_x && self.y == self.home_station_y {
+            return Some(0.0);
+        }
+        
+        // Le chemin déjà planifié vers la station évite une nouvelle recherche A*
+        let home = (self.home_station_x, self.home_station_y);
+        if self.current_target == Some(home) && self.next_step_is_valid(map) {
+            return Some(self.path_energy_cost(map, &self.current_path));
         }
         
-        if !unexplored_tiles.is_empty() {
-            unexplored_tiles.sort_by_key(|&(_, _, dist)| dist);
-            let target = (unexplored_tiles[0].0, unexplored_tiles[0].1);
-            let path = self.find_path(map, target);
-            
-            if !path.is_empty() {
-                let next = path[0];
-                self.move_to(next.0, next.1);
-                return;
+        // Le chemin du retour déjà calculé sert tant que la carte n'a pas changé et que le robot le suit
+        let mut route = self.home_route.borrow_mut();
+        if let Some(cached) = route.as_mut()
+            && cached.shortest && cached.home == home && cached.revision == map.revision() {
+            if let Some(walked) = cached.steps.iter().position(|&step| step == (self.x, self.y)) {
+                cached.steps.drain(..=walked);
+                cached.from = (self.x, self.y);
+            }
+            if cached.from == (self.x, self.y) {
+                return Some(self.path_energy_cost(map, &cached.steps));
             }
         }
         
-        // Mouvement aléatoire simple pour les collecteurs
-        let mut rng = rand::thread_rng();
-        let mut possible_moves = Vec::new();
+        let path = self.find_path(map, home);
+        if path.is_empty() {
+            *route = None;
+            return None;
+        }
         
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-                
-                let nx = self.x as isize + dx;
-                let ny = self.y as isize + dy;
-                
-                if nx >= 0 && nx < MAP_SIZE as isize && ny >= 0 && ny < MAP_SIZE as isize 
-                   && map.is_valid_position(nx as usize, ny as usize) {
-                    possible_moves.push((nx as usize, ny as usize));
-                }
-            }
+        let cost = self.path_energy_cost(map, &path);
+        *route = Some(HomeRoute { home, revision: map.revision(), from: (self.x, self.y), steps: path, shortest: true });
+        Some(cost)
+    }
+    
+    // NOTE - Cost of a way home that is valid but maybe not the shortest, without any A* search: the route
+    // planned by `energy_to_return_home`, extended by the step back when the robot has just moved off it
+    fn home_cost_bound(&self, map: &Map) -> Option<f32> {
+        let mut route = self.home_route.borrow_mut();
+        let cached = route.as_mut()
+            .filter(|cached| cached.home == (self.home_station_x, self.home_station_y) && cached.revision == map.revision())?;
+        let here = (self.x, self.y);
+        if cached.from == here || cached.steps.contains(&here)
+            || self.position().chebyshev_distance(cached.from.into()) != 1 || !map.can_step(here, cached.from) {
+            return None;
         }
         
-        if !possible_moves.is_empty() {
-            let (nx, ny) = possible_moves[rng.gen_range(0..possible_moves.len())];
-            self.move_to(nx, ny);
+        cached.steps.push_front(cached.from);
+        cached.from = here;
+        cached.shortest = false;
+        Some(self.route_energy_cost(map, here, &cached.steps))
+    }
+    
+    /// Estimated energy needed to follow `path`, `speed` steps per update.
+    /// 
+    /// Uses the same per-type and per-terrain movement cost as actual moves,
+    /// plus the metabolism consumed at each update spent walking.
+    /// 
+    /// # Examples
+    /// 
+    /// A low-energy collector aborts a far collection target and heads home
+    /// instead of dying en route; with a full battery it goes for it:
+    /// 
+    /// ```rust
+    /// use ereea::map::Map;
+    /// use ereea::occupancy::OccupancyGrid;
+    /// use ereea::robot::Robot;
+    /// use ereea::station::Station;
+    /// use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+    /// use std::collections::VecDeque;
+    /// 
+    /// let mut map = Map::open();
+    /// map.set_tile(19, 19, TileType::Mineral);
+    /// map.station_x = 0;
+    /// map.station_y = 0;
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// station.reveal_map(&map);
+    /// 
+    /// let known = station.global_memory.clone();
+    /// let collector = |energy: f32| {
+    ///     let mut robot = Robot::new(0, 0, RobotType::MineralCollector);
+    ///     robot.memory = known.clone();
+    ///     robot.reindex_memory();
+    ///     robot.x = 5;
+    ///     robot.energy = energy;
+    ///     robot.mode = RobotMode::Collecting;
+    ///     robot
+    /// };
+    /// 
+    /// // 0.5 per step for a mineral collector, plus 0.1 of metabolism
+    /// let path: VecDeque<_> = (6..=8).map(|x| (x, 0)).collect();
+    /// assert!((collector(0.0).path_energy_cost(&map, &path) - 3.0 * 0.6).abs() < 1e-4);
+    /// 
+    /// // 19 steps to the mineral and 19 back cost ~22.8: too much for 10 units
+    /// let mut robot = collector(10.0);
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!(robot.mode, RobotMode::ReturnToStation);
+    /// for _ in 0..5 {
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     assert_eq!(robot.mode, RobotMode::ReturnToStation);
+    /// }
+    /// assert_eq!((robot.x, robot.y), (0, 0));
+    /// 
+    /// let mut robot = collector(100.0);
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!(robot.mode, RobotMode::Collecting);
+    /// assert_eq!(robot.current_path.back(), Some(&(19, 19)));
+    /// ```
+    pub fn path_energy_cost(&self, map: &Map, path: &VecDeque<(usize, usize)>) -> f32 {
+        self.route_energy_cost(map, (self.x, self.y), path)
+    }
+    
+    // NOTE - Energy needed to follow `path` from `start`, terrain costs included
+    fn route_energy_cost(&self, map: &Map, start: (usize, usize), path: &VecDeque<(usize, usize)>) -> f32 {
+        let mut movement = 0.0;
+        let mut previous = start;
+        for &(x, y) in path {
+            let dx = (x as isize - previous.0 as isize).unsigned_abs();
+            let dy = (y as isize - previous.1 as isize).unsigned_abs();
+            movement += self.move_cost(dx.max(dy) as f32, &map.get_tile(x, y));
+            previous = (x, y);
         }
+        
+        movement + (path.len() as f32 / self.speed()).ceil() * self.metabolism_cost()
     }
     
-    // NOTE - Find nearest known resource in explored areas
-    fn find_nearest_known_resource(&self, map: &Map, station: &Station) -> Option<(usize, usize)> {
-        let target_resource = match self.robot_type {
-            RobotType::Explorer => return None,
-            RobotType::EnergyCollector => TileType::Energy,
-            RobotType::MineralCollector => TileType::Mineral,
-            RobotType::ScientificCollector => TileType::Scientific,
-        };
+    // NOTE - Plan a collection trip only if the energy covers the way there, the way back
+    // and the return margin; otherwise head home now. Returns true if the trip was planned.
+    fn plan_collection_path(&mut self, map: &Map, station: &mut Station, resource_pos: (usize, usize)) -> bool {
+        // Une ressource réservée par un autre collecteur n'est pas une cible
+        if station.is_target_reserved_by_other(self.id, resource_pos) {
+            return false;
+        }
         
-        let mut nearest = None;
-        let mut min_distance = usize::MAX;
+        let path = self.find_path(map, resource_pos);
+        let home = (self.home_station_x, self.home_station_y);
+        let way_back = self.find_path_from(map, resource_pos, home);
         
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                // Vérifier que la case est explorée ET contient la ressource recherchée
-                if station.global_memory[y][x].explored && map.get_tile(x, y) == target_resource {
-                    let distance = self.heuristic((self.x, self.y), (x, y));
-                    if distance < min_distance {
-                        min_distance = distance;
-                        nearest = Some((x, y));
-                    }
-                }
-            }
+        let trip_cost = self.path_energy_cost(map, &path)
+            + self.analysis_energy(&self.memory[(resource_pos.0, resource_pos.1)].tile_type)
+            + self.route_energy_cost(map, resource_pos, &way_back)
+            + RETURN_MARGIN_STEPS * (self.step_energy_cost() + self.metabolism_cost());
+        
+        let reachable = !path.is_empty() || (self.x, self.y) == resource_pos;
+        let returnable = !way_back.is_empty() || resource_pos == home;
+        if reachable && returnable && self.energy + ENERGY_EPSILON >= trip_cost {
+            station.reserve_target(self.id, resource_pos.0, resource_pos.1);
+            self.current_target = Some(resource_pos);
+            self.current_path = path;
+            return true;
         }
         
-        nearest
+        self.goal_unreachable = !reachable || !returnable;
+        let (reason, why) = if !reachable {
+            (TransitionReason::Unreachable, GiveUpReason::Unreachable)
+        } else if !returnable {
+            (TransitionReason::Unreachable, GiveUpReason::NoWayBack)
+        } else {
+            (TransitionReason::LowEnergy, GiveUpReason::LowEnergy)
+        };
+        self.events.push(RobotEvent::TargetAbandoned { robot_id: self.id, x: resource_pos.0, y: resource_pos.1, reason: why });
+        // Déjà à quai : attendre sur place plutôt que repasser par le retour à la station
+        if (self.x, self.y) == home {
+            self.set_mode(RobotMode::Idle, reason);
+        } else {
+            self.set_mode(RobotMode::ReturnToStation, reason);
+            self.plan_path_to_station(map);
+        }
+        false
     }
     
-    // NOTE - Collect resources based on robot type
-    fn collect_resources(&mut self, map: &mut Map) {
-        let tile = map.get_tile(self.x, self.y);
+    // NOTE - Make the nearest station the robot's home, dropping a path planned to the previous one
+    fn select_home_station(&mut self, map: &Map) {
+        let home = (self.home_station_x, self.home_station_y);
+        let nearest = map.nearest_station(self.x, self.y);
+        if nearest != home {
+            if self.current_target == Some(home) {
+                self.invalidate_path();
+            }
+            (self.home_station_x, self.home_station_y) = nearest;
+        }
+    }
+    
+    // NOTE - Draw energy from the station through a charging bay. Returns true once the robot
+    // may leave: full, or refused by the station; otherwise it stays docked in Charging mode
+    fn charge_at_station(&mut self, station: &mut Station) -> bool {
+        // Les bornes ne délivrent que des unités entières
+        if self.max_energy - self.energy < 1.0 {
+            station.release_charging_bay(self.id);
+            return true;
+        }
         
-        match (self.robot_type, tile) {
-            (RobotType::EnergyCollector, TileType::Energy) => {
-                if self.energy < self.max_energy {
-                    self.energy += 10.0;
-                    if self.energy > self.max_energy {
-                        self.energy = self.max_energy;
-                    }
-                    map.consume_resource(self.x, self.y);
-                    println!("🔋 Robot #{} a collecté de l'énergie à ({}, {})", self.id, self.x, self.y);
-                }
+        match station.request_charge(self.id, self.max_energy - self.energy) {
+            // Réserves de la station au plancher : repartir avec l'énergie disponible
+            Some(0) => {
+                station.release_charging_bay(self.id);
+                true
             },
-            (RobotType::MineralCollector, TileType::Mineral) => {
-                self.minerals += 1;
-                map.consume_resource(self.x, self.y);
-                println!("⛏️ Robot #{} a collecté un minerai à ({}, {})", self.id, self.x, self.y);
+            Some(amount) => {
+                self.energy += amount as f32;
+                if self.max_energy - self.energy < 1.0 {
+                    station.release_charging_bay(self.id);
+                    return true;
+                }
+                if self.mode != RobotMode::Charging {
+                    self.notice(format!("🔌 Robot #{} en charge à la station ({:.0}/{:.0})", self.id, self.energy, self.max_energy));
+                }
+                self.set_mode(RobotMode::Charging, TransitionReason::Recharging);
+                false
             },
-            (RobotType::ScientificCollector, TileType::Scientific) => {
-                self.scientific_data += 1;
-                map.consume_resource(self.x, self.y);
-                println!("🧪 Robot #{} a collecté des données scientifiques à ({}, {})", self.id, self.x, self.y);
+            None => {
+                if self.mode != RobotMode::Charging {
+                    self.notice(format!("⏳ Robot #{} attend une borne de recharge", self.id));
+                }
+                self.set_mode(RobotMode::Charging, TransitionReason::Recharging);
+                false
             },
-            _ => {
-                // Si pas de ressource à collecter, explorer
-                self.explore_move(map);
-            }
         }
+    }
+    
+    /// Brings a stranded (or broken) robot back to its station, repaired, in a clean state.
+    /// 
+    /// Used by the simulation when no repairer can answer the distress call.
+    /// The repatriation has a price: the robot is left with half its battery,
+    /// whatever it had before. Its path, target and movement history are
+    /// dropped so it resumes from `Idle` as if freshly deployed; its ID,
+    /// memory, cargo, modules and queued tasks are kept. The caller updates
+    /// the occupancy grid and cancels the robot's distress call.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::robot::Robot;
+    /// # use ereea::types::{RobotMode, RobotType};
+    /// let mut robot = Robot::new(0, 0, RobotType::Explorer);
+    /// robot.memory[(3, 3)].explored = true;
+    /// robot.reindex_memory();
+    /// (robot.x, robot.y, robot.energy, robot.mode) = (15, 12, 0.0, RobotMode::Stranded);
+    /// assert!(robot.needs_rescue());
+    /// 
+    /// robot.emergency_recover();
+    /// assert_eq!((robot.x, robot.y, robot.mode), (0, 0, RobotMode::Idle));
+    /// assert_eq!(robot.energy, robot.max_energy / 2.0);
+    /// assert!(!robot.needs_rescue());
+    /// assert!(robot.current_path.is_empty() && robot.recent_positions.is_empty());
+    /// assert_eq!(robot.explored_count(), 1);
+    /// ```
+    pub fn emergency_recover(&mut self) {
+        self.x = self.home_station_x;
+        self.y = self.home_station_y;
+        self.energy = self.max_energy * EMERGENCY_RECOVERY_ENERGY_SHARE;
+        self.malfunction = None;
+        self.set_mode(RobotMode::Idle, TransitionReason::Repatriated);
         
-        // Après avoir collecté, vérifier s'il reste des ressources
-        if let Some(resource_pos) = self.find_nearest_resource(map) {
-            self.path_to_station = self.find_path(map, resource_pos);
-        } else {
-            // Si plus de ressources, retourner à la station
-            self.mode = RobotMode::ReturnToStation;
-            self.plan_path_to_station(map);
+        // NOTE - Nothing from the interrupted trip carries over to the next decision
+        self.invalidate_path();
+        self.recent_positions.clear();
+        self.blocked_ticks = 0;
+        self.stuck_ticks = 0;
+        self.target_progress = None;
+        self.goal_unreachable = false;
+        self.lost = false;
+        self.movement_budget = 0.0;
+        self.short_of_step = false;
+    }
+    
+    // NOTE - Give up going home: no walkable way is left (announced once)
+    fn declare_lost(&mut self) {
+        if !self.lost {
+            self.lost = true;
+            self.events.push(RobotEvent::CutOff { robot_id: self.id, x: self.x, y: self.y });
         }
+        self.invalidate_path();
+        self.set_mode(RobotMode::Idle, TransitionReason::CutOff);
     }
     
-    // NOTE - Check if robot should return to station
-    fn should_return_to_station(&self, map: &Map) -> bool {
-        let _ = map;
-        
-        // Pour les explorateurs : retourner si exploration terminée OU énergie faible
-        if self.robot_type == RobotType::Explorer {
-            if self.is_exploration_complete() {
-                return true;
-            }
+    /// Tells whether the robot found no walkable way back to its home station.
+    /// 
+    /// Set when obstacles seal the robot off from its station, cleared as
+    /// soon as a way opens again. A lost robot waits where it stands instead
+    /// of calling a repairer that could not reach it either, and no longer
+    /// holds up the end of the mission (see `simulation::step_simulation`).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::occupancy::OccupancyGrid;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::station::Station;
+    /// # use ereea::types::{RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// let (station_x, station_y) = (map.station_x, map.station_y);
+    /// // Walls around (2, 2)
+    /// for (x, y) in [(1, 1), (2, 1), (3, 1), (1, 2), (3, 2), (1, 3), (2, 3), (3, 3)] {
+    ///     map.set_tile(x, y, TileType::Obstacle);
+    /// }
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(station_x, station_y);
+    /// let mut robot = Robot::new(station_x, station_y, RobotType::Explorer);
+    /// (robot.x, robot.y, robot.mode) = (2, 2, RobotMode::ReturnToStation);
+    /// 
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert!(robot.is_lost());
+    /// assert_eq!(robot.mode, RobotMode::Idle);
+    /// 
+    /// // Opening the wall lets it head home again
+    /// map.set_tile(3, 2, TileType::Empty);
+    /// robot.mode = RobotMode::ReturnToStation;
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert!(!robot.is_lost());
+    /// assert_eq!((robot.x, robot.y), (3, 2));
+    /// ```
+    pub fn is_lost(&self) -> bool {
+        self.lost
+    }
+    
+    // NOTE - True if the robot waits for a repairer (stranded or severely broken)
+    pub fn needs_rescue(&self) -> bool {
+        self.mode == RobotMode::Stranded || self.malfunction == Some(Malfunction::Severe)
+    }
+    
+    /// Draws a random breakdown for this cycle, following `config`.
+    /// 
+    /// Called by the simulation before `update`, with its own random number
+    /// generator. Robots docked at the station (serviced there), already
+    /// broken or stranded never break down. On failure the robot switches to
+    /// `RobotMode::Broken` and drops its path; returns `true`.
+    /// 
+    /// # Examples
+    /// 
+    /// An injected generator forces a minor breakdown, fixed by the robot
+    /// itself after `self_repair_ticks` updates:
+    /// 
+    /// ```rust
+    /// # use rand::rngs::mock::StepRng;
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::{Malfunction, Robot}, station::{MalfunctionConfig, Station}};
+    /// # use ereea::types::{RobotMode, RobotType};
+    /// let mut map = Map::open();
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// let config = MalfunctionConfig { failure_rate: 0.001, severe_ratio: 0.0, self_repair_ticks: 3, ..MalfunctionConfig::DEFAULT };
+    /// // Always draws 0: every random event with a non-zero probability happens
+    /// let mut rng = StepRng::new(0, 0);
+    /// 
+    /// let mut robot = Robot::new(map.station_x, map.station_y, RobotType::Explorer);
+    /// assert!(!robot.roll_malfunction(&config, &mut rng), "docked robots are serviced");
+    /// (robot.x, robot.y) = (3, 3);
+    /// assert!(!robot.roll_malfunction(&MalfunctionConfig::DEFAULT, &mut rng), "disabled by default");
+    /// 
+    /// assert!(robot.roll_malfunction(&config, &mut rng));
+    /// assert_eq!(robot.mode, RobotMode::Broken);
+    /// assert_eq!(robot.malfunction, Some(Malfunction::Minor { ticks_left: 3 }));
+    /// for _ in 0..2 {
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    ///     assert_eq!((robot.x, robot.y, robot.mode), (3, 3, RobotMode::Broken));
+    /// }
+    /// 
+    /// // Self-diagnostics done: the robot heads home for a check-up
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!((robot.mode, robot.malfunction), (RobotMode::ReturnToStation, None));
+    /// robot.update(&mut map, &mut station, &mut occupancy);
+    /// assert_ne!((robot.x, robot.y), (3, 3));
+    /// ```
+    pub fn roll_malfunction<R: Rng + ?Sized>(&mut self, config: &MalfunctionConfig, rng: &mut R) -> bool {
+        let docked = (self.x, self.y) == (self.home_station_x, self.home_station_y);
+        if config.failure_rate <= 0.0 || docked || matches!(self.mode, RobotMode::Broken | RobotMode::Stranded) {
+            return false;
+        }
+        if !rng.gen_bool(config.probability(self.age_ticks, self.distance_traveled)) {
+            return false;
         }
         
-        // Retourner si énergie faible
-        if self.energy < self.max_energy * 0.3 {
-            return true;
+        let severe = rng.gen_bool(config.severe_ratio.clamp(0.0, 1.0));
+        self.malfunction = Some(if severe {
+            Malfunction::Severe
+        } else {
+            Malfunction::Minor { ticks_left: config.self_repair_ticks }
+        });
+        self.set_mode(RobotMode::Broken, TransitionReason::Breakdown);
+        self.invalidate_path();
+        self.events.push(RobotEvent::BrokeDown { robot_id: self.id, x: self.x, y: self.y, severe });
+        true
+    }
+    
+    // NOTE - One update of a broken robot: count down self-repair, or call (and wait for) a repairer
+    fn update_malfunction(&mut self, station: &mut Station) {
+        // Immobilisé : ses réservations profitent aux autres robots
+        station.release_target(self.id);
+        if self.robot_type == RobotType::Repairer {
+            station.release_rescue(self.id);
         }
         
-        // Retourner si inventaire plein (selon le type)
-        match self.robot_type {
-            RobotType::MineralCollector => self.minerals >= 5,
-            RobotType::ScientificCollector => self.scientific_data >= 3,
-            _ => false
+        let repaired = match self.malfunction {
+            Some(Malfunction::Minor { ticks_left }) if ticks_left > 1 => {
+                self.malfunction = Some(Malfunction::Minor { ticks_left: ticks_left - 1 });
+                false
+            },
+            Some(Malfunction::Severe) => {
+                // Appel renouvelé à chaque cycle ; l'arrivée du réparateur répare le robot
+                station.report_stranded(self.id, self.x, self.y);
+                match station.take_rescue_energy(self.id) {
+                    Some(energy) => {
+                        self.energy = (self.energy + energy).min(self.max_energy);
+                        true
+                    },
+                    None => false,
+                }
+            },
+            _ => true,
+        };
+        
+        if repaired {
+            self.malfunction = None;
+            self.set_mode(RobotMode::ReturnToStation, TransitionReason::Repaired);
+            self.events.push(RobotEvent::Repaired { robot_id: self.id, x: self.x, y: self.y });
         }
     }
     
-    // NOTE - Plan path to station using A*
-    fn plan_path_to_station(&mut self, map: &Map) {
-        let target = (self.home_station_x, self.home_station_y);
-        self.path_to_station = self.find_path(map, target);
+    // NOTE - True if this robot type collects the resource found on `tile`
+    fn collects(&self, tile: &TileType) -> bool {
+        self.collected_resource().as_ref() == Some(tile)
     }
     
-    // NOTE - Find nearest resource for robot type
-    fn find_nearest_resource(&self, map: &Map) -> Option<(usize, usize)> {
-        let target_resource = match self.robot_type {
-            RobotType::Explorer => None,
+    // NOTE - Resource this robot collects (None for robots that collect nothing)
+    fn collected_resource(&self) -> Option<TileType> {
+        match self.robot_type {
             RobotType::EnergyCollector => Some(TileType::Energy),
             RobotType::MineralCollector => Some(TileType::Mineral),
             RobotType::ScientificCollector => Some(TileType::Scientific),
-        };
+            RobotType::Explorer | RobotType::Repairer | RobotType::Relay => None,
+        }
+    }
+    
+    // NOTE - Plan path to station using A* (the cached path is kept if still heading home)
+    fn plan_path_to_station(&mut self, map: &Map) {
+        let target = (self.home_station_x, self.home_station_y);
+        self.plan_path(map, target);
+    }
+    
+    // NOTE - Plan the last trip home with an exact (Dijkstra) search: the robot will not leave again
+    fn plan_final_return(&mut self, map: &Map) {
+        let target = (self.home_station_x, self.home_station_y);
+        self.plan_path_with(map, target, Self::find_path_dijkstra);
+    }
+    
+    // NOTE - Make `target` the current destination, replanning only if the cached path is unusable
+    fn plan_path(&mut self, map: &Map, target: (usize, usize)) {
+        self.plan_path_with(map, target, Self::find_path);
+    }
+    
+    // NOTE - `plan_path` with the given search (A* or Dijkstra) when a new path is needed
+    fn plan_path_with(&mut self, map: &Map, target: (usize, usize), search: PathSearch) {
+        if (self.x, self.y) == target {
+            self.invalidate_path();
+            return;
+        }
+        if self.current_target == Some(target) && self.next_step_is_valid(map) {
+            return;
+        }
         
-        let target_resource = match target_resource {
-            Some(res) => res,
-            None => return None,
-        };
+        let path = search(self, map, target);
+        self.current_target = Some(target);
+        self.current_path = path;
+    }
+    
+    // NOTE - True if a path is planned and its next step is adjacent and can still be taken
+    fn next_step_is_valid(&self, map: &Map) -> bool {
+        self.current_path.front().is_some_and(|&next| {
+            self.position().chebyshev_distance(next.into()) == 1
+                && map.can_step((self.x, self.y), next)
+        })
+    }
+    
+    /// Drops the planned path and its target; the next update plans a new one.
+    /// 
+    /// Called when the target is no longer worth reaching, e.g. the resource
+    /// a collector was heading to has been consumed by another robot.
+    pub fn invalidate_path(&mut self) {
+        self.current_path.clear();
+        self.current_target = None;
+    }
+    
+    /// Reacts to the deposits emptied during the last cycle (`Map::take_recent_changes`).
+    /// 
+    /// The robot's memory learns that those tiles are empty, as if it had seen
+    /// them (shared at its next synchronization or radio contact), and a robot
+    /// heading for one of them drops its path and its reservation: it picks
+    /// another target at its next update instead of finding nothing on
+    /// arrival.
+    /// 
+    /// # Examples
+    /// 
+    /// Two mineral collectors race for the deposit at (10, 5): #2 heads for
+    /// it as the nearest one, while #1 was sent there by a task. As soon as
+    /// #1 empties it, #2 turns to the next nearest deposit, long before it
+    /// could see the empty tile by itself.
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::occupancy::OccupancyGrid;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::station::Station;
+    /// # use ereea::types::{RobotMode, RobotTask, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(10, 5, TileType::Mineral);
+    /// map.set_tile(10, 16, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.reveal_map(&map);
+    /// 
+    /// let mut robots: Vec<Robot> = [(1, 8), (2, 0)].into_iter()
+    ///     .map(|(id, x)| Robot::new_with_memory(x, 5, RobotType::MineralCollector, id, 0, 0, station.global_memory.clone()))
+    ///     .collect();
+    /// robots[0].assign_task(RobotTask::CollectAt(10, 5));
+    /// robots[1].mode = RobotMode::Collecting;
+    /// 
+    /// let mut emptied_at = None;
+    /// for tick in 0..10 {
+    ///     station.tick();
+    ///     let mut occupancy = OccupancyGrid::from_robots(&robots, 0, 0);
+    ///     for robot in robots.iter_mut() {
+    ///         robot.update(&mut map, &mut station, &mut occupancy);
+    ///     }
+    ///     if tick == 0 {
+    ///         assert_eq!(robots[1].current_target, Some((10, 5)));
+    ///     }
+    ///     let changes = map.take_recent_changes();
+    ///     for robot in robots.iter_mut() {
+    ///         robot.handle_map_changes(&changes, &map, &mut station);
+    ///     }
+    ///     if changes.contains(&(10, 5)) {
+    ///         emptied_at = Some(tick);
+    ///         assert_eq!(robots[0].minerals(), 1);
+    ///         assert!(robots[1].current_path.is_empty());
+    ///         // Shared with the station at the next synchronization
+    ///         assert_eq!(robots[1].memory[(10, 5)].tile_type, TileType::Empty);
+    ///         assert!(robots[1].unsynced_tiles().contains(&(10, 5)));
+    ///     } else if emptied_at.is_some() {
+    ///         break;
+    ///     }
+    /// }
+    /// // One cycle later, #2 is on its way to the other deposit, still far from (10, 5)
+    /// assert!(emptied_at.is_some());
+    /// assert_eq!(robots[1].current_target, Some((10, 16)));
+    /// assert!(robots[1].x < 8);
+    /// ```
+    pub fn handle_map_changes(&mut self, changes: &[(usize, usize)], map: &Map, station: &mut Station) {
+        // Noté comme une observation : la station et les autres robots l'apprennent à la prochaine synchronisation
+        for &(x, y) in changes {
+            let tile = map.get_tile(x, y);
+            if self.memory[(x, y)].explored && self.memory[(x, y)].tile_type != tile {
+                self.observe_tile(x, y, TerrainData {
+                    explored: true,
+                    timestamp: station.current_time,
+                    robot_id: self.id,
+                    robot_type: self.robot_type,
+                    tile_type: tile,
+                });
+            }
+        }
+        
+        if let Some(target) = self.current_target
+            && changes.contains(&target)
+            && !self.collects(&map.get_tile(target.0, target.1)) {
+            self.events.push(RobotEvent::TargetAbandoned { robot_id: self.id, x: target.0, y: target.1, reason: GiveUpReason::Depleted });
+            self.invalidate_path();
+            station.release_target(self.id);
+        }
+    }
+    
+    /// Number of A* searches this robot has run since its creation
+    pub fn path_search_count(&self) -> u64 {
+        self.path_searches.get()
+    }
+    
+    /// Nearest tile holding this collector's resource, according to its memory.
+    /// 
+    /// Only tiles the robot has explored itself, or received from the station
+    /// during a synchronization, are considered: a resource lying in an
+    /// unexplored region stays unknown until a robot has seen it. A resource
+    /// consumed by another robot is dropped from memory as soon as the
+    /// collector sees its tile again, so it moves on to the next candidate.
+    /// Resources reserved by another collector through the station are
+    /// skipped. Explorers never target resources and always get `None`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(19, 19, TileType::Mineral);
+    /// (map.station_x, map.station_y) = (0, 0);
+    /// 
+    /// // Everything is explored except the corner holding the mineral
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// for ((x, y), tile) in station.global_memory.iter_coords_mut() {
+    ///     tile.explored = x < 15 || y < 15;
+    /// }
+    /// station.reindex_memory();
+    /// 
+    /// let mut collector = Robot::new(0, 0, RobotType::MineralCollector);
+    /// for _ in 0..10 {
+    ///     station.tick();
+    ///     collector.update(&mut map, &mut station, &mut occupancy);
+    ///     assert_eq!(collector.find_nearest_resource(&station), None);
+    ///     assert_ne!(collector.mode, RobotMode::Collecting);
+    ///     assert_eq!((collector.x, collector.y), (0, 0));
+    /// }
+    /// 
+    /// // An explorer sees the corner, then reports to the station
+    /// let mut explorer = Robot::new(0, 0, RobotType::Explorer);
+    /// (explorer.x, explorer.y) = (17, 17);
+    /// explorer.update_memory(&map, &station);
+    /// (explorer.x, explorer.y) = (0, 0);
+    /// station.share_knowledge(&mut explorer);
+    /// 
+    /// // The collector learns about the mineral at its next sync and heads for it
+    /// station.tick();
+    /// collector.update(&mut map, &mut station, &mut occupancy);
+    /// assert_eq!(collector.find_nearest_resource(&station), Some((19, 19)));
+    /// assert_eq!(collector.mode, RobotMode::Collecting);
+    /// assert_eq!(collector.current_target, Some((19, 19)));
+    /// ```
+    pub fn find_nearest_resource(&self, station: &Station) -> Option<(usize, usize)> {
+        let target_resource = self.collected_resource()?;
         
         let mut nearest = None;
         let mut min_distance = usize::MAX;
         
-        // Chercher dans TOUTE la carte (pour compatibilité avec l'ancien code)
+        // Chercher uniquement parmi les cases explorées (par le robot ou reçues de la station)
         for y in 0..MAP_SIZE {
             for x in 0..MAP_SIZE {
-                if map.get_tile(x, y) == target_resource {
-                    let distance = self.heuristic((self.x, self.y), (x, y));
+                let tile = &self.memory[(x, y)];
+                if tile.explored && tile.tile_type == target_resource && !self.is_avoided((x, y))
+                   && !station.is_target_reserved_by_other(self.id, (x, y)) {
+                    let distance = self.position().chebyshev_distance((x, y).into());
                     if distance < min_distance {
                         min_distance = distance;
                         nearest = Some((x, y));
@@ -719,142 +3887,560 @@ impl Robot {
         nearest
     }
     
-    // NOTE - A* pathfinding algorithm for optimal route
-    fn find_path(&self, map: &Map, target: (usize, usize)) -> VecDeque<(usize, usize)> {
+    /// A* path from the robot's current position to `target` (start excluded).
+    /// 
+    /// Step costs follow `movement_cost` of the destination tile, so expensive
+    /// terrain is crossed only when no cheaper detour exists. Returns an empty
+    /// path if the target is unreachable or already reached.
+    /// 
+    /// # Examples
+    /// 
+    /// A short band of mineral tiles is walked around, and the energy spent
+    /// following the path is the sum of the per-tile costs:
+    /// 
+    /// ```rust
+    /// use ereea::map::Map;
+    /// use ereea::occupancy::OccupancyGrid;
+    /// use ereea::robot::{movement_cost, Robot};
+    /// use ereea::station::Station;
+    /// use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+    /// 
+    /// let mut map = Map::open();
+    /// for y in 4..=6 {
+    ///     map.set_tile(2, y, TileType::Mineral);
+    /// }
+    /// 
+    /// let robot = Robot::new(0, 5, RobotType::Explorer);
+    /// let path = robot.find_path(&map, (4, 5));
+    /// assert_eq!(path.len(), 4);
+    /// assert!(path.iter().all(|&(x, y)| map.tiles[y][x] == TileType::Empty), "{:?}", path);
+    /// 
+    /// // A full column leaves no detour: the band is crossed exactly once
+    /// for y in 0..MAP_SIZE {
+    ///     map.set_tile(2, y, TileType::Mineral);
+    /// }
+    /// let path = robot.find_path(&map, (4, 5));
+    /// assert_eq!(path.len(), 4);
+    /// assert_eq!(path.iter().filter(|&&(x, _)| x == 2).count(), 1);
+    /// 
+    /// // 0.3 per step for an explorer times the terrain cost, plus 0.1 of metabolism
+    /// let terrain: f32 = path.iter().map(|&(x, y)| movement_cost(&map.tiles[y][x])).sum();
+    /// assert!((terrain - 4.2).abs() < 1e-4);
+    /// 
+    /// let mut station = Station::new();
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// let mut robot = Robot::new(0, 5, RobotType::Explorer);
+    /// (robot.home_station_x, robot.home_station_y) = (4, 5);
+    /// robot.mode = RobotMode::ReturnToStation;
+    /// robot.current_path = path;
+    /// robot.current_target = Some((4, 5));
+    /// for _ in 0..4 {
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!((robot.x, robot.y), (4, 5));
+    /// assert!((robot.total_energy_spent - (4.0 * 0.1 + 0.3 * terrain)).abs() < 1e-4);
+    /// ```
+    pub fn find_path(&self, map: &Map, target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.find_path_from(map, (self.x, self.y), target)
+    }
+    
+    /// Same as `find_path`, but tiles held by other robots within the first
+    /// few steps (`OCCUPANCY_HORIZON`) are treated as obstacles.
+    /// 
+    /// Robots further away are ignored: they will most likely have moved by
+    /// the time this robot gets there. Used to walk around a robot blocking
+    /// the next step of the planned path.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::occupancy::OccupancyGrid;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::types::{RobotType, TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// 
+    /// // A wall with a single gap at (12, 5)
+    /// for y in 0..MAP_SIZE {
+    ///     if y != 5 {
+    ///         map.tiles[y][12] = TileType::Obstacle;
+    ///     }
+    /// }
+    /// let others = [Robot::new(1, 5, RobotType::MineralCollector), Robot::new(12, 5, RobotType::Explorer)];
+    /// let occupancy = OccupancyGrid::from_robots(&others, 19, 19);
+    /// 
+    /// // The robot next to the start is walked around at no extra cost, while
+    /// // the one in the gap is too far away to matter
+    /// let robot = Robot::new(0, 5, RobotType::Explorer);
+    /// let detour = robot.find_path_avoiding(&map, (15, 5), &occupancy);
+    /// assert_eq!(detour.len(), robot.find_path(&map, (15, 5)).len());
+    /// assert!(!detour.contains(&(1, 5)));
+    /// assert!(detour.contains(&(12, 5)));
+    /// 
+    /// // Close to the gap, its occupant blocks the only way through
+    /// let robot = Robot::new(10, 5, RobotType::Explorer);
+    /// assert!(!robot.find_path(&map, (15, 5)).is_empty());
+    /// assert!(robot.find_path_avoiding(&map, (15, 5), &occupancy).is_empty());
+    /// ```
+    pub fn find_path_avoiding(&self, map: &Map, target: (usize, usize), occupancy: &OccupancyGrid) -> VecDeque<(usize, usize)> {
+        // Les cases occupées par d'autres robots ne bloquent que les premiers pas
         let start = (self.x, self.y);
-        
-        // Si déjà à destination
-        if start == target {
-            return VecDeque::new();
+        let occupied_nearby = |tile: (usize, usize)| {
+            occupancy.is_occupied(tile.0, tile.1) && Position::from(start).chebyshev_distance(tile.into()) <= OCCUPANCY_HORIZON
+        };
+        self.search_path(map, start, target, &PathOptions::new().avoiding(&occupied_nearby)).0
+    }
+    
+    /// A* path from the robot's current position to `target` (start excluded)
+    /// under a custom cost table, with its total cost.
+    /// 
+    /// `cost_fn` gives the cost of stepping onto a tile, `None` when it cannot
+    /// be entered; obstacles stay impassable whatever it says, and no corner
+    /// is cut between two tiles that cannot be entered. The distance estimate is scaled by the cheapest
+    /// step of the table, so the path is the cheapest one under that table.
+    /// `terrain_cost` gives each robot type a default table; `find_path`
+    /// keeps the terrain multipliers of `movement_cost`.
+    /// 
+    /// # Returns
+    /// 
+    /// The path and the sum of the costs of its tiles, `None` if `target`
+    /// cannot be reached.
+    /// 
+    /// # Examples
+    /// 
+    /// A straight corridor full of minerals, or a detour two steps longer on
+    /// bare ground: the explorer takes the corridor, the collector walks around.
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::robot::{terrain_cost, Robot};
+    /// # use ereea::types::{RobotType, TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// for x in 2..=8 {
+    ///     for y in [3, 4, 6, 7] {
+    ///         map.tiles[y][x] = TileType::Obstacle;
+    ///     }
+    ///     map.set_tile(x, 5, TileType::Mineral);
+    /// }
+    /// let minerals = |path: &std::collections::VecDeque<(usize, usize)>| {
+    ///     path.iter().filter(|&&(x, y)| map.tiles[y][x] == TileType::Mineral).count()
+    /// };
+    /// 
+    /// let mut crossed = Vec::new();
+    /// for robot_type in [RobotType::Explorer, RobotType::MineralCollector] {
+    ///     let robot = Robot::new(0, 5, robot_type);
+    ///     let cost_fn = |tile| terrain_cost(robot_type, tile);
+    ///     let (path, cost) = robot.find_path_weighted(&map, (10, 5), cost_fn).unwrap();
+    ///     assert_eq!(path.back(), Some(&(10, 5)));
+    ///     // The reported cost is the sum of the costs of the tiles entered
+    ///     let tiles: u32 = path.iter().map(|&(x, y)| cost_fn(map.tiles[y][x].clone()).unwrap()).sum();
+    ///     assert_eq!(cost, tiles);
+    ///     crossed.push(minerals(&path));
+    /// }
+    /// assert_eq!(crossed, [7, 0]);
+    /// 
+    /// // A table that forbids minerals closes the corridor; a wall of them closes the way
+    /// let robot = Robot::new(0, 5, RobotType::Explorer);
+    /// let no_minerals = |tile| (tile != TileType::Mineral).then_some(1);
+    /// let (path, cost) = robot.find_path_weighted(&map, (10, 5), no_minerals).unwrap();
+    /// assert_eq!((minerals(&path), cost as usize), (0, path.len()));
+    /// for y in 0..MAP_SIZE {
+    ///     map.set_tile(9, y, TileType::Mineral);
+    /// }
+    /// assert!(robot.find_path_weighted(&map, (10, 5), no_minerals).is_none());
+    /// ```
+    pub fn find_path_weighted(&self, map: &Map, target: (usize, usize), cost_fn: impl Fn(TileType) -> Option<u32>) -> Option<(VecDeque<(usize, usize)>, u32)> {
+        // Les obstacles restent infranchissables, quoi qu'en dise la table
+        let step_cost = |tile: TileType| if tile == TileType::Obstacle { None } else { cost_fn(tile) };
+        let (path, _, cost) = self.search_path(map, (self.x, self.y), target, &PathOptions::new().with_step_cost(&step_cost));
+        cost.map(|cost| (path, cost))
+    }
+    
+    /// Uniform-cost (Dijkstra) path from the robot's current position to
+    /// `target` (start excluded): the cheapest path, whatever the heuristic.
+    /// 
+    /// Runs the same search as `find_path` (same neighbours, diagonal rule
+    /// and terrain costs) with a zero distance estimate. `find_path`'s
+    /// Chebyshev estimate never overestimates, so both return equally cheap
+    /// paths; Dijkstra simply does not rely on that assumption. The price is
+    /// speed: with no estimate to steer it, the search expands every tile
+    /// cheaper to reach than the target, typically several times more nodes
+    /// than A* on open ground. Robots use it only for their final trip home.
+    /// 
+    /// # Examples
+    /// 
+    /// A maze of carved corridors with costlier resource tiles: both searches
+    /// agree on the path length and cost, Dijkstra working harder for it.
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::robot::{movement_cost, Robot};
+    /// # use ereea::types::{Position, RobotType, TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// // Walls every 4 columns, open alternately at the bottom and at the top
+    /// for (i, x) in [3, 7, 11, 15].into_iter().enumerate() {
+    ///     for y in 0..MAP_SIZE {
+    ///         let gap = if i % 2 == 0 { y >= MAP_SIZE - 2 } else { y < 2 };
+    ///         if !gap {
+    ///             map.tiles[y][x] = TileType::Obstacle;
+    ///         }
+    ///     }
+    /// }
+    /// // Mineral bands slowing down the obvious lines
+    /// for y in 5..15 {
+    ///     map.set_tile(1, y, TileType::Mineral);
+    ///     map.set_tile(9, y, TileType::Mineral);
+    ///     map.set_tile(17, y, TileType::Mineral);
+    /// }
+    /// 
+    /// let robot = Robot::new(0, 0, RobotType::Explorer);
+    /// let cost = |path: &std::collections::VecDeque<(usize, usize)>| -> f32 {
+    ///     path.iter().map(|&(x, y)| movement_cost(&map.tiles[y][x])).sum()
+    /// };
+    /// let astar = robot.find_path(&map, (19, 0));
+    /// let dijkstra = robot.find_path_dijkstra(&map, (19, 0));
+    /// assert!(!astar.is_empty());
+    /// assert_eq!(astar.len(), dijkstra.len());
+    /// assert!((cost(&astar) - cost(&dijkstra)).abs() < 1e-4);
+    /// 
+    /// let no_estimate = |_: Position, _: Position| 0;
+    /// let astar_work = robot.path_search_stats(&map, (19, 0), Position::chebyshev_distance);
+    /// let dijkstra_work = robot.path_search_stats(&map, (19, 0), no_estimate);
+    /// assert!(dijkstra_work.expanded_nodes >= astar_work.expanded_nodes);
+    /// ```
+    pub fn find_path_dijkstra(&self, map: &Map, target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.search_path(map, (self.x, self.y), target, &PathOptions::new().with_heuristic(|_, _| 0)).0
+    }
+    
+    // NOTE - A* pathfinding algorithm for optimal route
+    fn find_path_from(&self, map: &Map, start: (usize, usize), target: (usize, usize)) -> VecDeque<(usize, usize)> {
+        self.search_path(map, start, target, &PathOptions::new()).0
+    }
+    
+    /// Runs the A* search of `find_path` with another distance estimate
+    /// (in tiles) and reports how much work it did.
+    /// 
+    /// Benchmarking aid: `find_path` always uses `Position::chebyshev_distance`.
+    /// See `benches/astar.rs`.
+    pub fn path_search_stats(&self, map: &Map, target: (usize, usize), heuristic: fn(Position, Position) -> usize) -> PathSearchStats {
+        self.search_path(map, (self.x, self.y), target, &PathOptions::new().with_heuristic(heuristic)).1
+    }
+    
+    // NOTE - A* search from `start` (see `pathfinding::find_path_with_stats`), counted in `path_search_count`:
+    // the path (start excluded, empty if unreachable), the search statistics and the path cost
+    fn search_path(&self, map: &Map, start: (usize, usize), target: (usize, usize), options: &PathOptions) -> (VecDeque<(usize, usize)>, PathSearchStats, Option<u32>) {
+        if start != target {
+            self.path_searches.set(self.path_searches.get() + 1);
         }
-        
-        let mut open_set = BinaryHeap::new();
-        let mut came_from: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
-        let mut g_score: HashMap<(usize, usize), usize> = HashMap::new();
-        
-        // Initialiser les valeurs de départ
-        g_score.insert(start, 0);
-        open_set.push(Node {
-            position: start,
-            g_cost: 0,
-            f_cost: self.heuristic(start, target),
-        });
-        
-        while let Some(current) = open_set.pop() {
-            let current_pos = current.position;
-            
-            // Si on est arrivé à destination
-            if current_pos == target {
-                // Reconstruire le chemin
-                let mut path = VecDeque::new();
-                let mut current = target;
-                
-                while current != start {
-                    path.push_front(current);
-                    current = *came_from.get(&current).unwrap();
-                }
-                
-                return path;
-            }
-            
-            // Examiner tous les voisins
-            for dy in -1..=1 {
-                for dx in -1..=1 {
-                    if dx == 0 && dy == 0 {
-                        continue; // Ignorer la position actuelle
-                    }
-                    
-                    let nx = current_pos.0 as isize + dx;
-                    let ny = current_pos.1 as isize + dy;
-                    
-                    // Vérifier si la position est valide
-                    if nx < 0 || nx >= MAP_SIZE as isize || ny < 0 || ny >= MAP_SIZE as isize {
-                        continue;
-                    }
-                    
-                    let neighbor = (nx as usize, ny as usize);
-                    
-                    // Vérifier si c'est un obstacle
-                    if !map.is_valid_position(neighbor.0, neighbor.1) {
-                        continue;
-                    }
-                    
-                    // Calculer le nouveau coût
-                    let tentative_g_score = g_score[&current_pos] + 1;
-                    
-                    // Si on a trouvé un meilleur chemin
-                    if !g_score.contains_key(&neighbor) || tentative_g_score < g_score[&neighbor] {
-                        came_from.insert(neighbor, current_pos);
-                        g_score.insert(neighbor, tentative_g_score);
-                        
-                        let f_score = tentative_g_score + self.heuristic(neighbor, target);
-                        open_set.push(Node {
-                            position: neighbor,
-                            g_cost: tentative_g_score,
-                            f_cost: f_score,
-                        });
-                    }
-                }
-            }
+        match pathfinding::find_path_with_stats(map, start, target, options) {
+            (Some((path, cost)), stats) => (path.into(), stats, Some(cost)),
+            (None, stats) => (VecDeque::new(), stats, None),
+        }
+    }
+    
+    // NOTE - Energy cost of moving one tile on bare ground (margins and estimates)
+    fn step_energy_cost(&self) -> f32 {
+        self.move_cost(1.0, &TileType::Empty)
+    }
+    
+    // NOTE - Energy spent at each update just to stay powered
+    fn metabolism_cost(&self) -> f32 {
+        self.energy_model.metabolism_cost(&self.config)
+    }
+    
+    // NOTE - Energy cost of moving `distance` tiles onto `tile`: the energy model's cost, lowered
+    // by efficiency modules and raised by the load carried
+    fn move_cost(&self, distance: f32, tile: &TileType) -> f32 {
+        let efficiency = self.upgrades.iter()
+            .filter(|&&upgrade| upgrade == Upgrade::EfficiencyModule)
+            .fold(1.0, |factor, _| factor * EFFICIENCY_MODULE_FACTOR);
+        self.energy_model.move_cost(&self.config, distance, tile) * efficiency * (1.0 + self.load_factor() * CARGO_WEIGHT_PENALTY)
+    }
+    
+    // NOTE - Share of the cargo hold in use, between 0 (empty or no hold) and 1 (full)
+    fn load_factor(&self) -> f32 {
+        if self.cargo_capacity > 0 {
+            (self.cargo_load() as f32 / self.cargo_capacity as f32).min(1.0)
+        } else {
+            0.0
         }
-        
-        // Si on ne trouve pas de chemin, retourner un chemin vide
-        VecDeque::new()
     }
     
-    // NOTE - Heuristic for A* (Manhattan distance)
-    fn heuristic(&self, a: (usize, usize), b: (usize, usize)) -> usize {
-        let dx = (a.0 as isize - b.0 as isize).abs() as usize;
-        let dy = (a.1 as isize - b.1 as isize).abs() as usize;
-        dx + dy
+    /// Tiles the robot walks along its path per update.
+    /// 
+    /// `RobotConfig::speed`, raised by propulsion modules (+25% each) and
+    /// lowered by the load carried (half speed with a full hold). Fractions
+    /// add up from one update to the next: at 0.5 the robot steps every
+    /// other update, at 2.0 it takes two steps per update. Each step pays
+    /// its energy and checks the other robots' positions as usual.
+    /// 
+    /// # Examples
+    /// 
+    /// A speed-2.0 explorer walks a straight corridor in half the updates:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::{Robot, RobotConfig}, station::Station};
+    /// # use ereea::types::{RobotMode, RobotTask, RobotType, TileType, MAP_SIZE};
+    /// let mut map = Map::new();
+    /// map.tiles = vec![vec![TileType::Obstacle; MAP_SIZE]; MAP_SIZE];
+    /// for x in 0..=16 {
+    ///     map.tiles[0][x] = TileType::Empty;
+    /// }
+    /// map.update_reachability();
+    /// (map.station_x, map.station_y) = (0, 0);
+    /// let mut station = Station::new();
+    /// 
+    /// let mut updates_to_walk = |speed: f32| {
+    ///     let config = RobotConfig::for_type(RobotType::Explorer).with_speed(speed);
+    ///     let mut robot = Robot::new(0, 0, RobotType::Explorer).with_config(config);
+    ///     robot.assign_task(RobotTask::MoveTo(16, 0));
+    ///     let mut occupancy = OccupancyGrid::new(0, 0);
+    ///     (1..=40).find(|_| {
+    ///         robot.update(&mut map, &mut station, &mut occupancy);
+    ///         robot.x == 16
+    ///     })
+    /// };
+    /// assert_eq!(updates_to_walk(1.0), Some(16));
+    /// assert_eq!(updates_to_walk(2.0), Some(8));
+    /// ```
+    /// 
+    /// A full mineral collector heading home moves every other update:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (0, 5);
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// let mut occupancy = OccupancyGrid::new(0, 5);
+    /// 
+    /// let mut robot = Robot::new_with_memory(8, 5, RobotType::MineralCollector, 1, 0, 5, station.global_memory.clone());
+    /// robot.mode = RobotMode::ReturnToStation;
+    /// robot.load(TileType::Mineral, 5);
+    /// assert_eq!(robot.speed(), 0.5);
+    /// 
+    /// let positions: Vec<usize> = (0..6)
+    ///     .map(|_| {
+    ///         robot.update(&mut map, &mut station, &mut occupancy);
+    ///         robot.x
+    ///     })
+    ///     .collect();
+    /// assert_eq!(positions, [8, 7, 7, 6, 6, 5]);
+    /// ```
+    pub fn speed(&self) -> f32 {
+        let propulsion = self.upgrades.iter()
+            .filter(|&&upgrade| upgrade == Upgrade::PropulsionModule)
+            .fold(1.0, |factor, _| factor * PROPULSION_MODULE_FACTOR);
+        self.config.speed * propulsion * (1.0 - self.load_factor() * CARGO_SPEED_PENALTY)
     }
     
-    // NOTE - Move robot to a position
-    fn move_to(&mut self, x: usize, y: usize) {
+    // NOTE - Move robot to a position; returns false (and stays put) if energy is insufficient
+    // or another robot holds the tile (the station tile never counts as occupied)
+    fn move_to(&mut self, map: &Map, occupancy: &mut OccupancyGrid, x: usize, y: usize) -> bool {
+        // Une case devenue infranchissable (ou occupée) depuis la planification n'est jamais franchie
+        if !map.is_valid_position(x, y) || occupancy.is_occupied(x, y) {
+            return false;
+        }
+        
         // Calculer la distance
         let dx = (x as isize - self.x as isize).abs();
         let dy = (y as isize - self.y as isize).abs();
         let distance = dx.max(dy) as f32;
         
-        // Consommer de l'énergie selon la distance et le type de robot
-        let energy_cost = match self.robot_type {
-            RobotType::Explorer => 0.3 * distance,
-            RobotType::EnergyCollector => 0.4 * distance,
-            RobotType::MineralCollector => 0.5 * distance,
-            RobotType::ScientificCollector => 0.6 * distance,
-        };
+        // Consommer de l'énergie selon la distance, le type de robot, sa charge et le terrain d'arrivée
+        let energy_cost = self.move_cost(distance, &map.get_tile(x, y));
+        if energy_cost > self.energy + ENERGY_EPSILON {
+            return false;
+        }
         
-        self.energy -= energy_cost;
+        self.consume_energy(energy_cost);
+        self.distance_traveled += distance;
         
         // Mettre à jour la position
+        occupancy.move_robot((self.x, self.y), (x, y));
         self.x = x;
         self.y = y;
+        self.visit_count[(x, y)] = self.visit_count[(x, y)].saturating_add(1);
+        self.last_visit[(x, y)] = self.age_ticks;
+        true
     }
     
-    // NOTE - Calculate percentage of map explored by this robot
-    pub fn get_exploration_percentage(&self) -> f32 {
-        let mut explored_count = 0;
+    // NOTE - Hand the cargo to the station, recording how far it was carried from the last pickup
+    fn unload(&mut self, station: &mut Station) {
+        let cargo = std::mem::take(&mut self.inventory);
+        if cargo.total_weight() > 0
+            && let Some(pickup) = self.last_pickup.take() {
+            station.record_haul(Position::from(pickup).chebyshev_distance(self.position()));
+        }
+        station.deposit_resources(cargo);
+    }
+    
+    // NOTE - Collector with cargo next to its station: go through the station if it costs at most
+    // `DROP_OFF_MAX_DETOUR` extra steps, the rest of the path is kept
+    fn plan_drop_off(&mut self, map: &Map) {
+        let home = (self.home_station_x, self.home_station_y);
+        let Some(&next) = self.current_path.front() else {
+            return;
+        };
+        if self.cargo_load() == 0 || next == home
+            || self.position().chebyshev_distance(home.into()) != 1
+            || Position::from(home).chebyshev_distance(next.into()) > DROP_OFF_MAX_DETOUR
+            || !map.can_step((self.x, self.y), home) || !map.can_step(home, next) {
+            return;
+        }
+        self.current_path.push_front(home);
+    }
+    
+    // NOTE - Unload at the station on the way, sync and take a single charge without keeping a bay;
+    // the route (path, target, reservation) resumes at the next update
+    fn drop_off(&mut self, station: &mut Station) {
+        let carried = self.cargo_load();
+        self.unload(station);
         
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                if self.memory[y][x].explored {
-                    explored_count += 1;
-                }
+        if station.current_time > self.last_sync_time {
+            station.share_knowledge(self);
+            self.last_sync_time = station.current_time;
+        }
+        
+        // Un appoint, pas une recharge : la borne est rendue aussitôt (ou la file quittée)
+        if self.max_energy - self.energy >= 1.0
+            && let Some(amount) = station.request_charge(self.id, self.max_energy - self.energy) {
+            self.energy += amount as f32;
+        }
+        station.release_charging_bay(self.id);
+        
+        self.passing_through = true;
+        self.notice(format!("📦 Robot #{} dépose {} unités en passant par la station", self.id, carried));
+    }
+    
+    // NOTE - Walk the planned path as far as the movement budget allows (`speed` tiles per update,
+    // the fraction of a step left over is kept for the next update)
+    fn step_along_path(&mut self, map: &Map, occupancy: &mut OccupancyGrid) {
+        self.movement_budget += self.speed();
+        self.short_of_step = self.movement_budget < 1.0 && !self.current_path.is_empty();
+        while self.movement_budget >= 1.0 && !self.current_path.is_empty() {
+            self.movement_budget -= 1.0;
+            if !self.take_path_step(map, occupancy) {
+                break;
             }
         }
+        // Un robot arrêté (arrivé, bloqué) ne thésaurise pas les pas non faits
+        self.movement_budget = self.movement_budget.fract();
         
-        (explored_count as f32 / (MAP_SIZE * MAP_SIZE) as f32) * 100.0
+        if self.current_path.is_empty() {
+            self.current_target = None;
+        }
     }
     
-    // NOTE - Check if exploration is complete (100%)
-    fn is_exploration_complete(&self) -> bool {
-        for y in 0..MAP_SIZE {
-            for x in 0..MAP_SIZE {
-                if !self.memory[y][x].explored {
-                    return false; // Il reste des cases non explorées
-                }
+    // NOTE - Take the next step of the planned path (kept if the move is not affordable); returns
+    // true if the robot moved. The path is replanned towards the same target if the map changed
+    // under it. A robot standing on the next step is walked around, or waited for.
+    fn take_path_step(&mut self, map: &Map, occupancy: &mut OccupancyGrid) -> bool {
+        if !self.next_step_is_valid(map)
+            && let Some(target) = self.current_target {
+            self.current_path = self.find_path(map, target);
+        }
+        
+        if let Some(&(x, y)) = self.current_path.front()
+            && occupancy.is_occupied(x, y) {
+            self.avoid_blocking_robot(map, occupancy);
+        }
+        
+        if let Some(&(x, y)) = self.current_path.front()
+            && self.move_to(map, occupancy, x, y) {
+            self.current_path.pop_front();
+            self.blocked_ticks = 0;
+            return true;
+        }
+        false
+    }
+    
+    // NOTE - Next step held by another robot: take a short detour if there is one, otherwise wait.
+    // After waiting too long (except on the way home), the target is given up for a while.
+    fn avoid_blocking_robot(&mut self, map: &Map, occupancy: &OccupancyGrid) {
+        if let Some(target) = self.current_target {
+            let detour = self.find_path_avoiding(map, target, occupancy);
+            if !detour.is_empty() && detour.len() <= self.current_path.len() + DETOUR_MARGIN {
+                self.current_path = detour;
+                return;
+            }
+        }
+        
+        self.blocked_ticks += 1;
+        if self.blocked_ticks > MAX_BLOCKED_TICKS && self.mode != RobotMode::ReturnToStation {
+            self.notice(format!("🚧 Robot #{} bloqué par un autre robot à ({}, {}) : abandon de sa cible", self.id, self.x, self.y));
+            if let Some(target) = self.current_target
+                && target != (self.home_station_x, self.home_station_y) {
+                self.avoided_targets.push((target, AVOID_TARGET_TICKS));
             }
+            self.invalidate_path();
+            self.blocked_ticks = 0;
         }
-        true // Toutes les cases sont explorées
     }
-}
\ No newline at end of file
+    
+    // NOTE - Decrease energy (never below zero) and keep the cumulative consumption up to date
+    fn consume_energy(&mut self, amount: f32) {
+        let spent = amount.min(self.energy).max(0.0);
+        self.energy -= spent;
+        self.total_energy_spent += spent;
+    }
+    
+    // NOTE - Calculate percentage of the exploration area explored by this robot
+    pub fn get_exploration_percentage(&self) -> f32 {
+        #[cfg(debug_assertions)]
+        self.check_explored_count();
+        
+        (self.explored_in_area as f32 / self.exploration_area.size() as f32) * 100.0
+    }
+    
+    // NOTE - Check if exploration is complete (every tile of the area known)
+    fn is_exploration_complete(&self) -> bool {
+        #[cfg(debug_assertions)]
+        self.check_explored_count();
+        
+        self.explored_in_area == self.exploration_area.size()
+    }
+    
+    // NOTE - Full scan of `memory`, used to (re)build the incremental counter
+    fn count_explored_tiles(&self) -> usize {
+        self.memory.iter().filter(|tile| tile.explored).count()
+    }
+    
+    // NOTE - Full scan of `memory` restricted to the exploration area
+    fn count_explored_in_area(&self) -> usize {
+        self.memory.iter_coords()
+            .filter(|&((x, y), tile)| tile.explored && self.exploration_area.contains(x, y))
+            .count()
+    }
+    
+    // NOTE - Reject robots built off the map or with a memory of the wrong size (debug builds only)
+    fn check_placement(x: usize, y: usize, station_x: usize, station_y: usize, memory: &Grid<TerrainData>) {
+        debug_assert!(x < MAP_SIZE && y < MAP_SIZE,
+                      "position du robot ({}, {}) hors de la carte {}x{}", x, y, MAP_SIZE, MAP_SIZE);
+        debug_assert!(station_x < MAP_SIZE && station_y < MAP_SIZE,
+                      "station ({}, {}) hors de la carte {}x{}", station_x, station_y, MAP_SIZE, MAP_SIZE);
+        debug_assert!(memory.width() == MAP_SIZE && memory.height() == MAP_SIZE,
+                      "mémoire de {}x{} cases ne correspondant pas à la carte {}x{}", memory.width(), memory.height(), MAP_SIZE, MAP_SIZE);
+    }
+    
+    // NOTE - Catch any memory update that bypassed `mark_explored` (debug builds only)
+    #[cfg(debug_assertions)]
+    fn check_explored_count(&self) {
+        assert_eq!(self.explored_count, self.count_explored_tiles(),
+                   "compteur de cases explorées désynchronisé (robot {})", self.id);
+        assert_eq!(self.explored_in_area, self.count_explored_in_area(),
+                   "compteur de la zone d'exploration désynchronisé (robot {})", self.id);
+    }
+}
+
+impl KnowledgeBase for Robot {
+    fn known_tile(&self, x: usize, y: usize) -> &TerrainData {
+        &self.memory[(x, y)]
+    }
+    
+    // NOTE - Goes through `mark_explored` to keep the frontier and counters in step
+    fn learn_tile(&mut self, x: usize, y: usize, data: TerrainData) {
+        self.mark_explored(x, y, data);
+    }
+}
@@ -0,0 +1,272 @@
+//! # Simulation Module
+//!
+//! One cycle of the mission, without networking nor timing: `step_simulation`
+//! advances the station clock, lets every robot act, answers emergencies,
+//! spreads knowledge between robots and grows the fleet. The simulation
+//! server calls it once per cycle and broadcasts the result; the tests run
+//! whole missions with it.
+//!
+//! `deploy_fleet` puts the initial robots on the map the way the server does.
+//!
+//! # Examples
+//!
+//! A whole mission, from deployment to the return of the last robot:
+//!
+//! ```rust
+//! # use ereea::knowledge::ExplorationArea;
+//! # use ereea::map::Map;
+//! # use ereea::simulation::{deploy_fleet, step_simulation, StepOutcome, INITIAL_FLEET};
+//! # use ereea::station::Station;
+//! let mut map = Map::with_seed(7);
+//! let mut station = Station::new();
+//! station.set_exploration_area(ExplorationArea::reachable(&map));
+//! let mut robots = deploy_fleet(&map, &mut station, &INITIAL_FLEET);
+//! assert_eq!(station.next_robot_id, INITIAL_FLEET.len() + 1);
+//!
+//! let mut iteration = 0;
+//! let mut objectives_met_at = None;
+//! loop {
+//!     let report = step_simulation(&mut map, &mut station, &mut robots, iteration);
+//!     iteration += 1;
+//!     match report.outcome {
+//!         StepOutcome::Running => assert!(iteration < 3000, "mission inachevée"),
+//!         StepOutcome::AwaitingReturn => { objectives_met_at.get_or_insert(iteration); },
+//!         StepOutcome::MissionComplete => break,
+//!     }
+//! }
+//! assert!(objectives_met_at.is_some_and(|cycle| cycle < iteration));
+//! assert_eq!(station.get_exploration_percentage(), 100.0);
+//! assert!(station.is_mission_complete(&map));
+//! ```
+
+use crate::events::RobotEvent;
+use crate::knowledge::{assign_relay_posts, sync_by_radio, sync_peers, sync_through_relays};
+use crate::map::Map;
+use crate::occupancy::OccupancyGrid;
+use crate::robot::Robot;
+use crate::station::Station;
+use crate::types::{RobotMode, RobotType};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Robots the mission starts with: one of each type
+pub const INITIAL_FLEET: [RobotType; 5] = [
+    RobotType::Explorer,
+    RobotType::EnergyCollector,
+    RobotType::MineralCollector,
+    RobotType::ScientificCollector,
+    RobotType::Repairer,
+];
+
+/// Cycles between two resource regenerations (endless mode)
+pub const REGENERATION_INTERVAL: u32 = 50;
+
+/// Cycles between two robots built or upgraded by the station
+pub const FLEET_GROWTH_INTERVAL: u32 = 50;
+
+/// Where the mission stands at the end of a cycle
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// Objectives not met yet (always the case in endless mode)
+    #[default]
+    Running,
+    /// Objectives met, robots still on their way back to base (see `MissionObjectives::require_all_home`)
+    AwaitingReturn,
+    /// Objectives met and every robot home when required: the mission is over
+    MissionComplete,
+}
+
+/// What happened during one cycle (see `step_simulation`)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StepReport {
+    /// Events of the robots and the station, in the order they happened
+    pub events: Vec<RobotEvent>,
+    /// Mission-wide log lines (blackouts, regenerated resources, fleet growth)
+    pub notices: Vec<String>,
+    /// Where the mission stands at the end of the cycle
+    pub outcome: StepOutcome,
+}
+
+/// Puts the initial robots on the station, built the way the station would build them.
+///
+/// Robots get consecutive IDs from 1 and `station.next_robot_id` follows the
+/// last one. Repairers stand by for distress calls, the others start
+/// exploring; their random choices are seeded from the map.
+pub fn deploy_fleet(map: &Map, station: &mut Station, fleet: &[RobotType]) -> Vec<Robot> {
+    let robots = fleet.iter().zip(1..).map(|(&robot_type, id)| {
+        let mut robot = Robot::new_with_memory(
+            map.station_x, map.station_y,
+            robot_type, id,
+            map.station_x, map.station_y,
+            station.global_memory.clone(),
+        ).with_config(station.robot_config(robot_type));
+        robot.mode = if robot_type == RobotType::Repairer { RobotMode::Idle } else { RobotMode::Exploring };
+        robot.vision_model = station.vision_model;
+        robot.set_exploration_area(station.exploration_area().clone());
+        robot.seed_rng(map.seed());
+        robot
+    }).collect::<Vec<_>>();
+    station.next_robot_id = robots.len() + 1;
+    robots
+}
+
+// NOTE - Whether every robot is docked at its home station (always true unless the objectives require it);
+// robots cut off from their station (`Robot::is_lost`) cannot come back and are not waited for
+fn all_robots_home(station: &Station, robots: &[Robot]) -> bool {
+    !station.objectives.require_all_home || robots.iter().all(|r| {
+        r.is_lost() ||
+        r.x == r.home_station_x && r.y == r.home_station_y &&
+        (r.mode == RobotMode::Idle || r.mode == RobotMode::ReturnToStation)
+    })
+}
+
+/// Runs one simulation cycle.
+///
+/// In order: the station clock advances and the life support burns its
+/// share of the reserves, relays take their posts, then each robot may
+/// break down and acts. A robot waiting for a repairer when none is left is
+/// repatriated. With `Station::repair_access`, robots cut off from their
+/// station get a corridor carved back to it, and the end of a fleet recall
+/// is announced once every robot is docked. Robots heading for emptied deposits pick another target,
+/// idle collectors retire (resting explorers refilling reserves dry for good, leftover resources given up once no energy is left), robots close to each other (or linked through
+/// relays) share their knowledge, and in endless mode resources grow back
+/// every `REGENERATION_INTERVAL` cycles. Until the objectives are met, the
+/// station upgrades itself or a robot every `FLEET_GROWTH_INTERVAL` cycles, or builds
+/// one robot per build slot (`Station::build_slots`), and builds a forward depot when it can
+/// afford one; once they are, the mission ends when the robots are back (`StepOutcome`).
+///
+/// Breakdowns and regrowth are drawn from the map seed and `iteration`, so
+/// a mission replays identically from the same seed.
+pub fn step_simulation(map: &mut Map, station: &mut Station, robots: &mut Vec<Robot>, iteration: u32) -> StepReport {
+    let mut report = StepReport::default();
+    let mut rng = StdRng::seed_from_u64(((map.seed() as u64) << 32) | iteration as u64);
+    station.tick();
+
+    // NOTE - Tiles held by robots this tick, updated as they move
+    let mut occupancy = OccupancyGrid::from_map(robots, map);
+
+    // NOTE - Stranded and broken robots wait for a repairer, if one is still operational
+    let repairer_available = robots.iter()
+        .any(|r| r.robot_type == RobotType::Repairer && !matches!(r.mode, RobotMode::Stranded | RobotMode::Broken));
+    let malfunctions = station.malfunctions;
+
+    // NOTE - The life support burns its share of the reserves before the robots act
+    station.drain_life_support(map, robots);
+    match station.take_blackout_change() {
+        Some(true) => report.notices.push("🪫 Réserves d'énergie épuisées: plus aucune construction de robot jusqu'à la prochaine livraison".to_string()),
+        Some(false) => report.notices.push(format!("🔋 Réserves d'énergie rétablies ({} unités)", station.energy_reserves)),
+        None => {}
+    }
+
+    // NOTE - Relays move to where they best link the fleet to the station
+    assign_relay_posts(robots, map, station.relay_range);
+
+    // NOTE - Update each robot
+    for robot in robots.iter_mut() {
+        robot.roll_malfunction(&malfunctions, &mut rng);
+        report.events.extend(robot.update(map, station, &mut occupancy));
+        report.events.extend(station.take_events());
+
+        // NOTE - Emergency: waiting for a repairer that will never come, repatriate the robot
+        if robot.needs_rescue() && !repairer_available {
+            report.events.push(RobotEvent::Repatriated { robot_id: robot.id, x: robot.x, y: robot.y });
+            let stranded_at = (robot.x, robot.y);
+            robot.emergency_recover();
+            station.cancel_rescue(robot.id);
+            occupancy.move_robot(stranded_at, (robot.x, robot.y));
+        }
+    }
+
+    // NOTE - Fleet recall: announce once that every robot is docked
+    station.update_recall(robots);
+
+    // NOTE - Robots cut off from their station: a corridor is carved for them, they head home next cycle
+    if station.repair_access {
+        let lost: Vec<(usize, usize)> = robots.iter().filter(|robot| robot.is_lost()).map(|robot| (robot.x, robot.y)).collect();
+        let carved = map.ensure_station_connectivity(&lost);
+        if !carved.is_empty() {
+            report.notices.push(format!("⛏️ Passage dégagé vers la station: {} obstacle(s) retiré(s) pour {} robot(s) coupé(s)", carved.len(), lost.len()));
+        }
+    }
+
+    // NOTE - Deposits emptied during this cycle: robots heading for them pick another target
+    let changes = map.take_recent_changes();
+    if !changes.is_empty() {
+        for robot in robots.iter_mut() {
+            robot.handle_map_changes(&changes, map, station);
+        }
+    }
+
+    // NOTE - Collectors with nothing left to collect retire, part of their minerals is recovered
+    station.retire_idle_robots(map, robots);
+    let retired = station.take_decommissioned();
+    robots.retain(|robot| !retired.contains(&robot.id));
+
+    // NOTE - Reserves dry for good: explorers done with their work lend their batteries to a waiting collector
+    let pooled = station.pool_idle_batteries(map, robots);
+    if pooled > 0 {
+        report.notices.push(format!("🔋 {} unités d'énergie reprises aux explorateurs au repos pour les derniers collecteurs", pooled));
+    }
+    if station.check_energy_exhausted(map, robots) {
+        report.notices.push(format!("🪫 Plus aucune énergie à récolter: {} ressource(s) laissée(s) sur place", map.reachable_resources().len()));
+    }
+
+    // NOTE - Robots close to each other merge what they learned since their last station visit
+    let peer_sync = sync_peers(robots, station.peer_sync_radius);
+    station.knowledge_exchanges += peer_sync.exchanges;
+    station.conflict_count += peer_sync.conflicts;
+
+    // NOTE - Robots linked to the station through relays sync remotely every few cycles
+    let relay_range = station.relay_range;
+    sync_through_relays(robots, station, map, relay_range);
+
+    // NOTE - With a radio, robots near a station or linked through relays hear what was known a few cycles ago
+    sync_by_radio(robots, station, map);
+
+    // NOTE - Endless mode: part of the depleted resources grow back (never under a robot)
+    if station.endless && iteration > 0 && iteration.is_multiple_of(REGENERATION_INTERVAL) {
+        let regenerated = map.regenerate_resources(&mut rng, &occupancy);
+        if regenerated > 0 {
+            report.notices.push(format!("🌱 {} ressources régénérées", regenerated));
+        }
+    }
+
+    // NOTE - Check if mission is complete BEFORE creating new robots
+    report.outcome = if !station.is_mission_complete(map) {
+        StepOutcome::Running
+    } else if all_robots_home(station, robots) {
+        StepOutcome::MissionComplete
+    } else {
+        StepOutcome::AwaitingReturn
+    };
+    let lost = robots.iter().filter(|robot| robot.is_lost()).count();
+    if report.outcome == StepOutcome::MissionComplete && lost > 0 {
+        report.notices.push(format!("📵 {} robot(s) coupé(s) de la station, laissé(s) sur place", lost));
+    }
+    if report.outcome == StepOutcome::Running && iteration.saturating_sub(station.last_fleet_growth) >= FLEET_GROWTH_INTERVAL {
+        // NOTE - A large fleet gets the station, then the robots upgraded first
+        if station.try_upgrade_station(robots).is_some() {
+            station.last_fleet_growth = iteration;
+        } else if let Some((robot_id, upgrade)) = station.try_upgrade_fleet(robots) {
+            station.last_fleet_growth = iteration;
+            report.notices.push(format!("🔧 Module {:?} installé sur le robot #{}", upgrade, robot_id));
+        } else {
+            // NOTE - One robot per build slot, as long as the reserves last
+            for _ in 0..station.build_slots() {
+                let Some(new_robot) = station.try_create_robot(map, robots) else { break };
+                robots.push(new_robot);
+                station.last_fleet_growth = iteration;
+                report.notices.push(format!("🤖 Nouveau robot déployé! Flotte totale: {} robots", robots.len()));
+            }
+        }
+    }
+
+    // NOTE - Minerals to spare: a forward depot near the remaining deposits shortens the hauls
+    if report.outcome == StepOutcome::Running
+        && let Some((x, y)) = station.try_build_depot(map) {
+        report.notices.push(format!("🏗️ Dépôt avancé construit en ({}, {}) pour {} minerais", x, y, station.depot_cost));
+    }
+
+    // NOTE - Station events outside robot updates (syncs, new robots)
+    report.events.extend(station.take_events());
+    report
+}
@@ -11,9 +11,331 @@
 //! - **Mission Planning**: Determine optimal robot types and deployment strategies
 //! - **Progress Monitoring**: Track mission completion and exploration status
 
-use crate::types::{TileType, RobotType, MAP_SIZE};
-use crate::map::Map;
-use crate::robot::Robot;
+use crate::types::{TileType, RobotType, RobotMode, RobotTask, CollectorPolicy, DayPhase, Inventory, MissionObjectives, MissionPhase, Position, StationUpgrade, Upgrade, VisionModel, MAP_SIZE};
+use crate::map::{Map, MAX_STATIONS};
+use crate::robot::{Robot, RobotConfig};
+use crate::knowledge::{merge_memory, merge_tile, merge_tiles, ExplorationArea, KnowledgeBase, PEER_SYNC_RADIUS, RADIO_LATENCY, RADIO_SYNC_INTERVAL, RELAY_RANGE};
+use crate::energy::{DefaultEnergyModel, EnergyModel};
+use crate::events::RobotEvent;
+use crate::grid::Grid;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+/// Number of cycles an exploration claim stays valid if its target is never reached
+pub const CLAIM_DURATION_TICKS: u32 = 40;
+
+/// Radius (in tiles) around a claimed target that other explorers avoid
+pub const CLAIM_RADIUS: usize = 4;
+
+/// Side (in tiles) of the square sectors the station shares out among explorers (see `Station::assign_sector`)
+pub const SECTOR_SIZE: usize = 5;
+
+/// Sectors per side of the map
+pub const SECTORS_PER_SIDE: usize = MAP_SIZE.div_ceil(SECTOR_SIZE);
+
+/// Number of cycles a resource reservation lasts before it is dropped
+pub const RESERVATION_DURATION_TICKS: u32 = 60;
+
+/// Default energy units the station spends to build a robot (see `Station::robot_cost`)
+pub const ROBOT_ENERGY_COST: u32 = 50;
+
+/// Default minerals the station spends to build a robot
+pub const ROBOT_MINERAL_COST: u32 = 15;
+
+/// Maximum number of explorers the station builds while other robot types are useful
+pub const MAX_EXPLORERS: usize = 3;
+
+/// Maximum number of modules installed on one robot (see `Station::try_install_upgrade`)
+pub const MAX_UPGRADES_PER_ROBOT: usize = 2;
+
+/// Fleet size from which the station upgrades docked robots before building new ones
+pub const UPGRADE_FLEET_SIZE: usize = 8;
+
+/// Default energy units a charging bay transfers to a docked robot per cycle (see `Station::charge_rate`)
+pub const CHARGE_RATE: u32 = 10;
+
+/// Default number of robots charging at the same time (see `Station::charging_bays`)
+pub const CHARGING_BAYS: usize = 2;
+
+/// Default length of a full day/night cycle, in cycles (see `Station::day_night_period`)
+pub const DAY_NIGHT_PERIOD: u32 = 100;
+
+/// Default energy units extracted from one energy deposit (see `Station::energy_per_deposit`)
+pub const ENERGY_PER_DEPOSIT: u32 = 25;
+
+/// Default energy a repairer brings to a stranded robot (see `Station::rescue_energy`)
+pub const RESCUE_ENERGY: f32 = 40.0;
+
+/// Default energy units the station starts the mission with (see `StationConfig::initial_energy`)
+pub const INITIAL_ENERGY: u32 = 100;
+
+/// Default capacity of the energy reserves (see `StationConfig::energy_storage`)
+pub const ENERGY_STORAGE: u32 = 1000;
+
+/// Energy storage added by `StationUpgrade::EnergyStorage`
+pub const STORAGE_UPGRADE_CAPACITY: u32 = 500;
+
+/// Charging bays added by `StationUpgrade::ChargingBays`
+pub const UPGRADE_CHARGING_BAYS: usize = 2;
+
+/// Share (in %) of the cost of a robot saved with `StationUpgrade::LeanAssembly`
+pub const LEAN_ASSEMBLY_DISCOUNT_PERCENT: u32 = 20;
+
+/// Share (in %) of the mineral cost of a robot (`StationConfig::robot_mineral_cost`) recovered when it is decommissioned
+pub const DECOMMISSION_REFUND_PERCENT: u32 = 50;
+
+/// Default cycles a collector stays idle at base, its resource exhausted, before retiring (see `Station::retire_after_idle_ticks`)
+pub const RETIRE_AFTER_IDLE_TICKS: u32 = 50;
+
+/// Cycles the whole fleet rests at base, with no energy left anywhere, before the remaining resources are given up (see `Station::check_energy_exhausted`)
+pub const ENERGY_EXHAUSTION_TICKS: u32 = 50;
+
+/// Default cycles between two searches of a collector parked for lack of resources (see `Station::idle_rescan_ticks`)
+pub const IDLE_RESCAN_TICKS: u32 = 10;
+
+/// Default energy units the life support burns per active robot and cycle (see `Station::life_support_drain`)
+pub const LIFE_SUPPORT_DRAIN: f32 = 0.05;
+
+/// Reserves below which a station with the default economy reports low energy: it can no longer build a robot
+pub const LOW_ENERGY_RESERVES: u32 = ROBOT_ENERGY_COST;
+
+/// Minerals a forward depot costs once enabled (see `Station::depot_cost`)
+pub const DEPOT_MINERAL_COST: u32 = 30;
+
+/// Minimum distance (in tiles) between a forward depot and any other station
+pub const DEPOT_SPACING: usize = 5;
+
+/// Resource tile reserved by a collector through the station.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResourceReservation {
+    /// Collector heading to the resource
+    pub robot_id: usize,
+    /// Station time after which the reservation is dropped
+    pub expires_at: u32,
+}
+
+/// Distress call of a stranded robot, queued at the station until a repairer answers it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RescueRequest {
+    /// Stranded robot
+    pub robot_id: usize,
+    /// Last position reported by the stranded robot
+    pub position: (usize, usize),
+    /// Repairer on its way, if any
+    pub repairer_id: Option<usize>,
+    /// Energy handed over by the repairer, not yet picked up by the stranded robot
+    pub delivered_energy: f32,
+}
+
+/// Random breakdown model of the fleet (see `Robot::roll_malfunction`).
+/// 
+/// Each cycle away from the station, a robot breaks down with probability
+/// `failure_rate`, raised by its age and the distance it has covered.
+/// Minor breakdowns are fixed by the robot's own diagnostics after
+/// `self_repair_ticks` cycles; severe ones need a repairer (or, with no
+/// repairer left, a repatriation by the station). Disabled by default.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// # use ereea::station::MalfunctionConfig;
+/// let config = MalfunctionConfig { failure_rate: 0.001, ..MalfunctionConfig::DEFAULT };
+/// 
+/// assert_eq!(MalfunctionConfig::DEFAULT.probability(1000, 500.0), 0.0);
+/// assert_eq!(config.probability(0, 0.0), 0.001);
+/// // Twice as likely after `wear_ticks` cycles, three times after `wear_distance` tiles as well
+/// assert!((config.probability(500, 0.0) - 0.002).abs() < 1e-12);
+/// assert!((config.probability(500, 200.0) - 0.003).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MalfunctionConfig {
+    /// Breakdown probability per cycle of a brand new robot (0 = no breakdown)
+    pub failure_rate: f64,
+    /// Age (in cycles) adding `failure_rate` to the probability
+    pub wear_ticks: f64,
+    /// Distance traveled (in tiles) adding `failure_rate` to the probability
+    pub wear_distance: f64,
+    /// Share of breakdowns severe enough to need a repairer
+    pub severe_ratio: f64,
+    /// Cycles needed by self-diagnostics to fix a minor breakdown
+    pub self_repair_ticks: u32,
+}
+
+impl MalfunctionConfig {
+    /// No breakdown; wear and repair settings used once `failure_rate` is set
+    pub const DEFAULT: Self = Self {
+        failure_rate: 0.0,
+        wear_ticks: 500.0,
+        wear_distance: 200.0,
+        severe_ratio: 0.25,
+        self_repair_ticks: 10,
+    };
+    
+    /// Breakdown probability for one cycle of a robot of this age and mileage
+    pub fn probability(&self, age_ticks: u32, distance_traveled: f32) -> f64 {
+        let wear = 1.0 + age_ticks as f64 / self.wear_ticks + distance_traveled as f64 / self.wear_distance;
+        (self.failure_rate * wear).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for MalfunctionConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Resources the station spends to build a robot (see `Station::robot_costs`).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RobotCost {
+    /// Energy units taken from the reserves
+    pub energy: u32,
+    /// Minerals taken from the collected minerals
+    pub minerals: u32,
+}
+
+impl RobotCost {
+    /// Historical cost of every robot type
+    pub const DEFAULT: Self = Self { energy: ROBOT_ENERGY_COST, minerals: ROBOT_MINERAL_COST };
+}
+
+impl Default for RobotCost {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Economy of the station: what robots cost, what it starts with and what
+/// it refines from the minerals (see `Station::new_with_config`).
+/// 
+/// `StationConfig::DEFAULT` is the historical economy: 100 units of energy
+/// to start with, robots at 50 energy and 15 minerals, minerals stockpiled
+/// as they are and every unit of the reserves available for manufacturing,
+/// up to 1000 units of energy in storage.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// # use ereea::{map::Map, robot::Robot, station::{RobotCost, Station, StationConfig}, types::RobotType};
+/// let map = Map::new();
+/// let fleet = vec![Robot::new(10, 10, RobotType::EnergyCollector)];
+/// let config = StationConfig { robot_energy_cost: 30, robot_mineral_cost: 10, reserve_floor: 50, ..StationConfig::DEFAULT };
+/// let mut station = Station::new_with_config(config);
+/// station.collected_minerals = 10;
+/// assert_eq!(station.robot_cost(RobotType::Explorer), RobotCost { energy: 30, minerals: 10 });
+/// 
+/// // 100 units of energy: enough for one explorer while keeping 50 in reserve, not for two
+/// assert!(station.try_create_robot(&map, &fleet).is_some());
+/// assert_eq!((station.energy_reserves, station.collected_minerals), (70, 0));
+/// station.collected_minerals = 10;
+/// assert!(!station.can_afford(RobotType::Explorer));
+/// assert!(station.try_create_robot(&map, &fleet).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StationConfig {
+    /// Energy units taken from the reserves to build a robot (unless `Station::robot_costs` says otherwise)
+    pub robot_energy_cost: u32,
+    /// Minerals taken from the collected minerals to build a robot (unless `Station::robot_costs` says otherwise)
+    pub robot_mineral_cost: u32,
+    /// Energy units refined from each delivered mineral, on top of the mineral itself (0 = no refining)
+    pub mineral_to_energy_ratio: f32,
+    /// Energy units in the reserves when the mission starts
+    pub initial_energy: u32,
+    /// Energy units the station keeps when building a robot (0 = it may spend everything)
+    pub reserve_floor: u32,
+    /// Energy units the reserves hold at most, before `StationUpgrade::EnergyStorage` (see `Station::energy_capacity`)
+    pub energy_storage: u32,
+}
+
+impl StationConfig {
+    /// Historical economy of the station
+    pub const DEFAULT: Self = Self {
+        robot_energy_cost: ROBOT_ENERGY_COST,
+        robot_mineral_cost: ROBOT_MINERAL_COST,
+        mineral_to_energy_ratio: 0.0,
+        initial_energy: INITIAL_ENERGY,
+        reserve_floor: 0,
+        energy_storage: ENERGY_STORAGE,
+    };
+    
+    /// Cost of the robots whose type has no override in `Station::robot_costs`
+    pub fn robot_cost(&self) -> RobotCost {
+        RobotCost { energy: self.robot_energy_cost, minerals: self.robot_mineral_cost }
+    }
+}
+
+impl Default for StationConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Exploration target reserved by an explorer through the station.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExplorationClaim {
+    /// Unexplored tile the explorer is heading to
+    pub target: (usize, usize),
+    /// Station time after which the claim is dropped
+    pub expires_at: u32,
+}
+
+/// Structured mission metrics, computed by `Station::statistics`.
+/// 
+/// Single source for every statistics display: server logs, the Earth
+/// client and end-of-mission reports (the struct serializes to JSON).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use ereea::{map::Map, robot::Robot, station::Station};
+/// use ereea::types::{Inventory, RobotType, TileType, MAP_SIZE};
+/// 
+/// let mut map = Map::open();
+/// map.set_tile(4, 3, TileType::Mineral);
+/// map.set_tile(2, 8, TileType::Mineral);
+/// map.set_tile(5, 5, TileType::Scientific);
+/// 
+/// let mut station = Station::new();
+/// station.deposit_resources(Inventory { energy: 0, minerals: 7, science: 2 });
+/// station.tick();
+/// let robots = vec![
+///     Robot::new(0, 0, RobotType::Explorer),
+///     Robot::new(0, 0, RobotType::Explorer),
+///     Robot::new(0, 0, RobotType::MineralCollector),
+/// ];
+/// 
+/// let stats = station.statistics(&map, &robots);
+/// assert_eq!((stats.collected_minerals, stats.collected_scientific_data), (7, 2));
+/// assert_eq!(stats.total_ticks, 1);
+/// assert_eq!(stats.robots_by_type[&RobotType::Explorer], 2);
+/// assert_eq!(stats.robots_by_type.get(&RobotType::EnergyCollector), None);
+/// assert_eq!(stats.resources_remaining[&TileType::Mineral], 2);
+/// assert_eq!(stats.resources_remaining[&TileType::Scientific], 1);
+/// 
+/// let report = serde_json::to_value(&stats).unwrap();
+/// assert_eq!(report["robots_by_type"]["Explorer"], 2);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MissionStats {
+    /// Energy deposits harvested by energy collectors
+    pub collected_energy: u32,
+    /// Mineral units delivered to the station
+    pub collected_minerals: u32,
+    /// Scientific data units delivered to the station
+    pub collected_scientific_data: u32,
+    /// Share of the map in the station's global memory
+    pub exploration_percentage: f32,
+    /// Timestamp conflicts resolved while merging robot memories
+    pub conflict_count: usize,
+    /// Knowledge exchanges performed (robot memories synchronized with the station)
+    pub peer_exchange_count: usize,
+    /// Deployed robots per type (types without any robot are absent), in type order
+    pub robots_by_type: BTreeMap<RobotType, usize>,
+    /// Simulation cycles elapsed since the mission started
+    pub total_ticks: u32,
+    /// Resource tiles still on the map per type (exhausted types are absent), in type order
+    pub resources_remaining: BTreeMap<TileType, usize>,
+}
 
 /// Represents detailed information about a specific map tile's exploration status.
 /// 
@@ -24,19 +346,22 @@ use crate::robot::Robot;
 /// # Conflict Resolution
 /// 
 /// When multiple robots explore the same tile at different times, the station
-/// uses timestamp-based conflict resolution to maintain data accuracy.
+/// uses timestamp-based conflict resolution to maintain data accuracy
+/// (`knowledge::merge_tile`).
 /// 
 /// # Examples
 /// 
 /// ```rust
 /// use ereea::station::TerrainData;
-/// use ereea::types::RobotType;
+/// use ereea::types::{RobotType, TileType};
 /// 
+/// # let existing_data = TerrainData { explored: true, timestamp: 100, robot_id: 1, robot_type: RobotType::Explorer, tile_type: TileType::Mineral };
 /// let tile_data = TerrainData {
 ///     explored: true,
 ///     timestamp: 150,
 ///     robot_id: 3,
 ///     robot_type: RobotType::Explorer,
+///     tile_type: TileType::Empty,
 /// };
 /// 
 /// // Check if this data is more recent than existing data
@@ -44,7 +369,7 @@ use crate::robot::Robot;
 ///     // Update with newer information
 /// }
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TerrainData {
     /// Indicates whether this tile has been explored by any robot
     /// 
@@ -70,6 +395,13 @@ pub struct TerrainData {
     /// Different robot types may have varying sensor capabilities
     /// or exploration accuracies, which could affect data reliability.
     pub robot_type: RobotType,
+    
+    /// Tile contents as last observed, at `timestamp`
+    /// 
+    /// Collectors plan against this observation rather than the actual map,
+    /// so a resource consumed since then stays in memory until a robot sees
+    /// the tile again. Meaningless (`Empty`) while the tile is unexplored.
+    pub tile_type: TileType,
 }
 
 /// Central command and coordination hub for the EREEA exploration mission.
@@ -102,7 +434,7 @@ pub struct TerrainData {
 /// let map = Map::new();
 /// 
 /// // Attempt to create a new robot
-/// if let Some(robot) = station.try_create_robot(&map) {
+/// if let Some(robot) = station.try_create_robot(&map, &[]) {
 ///     println!("Deployed new robot: {:?}", robot.robot_type);
 /// }
 /// 
@@ -117,14 +449,36 @@ pub struct Station {
     /// 
     /// Energy is consumed for:
     /// - Manufacturing new robots (50 units per robot)
-    /// - Station life support and communication systems
+    /// - Station life support (`life_support_drain` per active robot and cycle)
     /// - Emergency robot rescue and recharging operations
     /// 
     /// Energy is replenished by:
-    /// - Robot collection of energy resources
-    /// - Conversion of excess minerals (1:1 ratio)
+    /// - Energy hauled back by EnergyCollector robots (`energy_per_deposit` per deposit)
+    /// 
+    /// The reserves never exceed `energy_capacity`.
     pub energy_reserves: u32,
     
+    /// Energy units the life support burns per active robot and cycle (see `drain_life_support`)
+    pub life_support_drain: f32,
+    
+    /// Fraction of an energy unit already owed to the life support
+    life_support_debt: f32,
+    
+    /// Energy units burned by the life support since the mission started
+    pub life_support_energy: u32,
+    
+    /// Whether explorers and collectors other than energy collectors stay docked while the reserves are empty
+    pub blackout_grounds_robots: bool,
+    
+    /// Whether the reserves were empty at the last `take_blackout_change`
+    blackout_reported: bool,
+    
+    /// Fleet recall in progress: robots head home and stay docked (see `recall_all`)
+    recall_active: bool,
+    
+    /// Whether the whole recalled fleet was reported docked (see `update_recall`)
+    fleet_docked: bool,
+    
     /// Total minerals collected and stored at the station
     /// 
     /// Minerals are essential for:
@@ -136,6 +490,9 @@ pub struct Station {
     /// from mineral deposits scattered across the exoplanet surface.
     pub collected_minerals: u32,
     
+    /// Mineral units delivered since the mission started, those spent since included
+    pub delivered_minerals: u32,
+    
     /// Scientific data points accumulated from exploration activities
     /// 
     /// Scientific data represents:
@@ -148,6 +505,24 @@ pub struct Station {
     /// from points of interest identified during exploration.
     pub collected_scientific_data: u32,
     
+    /// Energy deposits harvested by EnergyCollector robots
+    /// 
+    /// Each deposit yields `energy_per_deposit` units, hauled to the
+    /// station and added to `energy_reserves` when the collector docks.
+    pub collected_energy: u32,
+    
+    /// Energy units an EnergyCollector extracts from one deposit, on average over a day
+    pub energy_per_deposit: u32,
+    
+    /// Energy units extracted from deposits since the mission started (day/night yields included)
+    pub harvested_energy: u32,
+    
+    /// Length of a full day/night cycle, in cycles: day first, then night (0 = always day)
+    /// 
+    /// Deposits yield a quarter more than `energy_per_deposit` by day and a
+    /// quarter less at night, the base value on average (see `energy_yield`).
+    pub day_night_period: u32,
+    
     /// Comprehensive exploration memory containing data for every map tile
     /// 
     /// This 2D grid mirrors the exploration map and stores detailed metadata
@@ -155,8 +530,8 @@ pub struct Station {
     /// authoritative source of exploration knowledge, synchronized from
     /// all active robots during their return visits.
     /// 
-    /// Structure: `global_memory[y][x]` corresponds to map position (x, y)
-    pub global_memory: Vec<Vec<TerrainData>>,
+    /// Structure: `global_memory[(x, y)]` corresponds to map position (x, y)
+    pub global_memory: Grid<TerrainData>,
     
     /// Total number of data conflicts resolved through timestamp-based arbitration
     /// 
@@ -166,6 +541,24 @@ pub struct Station {
     /// may indicate coordination issues or sensor malfunctions.
     pub conflict_count: usize,
     
+    /// Number of knowledge exchanges with robots (`share_knowledge` calls that taught either side something)
+    pub knowledge_exchanges: usize,
+    
+    /// Distance at which robots exchange their knowledge in the field (0 = disabled, see `knowledge::sync_peers`)
+    pub peer_sync_radius: usize,
+    
+    /// Reach of one relay hop towards the station (0 = relays disabled, see `knowledge::sync_through_relays`)
+    pub relay_range: usize,
+    
+    /// Distance to a station within which robots sync by radio (0 = no radio, see `knowledge::sync_by_radio`)
+    pub radio_range: usize,
+    
+    /// Cycles data takes to cross the radio link, both ways
+    pub radio_latency: u32,
+    
+    /// Cycles between two radio synchronizations
+    pub radio_sync_interval: u32,
+    
     /// Identifier that will be assigned to the next robot created
     /// 
     /// Robot IDs are sequential and unique across the entire mission,
@@ -173,6 +566,9 @@ pub struct Station {
     /// performance and contributions. Incremented after each robot creation.
     pub next_robot_id: usize,
     
+    /// Cycle of the last robot built or upgraded (see `simulation::FLEET_GROWTH_INTERVAL`)
+    pub last_fleet_growth: u32,
+    
     /// Global simulation time counter tracking mission duration
     /// 
     /// Incremented once per simulation cycle, this timestamp is used for:
@@ -181,10 +577,172 @@ pub struct Station {
     /// - Performance analysis and optimization
     /// - Synchronization of distributed robot operations
     pub current_time: u32,
+    
+    /// Number of explored tiles in `global_memory`
+    /// 
+    /// Incremented when a tile of the global memory becomes explored, so the
+    /// exploration percentage does not rescan the grid. Call `reindex_memory`
+    /// after editing `global_memory` directly.
+    explored_count: usize,
+    
+    /// Tiles that count in the exploration percentage (see `set_exploration_area`)
+    exploration_area: ExplorationArea,
+    
+    /// Number of explored tiles of the global memory inside `exploration_area`
+    explored_in_area: usize,
+    
+    /// Tiles learned during the last `radio_latency` cycles, oldest first: not on the air yet
+    /// 
+    /// Only kept while the radio is on (see `share_knowledge_by_radio`).
+    radio_recent: VecDeque<(u32, (usize, usize))>,
+    
+    /// Exploration targets currently reserved, keyed by robot ID
+    /// 
+    /// Explorers share the synchronized memory and would otherwise all pick
+    /// the same nearest unexplored tiles. Each explorer claims its target here
+    /// and skips tiles near another robot's claim. Claims are released when
+    /// reached and dropped by `tick` once expired.
+    pub exploration_claims: HashMap<usize, ExplorationClaim>,
+    
+    /// Whether explorers get a sector of their own to explore (see `assign_sector`)
+    pub sector_planning: bool,
+    
+    /// Whether collectors passing next to the station unload on the way
+    /// 
+    /// A collector heading for a deposit with cargo on board goes through
+    /// the station when it costs at most one extra step: it unloads, syncs
+    /// and takes a single charge without keeping a charging bay, then
+    /// resumes its route with the same path and reservation.
+    pub drop_off_in_passing: bool,
+    
+    /// Sector assigned to each explorer, keyed by robot ID
+    sector_assignments: HashMap<usize, (usize, usize)>,
+    
+    /// Resource tiles currently reserved by collectors, keyed by position
+    /// 
+    /// Collectors of the same type would otherwise all head for the same
+    /// nearest resource, and all but one would arrive at an empty tile.
+    /// Each collector holds at most one reservation, released once the
+    /// resource is collected or the robot stops collecting, and dropped by
+    /// `tick` once expired.
+    pub resource_reservations: HashMap<(usize, usize), ResourceReservation>,
+    
+    /// Exploration thresholds at which each collector type starts working
+    /// 
+    /// Read by robots to decide whether they may leave the station, and by
+    /// `get_status` to name the current mission phase. Defaults to
+    /// `CollectorPolicy::DEFAULT`; the simulation can override it at startup.
+    pub collector_policy: CollectorPolicy,
+    
+    /// Distress calls of stranded (or severely broken) robots, oldest first
+    /// 
+    /// Stranded robots report (and refresh) their position every cycle;
+    /// each call is answered by at most one repairer. A call is removed
+    /// once the stranded robot picks up the delivered energy, or when it
+    /// gets going again on its own.
+    pub rescue_requests: Vec<RescueRequest>,
+    
+    /// Energy a repairer loads at the station for each rescue
+    pub rescue_energy: f32,
+    
+    /// Random breakdowns of the robots (none by default)
+    pub malfunctions: MalfunctionConfig,
+    
+    /// Energy units a charging bay transfers to a docked robot per cycle
+    pub charge_rate: u32,
+    
+    /// Number of robots charging at the same time; the others queue
+    pub charging_bays: usize,
+    
+    /// Reserves kept by the station: below this floor, charging is refused
+    pub charge_reserve_floor: u32,
+    
+    /// Energy units transferred to docked robots since the mission started
+    pub charged_energy: u32,
+    
+    /// Robots currently holding a charging bay
+    charging_robots: Vec<usize>,
+    
+    /// Robots waiting for a free charging bay, first come first served
+    charging_queue: VecDeque<usize>,
+    
+    /// Cycles a collector must spend idle at base, with its resource exhausted, before it retires (0 = never)
+    pub retire_after_idle_ticks: u32,
+    
+    /// Cycles between two searches of the station memory by a collector parked for lack of resources
+    pub idle_rescan_ticks: u32,
+    
+    /// Consecutive cycles each retirable collector has spent idle at base (see `retire_idle_robots`)
+    idle_at_base: HashMap<usize, u32>,
+    
+    /// Decommissioned robots still to be removed from the fleet (see `take_decommissioned`)
+    decommissioned: Vec<usize>,
+    
+    /// Whether the resources left on the map were given up for lack of energy (see `check_energy_exhausted`)
+    pub energy_exhausted: bool,
+    
+    /// Consecutive cycles spent in the energy dead end (see `check_energy_exhausted`)
+    exhausted_ticks: u32,
+    
+    /// Energy model given to every robot the station builds (see `energy::EnergyModel`)
+    pub energy_model: Arc<dyn EnergyModel>,
+    
+    /// Vision given to every robot the station builds (see `Robot::update_memory`)
+    pub vision_model: VisionModel,
+    
+    /// Configurations replacing `RobotConfig::for_type` for the robots the station builds (see `robot_config`)
+    pub robot_configs: BTreeMap<RobotType, RobotConfig>,
+    
+    /// Costs replacing the cost set by `config` for the robots the station builds (see `robot_cost`)
+    pub robot_costs: BTreeMap<RobotType, RobotCost>,
+    
+    /// Economy of the station: robot costs, starting energy, refining and reserve floor
+    pub config: StationConfig,
+    
+    /// Fraction of a unit of refined energy not credited yet (see `StationConfig::mineral_to_energy_ratio`)
+    refining_remainder: f32,
+    
+    /// Tasks dispatched to each robot, handed over at its next update (see `dispatch_task`)
+    pending_tasks: HashMap<usize, VecDeque<RobotTask>>,
+    
+    /// Events since the last `take_events` (distress calls, new robots, modules...)
+    events: Vec<RobotEvent>,
+    
+    /// Endless (sandbox) mode: the mission never completes
+    /// 
+    /// Meant for runs where depleted resources regenerate over time
+    /// (`Map::regenerate_resources`): `is_mission_complete` then always
+    /// returns `false`. Enabled by the simulation's `--endless` flag.
+    pub endless: bool,
+    
+    /// Exploration-only scenario: the station builds nothing but explorers (see `try_create_robot`)
+    pub explorers_only: bool,
+    
+    /// Minerals spent on a forward depot (0 = the station never builds one, see `try_build_depot`)
+    pub depot_cost: u32,
+    
+    /// Whether the station clears a way back for robots cut off from it (see `Map::ensure_station_connectivity`)
+    pub repair_access: bool,
+    
+    /// Upgrades installed on the station, in purchase order (see `purchase_upgrade`)
+    pub upgrades: Vec<StationUpgrade>,
+    
+    /// Whether the station buys its own upgrades once the fleet is large (see `try_upgrade_station`)
+    pub auto_upgrades: bool,
+    
+    /// Loads unloaded at a station since the mission started (see `average_haul_distance`)
+    hauls: u32,
+    
+    /// Sum of the distances those loads were carried
+    haul_distance: u64,
+    
+    /// Goals that end the mission (see `is_mission_complete`)
+    pub objectives: MissionObjectives,
 }
 
 impl Station {
-    /// Constructs a new Station with initial default values and empty exploration memory.
+    /// Constructs a new Station with the historical economy (`StationConfig::DEFAULT`)
+    /// and empty exploration memory.
     /// 
     /// The constructor initializes all station systems and prepares for mission operations.
     /// Initial resource allocations are balanced to enable immediate robot deployment
@@ -205,36 +763,115 @@ impl Station {
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::station::Station;
     /// let station = Station::new();
     /// assert_eq!(station.energy_reserves, 100);
     /// assert_eq!(station.next_robot_id, 1);
     /// assert_eq!(station.get_exploration_percentage(), 0.0);
     /// ```
     pub fn new() -> Self {
+        Self::new_with_config(StationConfig::DEFAULT)
+    }
+    
+    /// Constructs a new Station running on the given economy.
+    /// 
+    /// Its reserves start at `config.initial_energy` (at most `config.energy_storage`); everything else is as
+    /// in `Station::new`, which is this constructor with `StationConfig::DEFAULT`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::station::{RobotCost, Station, StationConfig};
+    /// # use ereea::types::RobotType;
+    /// let station = Station::new_with_config(StationConfig { initial_energy: 250, ..StationConfig::DEFAULT });
+    /// assert_eq!(station.energy_reserves, 250);
+    /// 
+    /// // The default economy is the one of `Station::new`
+    /// let (default, historical) = (Station::new_with_config(StationConfig::default()), Station::new());
+    /// assert_eq!(default.config, historical.config);
+    /// assert_eq!(default.energy_reserves, historical.energy_reserves);
+    /// assert_eq!(default.robot_cost(RobotType::Explorer), RobotCost::DEFAULT);
+    /// ```
+    pub fn new_with_config(config: StationConfig) -> Self {
         // NOTE - Initializing global exploration memory grid
-        let mut global_memory = Vec::with_capacity(MAP_SIZE);
-        for _ in 0..MAP_SIZE {
-            let row = vec![
-                TerrainData {
-                    explored: false,                    // All tiles start unexplored
-                    timestamp: 0,                       // No exploration timestamp yet
-                    robot_id: 0,                        // No robot has visited yet
-                    robot_type: RobotType::Explorer,    // Default robot type for unvisited tiles
-                }; 
-                MAP_SIZE
-            ];
-            global_memory.push(row);
-        }
+        let global_memory = Grid::filled(TerrainData {
+            explored: false,                    // All tiles start unexplored
+            timestamp: 0,                       // No exploration timestamp yet
+            robot_id: 0,                        // No robot has visited yet
+            robot_type: RobotType::Explorer,    // Default robot type for unvisited tiles
+            tile_type: TileType::Empty,         // Contents unknown until observed
+        });
         
         // NOTE - Station struct initialization with default values
         Self {
-            energy_reserves: 100,              // Starting energy for initial operations
+            energy_reserves: config.initial_energy.min(config.energy_storage), // Starting energy for initial operations
+            life_support_drain: LIFE_SUPPORT_DRAIN, // A ten-robot fleet burns one unit every two cycles
+            life_support_debt: 0.0,            // Nothing owed yet
+            life_support_energy: 0,            // Nothing burned yet
+            blackout_grounds_robots: false,    // Robots keep working through a blackout
+            blackout_reported: false,          // The reserves start full
+            recall_active: false,              // Robots work freely
+            fleet_docked: false,
             collected_minerals: 0,             // No minerals until robots collect them
+            delivered_minerals: 0,
             collected_scientific_data: 0,      // No scientific data initially
+            collected_energy: 0,               // No energy harvested yet
+            energy_per_deposit: ENERGY_PER_DEPOSIT, // One deposit pays half a robot
+            harvested_energy: 0,               // Nothing extracted yet
+            day_night_period: DAY_NIGHT_PERIOD, // Fifty cycles of daylight, fifty of night
             global_memory,                     // Freshly initialized exploration grid
             conflict_count: 0,                 // No conflicts yet
+            knowledge_exchanges: 0,            // No robot synchronized yet
+            peer_sync_radius: PEER_SYNC_RADIUS, // Robots a few tiles apart talk to each other
+            relay_range: RELAY_RANGE,          // One relay hop covers a few tiles
+            radio_range: 0,                    // Knowledge only flows at the station
+            radio_latency: RADIO_LATENCY,      // A few cycles of transmission delay
+            radio_sync_interval: RADIO_SYNC_INTERVAL, // Same pace as the relay chains
             next_robot_id: 1,                  // First robot will be ID #1
+            last_fleet_growth: 0,              // Nothing built yet
             current_time: 0,                   // Mission starts at time 0
+            explored_count: 0,                 // Nothing explored yet
+            exploration_area: ExplorationArea::whole_map(), // Every tile counts until told otherwise
+            explored_in_area: 0,
+            radio_recent: VecDeque::new(),     // Nothing learned yet
+            exploration_claims: HashMap::new(), // No exploration target reserved yet
+            sector_planning: true,             // Explorers share the map out
+            drop_off_in_passing: true,         // Collectors unload on the way
+            sector_assignments: HashMap::new(), // No sector assigned yet
+            resource_reservations: HashMap::new(), // No resource reserved yet
+            collector_policy: CollectorPolicy::DEFAULT, // Historical collector pacing
+            rescue_requests: Vec::new(),       // Nobody stranded yet
+            rescue_energy: RESCUE_ENERGY,      // Enough for most trips home
+            malfunctions: MalfunctionConfig::DEFAULT, // Robots never break down
+            charge_rate: CHARGE_RATE,          // A robot fills up in about ten cycles
+            charging_bays: CHARGING_BAYS,      // Two robots charge at once
+            charge_reserve_floor: 0,           // Every unit of energy may go to the robots
+            charged_energy: 0,                 // No robot charged yet
+            charging_robots: Vec::new(),       // Nobody charging yet
+            charging_queue: VecDeque::new(),   // Nobody waiting yet
+            retire_after_idle_ticks: RETIRE_AFTER_IDLE_TICKS, // Idle collectors eventually retire
+            idle_rescan_ticks: IDLE_RESCAN_TICKS, // Parked collectors look for new discoveries now and then
+            idle_at_base: HashMap::new(),      // Nobody idle yet
+            decommissioned: Vec::new(),        // Whole fleet in service
+            energy_exhausted: false,           // Every resource is still worth collecting
+            exhausted_ticks: 0,                // Not in the energy dead end
+            energy_model: Arc::new(DefaultEnergyModel), // Historical energy economy
+            vision_model: VisionModel::default(), // Obstacles block the view
+            robot_configs: BTreeMap::new(),    // Factory defaults for every type
+            robot_costs: BTreeMap::new(),      // Every type costs the same
+            config,
+            refining_remainder: 0.0,           // Nothing refined yet
+            pending_tasks: HashMap::new(),     // No task dispatched yet
+            events: Vec::new(),                // Nothing happened yet
+            endless: false,                    // The mission ends once its objectives are met
+            explorers_only: false,             // The whole fleet is built
+            depot_cost: 0,                     // A single station unless told otherwise
+            repair_access: false,              // Walled-off robots stay where they are
+            upgrades: Vec::new(),              // Bare station
+            auto_upgrades: false,              // Every spare resource goes to the fleet
+            hauls: 0,                          // Nothing delivered yet
+            haul_distance: 0,
+            objectives: MissionObjectives::DEFAULT, // Everything explored, collected and brought home
         }
     }
     
@@ -253,6 +890,7 @@ impl Station {
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::station::Station;
     /// let mut station = Station::new();
     /// assert_eq!(station.current_time, 0);
     /// 
@@ -262,108 +900,1447 @@ impl Station {
     pub fn tick(&mut self) {
         // NOTE - Advancing simulation time
         self.current_time += 1;
+        
+        // NOTE - Dropping exploration claims that were never reached in time
+        let now = self.current_time;
+        self.exploration_claims.retain(|_, claim| claim.expires_at > now);
+        
+        // NOTE - Dropping resource reservations held for too long
+        self.resource_reservations.retain(|_, reservation| reservation.expires_at > now);
     }
     
-    /// Attempts to create a new robot for exploration or resource collection.
+    /// Burns the life support energy of one cycle and returns the units taken from the reserves.
     /// 
-    /// This method consumes a portion of the station's energy and minerals
-    /// reserves to manufacture a new robot. The type of robot created depends
-    /// on the current mission needs and resource availability.
+    /// The drain is `life_support_drain` per active robot: robots on standby
+    /// (idle at a station) do not count. Fractions of a unit add up from one
+    /// cycle to the next. Once the reserves are empty (`is_blacked_out`), the
+    /// station builds no more robots and, with `blackout_grounds_robots`,
+    /// keeps explorers and the other collectors docked until the energy
+    /// collectors deliver.
     /// 
-    /// # Resource Costs
+    /// # Examples
     /// 
-    /// - Energy: 50 units are consumed from the station's reserves
-    /// - Minerals: 15 units are deducted from the collected minerals
+    /// Without energy income the reserves decline, then recover when a collector delivers:
     /// 
-    /// # Returns
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::station::Station;
+    /// # use ereea::types::{Inventory, RobotType};
+    /// let map = Map::new();
+    /// let mut station = Station::new();
+    /// station.life_support_drain = 0.5;
+    /// let fleet: Vec<Robot> = (0..4).map(|_| Robot::new(0, 0, RobotType::Explorer)).collect();
     /// 
-    /// An `Option<Robot>` which is:
-    /// - `Some(robot)`: A new robot instance configured for its mission
-    /// - `None`: Insufficient resources to create a robot
+    /// // Four active robots burn two units per cycle
+    /// assert_eq!(station.drain_life_support(&map, &fleet), 2);
+    /// for _ in 0..49 {
+    ///     station.drain_life_support(&map, &fleet);
+    /// }
+    /// assert_eq!(station.energy_reserves, 0);
+    /// assert!(station.is_blacked_out());
+    /// assert!(station.try_create_robot(&map, &fleet).is_none());
+    /// 
+    /// // An energy delivery restores the reserves
+    /// station.deposit_resources(Inventory { energy: 75, ..Inventory::default() });
+    /// assert!(!station.is_blacked_out());
+    /// assert_eq!(station.energy_reserves, 75);
+    /// assert!(!station.is_energy_low());
+    /// assert_eq!(station.life_support_energy, 100);
+    /// ```
+    pub fn drain_life_support(&mut self, map: &Map, robots: &[Robot]) -> u32 {
+        let active_robots = robots.iter()
+            .filter(|robot| !(robot.mode == RobotMode::Idle && map.is_station(robot.x, robot.y)))
+            .count();
+        self.life_support_debt += self.life_support_drain * active_robots as f32;
+        
+        // Seules les unités entières sont prélevées, le reste attend le cycle suivant
+        let owed = self.life_support_debt.floor();
+        self.life_support_debt -= owed;
+        let burned = (owed as u32).min(self.energy_reserves);
+        self.energy_reserves -= burned;
+        self.life_support_energy += burned;
+        burned
+    }
+    
+    /// Tells whether the reserves are empty: no robot can be built until energy is delivered
+    pub fn is_blacked_out(&self) -> bool {
+        self.energy_reserves == 0
+    }
+    
+    /// Reports a blackout starting (`Some(true)`) or ending (`Some(false)`) since the last call
+    pub fn take_blackout_change(&mut self) -> Option<bool> {
+        let blacked_out = self.is_blacked_out();
+        (blacked_out != self.blackout_reported).then(|| {
+            self.blackout_reported = blacked_out;
+            blacked_out
+        })
+    }
+    
+    /// Tells whether the reserves are too low to build a robot at the cost set
+    /// by `config`, its reserve floor kept (`LOW_ENERGY_RESERVES` by default)
+    pub fn is_energy_low(&self) -> bool {
+        self.energy_reserves < self.config.robot_energy_cost + self.config.reserve_floor
+    }
+    
+    /// Tells whether a robot of this type must stay docked (see `blackout_grounds_robots`)
+    pub fn grounds(&self, robot_type: RobotType) -> bool {
+        self.blackout_grounds_robots && self.is_blacked_out() && robot_type != RobotType::EnergyCollector
+    }
+    
+    /// Calls the whole fleet home.
+    /// 
+    /// From their next update, explorers, collectors and relays drop their
+    /// targets, head for their home station and stay there (unloaded,
+    /// synchronized and charged) until `resume_operations`; their queued
+    /// tasks wait as well. Repairers keep answering distress calls, so that
+    /// stranded robots make it home too. Reported by a `RecallStarted`
+    /// event, then `FleetDocked` once every robot is docked (see
+    /// `update_recall`). Does nothing while a recall is already active.
     /// 
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType};
+    /// let mut map = Map::open();
+    /// (map.station_x, map.station_y) = (10, 10);
     /// let mut station = Station::new();
-    /// let map = Map::new();
+    /// let mut occupancy = OccupancyGrid::new(10, 10);
+    /// let mut robot = Robot::new_with_memory(16, 10, RobotType::Explorer, 1, 10, 10, station.global_memory.clone());
+    /// robot.mode = RobotMode::Exploring;
+    /// 
+    /// station.recall_all();
+    /// assert!(station.is_recall_active());
+    /// for _ in 0..30 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!((robot.x, robot.y, robot.mode), (10, 10, RobotMode::Idle));
     /// 
-    /// // Create a new robot for exploration
-    /// if let Some(robot) = station.try_create_robot(&map) {
-    ///     println!("New robot created: ID={}, Type={:?}", robot.id, robot.robot_type);
-    /// } else {
-    ///     println!("Not enough resources to create a robot.");
+    /// // Back to work once operations resume
+    /// station.resume_operations();
+    /// for _ in 0..5 {
+    ///     station.tick();
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
     /// }
+    /// assert_ne!((robot.x, robot.y), (10, 10));
     /// ```
-    pub fn try_create_robot(&mut self, map: &Map) -> Option<Robot> {
-        // NOTE - Robot creation resource cost check
-        let energy_cost = 50;   // Énergie requise
-        let mineral_cost = 15;  // Minerais requis
-        
-        // NOTE - Checking if enough resources to create a robot
-        if self.energy_reserves >= energy_cost && self.collected_minerals >= mineral_cost {
-            // NOTE - Determining most needed robot type
-            let robot_type = self.determine_needed_robot_type(map);
-            
-            // NOTE - Consuming resources for robot creation
-            self.energy_reserves -= energy_cost;
-            self.collected_minerals -= mineral_cost;
-            
-            println!("Station: Création d'un nouveau robot #{} de type {:?}", 
-                     self.next_robot_id, robot_type);
-            
-            // NOTE - Creating robot with current global memory
-            let new_robot = Robot::new_with_memory(
-                map.station_x, 
-                map.station_y, 
-                robot_type, 
-                self.next_robot_id,
-                map.station_x, 
-                map.station_y,
-                self.global_memory.clone()
-            );
-            
-            // NOTE - Incrementing robot ID counter
-            self.next_robot_id += 1;
-            
-            return Some(new_robot);
+    pub fn recall_all(&mut self) {
+        if !self.recall_active {
+            self.recall_active = true;
+            self.fleet_docked = false;
+            self.events.push(RobotEvent::RecallStarted);
         }
-        
-        None // Pas assez de ressources
     }
     
-    /// Determines the most needed type of robot based on current mission status and resource availability.
-    /// 
-    /// This function analyzes the exploration progress, resource counts, and existing robot types
-    /// to decide whether to create more Explorers, EnergyCollectors, MineralCollectors, or ScientificCollectors.
-    /// 
-    /// # Returns
+    /// Ends a recall (see `recall_all`): robots go back to work from their next update
+    pub fn resume_operations(&mut self) {
+        if self.recall_active {
+            self.recall_active = false;
+            self.events.push(RobotEvent::OperationsResumed);
+        }
+    }
+    
+    /// Tells whether a fleet recall is in progress (see `recall_all`)
+    pub fn is_recall_active(&self) -> bool {
+        self.recall_active
+    }
+    
+    /// Reports (once per recall) that every recalled robot is docked.
+    /// 
+    /// A robot counts as docked at its home station, idle or charging;
+    /// robots cut off from their station (`Robot::is_lost`) are not waited
+    /// for. Called by `step_simulation` after the robots acted.
+    pub fn update_recall(&mut self, robots: &[Robot]) {
+        if !self.recall_active || self.fleet_docked {
+            return;
+        }
+        let docked = |robot: &&Robot| (robot.x, robot.y) == (robot.home_station_x, robot.home_station_y)
+            && matches!(robot.mode, RobotMode::Idle | RobotMode::Charging);
+        if robots.iter().filter(|robot| !robot.is_lost()).all(|robot| docked(&robot)) {
+            self.fleet_docked = true;
+            self.events.push(RobotEvent::FleetDocked { robots: robots.iter().filter(docked).count() });
+        }
+    }
+    
+    /// Reserves an exploration target for a robot, replacing its previous claim.
     /// 
-    /// The `RobotType` that is deemed most necessary for the next phase of the mission.
+    /// The claim lasts `CLAIM_DURATION_TICKS` cycles unless released earlier.
+    /// While it is active, other robots asking `is_claimed_by_other` for a tile
+    /// within `CLAIM_RADIUS` of the target are told to look elsewhere.
     /// 
     /// # Examples
     /// 
     /// ```rust
-    /// let station = Station::new();
-    /// let map = Map::new();
-    /// 
-    /// // Initially, explorers are needed
-    /// assert_eq!(station.determine_needed_robot_type(&map), RobotType::Explorer);
-    /// 
-    /// // After some exploration, more energy collectors might be needed
-    /// station.global_memory[0][0].explored = true;
-    /// station.global_memory[0][0].timestamp = 1;
-    /// assert_eq!(station.determine_needed_robot_type(&map), RobotType::EnergyCollector);
+    /// # use ereea::station::{Station, CLAIM_DURATION_TICKS};
+    /// let mut station = Station::new();
+    /// station.reserve_exploration_target(1, (10, 10));
+    /// assert_eq!(station.exploration_claim_count(), 1);
+    /// 
+    /// // Other robots avoid the area, the owner does not
+    /// assert!(station.is_claimed_by_other(2, (12, 9)));
+    /// assert!(!station.is_claimed_by_other(1, (10, 10)));
+    /// assert!(!station.is_claimed_by_other(2, (19, 19)));
+    /// 
+    /// // Released when reached...
+    /// station.release_exploration_target(1);
+    /// assert_eq!(station.exploration_claim_count(), 0);
+    /// 
+    /// // ...or dropped once expired
+    /// station.reserve_exploration_target(1, (10, 10));
+    /// for _ in 0..CLAIM_DURATION_TICKS {
+    ///     station.tick();
+    /// }
+    /// assert!(!station.is_claimed_by_other(2, (10, 10)));
+    /// assert_eq!(station.exploration_claim_count(), 0);
     /// ```
-    fn determine_needed_robot_type(&self, map: &Map) -> RobotType {
-        // NOTE - Calculating exploration percentage
-        let exploration_percentage = self.get_exploration_percentage();
-        
-        // NOTE - Phase 1: Prioritize exploration
-        if exploration_percentage < 50.0 {
-            return RobotType::Explorer;
-        }
-        
-        // NOTE - Counting remaining resources on the map
+    pub fn reserve_exploration_target(&mut self, robot_id: usize, target: (usize, usize)) {
+        self.exploration_claims.insert(robot_id, ExplorationClaim {
+            target,
+            expires_at: self.current_time + CLAIM_DURATION_TICKS,
+        });
+    }
+    
+    /// Releases the exploration claim of a robot, if any
+    pub fn release_exploration_target(&mut self, robot_id: usize) {
+        self.exploration_claims.remove(&robot_id);
+    }
+    
+    /// Returns the target currently claimed by a robot, if any
+    pub fn exploration_target_of(&self, robot_id: usize) -> Option<(usize, usize)> {
+        self.exploration_claims.get(&robot_id).map(|claim| claim.target)
+    }
+    
+    /// Checks whether a tile lies within `CLAIM_RADIUS` of another robot's claim
+    pub fn is_claimed_by_other(&self, robot_id: usize, pos: (usize, usize)) -> bool {
+        self.exploration_claims.iter().any(|(&owner, claim)| {
+            owner != robot_id
+                && pos.0.abs_diff(claim.target.0) <= CLAIM_RADIUS
+                && pos.1.abs_diff(claim.target.1) <= CLAIM_RADIUS
+        })
+    }
+    
+    /// Number of active exploration claims (debugging aid)
+    pub fn exploration_claim_count(&self) -> usize {
+        self.exploration_claims.len()
+    }
+    
+    /// Unexplored tiles of the exploration area in each sector of the
+    /// station memory, indexed `[row][column]` (see `SECTOR_SIZE`).
+    pub fn sector_unexplored_counts(&self) -> Vec<Vec<usize>> {
+        self.count_unexplored_by_sector(|_, _| true)
+    }
+    
+    // NOTE - Unexplored tiles of the exploration area in each sector, among the tiles `unknown` accepts
+    fn count_unexplored_by_sector(&self, unknown: impl Fn(usize, usize) -> bool) -> Vec<Vec<usize>> {
+        let mut counts = vec![vec![0; SECTORS_PER_SIDE]; SECTORS_PER_SIDE];
+        for ((x, y), tile) in self.global_memory.iter_coords() {
+            if !tile.explored && self.exploration_area.contains(x, y) && unknown(x, y) {
+                counts[y / SECTOR_SIZE][x / SECTOR_SIZE] += 1;
+            }
+        }
+        counts
+    }
+    
+    /// Assigns an explorer the sector it should explore next, as
+    /// `(column, row)` of the sector grid, replacing its previous one.
+    /// 
+    /// Tiles count as unexplored when neither the station nor the explorer
+    /// (which may have learned more since its last sync) knows them, and the
+    /// explorer can still reach them (see `Robot::reachable_unknown_tiles`). The
+    /// station picks, among the sectors no other explorer holds, the one with
+    /// the most unexplored tiles, the nearest to the explorer on a tie. Once
+    /// every sector with unexplored tiles is taken, explorers share them.
+    /// Returns `None` (and assigns nothing) when nothing is left to explore.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{robot::Robot, station::{Station, SECTOR_SIZE}, types::RobotType};
+    /// let mut station = Station::new();
+    /// // The area around the station (10, 10) is already known
+    /// for y in 5..15 {
+    ///     for x in 5..15 {
+    ///         station.global_memory[(x, y)].explored = true;
+    ///     }
+    /// }
+    /// station.reindex_memory();
+    /// let mut explorers: Vec<Robot> = (1..=3)
+    ///     .map(|id| Robot::new_with_memory(10, 10, RobotType::Explorer, id, 10, 10, station.global_memory.clone()))
+    ///     .collect();
+    /// 
+    /// // Three explorers leaving the station get three distinct untouched sectors
+    /// let sectors: Vec<_> = explorers.iter().map(|robot| station.assign_sector(robot).unwrap()).collect();
+    /// assert!(sectors.iter().all(|&(column, row)| station.sector_unexplored_counts()[row][column] == SECTOR_SIZE * SECTOR_SIZE));
+    /// assert!(sectors[0] != sectors[1] && sectors[1] != sectors[2] && sectors[0] != sectors[2]);
+    /// 
+    /// // Asking again hands the explorer's own sector back to the pool
+    /// assert_eq!(station.assign_sector(&explorers[0]), Some(sectors[0]));
+    /// assert_eq!(station.sector_of(1), Some(sectors[0]));
+    /// 
+    /// // An explorer that mapped its sector since its last sync gets another one
+    /// let (column, row) = sectors[0];
+    /// for y in row * SECTOR_SIZE..(row + 1) * SECTOR_SIZE {
+    ///     for x in column * SECTOR_SIZE..(column + 1) * SECTOR_SIZE {
+    ///         explorers[0].memory[(x, y)].explored = true;
+    ///     }
+    /// }
+    /// assert!(!sectors.contains(&station.assign_sector(&explorers[0]).unwrap()));
+    /// 
+    /// // Nothing left to explore: no sector
+    /// for tile in station.global_memory.iter_mut() {
+    ///     tile.explored = true;
+    /// }
+    /// station.reindex_memory();
+    /// assert_eq!(station.assign_sector(&explorers[1]), None);
+    /// assert_eq!(station.sector_of(2), None);
+    /// ```
+    /// 
+    /// Explorers bring each mapped sector home, so the station knows 90 % of
+    /// the map sooner than when they roam the frontier on their own:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station, types::RobotType};
+    /// fn cycles_to_90_percent(seed: u32, sector_planning: bool) -> u32 {
+    ///     let mut map = Map::with_seed(seed);
+    ///     let mut station = Station::new();
+    ///     station.sector_planning = sector_planning;
+    ///     let mut robots: Vec<Robot> = (1..=3)
+    ///         .map(|id| {
+    ///             let mut robot = Robot::new_with_memory(map.station_x, map.station_y, RobotType::Explorer, id,
+    ///                                                    map.station_x, map.station_y, station.global_memory.clone());
+    ///             robot.seed_rng(map.seed());
+    ///             robot
+    ///         })
+    ///         .collect();
+    ///     
+    ///     for tick in 1..=1000 {
+    ///         station.tick();
+    ///         let mut occupancy = OccupancyGrid::from_robots(&robots, map.station_x, map.station_y);
+    ///         for robot in robots.iter_mut() {
+    ///             robot.update(&mut map, &mut station, &mut occupancy);
+    ///         }
+    ///         if station.get_exploration_percentage() >= 90.0 {
+    ///             return tick;
+    ///         }
+    ///     }
+    ///     panic!("seed {}: 90 % never reached", seed);
+    /// }
+    /// 
+    /// for seed in 1..=3 {
+    ///     let (sectors, roaming) = (cycles_to_90_percent(seed, true), cycles_to_90_percent(seed, false));
+    ///     assert!(sectors < roaming, "seed {}: {} cycles vs {}", seed, sectors, roaming);
+    /// }
+    /// ```
+    pub fn assign_sector(&mut self, robot: &Robot) -> Option<(usize, usize)> {
+        self.sector_assignments.remove(&robot.id);
+        let reachable = robot.reachable_unknown_tiles();
+        let counts = self.count_unexplored_by_sector(|x, y| reachable.contains(&(x, y)));
+        let from = (robot.x, robot.y);
+        let center = |column: usize, row: usize| {
+            ((column * SECTOR_SIZE + SECTOR_SIZE / 2).min(MAP_SIZE - 1), (row * SECTOR_SIZE + SECTOR_SIZE / 2).min(MAP_SIZE - 1))
+        };
+        let distance = |(x, y): (usize, usize)| from.0.abs_diff(x).max(from.1.abs_diff(y));
+        
+        let sectors = (0..SECTORS_PER_SIDE)
+            .flat_map(|row| (0..SECTORS_PER_SIDE).map(move |column| (column, row)))
+            .filter(|&(column, row)| counts[row][column] > 0);
+        // Le plus de cases inconnues, puis le plus proche (puis par position, pour un choix reproductible)
+        let priority = |&(column, row): &(usize, usize)| {
+            (std::cmp::Reverse(counts[row][column]), distance(center(column, row)), row, column)
+        };
+        let sector = sectors.clone()
+            .filter(|sector| !self.sector_assignments.values().any(|taken| taken == sector))
+            .min_by_key(priority)
+            .or_else(|| sectors.min_by_key(priority))?;
+        
+        self.sector_assignments.insert(robot.id, sector);
+        Some(sector)
+    }
+    
+    /// Sector currently assigned to an explorer, if any
+    pub fn sector_of(&self, robot_id: usize) -> Option<(usize, usize)> {
+        self.sector_assignments.get(&robot_id).copied()
+    }
+    
+    /// Drops the sector assigned to an explorer, if any
+    pub fn release_sector(&mut self, robot_id: usize) {
+        self.sector_assignments.remove(&robot_id);
+    }
+    
+    /// Reserves the resource at `(x, y)` for a collector, replacing its previous reservation.
+    /// 
+    /// Returns `false`, leaving the robot's current reservation untouched, if
+    /// another robot already holds the tile. Reserving a tile the robot already
+    /// holds renews it for `RESERVATION_DURATION_TICKS` cycles.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::station::{Station, RESERVATION_DURATION_TICKS};
+    /// let mut station = Station::new();
+    /// assert!(station.reserve_target(1, 4, 4));
+    /// assert!(!station.reserve_target(2, 4, 4));
+    /// assert!(station.is_target_reserved_by_other(2, (4, 4)));
+    /// 
+    /// // One reservation per robot: reserving elsewhere frees the first tile
+    /// assert!(station.reserve_target(1, 9, 9));
+    /// assert!(station.reserve_target(2, 4, 4));
+    /// 
+    /// // Released explicitly, or dropped once expired
+    /// station.release_target(1);
+    /// assert_eq!(station.reserved_target_of(1), None);
+    /// for _ in 0..RESERVATION_DURATION_TICKS {
+    ///     station.tick();
+    /// }
+    /// assert!(station.resource_reservations.is_empty());
+    /// ```
+    pub fn reserve_target(&mut self, robot_id: usize, x: usize, y: usize) -> bool {
+        if self.is_target_reserved_by_other(robot_id, (x, y)) {
+            return false;
+        }
+        
+        self.release_target(robot_id);
+        self.resource_reservations.insert((x, y), ResourceReservation {
+            robot_id,
+            expires_at: self.current_time + RESERVATION_DURATION_TICKS,
+        });
+        true
+    }
+    
+    /// Releases the resource reservation of a robot, if any
+    pub fn release_target(&mut self, robot_id: usize) {
+        self.resource_reservations.retain(|_, reservation| reservation.robot_id != robot_id);
+    }
+    
+    /// Returns the resource tile currently reserved by a robot, if any
+    pub fn reserved_target_of(&self, robot_id: usize) -> Option<(usize, usize)> {
+        self.resource_reservations.iter()
+            .find(|(_, reservation)| reservation.robot_id == robot_id)
+            .map(|(&pos, _)| pos)
+    }
+    
+    /// Checks whether a resource tile is reserved by another robot
+    pub fn is_target_reserved_by_other(&self, robot_id: usize, pos: (usize, usize)) -> bool {
+        self.resource_reservations.get(&pos)
+            .is_some_and(|reservation| reservation.robot_id != robot_id)
+    }
+    
+    /// Records the distress call of a stranded robot, or refreshes its position.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::station::Station;
+    /// let mut station = Station::new();
+    /// station.report_stranded(3, 2, 2);
+    /// station.report_stranded(3, 2, 3);
+    /// assert_eq!(station.rescue_requests.len(), 1);
+    /// assert_eq!(station.rescue_requests[0].position, (2, 3));
+    /// 
+    /// // The robot got going again on its own
+    /// station.cancel_rescue(3);
+    /// assert_eq!(station.pending_rescue_count(), 0);
+    /// ```
+    pub fn report_stranded(&mut self, robot_id: usize, x: usize, y: usize) {
+        if let Some(request) = self.rescue_requests.iter_mut().find(|request| request.robot_id == robot_id) {
+            request.position = (x, y);
+            return;
+        }
+        
+        self.notice(robot_id, format!("📡 Station: appel de détresse du robot #{} en ({}, {})", robot_id, x, y));
+        self.rescue_requests.push(RescueRequest {
+            robot_id,
+            position: (x, y),
+            repairer_id: None,
+            delivered_energy: 0.0,
+        });
+    }
+    
+    /// Drops the distress call of a robot (moving again, or repatriated)
+    pub fn cancel_rescue(&mut self, robot_id: usize) {
+        self.rescue_requests.retain(|request| request.robot_id != robot_id);
+    }
+    
+    /// Number of distress calls no repairer has answered yet
+    pub fn pending_rescue_count(&self) -> usize {
+        self.rescue_requests.iter().filter(|request| request.repairer_id.is_none()).count()
+    }
+    
+    /// Gives a repairer the oldest unanswered distress call.
+    /// 
+    /// A repairer that already answers a call keeps it. Returns `None` when
+    /// no call is waiting.
+    /// 
+    /// # Examples
+    /// 
+    /// Two repairers never go for the same casualty:
+    /// 
+    /// ```rust
+    /// # use ereea::station::Station;
+    /// let mut station = Station::new();
+    /// station.report_stranded(3, 2, 2);
+    /// 
+    /// assert_eq!(station.assign_rescue(10).map(|request| request.robot_id), Some(3));
+    /// assert_eq!(station.assign_rescue(11), None);
+    /// assert_eq!(station.assign_rescue(10).map(|request| request.robot_id), Some(3));
+    /// 
+    /// // Given up by the first repairer (e.g. low on energy), the call goes to the next one
+    /// station.release_rescue(10);
+    /// assert_eq!(station.assign_rescue(11).map(|request| request.robot_id), Some(3));
+    /// ```
+    pub fn assign_rescue(&mut self, repairer_id: usize) -> Option<RescueRequest> {
+        if let Some(request) = self.rescue_assigned_to(repairer_id) {
+            return Some(request);
+        }
+        
+        let request = self.rescue_requests.iter_mut().find(|request| request.repairer_id.is_none())?;
+        request.repairer_id = Some(repairer_id);
+        Some(*request)
+    }
+    
+    /// Distress call a repairer is answering (until it delivers its energy)
+    pub fn rescue_assigned_to(&self, repairer_id: usize) -> Option<RescueRequest> {
+        self.rescue_requests.iter()
+            .find(|request| request.repairer_id == Some(repairer_id) && request.delivered_energy == 0.0)
+            .copied()
+    }
+    
+    /// Gives up the distress call a repairer was answering, so another repairer can take it
+    pub fn release_rescue(&mut self, repairer_id: usize) {
+        for request in self.rescue_requests.iter_mut() {
+            if request.repairer_id == Some(repairer_id) && request.delivered_energy == 0.0 {
+                request.repairer_id = None;
+            }
+        }
+    }
+    
+    /// Hands over the energy a repairer brought to the robot whose call it answers
+    pub fn deliver_rescue(&mut self, repairer_id: usize, energy: f32) {
+        if let Some(request) = self.rescue_requests.iter_mut()
+            .find(|request| request.repairer_id == Some(repairer_id) && request.delivered_energy == 0.0) {
+            request.delivered_energy = energy;
+        }
+    }
+    
+    /// Energy delivered to a stranded robot, if any; closes its distress call
+    pub fn take_rescue_energy(&mut self, robot_id: usize) -> Option<f32> {
+        let index = self.rescue_requests.iter()
+            .position(|request| request.robot_id == robot_id && request.delivered_energy > 0.0)?;
+        Some(self.rescue_requests.remove(index).delivered_energy)
+    }
+    
+    /// Queues a task for a robot; it is handed over at the robot's next update.
+    /// 
+    /// Tasks reach the robot in dispatch order and are added after the ones
+    /// it already has (see `Robot::assign_task`). Used by the simulation for
+    /// the commands sent by Earth.
+    pub fn dispatch_task(&mut self, robot_id: usize, task: RobotTask) {
+        self.notice(robot_id, format!("📋 Station: tâche « {} » transmise au robot #{}", task, robot_id));
+        self.pending_tasks.entry(robot_id).or_default().push_back(task);
+    }
+    
+    /// Events of the station since the last call, oldest first.
+    ///
+    /// Each one is about the robot it names: the robot that called for help,
+    /// was built, received a task or a module, or synced conflicting knowledge.
+    pub fn take_events(&mut self) -> Vec<RobotEvent> {
+        std::mem::take(&mut self.events)
+    }
+    
+    // NOTE - Log line of the station about a robot
+    fn notice(&mut self, robot_id: usize, message: String) {
+        self.events.push(RobotEvent::Notice { robot_id, message });
+    }
+    
+    /// Tasks dispatched to a robot since its last update, oldest first
+    pub fn take_tasks(&mut self, robot_id: usize) -> VecDeque<RobotTask> {
+        self.pending_tasks.remove(&robot_id).unwrap_or_default()
+    }
+    
+    /// Takes a robot out of service and returns the minerals recovered from it.
+    /// 
+    /// The station refunds `DECOMMISSION_REFUND_PERCENT` % of
+    /// `ROBOT_MINERAL_COST` (rounded down), forgets everything it held for
+    /// the robot (claims, reservations, charging bay, rescue, tasks) and
+    /// reports a `RobotEvent::Decommissioned`. The station does not own the
+    /// fleet: the robot is removed by the caller, from the ids returned by
+    /// `take_decommissioned`. A robot already decommissioned brings nothing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::{Station, ROBOT_MINERAL_COST, DECOMMISSION_REFUND_PERCENT}};
+    /// # use ereea::network::create_simulation_state;
+    /// # use ereea::types::RobotType;
+    /// let map = Map::new();
+    /// let mut station = Station::new();
+    /// let mut robots: Vec<Robot> = (1..=3)
+    ///     .map(|id| Robot::new_with_memory(map.station_x, map.station_y, RobotType::EnergyCollector, id,
+    ///                                      map.station_x, map.station_y, station.global_memory.clone()))
+    ///     .collect();
+    /// 
+    /// let refund = ROBOT_MINERAL_COST * DECOMMISSION_REFUND_PERCENT / 100;
+    /// assert_eq!(refund, 7);
+    /// assert_eq!(station.decommission_robot(2), refund);
+    /// assert_eq!(station.decommission_robot(2), 0);
+    /// assert_eq!(station.collected_minerals, refund);
+    /// 
+    /// let retired = station.take_decommissioned();
+    /// robots.retain(|robot| !retired.contains(&robot.id));
+    /// 
+    /// // Frames no longer carry the robot, the others keep their ids
+    /// let state = create_simulation_state(&map, &station, &robots, 1, 0.0);
+    /// let ids: Vec<usize> = state.robots_data.iter().map(|robot| robot.id).collect();
+    /// assert_eq!(ids, vec![1, 3]);
+    /// ```
+    pub fn decommission_robot(&mut self, robot_id: usize) -> u32 {
+        if self.decommissioned.contains(&robot_id) {
+            return 0;
+        }
+        
+        self.release_exploration_target(robot_id);
+        self.release_sector(robot_id);
+        self.release_target(robot_id);
+        self.release_charging_bay(robot_id);
+        self.cancel_rescue(robot_id);
+        self.release_rescue(robot_id);
+        self.pending_tasks.remove(&robot_id);
+        self.idle_at_base.remove(&robot_id);
+        
+        let refund = self.config.robot_mineral_cost * DECOMMISSION_REFUND_PERCENT / 100;
+        self.collected_minerals += refund;
+        self.decommissioned.push(robot_id);
+        self.events.push(RobotEvent::Decommissioned { robot_id, refund });
+        refund
+    }
+    
+    /// Robots decommissioned since the last call, to remove from the fleet
+    pub fn take_decommissioned(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.decommissioned)
+    }
+    
+    /// Decommissions the collectors that have nothing left to collect.
+    /// 
+    /// To be called once per cycle. A collector retires after
+    /// `retire_after_idle_ticks` consecutive calls spent idle at its station
+    /// while no tile of its resource is left on the map. Never happens in
+    /// endless mode, where resources grow back.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.retire_after_idle_ticks = 10;
+    /// let mut robots: Vec<Robot> = [RobotType::EnergyCollector, RobotType::MineralCollector, RobotType::Explorer]
+    ///     .into_iter()
+    ///     .zip(1..)
+    ///     .map(|(robot_type, id)| Robot::new_with_memory(map.station_x, map.station_y, robot_type, id,
+    ///                                                    map.station_x, map.station_y, station.global_memory.clone()))
+    ///     .collect();
+    /// for robot in robots.iter_mut() {
+    ///     robot.mode = RobotMode::Idle;
+    /// }
+    /// 
+    /// for _ in 0..10 {
+    ///     station.retire_idle_robots(&map, &robots);
+    /// }
+    /// // No energy left: only the energy collector retires
+    /// assert_eq!(station.take_decommissioned(), vec![1]);
+    /// ```
+    pub fn retire_idle_robots(&mut self, map: &Map, robots: &[Robot]) {
+        if self.endless || self.retire_after_idle_ticks == 0 {
+            return;
+        }
+        
+        let remaining = Self::remaining_resources(map);
+        for robot in robots {
+            let Some(resource) = Self::collected_resource(robot.robot_type) else {
+                continue;
+            };
+            if !Self::resting_at_station(robot) || remaining.contains_key(&resource) {
+                self.idle_at_base.remove(&robot.id);
+                continue;
+            }
+            
+            let idle_ticks = self.idle_at_base.entry(robot.id).or_insert(0);
+            *idle_ticks += 1;
+            if *idle_ticks >= self.retire_after_idle_ticks {
+                self.decommission_robot(robot.id);
+            }
+        }
+    }
+    
+    /// Refills the empty reserves from the batteries of the explorers resting at a station.
+    /// 
+    /// Only for the dead end where the reserves can no longer refill: the
+    /// station is blacked out, no energy deposit is left on the map, no robot
+    /// carries energy home, and a collector waits at a station for a charge
+    /// while its resource is still on the map. Explorers idle at a station
+    /// have finished their exploration: their whole units of energy go to the
+    /// reserves (within `energy_capacity`), so that the collector can set out
+    /// again. Returns the energy units pooled.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::Station};
+    /// # use ereea::types::{RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.energy_reserves = 0;
+    /// let mut robots: Vec<Robot> = [RobotType::Explorer, RobotType::MineralCollector]
+    ///     .into_iter()
+    ///     .zip(1..)
+    ///     .map(|(robot_type, id)| Robot::new_with_memory(map.station_x, map.station_y, robot_type, id,
+    ///                                                    map.station_x, map.station_y, station.global_memory.clone()))
+    ///     .collect();
+    /// (robots[0].energy, robots[1].energy) = (80.5, 2.0);
+    /// for robot in robots.iter_mut() {
+    ///     robot.mode = RobotMode::Idle;
+    /// }
+    /// 
+    /// // An energy deposit is left: the reserves will refill without help
+    /// map.set_tile(5, 5, TileType::Energy);
+    /// assert_eq!(station.pool_idle_batteries(&map, &mut robots), 0);
+    /// 
+    /// map.set_tile(5, 5, TileType::Empty);
+    /// assert_eq!(station.pool_idle_batteries(&map, &mut robots), 80);
+    /// assert_eq!((station.energy_reserves, robots[0].energy, robots[1].energy), (80, 0.5, 2.0));
+    /// ```
+    pub fn pool_idle_batteries(&mut self, map: &Map, robots: &mut [Robot]) -> u32 {
+        if !self.is_blacked_out() || robots.iter().any(|robot| robot.energy_cargo() > 0) {
+            return 0;
+        }
+        let remaining = Self::remaining_resources(map);
+        let stuck = robots.iter().any(|robot| Self::resting_at_station(robot)
+            && Self::collected_resource(robot.robot_type).is_some_and(|resource| remaining.contains_key(&resource)));
+        if remaining.contains_key(&TileType::Energy) || !stuck {
+            return 0;
+        }
+        
+        let mut pooled = 0;
+        for robot in robots.iter_mut().filter(|robot| robot.robot_type == RobotType::Explorer && Self::resting_at_station(robot)) {
+            let room = self.energy_capacity().saturating_sub(self.energy_reserves);
+            let units = (robot.energy.max(0.0) as u32).min(room);
+            robot.energy -= units as f32;
+            self.energy_reserves += units;
+            pooled += units;
+        }
+        pooled
+    }
+    
+    /// Gives up the resources left on the map once no energy can be found to collect them.
+    /// 
+    /// To be called once per cycle, after `pool_idle_batteries`. The dead end
+    /// is when the station is blacked out, no energy deposit is left on the
+    /// map, no robot carries energy home and the whole fleet rests at its
+    /// stations: nobody can afford a trip, and nothing will ever refill the
+    /// reserves. After [`ENERGY_EXHAUSTION_TICKS`] such cycles in a row,
+    /// `energy_exhausted` is set and `collect_all_resources` no longer holds
+    /// the mission back (the other objectives still do). Returns true on the
+    /// cycle the resources are given up. Never happens in endless mode.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::{Station, ENERGY_EXHAUSTION_TICKS}};
+    /// # use ereea::types::{RobotMode, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.set_exploration_area(ereea::knowledge::ExplorationArea::whole_map());
+    /// station.reveal_map(&map);
+    /// station.energy_reserves = 0;
+    /// let mut collector = Robot::new_with_memory(map.station_x, map.station_y, RobotType::MineralCollector, 1,
+    ///                                            map.station_x, map.station_y, station.global_memory.clone());
+    /// collector.mode = RobotMode::Idle;
+    /// collector.energy = 1.0;
+    /// let robots = vec![collector];
+    /// 
+    /// assert!(!station.is_mission_complete(&map));
+    /// for _ in 1..ENERGY_EXHAUSTION_TICKS {
+    ///     assert!(!station.check_energy_exhausted(&map, &robots));
+    /// }
+    /// assert!(station.check_energy_exhausted(&map, &robots));
+    /// assert!(station.energy_exhausted && station.is_mission_complete(&map));
+    /// ```
+    pub fn check_energy_exhausted(&mut self, map: &Map, robots: &[Robot]) -> bool {
+        if self.endless || self.energy_exhausted {
+            return false;
+        }
+        let dead_end = self.is_blacked_out()
+            && !Self::remaining_resources(map).contains_key(&TileType::Energy)
+            && robots.iter().all(|robot| robot.energy_cargo() == 0 && Self::resting_at_station(robot));
+        if !dead_end {
+            self.exhausted_ticks = 0;
+            return false;
+        }
+        
+        self.exhausted_ticks += 1;
+        self.energy_exhausted = self.exhausted_ticks >= ENERGY_EXHAUSTION_TICKS;
+        self.energy_exhausted
+    }
+    
+    // NOTE - Tiles of each resource type left on the map
+    fn remaining_resources(map: &Map) -> BTreeMap<TileType, usize> {
+        let mut remaining = BTreeMap::new();
+        for y in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                let tile = map.get_tile(x, y);
+                if tile.is_resource() {
+                    *remaining.entry(tile).or_insert(0) += 1;
+                }
+            }
+        }
+        remaining
+    }
+    
+    // NOTE - Resource gathered by a collector type, None for the other robots
+    fn collected_resource(robot_type: RobotType) -> Option<TileType> {
+        match robot_type {
+            RobotType::EnergyCollector => Some(TileType::Energy),
+            RobotType::MineralCollector => Some(TileType::Mineral),
+            RobotType::ScientificCollector => Some(TileType::Scientific),
+            _ => None,
+        }
+    }
+    
+    // NOTE - Idle at its station
+    fn resting_at_station(robot: &Robot) -> bool {
+        robot.mode == RobotMode::Idle && (robot.x, robot.y) == (robot.home_station_x, robot.home_station_y)
+    }
+    
+    /// Type of the robot the station would build next, and whether the
+    /// reserves cover its cost right now.
+    /// 
+    /// Changes nothing: `try_create_robot` builds exactly this recommendation
+    /// once it is affordable. Returns `None` once the mission is complete.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::{Station, ROBOT_ENERGY_COST, ROBOT_MINERAL_COST}};
+    /// # use ereea::types::{RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// let fleet = vec![Robot::new(10, 10, RobotType::EnergyCollector)];
+    /// let mut station = Station::new();
+    /// 
+    /// // Five minerals short: an explorer is recommended but cannot be built yet
+    /// station.energy_reserves = ROBOT_ENERGY_COST;
+    /// station.collected_minerals = ROBOT_MINERAL_COST - 5;
+    /// assert_eq!(station.next_robot_recommendation(&map, &fleet), Some((RobotType::Explorer, false)));
+    /// assert!(station.try_create_robot(&map, &fleet).is_none());
+    /// assert_eq!(station.collected_minerals, ROBOT_MINERAL_COST - 5);
+    /// 
+    /// // Enough of both: the recommended robot is the one built
+    /// station.collected_minerals = ROBOT_MINERAL_COST;
+    /// assert_eq!(station.next_robot_recommendation(&map, &fleet), Some((RobotType::Explorer, true)));
+    /// assert_eq!(station.try_create_robot(&map, &fleet).unwrap().robot_type, RobotType::Explorer);
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (0, 0));
+    /// 
+    /// // Mission complete, everything explored and nothing left to collect: nothing to build
+    /// map.consume_resource(3, 3);
+    /// station.reveal_map(&map);
+    /// assert_eq!(station.next_robot_recommendation(&map, &fleet), None);
+    /// ```
+    pub fn next_robot_recommendation(&self, map: &Map, robots: &[Robot]) -> Option<(RobotType, bool)> {
+        if self.is_mission_complete(map) {
+            return None;
+        }
+        
+        let robot_type = self.determine_needed_robot_type(map, robots);
+        Some((robot_type, self.can_afford(robot_type)))
+    }
+    
+    /// Attempts to create a new robot for exploration or resource collection.
+    /// 
+    /// This method consumes a portion of the station's energy and minerals
+    /// reserves to manufacture a new robot. The type of robot created depends
+    /// on the current mission needs, resource availability and the composition
+    /// of the existing fleet (`robots`), see `next_robot_recommendation`.
+    /// 
+    /// # Resource Costs
+    /// 
+    /// The cost of the robot type built, see `robot_cost`: by default
+    /// [`ROBOT_ENERGY_COST`] (50) units of energy taken from the station's
+    /// reserves and [`ROBOT_MINERAL_COST`] (15) collected minerals.
+    /// 
+    /// # Returns
+    /// 
+    /// An `Option<Robot>` which is:
+    /// - `Some(robot)`: A new robot instance configured for its mission
+    /// - `None`: Insufficient resources to create a robot, or mission complete
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, station::Station};
+    /// let mut station = Station::new();
+    /// let map = Map::new();
+    /// 
+    /// // Create a new robot for exploration
+    /// if let Some(robot) = station.try_create_robot(&map, &[]) {
+    ///     println!("New robot created: ID={}, Type={:?}", robot.id, robot.robot_type);
+    /// } else {
+    ///     println!("Not enough resources to create a robot.");
+    /// }
+    /// ```
+    /// 
+    /// The fleet is balanced: explorers are capped at [`MAX_EXPLORERS`] and an
+    /// energy collector is built first when the fleet has none:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::{Station, MAX_EXPLORERS}, types::{RobotType, TileType, MAP_SIZE}};
+    /// let mut station = Station::new();
+    /// let map = Map::new();
+    /// # assert!((0..MAP_SIZE).any(|y| (0..MAP_SIZE).any(|x| map.get_tile(x, y) == TileType::Energy)));
+    /// station.energy_reserves = 1000;
+    /// station.collected_minerals = 1000;
+    /// 
+    /// // Early exploration, no explorer yet: an explorer is built
+    /// let mut fleet = vec![Robot::new(10, 10, RobotType::EnergyCollector)];
+    /// let robot = station.try_create_robot(&map, &fleet).unwrap();
+    /// assert_eq!(robot.robot_type, RobotType::Explorer);
+    /// 
+    /// // Once the explorer cap is reached, the station builds something else
+    /// fleet = (0..MAX_EXPLORERS).map(|_| Robot::new(10, 10, RobotType::Explorer)).collect();
+    /// let robot = station.try_create_robot(&map, &fleet).unwrap();
+    /// assert_eq!(robot.robot_type, RobotType::EnergyCollector);
+    /// 
+    /// // With an energy collector in the fleet, the next robot follows the mission needs
+    /// fleet.push(robot);
+    /// let robot = station.try_create_robot(&map, &fleet).unwrap();
+    /// assert_ne!(robot.robot_type, RobotType::Explorer);
+    /// ```
+    /// 
+    /// In an exploration-only scenario (`explorers_only`), the station builds
+    /// explorers whatever the fleet and the mission needs:
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::{Station, MAX_EXPLORERS}, types::RobotType};
+    /// let mut station = Station::new();
+    /// let map = Map::new();
+    /// station.energy_reserves = 1000;
+    /// station.collected_minerals = 1000;
+    /// station.explorers_only = true;
+    /// 
+    /// let mut fleet: Vec<Robot> = (0..MAX_EXPLORERS).map(|_| Robot::new(10, 10, RobotType::Explorer)).collect();
+    /// for _ in 0..3 {
+    ///     let robot = station.try_create_robot(&map, &fleet).unwrap();
+    ///     assert_eq!(robot.robot_type, RobotType::Explorer);
+    ///     fleet.push(robot);
+    /// }
+    /// ```
+    pub fn try_create_robot(&mut self, map: &Map, robots: &[Robot]) -> Option<Robot> {
+        // NOTE - Most needed robot type, built only if the reserves cover its cost
+        let (robot_type, affordable) = self.next_robot_recommendation(map, robots)?;
+        if affordable {
+            // NOTE - Consuming resources for robot creation
+            let cost = self.robot_cost(robot_type);
+            self.energy_reserves -= cost.energy;
+            self.collected_minerals -= cost.minerals;
+            
+            self.notice(self.next_robot_id, format!("Station: Création d'un nouveau robot #{} de type {:?}",
+                                                    self.next_robot_id, robot_type));
+            
+            // NOTE - Creating robot with current global memory, its random choices seeded from the map
+            let mut new_robot = Robot::new_with_memory(
+                map.station_x, 
+                map.station_y, 
+                robot_type, 
+                self.next_robot_id,
+                map.station_x, 
+                map.station_y,
+                self.global_memory.clone()
+            );
+            new_robot = new_robot.with_config(self.robot_config(robot_type));
+            new_robot.seed_rng(map.seed());
+            new_robot.energy_model = self.energy_model.clone();
+            new_robot.vision_model = self.vision_model;
+            new_robot.set_exploration_area(self.exploration_area.clone());
+            
+            // NOTE - Incrementing robot ID counter
+            self.next_robot_id += 1;
+            
+            return Some(new_robot);
+        }
+        
+        None // Pas assez de ressources
+    }
+    
+    /// Configuration of the robots of a type built by the station: the
+    /// override of `robot_configs` if any, `RobotConfig::for_type` otherwise.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::{Robot, RobotConfig}, station::Station, types::RobotType};
+    /// let mut station = Station::new();
+    /// let map = Map::new();
+    /// station.energy_reserves = 1000;
+    /// station.collected_minerals = 1000;
+    /// let long_range = RobotConfig::for_type(RobotType::Explorer).with_max_energy(200.0);
+    /// station.robot_configs.insert(RobotType::Explorer, long_range);
+    /// 
+    /// assert_eq!(station.robot_config(RobotType::Explorer), long_range);
+    /// assert_eq!(station.robot_config(RobotType::Relay), RobotConfig::for_type(RobotType::Relay));
+    /// 
+    /// // No explorer in the fleet yet: an explorer is built, with its override
+    /// let fleet = vec![Robot::new(10, 10, RobotType::EnergyCollector)];
+    /// let robot = station.try_create_robot(&map, &fleet).unwrap();
+    /// assert_eq!(robot.robot_type, RobotType::Explorer);
+    /// assert_eq!((robot.config, robot.max_energy, robot.energy), (long_range, 200.0, 200.0));
+    /// ```
+    pub fn robot_config(&self, robot_type: RobotType) -> RobotConfig {
+        self.robot_configs.get(&robot_type).copied().unwrap_or_else(|| RobotConfig::for_type(robot_type))
+    }
+    
+    /// Resources spent to build a robot of a type: the override of
+    /// `robot_costs` if any, the cost set by `config` otherwise, less
+    /// [`LEAN_ASSEMBLY_DISCOUNT_PERCENT`] with `StationUpgrade::LeanAssembly`.
+    pub fn robot_cost(&self, robot_type: RobotType) -> RobotCost {
+        let cost = self.robot_costs.get(&robot_type).copied().unwrap_or_else(|| self.config.robot_cost());
+        if !self.has_upgrade(StationUpgrade::LeanAssembly) {
+            return cost;
+        }
+        let discounted = |amount: u32| amount - (amount as u64 * LEAN_ASSEMBLY_DISCOUNT_PERCENT as u64 / 100) as u32;
+        RobotCost { energy: discounted(cost.energy), minerals: discounted(cost.minerals) }
+    }
+    
+    /// Whether the reserves cover the cost of a robot of this type right now,
+    /// the reserve floor of `config` kept.
+    /// 
+    /// Changes nothing, so that clients can preview a build; `try_create_robot`
+    /// deducts the same `robot_cost`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, robot::Robot, station::{RobotCost, Station}};
+    /// # use ereea::types::{RobotType, TileType};
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Scientific);
+    /// let mut station = Station::new();
+    /// station.robot_costs.insert(RobotType::ScientificCollector, RobotCost { energy: 80, minerals: 25 });
+    /// station.energy_reserves = 60;
+    /// station.collected_minerals = 20;
+    /// 
+    /// // Enough for an explorer at the default cost, not for a scientific collector
+    /// assert_eq!(station.robot_cost(RobotType::Explorer), RobotCost::DEFAULT);
+    /// assert!(station.can_afford(RobotType::Explorer));
+    /// assert!(!station.can_afford(RobotType::ScientificCollector));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (60, 20));
+    /// 
+    /// // Explored map, explorers capped: the scientific collector is needed, and waits for its cost
+    /// station.reveal_map(&map);
+    /// let fleet = vec![Robot::new(10, 10, RobotType::EnergyCollector), Robot::new(10, 10, RobotType::Explorer)];
+    /// assert_eq!(station.next_robot_recommendation(&map, &fleet), Some((RobotType::ScientificCollector, false)));
+    /// assert!(station.try_create_robot(&map, &fleet).is_none());
+    /// 
+    /// station.energy_reserves = 100;
+    /// station.collected_minerals = 30;
+    /// let robot = station.try_create_robot(&map, &fleet).unwrap();
+    /// assert_eq!(robot.robot_type, RobotType::ScientificCollector);
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (20, 5));
+    /// ```
+    pub fn can_afford(&self, robot_type: RobotType) -> bool {
+        let cost = self.robot_cost(robot_type);
+        self.energy_reserves >= cost.energy + self.config.reserve_floor && self.collected_minerals >= cost.minerals
+    }
+    
+    /// Phase of the day/night cycle at the current mission time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::station::Station;
+    /// # use ereea::types::DayPhase;
+    /// let mut station = Station::new();
+    /// station.day_night_period = 10;
+    /// station.energy_per_deposit = 20;
+    /// 
+    /// assert_eq!(station.day_phase(), DayPhase::Day);
+    /// assert_eq!(station.energy_yield(), 25);
+    /// 
+    /// for _ in 0..5 {
+    ///     station.tick();
+    /// }
+    /// assert_eq!(station.day_phase(), DayPhase::Night);
+    /// assert_eq!(station.energy_yield(), 15);
+    /// 
+    /// // Without a cycle, deposits always yield their base value
+    /// station.day_night_period = 0;
+    /// assert_eq!(station.day_phase(), DayPhase::Day);
+    /// assert_eq!(station.energy_yield(), 20);
+    /// ```
+    pub fn day_phase(&self) -> DayPhase {
+        if self.day_night_period == 0 || self.current_time % self.day_night_period < self.day_night_period / 2 {
+            DayPhase::Day
+        } else {
+            DayPhase::Night
+        }
+    }
+    
+    // NOTE - Energy units extracted from one deposit right now: 125% by day, 75% at night (the base value on average)
+    pub fn energy_yield(&self) -> u32 {
+        if self.day_night_period == 0 {
+            return self.energy_per_deposit;
+        }
+        match self.day_phase() {
+            DayPhase::Day => self.energy_per_deposit * 5 / 4,
+            DayPhase::Night => self.energy_per_deposit * 3 / 4,
+        }
+    }
+    
+    /// Transfers energy from the station reserves to a docked robot.
+    /// 
+    /// The robot first needs a charging bay: with all [`charging_bay_count`](Self::charging_bay_count)
+    /// bays taken, it joins the queue and gets `None` until a bay frees up (bays
+    /// are handed out in arrival order). A robot holding a bay receives up to
+    /// `charge_rate` whole units, never more than `missing`, and nothing that
+    /// would take the reserves below `charge_reserve_floor`. The reserves
+    /// drop by exactly the returned amount.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::station::Station;
+    /// let mut station = Station::new();
+    /// station.charging_bays = 2;
+    /// station.charge_rate = 10;
+    /// station.energy_reserves = 25;
+    /// 
+    /// assert_eq!(station.request_charge(1, 50.0), Some(10));
+    /// assert_eq!(station.request_charge(2, 4.5), Some(4));
+    /// // Both bays are taken: robot #3 waits
+    /// assert_eq!(station.request_charge(3, 50.0), None);
+    /// assert_eq!(station.energy_reserves, 11);
+    /// 
+    /// station.release_charging_bay(2);
+    /// assert_eq!(station.request_charge(3, 50.0), Some(10));
+    /// assert!(station.has_charging_bay(3));
+    /// 
+    /// // Below the reserve floor, the station refuses
+    /// station.charge_reserve_floor = 1;
+    /// assert_eq!(station.request_charge(1, 50.0), Some(0));
+    /// assert_eq!(station.energy_reserves, 1);
+    /// ```
+    pub fn request_charge(&mut self, robot_id: usize, missing: f32) -> Option<u32> {
+        if !self.charging_robots.contains(&robot_id) {
+            if !self.charging_queue.contains(&robot_id) {
+                self.charging_queue.push_back(robot_id);
+            }
+            let bay_free = self.charging_robots.len() < self.charging_bay_count();
+            if !bay_free || self.charging_queue.front() != Some(&robot_id) {
+                return None;
+            }
+            self.charging_queue.pop_front();
+            self.charging_robots.push(robot_id);
+        }
+        
+        // NOTE - Whole units only, so the robot never receives more than it misses
+        let available = self.energy_reserves.saturating_sub(self.charge_reserve_floor);
+        let amount = self.charge_rate.min(missing.max(0.0) as u32).min(available);
+        self.energy_reserves -= amount;
+        self.charged_energy += amount;
+        Some(amount)
+    }
+    
+    /// Frees the robot's charging bay, or takes it out of the queue
+    pub fn release_charging_bay(&mut self, robot_id: usize) {
+        self.charging_robots.retain(|&id| id != robot_id);
+        self.charging_queue.retain(|&id| id != robot_id);
+    }
+    
+    /// Checks whether a robot currently holds a charging bay
+    pub fn has_charging_bay(&self, robot_id: usize) -> bool {
+        self.charging_robots.contains(&robot_id)
+    }
+    
+    /// Installs a module on a docked robot, paid from the station stocks.
+    /// 
+    /// The robot must be at its station and in working order, must not
+    /// already carry this module and must have fewer than
+    /// [`MAX_UPGRADES_PER_ROBOT`] modules. Returns false (and charges
+    /// nothing) if any condition fails or if the station cannot afford
+    /// `Upgrade::cost`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{robot::Robot, station::Station};
+    /// # use ereea::types::{RobotType, Upgrade};
+    /// let mut station = Station::new();
+    /// station.energy_reserves = 100;
+    /// station.collected_minerals = 30;
+    /// let mut robot = Robot::new(10, 10, RobotType::Explorer);
+    /// 
+    /// assert!(station.try_install_upgrade(&mut robot, Upgrade::BatteryModule));
+    /// assert_eq!(Upgrade::BatteryModule.cost(), (30, 10));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (70, 20));
+    /// assert_eq!(robot.max_energy, 120.0);
+    /// 
+    /// // The same module is never sold twice
+    /// assert!(!station.try_install_upgrade(&mut robot, Upgrade::BatteryModule));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (70, 20));
+    /// assert_eq!(robot.max_energy, 120.0);
+    /// 
+    /// // Two modules at most per robot
+    /// assert!(station.try_install_upgrade(&mut robot, Upgrade::VisionModule));
+    /// assert!(!station.try_install_upgrade(&mut robot, Upgrade::EfficiencyModule));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (50, 10));
+    /// 
+    /// // Away from the station, nothing can be installed
+    /// let mut away = Robot::new(10, 10, RobotType::Explorer);
+    /// away.x = 3;
+    /// assert!(!station.try_install_upgrade(&mut away, Upgrade::VisionModule));
+    /// assert!(away.upgrades.is_empty());
+    /// ```
+    pub fn try_install_upgrade(&mut self, robot: &mut Robot, upgrade: Upgrade) -> bool {
+        let docked = (robot.x, robot.y) == (robot.home_station_x, robot.home_station_y);
+        let working = !matches!(robot.mode, RobotMode::Stranded | RobotMode::Broken);
+        if !docked || !working || robot.has_upgrade(upgrade) || robot.upgrades.len() >= MAX_UPGRADES_PER_ROBOT {
+            return false;
+        }
+        
+        let (energy_cost, mineral_cost) = upgrade.cost();
+        if self.energy_reserves < energy_cost || self.collected_minerals < mineral_cost {
+            return false;
+        }
+        
+        self.energy_reserves -= energy_cost;
+        self.collected_minerals -= mineral_cost;
+        robot.install_upgrade(upgrade);
+        self.notice(robot.id, format!("Station: Installation du module {:?} sur le robot #{}", upgrade, robot.id));
+        true
+    }
+    
+    /// Upgrades a docked robot instead of building a new one once the fleet is large.
+    /// 
+    /// Below [`UPGRADE_FLEET_SIZE`] robots, nothing happens: more robots are
+    /// worth more than better ones. Otherwise the station picks the first
+    /// docked robot missing the module most useful to its type (see
+    /// `determine_needed_upgrade`) and installs it if it can afford it.
+    /// Returns the upgraded robot ID and the module.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{robot::Robot, station::{Station, UPGRADE_FLEET_SIZE}};
+    /// # use ereea::types::{RobotType, Upgrade};
+    /// let mut station = Station::new();
+    /// station.energy_reserves = 1000;
+    /// station.collected_minerals = 1000;
+    /// let mut fleet: Vec<Robot> = (1..UPGRADE_FLEET_SIZE).map(|id| {
+    ///     let mut robot = Robot::new(10, 10, RobotType::MineralCollector);
+    ///     robot.id = id;
+    ///     robot
+    /// }).collect();
+    /// 
+    /// // A small fleet still grows
+    /// assert_eq!(station.try_upgrade_fleet(&mut fleet), None);
+    /// 
+    /// fleet.push(Robot::new(10, 10, RobotType::Explorer));
+    /// fleet[0].x = 3;
+    /// assert_eq!(station.try_upgrade_fleet(&mut fleet), Some((2, Upgrade::EfficiencyModule)));
+    /// assert!(fleet[1].has_upgrade(Upgrade::EfficiencyModule));
+    /// assert_eq!(station.energy_reserves, 1000 - Upgrade::EfficiencyModule.cost().0);
+    /// ```
+    pub fn try_upgrade_fleet(&mut self, robots: &mut [Robot]) -> Option<(usize, Upgrade)> {
+        if robots.len() < UPGRADE_FLEET_SIZE {
+            return None;
+        }
+        
+        for robot in robots.iter_mut() {
+            if let Some(upgrade) = Self::determine_needed_upgrade(robot)
+                && self.try_install_upgrade(robot, upgrade) {
+                return Some((robot.id, upgrade));
+            }
+        }
+        None
+    }
+    
+    // NOTE - Most useful module not yet installed: wider vision for explorers, cheaper moves for
+    // collectors, speed for repairers, bigger battery for relays, then the next best
+    fn determine_needed_upgrade(robot: &Robot) -> Option<Upgrade> {
+        let preferences = match robot.robot_type {
+            RobotType::Explorer => [Upgrade::VisionModule, Upgrade::BatteryModule],
+            RobotType::Repairer => [Upgrade::PropulsionModule, Upgrade::BatteryModule],
+            RobotType::Relay => [Upgrade::BatteryModule, Upgrade::EfficiencyModule],
+            _ => [Upgrade::EfficiencyModule, Upgrade::BatteryModule],
+        };
+        preferences.into_iter().find(|&upgrade| !robot.has_upgrade(upgrade))
+    }
+    
+    /// Buys an upgrade for the station itself, paid from its stocks.
+    /// 
+    /// Each upgrade is bought once: returns false (and charges nothing) if
+    /// it is already installed, if the station cannot afford `StationUpgrade::cost`
+    /// or if paying it would leave less energy than the reserve floor of `config`
+    /// plus the energy cost of a robot, so that the fleet can still be charged
+    /// and grown. Its effect applies from then on, see
+    /// `charging_bay_count`, `build_slots`, `energy_capacity` and `robot_cost`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::station::{RobotCost, Station, CHARGING_BAYS};
+    /// # use ereea::types::{RobotType, StationUpgrade};
+    /// let mut station = Station::new();
+    /// station.energy_reserves = 250;
+    /// station.collected_minerals = 30;
+    /// 
+    /// assert!(station.purchase_upgrade(StationUpgrade::ChargingBays));
+    /// assert_eq!(StationUpgrade::ChargingBays.cost(), (60, 10));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (190, 20));
+    /// assert_eq!(station.charging_bay_count(), CHARGING_BAYS + 2);
+    /// 
+    /// // Never bought twice, and never at the expense of the next robot (150 + 50 > 190)
+    /// assert!(!station.purchase_upgrade(StationUpgrade::ChargingBays));
+    /// assert!(!station.purchase_upgrade(StationUpgrade::BuildSlot));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (190, 20));
+    /// assert_eq!(station.build_slots(), 1);
+    /// 
+    /// // Leaner assembly: robots cost a fifth less
+    /// assert!(station.purchase_upgrade(StationUpgrade::LeanAssembly));
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (90, 0));
+    /// assert_eq!(station.robot_cost(RobotType::Explorer), RobotCost { energy: 40, minerals: 12 });
+    /// station.robot_costs.insert(RobotType::Relay, RobotCost { energy: u32::MAX, minerals: 300_000_000 });
+    /// assert_eq!(station.robot_cost(RobotType::Relay), RobotCost { energy: 3_435_973_836, minerals: 240_000_000 });
+    /// assert_eq!(station.upgrades, vec![StationUpgrade::ChargingBays, StationUpgrade::LeanAssembly]);
+    /// ```
+    pub fn purchase_upgrade(&mut self, upgrade: StationUpgrade) -> bool {
+        let (energy_cost, mineral_cost) = upgrade.cost();
+        let energy_floor = self.config.reserve_floor.saturating_add(self.config.robot_energy_cost);
+        if self.has_upgrade(upgrade)
+            || self.energy_reserves < energy_cost.saturating_add(energy_floor)
+            || self.collected_minerals < mineral_cost {
+            return false;
+        }
+        
+        self.energy_reserves -= energy_cost;
+        self.collected_minerals -= mineral_cost;
+        self.upgrades.push(upgrade);
+        self.events.push(RobotEvent::StationUpgraded { upgrade });
+        true
+    }
+    
+    /// Checks whether an upgrade is installed on the station
+    pub fn has_upgrade(&self, upgrade: StationUpgrade) -> bool {
+        self.upgrades.contains(&upgrade)
+    }
+    
+    /// Buys the next upgrade of `StationUpgrade::LADDER` once the fleet is large.
+    /// 
+    /// Only with `auto_upgrades`, and from [`UPGRADE_FLEET_SIZE`] robots on,
+    /// like `try_upgrade_fleet`: the station then improves itself before its
+    /// robots. Upgrades are bought in the order of the ladder, each one as
+    /// soon as the station can afford it, never while its energy is low (see
+    /// `is_energy_low`). Returns the upgrade bought.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{robot::Robot, station::{Station, UPGRADE_FLEET_SIZE}};
+    /// # use ereea::types::{RobotType, StationUpgrade};
+    /// let mut station = Station::new();
+    /// station.energy_reserves = 1000;
+    /// station.collected_minerals = 1000;
+    /// let fleet: Vec<Robot> = (0..UPGRADE_FLEET_SIZE).map(|_| Robot::new(10, 10, RobotType::Explorer)).collect();
+    /// 
+    /// // Nothing bought unless enabled, nor for a small fleet
+    /// assert_eq!(station.try_upgrade_station(&fleet), None);
+    /// station.auto_upgrades = true;
+    /// assert_eq!(station.try_upgrade_station(&fleet[1..]), None);
+    /// 
+    /// let bought: Vec<StationUpgrade> = (0..5).filter_map(|_| station.try_upgrade_station(&fleet)).collect();
+    /// assert_eq!(bought, StationUpgrade::LADDER);
+    /// assert_eq!(station.upgrades, StationUpgrade::LADDER);
+    /// assert_eq!((station.build_slots(), station.energy_capacity()), (2, 1500));
+    /// ```
+    pub fn try_upgrade_station(&mut self, robots: &[Robot]) -> Option<StationUpgrade> {
+        if !self.auto_upgrades || robots.len() < UPGRADE_FLEET_SIZE || self.is_energy_low() {
+            return None;
+        }
+        
+        let upgrade = StationUpgrade::LADDER.into_iter().find(|&upgrade| !self.has_upgrade(upgrade))?;
+        self.purchase_upgrade(upgrade).then_some(upgrade)
+    }
+    
+    /// Number of robots charging at the same time: `charging_bays`, plus
+    /// [`UPGRADE_CHARGING_BAYS`] with `StationUpgrade::ChargingBays`
+    pub fn charging_bay_count(&self) -> usize {
+        if self.has_upgrade(StationUpgrade::ChargingBays) {
+            self.charging_bays + UPGRADE_CHARGING_BAYS
+        } else {
+            self.charging_bays
+        }
+    }
+    
+    /// Robots built at most at each fleet growth: two with `StationUpgrade::BuildSlot`, one otherwise
+    pub fn build_slots(&self) -> usize {
+        if self.has_upgrade(StationUpgrade::BuildSlot) { 2 } else { 1 }
+    }
+    
+    /// Energy units the reserves hold at most: `config.energy_storage`, plus
+    /// [`STORAGE_UPGRADE_CAPACITY`] with `StationUpgrade::EnergyStorage`
+    pub fn energy_capacity(&self) -> u32 {
+        if self.has_upgrade(StationUpgrade::EnergyStorage) {
+            self.config.energy_storage.saturating_add(STORAGE_UPGRADE_CAPACITY)
+        } else {
+            self.config.energy_storage
+        }
+    }
+    
+    /// Determines the most needed type of robot based on current mission status, resource
+    /// availability and fleet composition.
+    /// 
+    /// This function analyzes the exploration progress, resource counts, and existing robot types
+    /// to decide whether to create more Explorers, EnergyCollectors, MineralCollectors, or ScientificCollectors.
+    /// Explorers are capped at [`MAX_EXPLORERS`] unless nothing else is useful, and the fleet always
+    /// gets an energy collector while energy remains on the map. A repairer is built first when a
+    /// stranded robot calls for help and the fleet has none. While exploration is under way, one
+    /// relay links the explorers working far from the station to it. Once the fleet reaches
+    /// [`UPGRADE_FLEET_SIZE`] robots, the simulation first tries `try_upgrade_station` (station
+    /// upgrades, with `auto_upgrades`), then `try_upgrade_fleet`.
+    /// 
+    /// # Returns
+    /// 
+    /// The `RobotType` that is deemed most necessary for the next phase of the mission.
+    fn determine_needed_robot_type(&self, map: &Map, robots: &[Robot]) -> RobotType {
+        // NOTE - Exploration-only scenario: nothing else is ever built
+        if self.explorers_only {
+            return RobotType::Explorer;
+        }
+        
+        // NOTE - Calculating exploration percentage
+        let exploration_percentage = self.get_exploration_percentage();
+        
+        // NOTE - Counting the current fleet by type
+        let fleet_count = |robot_type: RobotType| {
+            robots.iter().filter(|robot| robot.robot_type == robot_type).count()
+        };
+        let explorers_needed = fleet_count(RobotType::Explorer) < MAX_EXPLORERS;
+        
+        // NOTE - A distress call nobody can answer comes first
+        if self.pending_rescue_count() > 0 && fleet_count(RobotType::Repairer) == 0 {
+            return RobotType::Repairer;
+        }
+        
+        // NOTE - Phase 1: Prioritize exploration
+        if exploration_percentage < 50.0 && explorers_needed {
+            return RobotType::Explorer;
+        }
+        
+        // NOTE - Counting remaining resources on the map
         let mut energy_count = 0;
         let mut mineral_count = 0;
         let mut scientific_count = 0;
@@ -379,6 +2356,16 @@ impl Station {
             }
         }
         
+        // NOTE - The fleet needs at least one energy collector to keep the station running
+        if energy_count > 0 && fleet_count(RobotType::EnergyCollector) == 0 {
+            return RobotType::EnergyCollector;
+        }
+        
+        // NOTE - A relay keeps distant explorers synchronized while the map is still being explored
+        if exploration_percentage < 80.0 && fleet_count(RobotType::Explorer) > 0 && fleet_count(RobotType::Relay) == 0 {
+            return RobotType::Relay;
+        }
+        
         // NOTE - Phase 2: Prioritize energy and mineral collection
         if exploration_percentage < 80.0 {
             if energy_count > 0 && (energy_count <= 3 || self.energy_reserves < 100) {
@@ -387,7 +2374,9 @@ impl Station {
             if mineral_count > 0 && (mineral_count <= 5 || self.collected_minerals < 30) {
                 return RobotType::MineralCollector;
             }
-            return RobotType::Explorer;
+            if explorers_needed {
+                return RobotType::Explorer;
+            }
         }
         
         // NOTE - Phase 3: Prioritize scientific collection
@@ -402,6 +2391,9 @@ impl Station {
         if mineral_count > 0 {
             return RobotType::MineralCollector;
         }
+        if scientific_count > 0 {
+            return RobotType::ScientificCollector;
+        }
         
         // NOTE - Default: create explorer to finish exploration
         RobotType::Explorer
@@ -421,92 +2413,308 @@ impl Station {
     /// 
     /// # Examples
     /// 
+    /// Both sides keep their explored-tile counters in step with their memory:
+    /// 
     /// ```rust
+    /// # use ereea::{grid::Grid, map::Map, robot::Robot, station::{Station, TerrainData}, types::{RobotType, VisionModel}};
+    /// let map = Map::new();
     /// let mut station = Station::new();
-    /// let mut robot = Robot::new();
-    /// 
-    /// // After the robot explores some tiles
-    /// robot.memory[0][0].explored = true;
-    /// robot.memory[0][0].timestamp = 5;
-    /// 
-    /// // Station synchronizes with the robot
-    /// station.share_knowledge(&mut robot);
+    /// let mut first = Robot::new(10, 10, RobotType::Explorer);
+    /// let mut second = Robot::new(10, 10, RobotType::MineralCollector);
+    /// first.vision_model = VisionModel::Square;
+    /// second.vision_model = VisionModel::Square;
+    /// 
+    /// // Each robot explores its own corner of the map (81 and 25 tiles)
+    /// (first.x, first.y) = (4, 4);
+    /// first.update_memory(&map, &station);
+    /// (second.x, second.y) = (17, 17);
+    /// second.update_memory(&map, &station);
+    /// 
+    /// // The first robot uploads its knowledge, the second one uploads and receives it
+    /// for robot in [&mut first, &mut second] {
+    ///     (robot.x, robot.y) = (robot.home_station_x, robot.home_station_y);
+    ///     station.share_knowledge(robot);
+    /// }
+    /// assert_eq!(station.explored_count(), 81 + 25);
+    /// assert_eq!(second.explored_count(), 81 + 25);
+    /// 
+    /// // Syncing again brings nothing new to the station, but completes the first robot
+    /// station.share_knowledge(&mut first);
+    /// assert_eq!(station.explored_count(), 81 + 25);
+    /// assert_eq!(first.explored_count(), 81 + 25);
+    /// 
+    /// // The counters match a full scan of each memory
+    /// let scan = |memory: &Grid<TerrainData>| memory.iter().filter(|tile| tile.explored).count();
+    /// assert_eq!(station.explored_count(), scan(&station.global_memory));
+    /// assert_eq!(first.explored_count(), scan(&first.memory));
+    /// assert!((station.get_exploration_percentage() - 26.5).abs() < 1e-3);
     /// ```
     pub fn share_knowledge(&mut self, robot: &mut Robot) {
         // NOTE - Only synchronize if robot is at the station
         if robot.x == robot.home_station_x && robot.y == robot.home_station_y {
-            let mut conflicts = 0;
-            let mut changes_made = false;
-            
-            // NOTE - Robot shares its knowledge with the station
-            for y in 0..MAP_SIZE {
-                for x in 0..MAP_SIZE {
-                    if robot.memory[y][x].explored {
-                        if self.global_memory[y][x].explored {
-                            // NOTE - Conflict: resolve by timestamp
-                            if robot.memory[y][x].timestamp > self.global_memory[y][x].timestamp {
-                                self.global_memory[y][x] = robot.memory[y][x].clone();
-                                conflicts += 1;
-                                changes_made = true;
-                            }
-                        } else {
-                            // NOTE - No conflict, add robot's knowledge
-                            self.global_memory[y][x] = robot.memory[y][x].clone();
-                            changes_made = true;
-                        }
-                    }
-                }
-            }
-            
-            // NOTE - Robot receives all global knowledge
-            for y in 0..MAP_SIZE {
-                for x in 0..MAP_SIZE {
-                    if self.global_memory[y][x].explored {
-                        robot.memory[y][x] = self.global_memory[y][x].clone();
-                    }
-                }
-            }
-            
-            // NOTE - Update conflict statistics if changes were made
-            if changes_made {
-                self.conflict_count += conflicts;
-                
-                if conflicts > 0 {
-                    println!("Robot {} a synchronisé ses connaissances. Conflits résolus: {}", 
-                             robot.id, conflicts);
-                }
+            self.share_knowledge_remotely(robot);
+        }
+    }
+    
+    /// Same exchange as `share_knowledge`, for a robot away from the station.
+    /// 
+    /// Used for robots linked to the station through relays
+    /// (`knowledge::sync_through_relays`).
+    pub fn share_knowledge_remotely(&mut self, robot: &mut Robot) {
+        let robot_known_tiles = robot.explored_count();
+        let station_known_tiles = self.explored_count;
+        
+        // NOTE - Robot shares its knowledge with the station (conflicts resolved by timestamp)
+        let conflicts = merge_memory(self, &*robot);
+        let changes_made = conflicts > 0 || self.explored_count != station_known_tiles;
+        
+        // NOTE - Robot receives all global knowledge, nothing left for peers to relay
+        merge_memory(robot, &*self);
+        robot.clear_unsynced_tiles();
+        
+        // NOTE - Count the exchange only if either side learned something
+        if changes_made || robot.explored_count() != robot_known_tiles {
+            self.knowledge_exchanges += 1;
+        }
+        
+        // NOTE - Conflicts are the station tiles overwritten by the robot (see `knowledge::merge_tile`)
+        self.conflict_count += conflicts;
+        if conflicts > 0 {
+            self.notice(robot.id, format!("Robot {} a synchronisé ses connaissances. Conflits résolus: {}",
+                                          robot.id, conflicts));
+        }
+    }
+    
+    /// Delayed exchange over the radio (see `knowledge::sync_by_radio`).
+    /// 
+    /// The station receives the robot's discoveries observed at or before
+    /// `cutoff` and not sent yet, and the robot receives every station tile
+    /// observed at or before `cutoff`: what either side knew `radio_latency`
+    /// cycles ago. Conflicts are resolved by timestamp, as for docked robots.
+    pub fn share_knowledge_by_radio(&mut self, robot: &mut Robot, cutoff: u32) {
+        let robot_known_tiles = robot.explored_count();
+        let station_known_tiles = self.explored_count;
+        
+        // NOTE - Robot's journal, tile by tile, as `merge_tiles` does for whole memories
+        let mut conflicts = 0;
+        for (x, y, data) in robot.take_radio_journal(cutoff) {
+            let learned = !self.known_tile(x, y).explored;
+            let (merged, conflict) = merge_tile(self.known_tile(x, y), &data);
+            if learned || conflict {
+                self.learn_tile(x, y, merged);
+                conflicts += usize::from(conflict);
             }
         }
+        
+        // NOTE - Station's knowledge as of `cutoff`: tiles learned since are held back
+        let too_recent: HashSet<(usize, usize)> = self.radio_recent.iter()
+            .filter(|&&(learned, _)| learned > cutoff)
+            .map(|&(_, position)| position)
+            .collect();
+        let old_enough: Vec<(usize, usize)> = self.global_memory.iter_coords()
+            .filter(|&(position, tile)| tile.explored && !too_recent.contains(&position))
+            .map(|(position, _)| position)
+            .collect();
+        merge_tiles(robot, &*self, old_enough);
+        
+        if conflicts > 0 || self.explored_count != station_known_tiles || robot.explored_count() != robot_known_tiles {
+            self.knowledge_exchanges += 1;
+        }
+        self.conflict_count += conflicts;
     }
     
     /// Deposits collected resources into the station's reserves.
     /// 
-    /// This method is called by robots to transfer the minerals and scientific data
-    /// they have collected back to the station. The station then incorporates these
-    /// resources into its global reserves, making them available for robot creation
-    /// and other station operations.
+    /// This method is called by robots to transfer the minerals, scientific data
+    /// and energy they have collected back to the station. The station then
+    /// incorporates these resources into its global reserves, making them
+    /// available for robot creation and other station operations.
     /// 
     /// # Parameters
     /// 
-    /// - `minerals`: The amount of minerals to deposit
-    /// - `scientific_data`: The amount of scientific data to deposit
+    /// - `inventory`: The resource units unloaded (hauled energy goes to the reserves)
     /// 
     /// # Examples
     /// 
     /// ```rust
+    /// # use ereea::{station::Station, types::Inventory};
     /// let mut station = Station::new();
     /// 
-    /// // Deposit 30 minerals and 10 scientific data units
-    /// station.deposit_resources(30, 10);
+    /// // Deposit 30 minerals, 10 scientific data units and 20 energy units
+    /// station.deposit_resources(Inventory { energy: 20, minerals: 30, science: 10 });
     /// 
     /// assert_eq!(station.collected_minerals, 30);
     /// assert_eq!(station.collected_scientific_data, 10);
+    /// assert_eq!(station.energy_reserves, 100 + 20);
+    /// ```
+    /// 
+    /// With a refining ratio (`StationConfig::mineral_to_energy_ratio`), the
+    /// delivered minerals also feed the reserves; fractions of a unit carry
+    /// over to the next delivery:
+    /// 
+    /// ```rust
+    /// # use ereea::{station::{Station, StationConfig}, types::Inventory};
+    /// let mut station = Station::new_with_config(StationConfig { mineral_to_energy_ratio: 0.5, ..StationConfig::DEFAULT });
+    /// 
+    /// station.deposit_resources(Inventory { energy: 0, minerals: 5, science: 0 });
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (102, 5));
+    /// station.deposit_resources(Inventory { energy: 10, minerals: 3, science: 0 });
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (114, 8));
+    /// ```
+    /// 
+    /// The reserves never exceed the storage (`energy_capacity`): energy
+    /// delivered to full tanks is lost, until `StationUpgrade::EnergyStorage`
+    /// makes room:
+    /// 
+    /// ```rust
+    /// # use ereea::{station::{Station, StationConfig}, types::{Inventory, StationUpgrade}};
+    /// let mut station = Station::new_with_config(StationConfig { energy_storage: 150, ..StationConfig::DEFAULT });
+    /// 
+    /// station.deposit_resources(Inventory { energy: 80, minerals: 10, science: 0 });
+    /// assert_eq!((station.energy_reserves, station.collected_minerals), (150, 10));
+    /// 
+    /// assert!(station.purchase_upgrade(StationUpgrade::EnergyStorage));
+    /// assert_eq!((station.energy_reserves, station.energy_capacity()), (70, 650));
+    /// station.deposit_resources(Inventory { energy: 1000, minerals: 0, science: 0 });
+    /// assert_eq!(station.energy_reserves, 650);
+    /// ```
+    pub fn deposit_resources(&mut self, inventory: Inventory) {
+        // NOTE - Depositing minerals, scientific data and hauled energy, the reserves capped by the storage
+        let capacity = self.energy_capacity();
+        self.energy_reserves = self.energy_reserves.saturating_add(inventory.energy).min(capacity);
+        self.collected_minerals += inventory.minerals;
+        self.delivered_minerals += inventory.minerals;
+        self.collected_scientific_data += inventory.science;
+        
+        // NOTE - Refining: the minerals also feed the reserves, fractions kept for the next delivery
+        if self.config.mineral_to_energy_ratio > 0.0 {
+            self.refining_remainder += inventory.minerals as f32 * self.config.mineral_to_energy_ratio;
+            let refined = self.refining_remainder.floor();
+            self.energy_reserves = self.energy_reserves.saturating_add(refined as u32).min(capacity);
+            self.refining_remainder -= refined;
+        }
+    }
+    
+    // NOTE - A load was unloaded `distance` tiles away from the tile it was last picked up on
+    pub(crate) fn record_haul(&mut self, distance: usize) {
+        self.hauls += 1;
+        self.haul_distance += distance as u64;
+    }
+    
+    /// Average distance (Chebyshev, in tiles) between the last pickup of a
+    /// load and the station it was unloaded at; `None` before any delivery.
+    /// 
+    /// Collectors unload at the nearest station, so forward depots close to
+    /// the deposits shorten the hauls (`cargo bench --bench depots`).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, occupancy::OccupancyGrid, robot::Robot, station::Station};
+    /// # use ereea::types::{CollectorPolicy, RobotType, TileType};
+    /// let mut map = Map::open();
+    /// let (station_x, station_y) = (map.station_x, map.station_y);
+    /// map.tiles[station_y][station_x + 4] = TileType::Mineral;
+    /// map.set_resource_amount(station_x + 4, station_y, 1);
+    /// 
+    /// let mut station = Station::new();
+    /// station.collector_policy = CollectorPolicy::IMMEDIATE;
+    /// let mut robot = Robot::new_with_memory(station_x, station_y, RobotType::MineralCollector, 1,
+    ///                                        station_x, station_y, station.global_memory.clone());
+    /// robot.memory[(station_x + 4, station_y)].explored = true;
+    /// robot.memory[(station_x + 4, station_y)].tile_type = TileType::Mineral;
+    /// robot.reindex_memory();
+    /// assert_eq!(station.average_haul_distance(), None);
+    /// 
+    /// for _ in 0..30 {
+    ///     station.tick();
+    ///     let mut occupancy = OccupancyGrid::new(station_x, station_y);
+    ///     robot.update(&mut map, &mut station, &mut occupancy);
+    /// }
+    /// assert_eq!(station.collected_minerals, 1);
+    /// assert_eq!(station.average_haul_distance(), Some(4.0));
+    /// ```
+    pub fn average_haul_distance(&self) -> Option<f32> {
+        (self.hauls > 0).then(|| self.haul_distance as f32 / self.hauls as f32)
+    }
+    
+    /// Builds a forward depot where it shortens the collectors' hauls the most.
+    /// 
+    /// Does nothing unless `depot_cost` is set, the station holds that many
+    /// minerals and the map has room for another station (`MAX_STATIONS`).
+    /// Candidates are the empty tiles of the global memory that robots can
+    /// walk to, at least [`DEPOT_SPACING`] tiles from every station; the one
+    /// bringing the known remaining deposits closest to a station wins, if
+    /// it brings them closer at all. The depot is added to the map
+    /// (`Map::add_depot`) and robots dock at it whenever it is their nearest
+    /// station; knowledge and robot manufacturing stay with this station.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::map::Map;
+    /// # use ereea::station::Station;
+    /// # use ereea::types::{TileType, MAP_SIZE};
+    /// let mut map = Map::open();
+    /// map.update_reachability();
+    /// let mut station = Station::new();
+    /// for y in 0..MAP_SIZE {
+    ///     for x in 0..MAP_SIZE {
+    ///         station.global_memory[(x, y)].explored = true;
+    ///     }
+    /// }
+    /// // Minerals left in the far corner only
+    /// for (x, y) in [(1, 1), (2, 1), (1, 2)] {
+    ///     station.global_memory[(x, y)].tile_type = TileType::Mineral;
+    /// }
+    /// station.reindex_memory();
+    /// station.collected_minerals = 40;
+    /// 
+    /// // Disabled by default
+    /// assert_eq!(station.try_build_depot(&mut map), None);
+    /// 
+    /// station.depot_cost = 30;
+    /// let depot = station.try_build_depot(&mut map).unwrap();
+    /// assert!(depot.0 <= 2 && depot.1 <= 2);
+    /// assert!(map.is_station(depot.0, depot.1));
+    /// assert_eq!(map.nearest_station(1, 1), depot);
+    /// assert_eq!(station.collected_minerals, 10);
+    /// 
+    /// // Not enough minerals left for another one
+    /// assert_eq!(station.try_build_depot(&mut map), None);
     /// ```
-    pub fn deposit_resources(&mut self, minerals: u32, scientific_data: u32) {
-        // NOTE - Depositing minerals and scientific data
-        self.collected_minerals += minerals;
-        self.collected_scientific_data += scientific_data;
-        self.energy_reserves += minerals; // Conversion minerais -> énergie
+    pub fn try_build_depot(&mut self, map: &mut Map) -> Option<(usize, usize)> {
+        if self.depot_cost == 0 || self.collected_minerals < self.depot_cost || map.stations().len() >= MAX_STATIONS {
+            return None;
+        }
+        
+        let deposits: Vec<Position> = self.global_memory.iter_coords()
+            .filter(|(_, tile)| tile.explored && matches!(tile.tile_type, TileType::Energy | TileType::Mineral | TileType::Scientific))
+            .map(|(position, _)| Position::from(position))
+            .collect();
+        let stations: Vec<Position> = map.stations().into_iter().map(Position::from).collect();
+        let nearest = |deposit: Position| stations.iter().map(|&station| deposit.chebyshev_distance(station)).min().unwrap_or(0);
+        // Distance totale des gisements connus à la station la plus proche, avec ou sans le nouveau dépôt
+        let haul_with = |depot: Position| deposits.iter()
+            .map(|&deposit| nearest(deposit).min(deposit.chebyshev_distance(depot)))
+            .sum::<usize>();
+        let current: usize = deposits.iter().map(|&deposit| nearest(deposit)).sum();
+        
+        let (total, site) = self.global_memory.iter_coords()
+            .filter(|&((x, y), tile)| {
+                tile.explored && tile.tile_type == TileType::Empty
+                    && map.get_tile(x, y) == TileType::Empty && map.is_reachable(x, y)
+                    && stations.iter().all(|&station| Position::new(x, y).chebyshev_distance(station) >= DEPOT_SPACING)
+            })
+            .map(|((x, y), _)| (haul_with(Position::new(x, y)), (x, y)))
+            .min()?;
+        if total >= current {
+            return None;
+        }
+        
+        self.collected_minerals -= self.depot_cost;
+        map.add_depot(site.0, site.1);
+        Some(site)
     }
     
     /// Generates a status report string summarizing the current state of the station.
@@ -515,45 +2723,70 @@ impl Station {
     /// conflict counts, and overall exploration progress. It is intended for display
     /// to the user or for logging purposes.
     /// 
+    /// # Parameters
+    /// 
+    /// - `map`: The current map, checked for remaining resources once exploration is complete
+    /// - `robots`: The fleet, which decides the next robot to build and so the cost reported
+    /// 
     /// # Returns
     /// 
     /// A formatted string containing the station's status report
     /// 
     /// # Examples
     /// 
+    /// The mission is reported complete once the whole map is explored and
+    /// every resource has been collected:
+    /// 
     /// ```rust
-    /// let station = Station::new();
-    /// let status_report = station.get_status();
-    /// println!("Status Report: {}", status_report);
+    /// # use ereea::{map::Map, station::Station, types::MAP_SIZE};
+    /// let mut map = Map::new();
+    /// let mut station = Station::new();
+    /// assert!(station.get_status(&map, &[]).starts_with("🔍 Phase d'exploration initiale"));
+    /// 
+    /// // Full exploration alone is not enough
+    /// station.reveal_map(&map);
+    /// assert!(station.get_status(&map, &[]).starts_with("🏁 Finalisation de la mission"));
+    /// 
+    /// // Drain every resource from the map
+    /// for y in 0..MAP_SIZE {
+    ///     for x in 0..MAP_SIZE {
+    ///         while map.consume_resource(x, y) > 0 {}
+    ///     }
+    /// }
+    /// assert!(station.get_status(&map, &[]).starts_with("🎉 MISSION TERMINÉE!"));
     /// ```
-    pub fn get_status(&self) -> String {
+    pub fn get_status(&self, map: &Map, robots: &[Robot]) -> String {
         // NOTE - Generating station status report string
         let exploration_pct = self.get_exploration_percentage();
         
-        let status = if exploration_pct >= 100.0 && self.are_all_resources_collected_placeholder() {
+        let status = if self.is_mission_complete(map) {
             "🎉 MISSION TERMINÉE!"
-        } else if exploration_pct < 30.0 {
-            "🔍 Phase d'exploration initiale"
-        } else if exploration_pct < 60.0 {
-            "⚡ Collecte d'énergie et minerais"
-        } else if exploration_pct < 100.0 {
-            "🧪 Collecte scientifique en cours"
         } else {
-            "🏁 Finalisation de la mission"
+            match self.objectives.phase(&self.collector_policy, exploration_pct) {
+                MissionPhase::InitialExploration => "🔍 Phase d'exploration initiale",
+                MissionPhase::ResourceCollection => "⚡ Collecte d'énergie et minerais",
+                MissionPhase::ScientificCollection => "🧪 Collecte scientifique en cours",
+                MissionPhase::Finalization => "🏁 Finalisation de la mission",
+            }
         };
         
-        format!("{} | Exploration: {:.1}% | Création robot: {}/{} énergie, {}/{} minerai | Conflits: {}", 
+        // Coût du prochain robot à construire (coût par défaut une fois la mission terminée)
+        let cost = self.next_robot_recommendation(map, robots)
+            .map(|(robot_type, _)| self.robot_cost(robot_type))
+            .unwrap_or_else(|| self.config.robot_cost());
+        let mut report = format!("{} | Exploration: {:.1}% | Création robot: {}/{} énergie, {}/{} minerai | Conflits: {} | Objectifs: {}", 
                 status,
                 exploration_pct,
-                self.energy_reserves.min(50), 50,
-                self.collected_minerals.min(15), 15,
-                self.conflict_count)
-    }
-
-    // Fonction temporaire pour éviter les erreurs de compilation
-    fn are_all_resources_collected_placeholder(&self) -> bool {
-        // NOTE - Placeholder for resource collection check
-        false
+                self.energy_reserves.min(cost.energy), cost.energy,
+                self.collected_minerals.min(cost.minerals), cost.minerals,
+                self.conflict_count,
+                self.objectives.summary());
+        if self.is_blacked_out() {
+            report.push_str(" | 🪫 Réserves d'énergie épuisées");
+        } else if self.is_energy_low() {
+            report.push_str(" | ⚠️ Réserves d'énergie basses");
+        }
+        report
     }
     
     /// Calculates the overall percentage of the map that has been explored.
@@ -569,38 +2802,182 @@ impl Station {
     /// # Examples
     /// 
     /// ```rust
-    /// let station = Station::new();
+    /// # use ereea::station::Station;
+    /// let mut station = Station::new();
     /// 
     /// // Initially, nothing is explored
     /// assert_eq!(station.get_exploration_percentage(), 0.0);
     /// 
-    /// // After marking some tiles as explored
-    /// station.global_memory[0][0].explored = true;
-    /// station.global_memory[1][0].explored = true;
-    /// assert_eq!(station.get_exploration_percentage(), 12.5);
+    /// // After marking some tiles as explored (2 out of 400)
+    /// station.global_memory[(0, 0)].explored = true;
+    /// station.global_memory[(0, 1)].explored = true;
+    /// station.reindex_memory();
+    /// assert_eq!(station.get_exploration_percentage(), 0.5);
     /// ```
     pub fn get_exploration_percentage(&self) -> f32 {
-        // NOTE - Counting explored tiles in global memory
-        let mut explored_count = 0;
+        // NOTE - Explored tiles are counted as they are merged into global memory
+        #[cfg(debug_assertions)]
+        self.check_explored_count();
+        
+        (self.explored_in_area as f32 / self.exploration_area.size() as f32) * 100.0
+    }
+    
+    /// Restricts the tiles that count in the exploration percentage.
+    /// 
+    /// With `ExplorationArea::reachable`, obstacles and pockets sealed off by
+    /// obstacles no longer keep the exploration from reaching 100%. Robots
+    /// built afterwards receive the same area; call
+    /// `Robot::set_exploration_area` for robots built before.
+    /// 
+    /// # Examples
+    /// 
+    /// A sealed 3x3 pocket does not keep the explorers from completing the map:
+    /// 
+    /// ```rust
+    /// # use ereea::knowledge::ExplorationArea;
+    /// # use ereea::map::Map;
+    /// # use ereea::occupancy::OccupancyGrid;
+    /// # use ereea::robot::Robot;
+    /// # use ereea::station::Station;
+    /// # use ereea::types::{RobotType, TileType, VisionModel};
+    /// let mut map = Map::open();
+    /// for y in 3..=7 {
+    ///     map.tiles[y][13] = TileType::Obstacle;
+    ///     map.tiles[y][17] = TileType::Obstacle;
+    /// }
+    /// for x in 13..=17 {
+    ///     map.tiles[3][x] = TileType::Obstacle;
+    ///     map.tiles[7][x] = TileType::Obstacle;
+    /// }
+    /// map.update_reachability();
+    /// 
+    /// let mut station = Station::new();
+    /// station.vision_model = VisionModel::LineOfSight;
+    /// station.set_exploration_area(ExplorationArea::reachable(&map));
+    /// let mut explorer = Robot::new(map.station_x, map.station_y, RobotType::Explorer);
+    /// explorer.vision_model = station.vision_model;
+    /// explorer.set_exploration_area(station.exploration_area().clone());
+    /// let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    /// 
+    /// for _ in 0..2000 {
+    ///     explorer.energy = explorer.max_energy;
+    ///     explorer.update(&mut map, &mut station, &mut occupancy);
+    ///     station.share_knowledge(&mut explorer);
+    ///     if station.get_exploration_percentage() == 100.0 {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(explorer.get_exploration_percentage(), 100.0);
+    /// assert_eq!(station.get_exploration_percentage(), 100.0);
+    /// assert!(!station.global_memory[(15, 5)].explored);
+    /// ```
+    pub fn set_exploration_area(&mut self, area: ExplorationArea) {
+        self.exploration_area = area;
+        self.reindex_memory();
+    }
+    
+    /// Tiles that count in the exploration percentage
+    pub fn exploration_area(&self) -> &ExplorationArea {
+        &self.exploration_area
+    }
+    
+    /// Number of explored tiles in the station's global memory
+    pub fn explored_count(&self) -> usize {
+        self.explored_count
+    }
+    
+    /// Marks every tile of `map` as explored in the global memory, as a
+    /// completed survey would.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::{map::Map, station::Station};
+    /// # use ereea::types::TileType;
+    /// let mut map = Map::open();
+    /// map.set_tile(3, 3, TileType::Mineral);
+    /// let mut station = Station::new();
+    /// station.reveal_map(&map);
+    /// assert_eq!(station.get_exploration_percentage(), 100.0);
+    /// assert_eq!(station.global_memory[(3, 3)].tile_type, TileType::Mineral);
+    /// ```
+    pub fn reveal_map(&mut self, map: &Map) {
+        for ((x, y), tile) in self.global_memory.iter_coords_mut() {
+            tile.explored = true;
+            tile.tile_type = map.get_tile(x, y);
+        }
+        self.reindex_memory();
+    }
+    
+    /// Recomputes the explored-tile count after `global_memory` was edited directly
+    pub fn reindex_memory(&mut self) {
+        self.explored_count = self.count_explored_tiles();
+        self.explored_in_area = self.count_explored_in_area();
+    }
+    
+    // NOTE - Full scan of global memory, used to (re)build the incremental counter
+    fn count_explored_tiles(&self) -> usize {
+        self.global_memory.iter().filter(|tile| tile.explored).count()
+    }
+    
+    // NOTE - Full scan of global memory restricted to the exploration area
+    fn count_explored_in_area(&self) -> usize {
+        self.global_memory.iter_coords()
+            .filter(|&((x, y), tile)| tile.explored && self.exploration_area.contains(x, y))
+            .count()
+    }
+    
+    // NOTE - Catch any global memory update that bypassed the counter (debug builds only)
+    #[cfg(debug_assertions)]
+    fn check_explored_count(&self) {
+        assert_eq!(self.explored_count, self.count_explored_tiles(),
+                   "compteur de cases explorées de la station désynchronisé");
+        assert_eq!(self.explored_in_area, self.count_explored_in_area(),
+                   "compteur de la zone d'exploration de la station désynchronisé");
+    }
+    
+    /// Gathers the current mission metrics into a `MissionStats`.
+    /// 
+    /// # Parameters
+    /// 
+    /// - `map`: The current map, scanned for remaining resources
+    /// - `robots`: The deployed fleet, counted per type
+    pub fn statistics(&self, map: &Map, robots: &[Robot]) -> MissionStats {
+        let mut robots_by_type = BTreeMap::new();
+        for robot in robots {
+            *robots_by_type.entry(robot.robot_type).or_insert(0) += 1;
+        }
         
+        let mut resources_remaining = BTreeMap::new();
         for y in 0..MAP_SIZE {
             for x in 0..MAP_SIZE {
-                if self.global_memory[y][x].explored {
-                    explored_count += 1;
+                let tile = map.get_tile(x, y);
+                if matches!(tile, TileType::Energy | TileType::Mineral | TileType::Scientific) {
+                    *resources_remaining.entry(tile).or_insert(0) += 1;
                 }
             }
         }
         
-        (explored_count as f32 / (MAP_SIZE * MAP_SIZE) as f32) * 100.0
+        MissionStats {
+            collected_energy: self.collected_energy,
+            collected_minerals: self.collected_minerals,
+            collected_scientific_data: self.collected_scientific_data,
+            exploration_percentage: self.get_exploration_percentage(),
+            conflict_count: self.conflict_count,
+            peer_exchange_count: self.knowledge_exchanges,
+            robots_by_type,
+            total_ticks: self.current_time,
+            resources_remaining,
+        }
     }
     
     // NOUVELLES FONCTIONS POUR LA MISSION COMPLÈTE
     
-    /// Checks if all mission objectives are complete, including full map exploration and resource collection.
+    /// Checks if all mission objectives are complete, robots back home included.
     /// 
-    /// This function verifies that the exploration percentage is at 100%, that all resources have been collected,
-    /// and that all robots are either idle at the station or in a completed state. This is used to determine
-    /// if the mission can be considered finished.
+    /// This function verifies that the mission objectives are met (`is_mission_complete`) and, when
+    /// `objectives.require_all_home` is set, that all robots are idle at their station. This is used to
+    /// determine if the mission can be considered finished.
     /// 
     /// # Parameters
     /// 
@@ -613,7 +2990,7 @@ impl Station {
     /// 
     /// # Examples
     /// 
-    /// ```rust
+    /// ```rust,ignore
     /// let station = Station::new();
     /// let map = Map::new();
     /// let robots = vec![Robot::new(), Robot::new()];
@@ -621,45 +2998,22 @@ impl Station {
     /// // After completing exploration and resource collection
     /// assert!(station.is_all_missions_complete(&map, &robots));
     /// ```
-    pub fn is_all_missions_complete(&self, map: &Map, robots: &Vec<Robot>) -> bool {
-        // NOTE - Check if map is fully explored
-        if self.get_exploration_percentage() < 100.0 {
-            return false;
-        }
-        
-        // NOTE - Check if all resources are collected
-        if !self.are_all_resources_collected(map) {
-            return false;
-        }
-        
-        // NOTE - Check if all robots are at the station and idle
-        for robot in robots {
-            match robot.robot_type {
-                RobotType::Explorer => {
-                    if robot.mode != crate::types::RobotMode::Idle || 
-                       robot.x != robot.home_station_x || 
-                       robot.y != robot.home_station_y {
-                        return false;
-                    }
-                },
-                _ => {
-                    if robot.mode != crate::types::RobotMode::Idle || 
-                       robot.x != robot.home_station_x || 
-                       robot.y != robot.home_station_y {
-                        return false;
-                    }
-                }
-            }
-        }
-        
-        true // Toutes les conditions sont remplies
+    pub fn is_all_missions_complete(&self, map: &Map, robots: &[Robot]) -> bool {
+        // NOTE - Check the objectives, then (if required) that all robots are at the station and idle
+        self.is_mission_complete(map)
+            && (!self.objectives.require_all_home || robots.iter().all(|robot| {
+                robot.mode == RobotMode::Idle && robot.x == robot.home_station_x && robot.y == robot.home_station_y
+            }))
     }
     
-    /// Checks if the current mission is complete, which requires all resources to be collected.
+    /// Checks if the mission objectives (`objectives`) are met.
     /// 
-    /// This function is a simplified check used when the mission parameters do not require
-    /// full exploration, but rather the collection of specific resources. It verifies that
-    /// no resources are left on the map.
+    /// With the default objectives, the whole map must be explored and no
+    /// resource left on the map. Lighter objectives end the mission sooner:
+    /// a share of the map explored, a number of minerals or scientific data
+    /// units delivered. Robots are not waited for (see `is_all_missions_complete`),
+    /// they head home once this returns `true`. In endless mode (`endless`),
+    /// the mission never completes.
     /// 
     /// # Parameters
     /// 
@@ -667,20 +3021,36 @@ impl Station {
     /// 
     /// # Returns
     /// 
-    /// `true` if the mission is complete (all resources collected), `false` otherwise
+    /// `true` if every objective is met, `false` otherwise
     /// 
     /// # Examples
     /// 
     /// ```rust
-    /// let station = Station::new();
-    /// let map = Map::new();
+    /// # use ereea::{map::Map, station::Station, types::MAP_SIZE};
+    /// let mut station = Station::new();
+    /// let mut map = Map::new();
+    /// station.reveal_map(&map);
+    /// assert!(!station.is_mission_complete(&map));
     /// 
     /// // After collecting all resources
+    /// for y in 0..MAP_SIZE {
+    ///     for x in 0..MAP_SIZE {
+    ///         while map.consume_resource(x, y) > 0 {}
+    ///     }
+    /// }
     /// assert!(station.is_mission_complete(&map));
+    /// 
+    /// // Endless runs keep going
+    /// station.endless = true;
+    /// assert!(!station.is_mission_complete(&map));
     /// ```
     pub fn is_mission_complete(&self, map: &Map) -> bool {
-        // NOTE - Check if all resources are collected
-        self.are_all_resources_collected(map)
+        // NOTE - Cheap objectives first, the resource scan last (never complete in endless mode)
+        !self.endless
+            && self.get_exploration_percentage() >= self.objectives.min_exploration_pct
+            && self.delivered_minerals >= self.objectives.min_minerals
+            && self.collected_scientific_data >= self.objectives.min_scientific_data
+            && (!self.objectives.collect_all_resources || self.energy_exhausted || self.are_all_resources_collected(map))
     }
     
     /// Vérifier que toutes les ressources ont été collectées
@@ -698,4 +3068,35 @@ impl Station {
         }
         true // Aucune ressource trouvée
     }
-}
\ No newline at end of file
+}
+
+impl KnowledgeBase for Station {
+    fn known_tile(&self, x: usize, y: usize) -> &TerrainData {
+        &self.global_memory[(x, y)]
+    }
+    
+    // NOTE - Keeps the explored counters in step with the global memory
+    fn learn_tile(&mut self, x: usize, y: usize, data: TerrainData) {
+        if data.explored && !self.global_memory[(x, y)].explored {
+            self.explored_count += 1;
+            if self.exploration_area.contains(x, y) {
+                self.explored_in_area += 1;
+            }
+        }
+        self.global_memory[(x, y)] = data;
+        
+        // NOTE - What the radio may not send yet (older entries have crossed the link)
+        if self.radio_range > 0 {
+            while self.radio_recent.front().is_some_and(|&(learned, _)| learned.saturating_add(self.radio_latency) < self.current_time) {
+                self.radio_recent.pop_front();
+            }
+            self.radio_recent.push_back((self.current_time, (x, y)));
+        }
+    }
+}
+
+impl Default for Station {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -9,14 +9,65 @@
 //! - **TileType**: Represents different terrain and resource types on the exploration map
 //! - **RobotType**: Defines the specialization categories for exploration robots
 //! - **RobotMode**: Describes the current behavioral state of robots
+//! - **Upgrade**: Modules the station installs on docked robots
+//! - **StationUpgrade**: Improvements the station buys for itself
+//! - **DayPhase**: Day or night, modulating the energy extracted from deposits
+//! - **VisionModel**: Whether obstacles hide what lies behind them from robots
+//! - **BrainKind**: Exploration policy a robot follows (see the `brain` module)
 //! - **MAP_SIZE**: Global constant defining the dimensions of the exploration grid
+//! - **Position**: Tile coordinates and the distance used for robot navigation
+//! - **CollectorPolicy**: Exploration thresholds gating each collector type, and the mission phases
+//! - **Inventory**: Resource units carried by a robot or unloaded at the station
 //! 
 //! All types are serializable for network transmission between simulation server and Earth control.
+//! Enums are written as their variant name in human-readable formats (JSON) and as a stable
+//! integer tag in binary formats (MessagePack), see `wire_enum!`.
 
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use std::fmt;
+use std::ops::{Add, AddAssign};
+
+// NOTE - Serde impls for the enums sent over the network.
+// Human-readable formats keep the variant name, binary formats use the given tag.
+// Tags are part of the wire protocol: never renumber or reuse them.
+macro_rules! wire_enum {
+    ($name:ident { $($variant:ident = $tag:literal),+ $(,)? }) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let (name, tag) = match self {
+                    $($name::$variant => (stringify!($variant), $tag),)+
+                };
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(name)
+                } else {
+                    serializer.serialize_u8(tag)
+                }
+            }
+        }
+        
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    let name = String::deserialize(deserializer)?;
+                    match name.as_str() {
+                        $(stringify!($variant) => Ok($name::$variant),)+
+                        other => Err(serde::de::Error::unknown_variant(other, &[$(stringify!($variant)),+])),
+                    }
+                } else {
+                    match u8::deserialize(deserializer)? {
+                        $($tag => Ok($name::$variant),)+
+                        other => Err(serde::de::Error::custom(
+                            format!("tag {} inconnu pour {}", other, stringify!($name))
+                        )),
+                    }
+                }
+            }
+        }
+    };
+}
 
 /// NOTE - Enum for all possible tile types on the map
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TileType {
     Empty,      // NOTE - Traversable empty tile
     Obstacle,   // NOTE - Impassable terrain
@@ -25,23 +76,573 @@ pub enum TileType {
     Scientific, // NOTE - Scientific data point
 }
 
+wire_enum!(TileType { Empty = 0, Obstacle = 1, Energy = 2, Mineral = 3, Scientific = 4 });
+
 /// NOTE - Enum for robot specialization types
-#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum RobotType {
     Explorer,             // NOTE - General exploration robot
     EnergyCollector,      // NOTE - Energy harvesting robot
     MineralCollector,     // NOTE - Mineral extraction robot
     ScientificCollector,  // NOTE - Scientific data robot
+    Repairer,             // NOTE - Brings energy to stranded robots
+    Relay,                // NOTE - Links distant robots to the station
 }
 
+wire_enum!(RobotType { Explorer = 0, EnergyCollector = 1, MineralCollector = 2, ScientificCollector = 3, Repairer = 4, Relay = 5 });
+
 /// NOTE - Enum for robot operational modes
-#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RobotMode {
     Exploring,        // NOTE - Mapping unknown territory
     Collecting,       // NOTE - Gathering resources
     ReturnToStation,  // NOTE - Returning to base
     Idle,             // NOTE - Standby at station
+    Stranded,         // NOTE - Cannot reach the station, waiting for rescue
+    Rescuing,         // NOTE - Repairer heading for a stranded robot
+    Broken,           // NOTE - Malfunction, cannot move or collect until repaired
+    Charging,         // NOTE - Docked, recharging from the station reserves (or waiting for a bay)
+    Scanning,         // NOTE - Explorer standing still for one update to scan a wide area
+}
+
+wire_enum!(RobotMode { Exploring = 0, Collecting = 1, ReturnToStation = 2, Idle = 3, Stranded = 4, Rescuing = 5, Broken = 6, Charging = 7, Scanning = 8 });
+
+/// NOTE - Enum for robot upgrade modules, installed by the station (see `Station::try_install_upgrade`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Upgrade {
+    VisionModule,      // NOTE - +2 vision range
+    BatteryModule,     // NOTE - +40 maximum energy
+    EfficiencyModule,  // NOTE - -20% movement energy cost
+    PropulsionModule,  // NOTE - +25% speed
+}
+
+wire_enum!(Upgrade { VisionModule = 0, BatteryModule = 1, EfficiencyModule = 2, PropulsionModule = 3 });
+
+/// NOTE - Enum for the phases of the day/night cycle (see `Station::day_phase`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DayPhase {
+    #[default]
+    Day,    // NOTE - Solar deposits yield more energy
+    Night,  // NOTE - Solar deposits yield less energy
+}
+
+wire_enum!(DayPhase { Day = 0, Night = 1 });
+
+/// NOTE - Enum for the regions of the map, each richer in one kind of deposit (see `Map::biome`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Biome {
+    EnergyRich,      // NOTE - Wide energy band, few minerals and scientific points
+    MineralRich,     // NOTE - Wide mineral band, little energy and science
+    ScientificRich,  // NOTE - Scientific points far more common than elsewhere
+}
+
+wire_enum!(Biome { EnergyRich = 0, MineralRich = 1, ScientificRich = 2 });
+
+/// NOTE - Enum for how robots see the tiles within their vision range (see `Robot::update_memory`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum VisionModel {
+    Square,           // NOTE - Every tile of the square around the robot, straight through obstacles
+    #[default]
+    LineOfSight,      // NOTE - Only tiles whose ray from the robot isn't interrupted by an obstacle
+}
+
+/// NOTE - Enum for the exploration policies built into the simulation (see `brain::RobotBrain`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BrainKind {
+    #[default]
+    Default,  // NOTE - Built-in frontier planner, coordinated with the station
+    Sweep,    // NOTE - Horizontal lanes swept back and forth
+}
+
+wire_enum!(BrainKind { Default = 0, Sweep = 1 });
+
+impl TileType {
+    // NOTE - True for the three kinds of deposit robots collect
+    pub fn is_resource(&self) -> bool {
+        matches!(self, TileType::Energy | TileType::Mineral | TileType::Scientific)
+    }
+}
+
+impl Upgrade {
+    /// Price of the module for the station, as `(energy, minerals)`
+    pub fn cost(self) -> (u32, u32) {
+        match self {
+            Upgrade::VisionModule => (20, 10),
+            Upgrade::BatteryModule => (30, 10),
+            Upgrade::EfficiencyModule => (25, 15),
+            Upgrade::PropulsionModule => (25, 20),
+        }
+    }
+}
+
+/// NOTE - Enum for the station's own upgrades, each bought once (see `Station::purchase_upgrade`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StationUpgrade {
+    ChargingBays,   // NOTE - +2 charging bays
+    BuildSlot,      // NOTE - A second robot built at each fleet growth
+    EnergyStorage,  // NOTE - +500 units of energy storage
+    LeanAssembly,   // NOTE - -20% on the cost of robots
+}
+
+wire_enum!(StationUpgrade { ChargingBays = 0, BuildSlot = 1, EnergyStorage = 2, LeanAssembly = 3 });
+
+impl StationUpgrade {
+    /// Order in which the station buys its upgrades (see `Station::try_upgrade_station`)
+    pub const LADDER: [StationUpgrade; 4] = [
+        StationUpgrade::ChargingBays,
+        StationUpgrade::EnergyStorage,
+        StationUpgrade::LeanAssembly,
+        StationUpgrade::BuildSlot,
+    ];
+    
+    /// Price of the upgrade, as `(energy, minerals)`
+    pub fn cost(self) -> (u32, u32) {
+        match self {
+            StationUpgrade::ChargingBays => (60, 10),
+            StationUpgrade::EnergyStorage => (80, 5),
+            StationUpgrade::LeanAssembly => (100, 20),
+            StationUpgrade::BuildSlot => (150, 30),
+        }
+    }
+}
+
+impl fmt::Display for StationUpgrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StationUpgrade::ChargingBays => write!(f, "baies de recharge supplémentaires"),
+            StationUpgrade::BuildSlot => write!(f, "seconde chaîne de montage"),
+            StationUpgrade::EnergyStorage => write!(f, "stockage d'énergie agrandi"),
+            StationUpgrade::LeanAssembly => write!(f, "montage allégé"),
+        }
+    }
+}
+
+/// NOTE - Enum for why a collector gave up the resource it was heading to (see `RobotEvent::TargetAbandoned`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GiveUpReason {
+    Unreachable,  // NOTE - No path from the robot to the resource
+    NoWayBack,    // NOTE - No path from the resource back to the station
+    LowEnergy,    // NOTE - Not enough energy for the round trip
+    Depleted,     // NOTE - Emptied by another robot on the way
+}
+
+wire_enum!(GiveUpReason { Unreachable = 0, NoWayBack = 1, LowEnergy = 2, Depleted = 3 });
+
+impl fmt::Display for GiveUpReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GiveUpReason::Unreachable => write!(f, "inaccessible"),
+            GiveUpReason::NoWayBack => write!(f, "aucun chemin de retour vers la station"),
+            GiveUpReason::LowEnergy => write!(f, "énergie insuffisante pour l'aller-retour"),
+            GiveUpReason::Depleted => write!(f, "épuisée par un autre robot"),
+        }
+    }
+}
+
+/// Resource units carried by a robot (see `Robot::inventory`) or unloaded
+/// at the station (see `Station::deposit_resources`).
+/// 
+/// Each unit weighs the same in a hold, whatever its kind: `total_weight`
+/// is what counts against a robot's `cargo_capacity`.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// # use ereea::types::{Inventory, TileType};
+/// let mut hold = Inventory::default();
+/// hold.add(TileType::Mineral, 2);
+/// hold.add(TileType::Scientific, 1);
+/// hold.add(TileType::Obstacle, 5);
+/// assert_eq!(hold, Inventory { energy: 0, minerals: 2, science: 1 });
+/// assert_eq!((hold.get(&TileType::Mineral), hold.total_weight()), (2, 3));
+/// 
+/// // Loads add up kind by kind
+/// let haul = Inventory { energy: 25, ..Inventory::default() };
+/// assert_eq!(hold + haul, Inventory { energy: 25, minerals: 2, science: 1 });
+/// let fleet: Inventory = [hold, haul, hold].into_iter().sum();
+/// assert_eq!(fleet.total_weight(), 31);
+/// 
+/// // Capacity checks
+/// assert_eq!(hold.space_left(5), 2);
+/// assert!(!hold.is_full(5) && hold.is_full(3));
+/// assert_eq!(haul.space_left(10), 0);
+/// assert!(Inventory::default().is_empty() && !hold.is_empty());
+/// 
+/// // Counters at their limit never overflow the weight
+/// let saturated = Inventory { energy: u32::MAX, minerals: 1, science: 0 };
+/// assert_eq!((saturated.total_weight(), saturated.space_left(10)), (u32::MAX, 0));
+/// assert!(!saturated.is_empty());
+/// 
+/// // Nor do additions: each kind stops at `u32::MAX`
+/// let mut nearly_full = Inventory { energy: u32::MAX - 1, minerals: u32::MAX, science: 3 };
+/// nearly_full.add(TileType::Energy, 5);
+/// nearly_full.add(TileType::Mineral, 1);
+/// assert_eq!(nearly_full, Inventory { energy: u32::MAX, minerals: u32::MAX, science: 3 });
+/// assert_eq!(nearly_full + saturated, Inventory { energy: u32::MAX, minerals: u32::MAX, science: 3 });
+/// let mut total = saturated;
+/// total += Inventory { energy: 1, minerals: u32::MAX, science: u32::MAX };
+/// assert_eq!(total, Inventory { energy: u32::MAX, minerals: u32::MAX, science: u32::MAX });
+/// ```
+/// 
+/// Serialized with one field per kind:
+/// 
+/// ```rust
+/// # use ereea::types::Inventory;
+/// let hold = Inventory { energy: 0, minerals: 2, science: 1 };
+/// let json = serde_json::to_string(&hold).unwrap();
+/// assert_eq!(json, r#"{"energy":0,"minerals":2,"science":1}"#);
+/// assert_eq!(serde_json::from_str::<Inventory>(r#"{"minerals":2,"science":1}"#).unwrap(), hold);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct Inventory {
+    /// Energy units hauled to the station's reserves
+    pub energy: u32,
+    /// Mineral units
+    pub minerals: u32,
+    /// Scientific data units
+    pub science: u32,
+}
+
+impl Inventory {
+    /// Adds units of a resource (tiles that are not resources are ignored; saturates at `u32::MAX`)
+    pub fn add(&mut self, resource: TileType, amount: u32) {
+        match resource {
+            TileType::Energy => self.energy = self.energy.saturating_add(amount),
+            TileType::Mineral => self.minerals = self.minerals.saturating_add(amount),
+            TileType::Scientific => self.science = self.science.saturating_add(amount),
+            _ => {}
+        }
+    }
+    
+    /// Units of a resource held (0 for tiles that are not resources)
+    pub fn get(&self, resource: &TileType) -> u32 {
+        match resource {
+            TileType::Energy => self.energy,
+            TileType::Mineral => self.minerals,
+            TileType::Scientific => self.science,
+            _ => 0,
+        }
+    }
+    
+    /// True when nothing is held
+    pub fn is_empty(&self) -> bool {
+        self.total_weight() == 0
+    }
+    
+    /// Units held, all kinds together (saturates at `u32::MAX` instead of overflowing)
+    pub fn total_weight(&self) -> u32 {
+        self.energy.saturating_add(self.minerals).saturating_add(self.science)
+    }
+    
+    /// Units that still fit under `capacity` (0 once it is reached or exceeded)
+    pub fn space_left(&self, capacity: u32) -> u32 {
+        capacity.saturating_sub(self.total_weight())
+    }
+    
+    /// True once `capacity` is reached (never for a capacity of 0, which means carrying nothing)
+    pub fn is_full(&self, capacity: u32) -> bool {
+        capacity > 0 && self.total_weight() >= capacity
+    }
+}
+
+impl AddAssign for Inventory {
+    fn add_assign(&mut self, other: Self) {
+        self.energy = self.energy.saturating_add(other.energy);
+        self.minerals = self.minerals.saturating_add(other.minerals);
+        self.science = self.science.saturating_add(other.science);
+    }
+}
+
+impl Add for Inventory {
+    type Output = Self;
+    
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl std::iter::Sum for Inventory {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, inventory| total + inventory)
+    }
 }
 
 /// NOTE - Global constant for map size (square grid)
-pub const MAP_SIZE: usize = 20;
\ No newline at end of file
+pub const MAP_SIZE: usize = 20;
+
+/// NOTE - Exploration thresholds (in %) at which each collector type starts working.
+/// 
+/// Below its threshold a collector stays at (or returns to) the station. The
+/// same values define the mission phases shown by the station status and the
+/// Earth client, so both always agree with robot behavior.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CollectorPolicy {
+    /// Exploration needed before energy collectors leave the station
+    pub energy_start_pct: f32,
+    /// Exploration needed before mineral collectors leave the station
+    pub mineral_start_pct: f32,
+    /// Exploration needed before scientific collectors leave the station
+    pub scientific_start_pct: f32,
+}
+
+/// NOTE - Mission phases derived from the exploration percentage and a `CollectorPolicy`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissionPhase {
+    /// No collector is allowed to work yet
+    InitialExploration,
+    /// Energy and/or mineral collectors are working, scientific ones still wait
+    ResourceCollection,
+    /// Every collector type is working
+    ScientificCollection,
+    /// The whole map has been explored
+    Finalization,
+}
+
+impl CollectorPolicy {
+    /// Historical pacing: energy and minerals from 30%, science from 60%
+    pub const DEFAULT: Self = Self {
+        energy_start_pct: 30.0,
+        mineral_start_pct: 30.0,
+        scientific_start_pct: 60.0,
+    };
+    
+    /// No exploration threshold: every collector may work from the first tick
+    pub const IMMEDIATE: Self = Self {
+        energy_start_pct: 0.0,
+        mineral_start_pct: 0.0,
+        scientific_start_pct: 0.0,
+    };
+    
+    /// Exploration percentage from which `robot_type` may collect (0 for explorers, repairers and relays)
+    pub fn start_threshold(&self, robot_type: RobotType) -> f32 {
+        match robot_type {
+            RobotType::Explorer | RobotType::Repairer | RobotType::Relay => 0.0,
+            RobotType::EnergyCollector => self.energy_start_pct,
+            RobotType::MineralCollector => self.mineral_start_pct,
+            RobotType::ScientificCollector => self.scientific_start_pct,
+        }
+    }
+    
+    /// Tells whether `robot_type` may collect at this exploration percentage
+    pub fn allows(&self, robot_type: RobotType, exploration_pct: f32) -> bool {
+        exploration_pct >= self.start_threshold(robot_type)
+    }
+    
+    /// Mission phase at this exploration percentage.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::types::{CollectorPolicy, MissionPhase, RobotType};
+    /// let policy = CollectorPolicy { energy_start_pct: 10.0, ..CollectorPolicy::DEFAULT };
+    /// 
+    /// assert_eq!(policy.phase(5.0), MissionPhase::InitialExploration);
+    /// assert_eq!(policy.phase(15.0), MissionPhase::ResourceCollection);
+    /// assert!(policy.allows(RobotType::EnergyCollector, 15.0));
+    /// assert!(!policy.allows(RobotType::MineralCollector, 15.0));
+    /// assert_eq!(policy.phase(60.0), MissionPhase::ScientificCollection);
+    /// assert_eq!(policy.phase(100.0), MissionPhase::Finalization);
+    /// ```
+    pub fn phase(&self, exploration_pct: f32) -> MissionPhase {
+        let first_start = self.energy_start_pct
+            .min(self.mineral_start_pct)
+            .min(self.scientific_start_pct);
+        let last_start = self.energy_start_pct
+            .max(self.mineral_start_pct)
+            .max(self.scientific_start_pct);
+        
+        if exploration_pct >= 100.0 {
+            MissionPhase::Finalization
+        } else if exploration_pct < first_start {
+            MissionPhase::InitialExploration
+        } else if exploration_pct < last_start {
+            MissionPhase::ResourceCollection
+        } else {
+            MissionPhase::ScientificCollection
+        }
+    }
+}
+
+impl Default for CollectorPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// NOTE - Goals the mission must reach to be complete (see `Station::is_mission_complete`).
+/// 
+/// `DEFAULT` is the historical mission: the whole map explored, every
+/// resource collected and every robot back at its station. Lighter goals
+/// turn the simulation into shorter scenarios, such as mapping 80% of the
+/// planet and bringing back 50 minerals.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct MissionObjectives {
+    /// Share of the map the station must know (in %)
+    pub min_exploration_pct: f32,
+    /// Mineral units the station must have received (spent ones included)
+    pub min_minerals: u32,
+    /// Scientific data units the station must have received
+    pub min_scientific_data: u32,
+    /// Whether every resource of the map must have been collected
+    pub collect_all_resources: bool,
+    /// Whether the mission waits for every robot to be back at its station
+    pub require_all_home: bool,
+}
+
+impl MissionObjectives {
+    /// Historical mission: everything explored, collected and brought home
+    pub const DEFAULT: Self = Self {
+        min_exploration_pct: 100.0,
+        min_minerals: 0,
+        min_scientific_data: 0,
+        collect_all_resources: true,
+        require_all_home: true,
+    };
+    
+    /// Mission phase at this exploration percentage: the phase of `policy`,
+    /// turned into `Finalization` once every collector works and the
+    /// exploration goal is reached.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::types::{CollectorPolicy, MissionObjectives, MissionPhase};
+    /// let policy = CollectorPolicy::DEFAULT;
+    /// let partial = MissionObjectives { min_exploration_pct: 80.0, ..MissionObjectives::DEFAULT };
+    /// 
+    /// assert_eq!(partial.phase(&policy, 50.0), MissionPhase::ResourceCollection);
+    /// assert_eq!(partial.phase(&policy, 85.0), MissionPhase::Finalization);
+    /// assert_eq!(MissionObjectives::DEFAULT.phase(&policy, 85.0), MissionPhase::ScientificCollection);
+    /// ```
+    pub fn phase(&self, policy: &CollectorPolicy, exploration_pct: f32) -> MissionPhase {
+        match policy.phase(exploration_pct) {
+            MissionPhase::ScientificCollection if exploration_pct >= self.min_exploration_pct => MissionPhase::Finalization,
+            phase => phase,
+        }
+    }
+    
+    /// Short description of the goals, for status lines and logs.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// # use ereea::types::MissionObjectives;
+    /// assert_eq!(MissionObjectives::DEFAULT.summary(), "exploration 100%, toutes les ressources, retour des robots");
+    /// 
+    /// let scenario = MissionObjectives { min_exploration_pct: 80.0, min_minerals: 50, collect_all_resources: false, ..MissionObjectives::DEFAULT };
+    /// assert_eq!(scenario.summary(), "exploration 80%, 50 minerais, retour des robots");
+    /// ```
+    pub fn summary(&self) -> String {
+        let mut goals = vec![format!("exploration {}%", self.min_exploration_pct)];
+        if self.min_minerals > 0 {
+            goals.push(format!("{} minerais", self.min_minerals));
+        }
+        if self.min_scientific_data > 0 {
+            goals.push(format!("{} données scientifiques", self.min_scientific_data));
+        }
+        if self.collect_all_resources {
+            goals.push("toutes les ressources".to_string());
+        }
+        if self.require_all_home {
+            goals.push("retour des robots".to_string());
+        }
+        goals.join(", ")
+    }
+}
+
+impl Default for MissionObjectives {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// NOTE - Coordinates of a tile on the map grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    /// Creates a position from its coordinates
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+    
+    /// Number of moves between two tiles when diagonal and straight steps
+    /// have the same cost (Chebyshev distance).
+    /// 
+    /// Robots move in 8 directions, so this is exactly the path length on a
+    /// map without obstacles and never more than the true length otherwise:
+    /// it is an admissible A* heuristic.
+    /// 
+    /// # Examples
+    /// 
+    /// On random obstacle-free maps, the heuristic never exceeds the length of
+    /// the path found by A*, and matches it when all tiles cost the same:
+    /// 
+    /// ```rust
+    /// use ereea::map::Map;
+    /// use ereea::robot::Robot;
+    /// use ereea::types::{Position, RobotType, TileType, MAP_SIZE};
+    /// use rand::Rng;
+    /// 
+    /// let mut rng = rand::thread_rng();
+    /// let terrain = [TileType::Empty, TileType::Empty, TileType::Energy, TileType::Mineral, TileType::Scientific];
+    /// 
+    /// for round in 0..50 {
+    ///     let mut map = Map::new();
+    ///     map.tiles = (0..MAP_SIZE).map(|_| (0..MAP_SIZE).map(|_| {
+    ///         if round % 2 == 0 { TileType::Empty } else { terrain[rng.gen_range(0..terrain.len())].clone() }
+    ///     }).collect()).collect();
+    /// 
+    ///     let start = Position::new(rng.gen_range(0..MAP_SIZE), rng.gen_range(0..MAP_SIZE));
+    ///     let target = Position::new(rng.gen_range(0..MAP_SIZE), rng.gen_range(0..MAP_SIZE));
+    ///     let robot = Robot::new(start.x, start.y, RobotType::Explorer);
+    ///     let path = robot.find_path(&map, (target.x, target.y));
+    /// 
+    ///     assert!(start.chebyshev_distance(target) <= path.len());
+    ///     if round % 2 == 0 {
+    ///         assert_eq!(start.chebyshev_distance(target), path.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn chebyshev_distance(self, other: Position) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// NOTE - Mission assigned to a robot from outside (station, Earth operator).
+/// 
+/// Queued tasks run in order before the robot's autonomous behavior (see
+/// `Robot::assign_task`); a robot low on energy still goes home first.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RobotTask {
+    /// Walk to the tile
+    MoveTo(usize, usize),
+    /// Walk to the deposit and harvest it until it is empty
+    CollectAt(usize, usize),
+    /// Explore every tile within `radius` (Chebyshev) of `center`
+    Survey { center: (usize, usize), radius: usize },
+    /// Go back to the home station
+    ReturnHome,
+}
+
+impl fmt::Display for RobotTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RobotTask::MoveTo(x, y) => write!(f, "aller en ({}, {})", x, y),
+            RobotTask::CollectAt(x, y) => write!(f, "collecter en ({}, {})", x, y),
+            RobotTask::Survey { center: (x, y), radius } => write!(f, "relevé autour de ({}, {}), rayon {}", x, y, radius),
+            RobotTask::ReturnHome => write!(f, "retour à la base"),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// Outils partagés par les tests d'intégration qui font avancer une flotte
+// sans la boucle complète du serveur de simulation
+
+use ereea::map::Map;
+use ereea::occupancy::OccupancyGrid;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::RobotType;
+
+// NOTE - Fleet docked at the central station, IDs from 1, without the server's deployment setup
+pub fn docked_fleet(map: &Map, station: &mut Station, fleet: &[RobotType]) -> Vec<Robot> {
+    station.next_robot_id = fleet.len() + 1;
+    fleet.iter().zip(1..)
+        .map(|(&robot_type, id)| Robot::new_with_memory(map.station_x, map.station_y, robot_type, id,
+                                                        map.station_x, map.station_y, station.global_memory.clone()))
+        .collect()
+}
+
+// NOTE - One station tick followed by an update of every robot, in fleet order, without the rest of `step_simulation`
+pub fn tick(map: &mut Map, station: &mut Station, robots: &mut [Robot]) {
+    station.tick();
+    let mut occupancy = OccupancyGrid::from_robots(robots, map.station_x, map.station_y);
+    for robot in robots.iter_mut() {
+        robot.update(map, station, &mut occupancy);
+    }
+}
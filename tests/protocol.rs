@@ -0,0 +1,370 @@
+// Protocole réseau: chaque état diffusé doit se relire à l'identique côté client,
+// qu'il vienne d'une vraie mission ou d'un générateur aléatoire couvrant tous les champs ;
+// la poignée de main d'authentification se joue contre le vrai serveur, sur une socket
+
+use ereea::events::{MissionEvent, RobotEvent};
+use ereea::map::Map;
+use ereea::network::auth::{AuthError, AUTH_TIMEOUT_SECS};
+use ereea::network::codec::{encode_frame, FrameDecoder};
+use ereea::network::{
+    create_lite_keyframe_state, create_simulation_state, roundtrip_check, ExplorationData, LiteStateEncoder, MapData,
+    NetworkMessage, RobotData, RobotDataLite, RobotKeyframe, SimulationState, StationData,
+};
+use ereea::simulation::{deploy_fleet, step_simulation, INITIAL_FLEET};
+use ereea::station::{MissionStats, RobotCost, Station};
+use ereea::types::{Biome, CollectorPolicy, DayPhase, GiveUpReason, Inventory, MissionObjectives, RobotMode, RobotType, StationUpgrade, TileType, Upgrade};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+const TILES: [TileType; 5] = [TileType::Empty, TileType::Obstacle, TileType::Energy, TileType::Mineral, TileType::Scientific];
+const ROBOT_TYPES: [RobotType; 6] = [
+    RobotType::Explorer, RobotType::EnergyCollector, RobotType::MineralCollector,
+    RobotType::ScientificCollector, RobotType::Repairer, RobotType::Relay,
+];
+const MODES: [RobotMode; 9] = [
+    RobotMode::Exploring, RobotMode::Collecting, RobotMode::ReturnToStation, RobotMode::Idle, RobotMode::Stranded,
+    RobotMode::Rescuing, RobotMode::Broken, RobotMode::Charging, RobotMode::Scanning,
+];
+const UPGRADES: [Upgrade; 4] = [Upgrade::VisionModule, Upgrade::BatteryModule, Upgrade::EfficiencyModule, Upgrade::PropulsionModule];
+const GIVE_UP_REASONS: [GiveUpReason; 4] = [GiveUpReason::Unreachable, GiveUpReason::NoWayBack, GiveUpReason::LowEnergy, GiveUpReason::Depleted];
+const BIOMES: [Biome; 3] = [Biome::EnergyRich, Biome::MineralRich, Biome::ScientificRich];
+// Textes piégeux pour JSON: vides, accents, emoji, guillemets, échappements
+const TEXTS: [&str; 5] = ["", "Analyse 3/5", "🔋 cycle 42 : Collecting → ReturnToStation (soute pleine)", "\"guillemets\" et \\", "ligne\nsuivante\t"];
+
+// NOTE - Small generator in the spirit of proptest: every field drawn, edge values included
+struct Generator {
+    rng: StdRng,
+}
+
+impl Generator {
+    fn pick<T: Clone>(&mut self, values: &[T]) -> T {
+        values[self.rng.gen_range(0..values.len())].clone()
+    }
+
+    fn vec<T>(&mut self, max_len: usize, mut item: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        let len = self.rng.gen_range(0..=max_len);
+        (0..len).map(|_| item(self)).collect()
+    }
+
+    fn grid<T>(&mut self, mut item: impl FnMut(&mut Self) -> T) -> Vec<Vec<T>> {
+        let (width, height) = (self.rng.gen_range(0..6), self.rng.gen_range(0..6));
+        (0..height).map(|_| (0..width).map(|_| item(self)).collect()).collect()
+    }
+
+    fn flag(&mut self) -> bool {
+        self.rng.gen_bool(0.5)
+    }
+
+    fn small(&mut self) -> u32 {
+        self.pick(&[0, 1, 7, 255, 256, 65_535, 65_536, u32::MAX])
+    }
+
+    fn size(&mut self) -> usize {
+        self.pick(&[0, 1, 19, 20, 400, u32::MAX as usize, usize::MAX])
+    }
+
+    fn real(&mut self) -> f32 {
+        match self.rng.gen_range(0..3) {
+            0 => self.pick(&[0.0, -0.0, 0.1, 100.0, f32::MIN_POSITIVE, 1e-45, f32::MAX, f32::MIN]),
+            1 => self.rng.gen_range(0.0..200.0),
+            _ => self.rng.gen_range(-1e30..1e30),
+        }
+    }
+
+    fn text(&mut self) -> String {
+        self.pick(&TEXTS).to_string()
+    }
+
+    fn position(&mut self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn inventory(&mut self) -> Inventory {
+        Inventory { energy: self.small(), minerals: self.small(), science: self.small() }
+    }
+
+    fn event(&mut self) -> RobotEvent {
+        let robot_id = self.size();
+        match self.rng.gen_range(0..20) {
+            0 => RobotEvent::Collected { robot_id, resource: self.pick(&TILES), x: self.size(), y: self.size(), left: self.small() },
+            1 => RobotEvent::ModeChanged { robot_id, from: self.pick(&MODES), to: self.pick(&MODES) },
+            2 => RobotEvent::ExplorationComplete { robot_id },
+            3 => RobotEvent::LowEnergy { robot_id, x: self.size(), y: self.size() },
+            4 => RobotEvent::Arrived { robot_id, x: self.size(), y: self.size() },
+            5 => RobotEvent::Stranded { robot_id, x: self.size(), y: self.size() },
+            6 => RobotEvent::Decommissioned { robot_id, refund: self.small() },
+            7 => RobotEvent::Idle { robot_id, resource: self.pick(&TILES) },
+            8 => RobotEvent::Stuck { robot_id, x: self.size(), y: self.size(), ticks: self.small() },
+            9 => RobotEvent::Notice { robot_id, message: self.text() },
+            10 => RobotEvent::RecallStarted,
+            11 => RobotEvent::FleetDocked { robots: self.size() },
+            12 => RobotEvent::StationUpgraded { upgrade: self.pick(&StationUpgrade::LADDER) },
+            13 => RobotEvent::TargetAbandoned { robot_id, x: self.size(), y: self.size(), reason: self.pick(&GIVE_UP_REASONS) },
+            14 => RobotEvent::CutOff { robot_id, x: self.size(), y: self.size() },
+            15 => RobotEvent::BrokeDown { robot_id, x: self.size(), y: self.size(), severe: self.flag() },
+            16 => RobotEvent::Repaired { robot_id, x: self.size(), y: self.size() },
+            17 => RobotEvent::Rescued { robot_id, energy: self.real() },
+            18 => RobotEvent::Repatriated { robot_id, x: self.size(), y: self.size() },
+            _ => RobotEvent::OperationsResumed,
+        }
+    }
+
+    fn robot(&mut self) -> RobotData {
+        RobotData {
+            id: self.size(),
+            x: self.size(),
+            y: self.size(),
+            energy: self.real(),
+            max_energy: self.real(),
+            minerals: self.small(),
+            scientific_data: self.small(),
+            robot_type: self.pick(&ROBOT_TYPES),
+            mode: self.pick(&MODES),
+            exploration_percentage: self.real(),
+            total_energy_spent: self.real(),
+            distance_traveled: self.real(),
+            energy_cargo: self.small(),
+            cargo_load: self.small(),
+            cargo_capacity: self.small(),
+            stuck_ticks: self.small(),
+            upgrades: self.vec(3, |generator| generator.pick(&UPGRADES)),
+            relay_linked: self.flag(),
+            stuck_events: self.small(),
+            frontier_size: self.size(),
+            current_task: self.flag().then(|| self.text()),
+            mode_detail: self.flag().then(|| self.text()),
+            inventory: self.inventory(),
+            brain: self.text(),
+            recent_transitions: self.vec(3, Self::text),
+            current_goal: self.flag().then(|| self.position()),
+        }
+    }
+
+    fn robot_lite(&mut self) -> RobotDataLite {
+        RobotDataLite {
+            id: self.rng.r#gen(),
+            x: self.rng.r#gen(),
+            y: self.rng.r#gen(),
+            energy_level: self.rng.r#gen(),
+            mode: self.pick(&MODES),
+            exploration_permille: self.rng.r#gen(),
+            total_energy_spent: self.small(),
+            distance_traveled: self.small(),
+            inventory: self.inventory(),
+            stuck_ticks: self.rng.r#gen(),
+            stuck_events: self.rng.r#gen(),
+            frontier_size: self.rng.r#gen(),
+            relay_linked: self.flag(),
+            current_task: self.flag().then(|| self.text()),
+            mode_detail: self.flag().then(|| self.text()),
+            current_goal: self.flag().then(|| (self.rng.r#gen(), self.rng.r#gen())),
+        }
+    }
+
+    fn keyframe(&mut self) -> RobotKeyframe {
+        RobotKeyframe {
+            id: self.size(),
+            robot_type: self.pick(&ROBOT_TYPES),
+            max_energy: self.real(),
+            cargo_capacity: self.small(),
+            upgrades: self.vec(3, |generator| generator.pick(&UPGRADES)),
+            brain: self.text(),
+            recent_transitions: self.vec(3, Self::text),
+        }
+    }
+
+    fn statistics(&mut self) -> MissionStats {
+        MissionStats {
+            collected_energy: self.small(),
+            collected_minerals: self.small(),
+            collected_scientific_data: self.small(),
+            exploration_percentage: self.real(),
+            conflict_count: self.size(),
+            peer_exchange_count: self.size(),
+            robots_by_type: self.vec(4, |generator| (generator.pick(&ROBOT_TYPES), generator.size())).into_iter().collect(),
+            total_ticks: self.small(),
+            resources_remaining: self.vec(4, |generator| (generator.pick(&TILES), generator.size())).into_iter().collect(),
+        }
+    }
+
+    fn station(&mut self) -> StationData {
+        StationData {
+            energy_reserves: self.small(),
+            collected_minerals: self.small(),
+            collected_scientific_data: self.small(),
+            exploration_percentage: self.real(),
+            conflict_count: self.size(),
+            robot_count: self.size(),
+            status_message: self.text(),
+            mission_complete: self.flag(),
+            current_time: self.small(),
+            collector_policy: CollectorPolicy {
+                energy_start_pct: self.real(),
+                mineral_start_pct: self.real(),
+                scientific_start_pct: self.real(),
+            },
+            statistics: self.statistics(),
+            day_phase: self.pick(&[DayPhase::Day, DayPhase::Night]),
+            next_robot: self.flag().then(|| self.pick(&ROBOT_TYPES)),
+            next_robot_cost: RobotCost { energy: self.small(), minerals: self.small() },
+            objectives: MissionObjectives {
+                min_exploration_pct: self.real(),
+                min_minerals: self.small(),
+                min_scientific_data: self.small(),
+                collect_all_resources: self.flag(),
+                require_all_home: self.flag(),
+            },
+            energy_low: self.flag(),
+            recall_active: self.flag(),
+            upgrades: self.vec(4, |generator| generator.pick(&StationUpgrade::LADDER)),
+            energy_capacity: self.small(),
+        }
+    }
+
+    fn state(&mut self) -> SimulationState {
+        SimulationState {
+            map_data: MapData {
+                tiles: self.grid(|generator| generator.pick(&TILES)),
+                station_x: self.size(),
+                station_y: self.size(),
+                extra_stations: self.vec(3, Self::position),
+                resource_amounts: self.grid(Self::small),
+                biome: self.grid(|generator| generator.pick(&BIOMES)),
+                reachable_tiles: self.size(),
+            },
+            robots_data: self.vec(4, Self::robot),
+            station_data: self.station(),
+            exploration_data: ExplorationData {
+                explored_tiles: self.grid(Self::flag),
+                last_seen: self.grid(Self::small),
+                explored_by: self.grid(|generator| generator.flag().then(|| generator.pick(&ROBOT_TYPES))),
+            },
+            iteration: self.small(),
+            elapsed_secs: self.pick(&[0.0, 2.1, 1e-300, f64::MAX]),
+            events: self.vec(4, |generator| MissionEvent { cycle: generator.small(), event: generator.event() }),
+            robots_lite: self.vec(3, Self::robot_lite),
+            robot_keyframes: self.vec(3, Self::keyframe),
+        }
+    }
+}
+
+#[test]
+fn random_states_survive_a_json_round_trip() {
+    for seed in 0..500 {
+        let state = Generator { rng: StdRng::seed_from_u64(seed) }.state();
+        if let Err(difference) = roundtrip_check(&state) {
+            panic!("graine {}: {}", seed, difference);
+        }
+    }
+}
+
+#[test]
+fn mission_states_survive_a_json_round_trip() {
+    let mut map = Map::with_seed(2);
+    let mut station = Station::new();
+    let mut robots = deploy_fleet(&map, &mut station, &INITIAL_FLEET);
+    let mut encoder = LiteStateEncoder::new();
+
+    for iteration in 0..400 {
+        step_simulation(&mut map, &mut station, &mut robots, iteration);
+        let state = create_simulation_state(&map, &station, &robots, iteration, iteration as f64 * 0.3);
+        let lite = encoder.encode(&state);
+        if iteration % 25 == 0 {
+            // Complet, allégé et image clé d'un client qui se connecte
+            for (kind, state) in [("complet", &state), ("allégé", &lite), ("image clé", &create_lite_keyframe_state(&state))] {
+                if let Err(difference) = roundtrip_check(state) {
+                    panic!("cycle {}, état {}: {}", iteration, kind, difference);
+                }
+            }
+        }
+    }
+}
+
+// NOTE - Simulation server started for a test, stopped when dropped (even if the test panics)
+struct Server {
+    process: Child,
+    port: u16,
+}
+
+impl Server {
+    fn start(args: &[&str]) -> Self {
+        // Port libre au moment du lancement
+        let port = TcpListener::bind("127.0.0.1:0").and_then(|listener| listener.local_addr()).unwrap().port();
+        let process = Command::new(env!("CARGO_BIN_EXE_simulation"))
+            .args(["--port", &port.to_string(), "--seed", "1"])
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        Server { process, port }
+    }
+
+    // NOTE - Connects once the server listens
+    fn connect(&self) -> TcpStream {
+        let started = Instant::now();
+        loop {
+            match TcpStream::connect(("127.0.0.1", self.port)) {
+                Ok(stream) => {
+                    stream.set_read_timeout(Some(Duration::from_secs(AUTH_TIMEOUT_SECS * 2))).unwrap();
+                    return stream;
+                },
+                Err(_) if started.elapsed() < Duration::from_secs(30) => std::thread::sleep(Duration::from_millis(50)),
+                Err(error) => panic!("serveur injoignable sur le port {}: {}", self.port, error),
+            }
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+// NOTE - Frames received until the server closes the connection or `wanted` of them arrived
+fn read_frames(stream: &mut TcpStream, wanted: usize) -> (Vec<NetworkMessage>, bool) {
+    let mut decoder = FrameDecoder::new();
+    let mut frames = Vec::new();
+    let mut chunk = [0; 4096];
+    while frames.len() < wanted {
+        match stream.read(&mut chunk) {
+            Ok(0) => return (frames, true),
+            Ok(read) => frames.extend(decoder.decode(&chunk[..read]).into_iter().map(|frame| frame.unwrap())),
+            Err(error) => panic!("lecture interrompue après {} trame(s): {}", frames.len(), error),
+        }
+    }
+    (frames, false)
+}
+
+#[test]
+fn the_server_checks_the_token_of_every_client() {
+    let server = Server::start(&["--auth-token", "s3cret"]);
+    let auth = |token: &str| encode_frame(&NetworkMessage::Auth { token: token.to_string() }).unwrap();
+
+    // Bon jeton : le client reçoit l'état courant
+    let mut client = server.connect();
+    client.write_all(&auth("s3cret")).unwrap();
+    let (frames, closed) = read_frames(&mut client, 1);
+    assert!(!closed && matches!(frames[0], NetworkMessage::State(_)), "{} trame(s), connexion fermée: {}", frames.len(), closed);
+
+    // Mauvais jeton : refus, puis connexion fermée
+    let mut intruder = server.connect();
+    intruder.write_all(&auth("guess")).unwrap();
+    let (frames, closed) = read_frames(&mut intruder, 2);
+    assert!(closed && matches!(frames[..], [NetworkMessage::AuthFailed { error: AuthError::InvalidToken }]), "{} trame(s), connexion fermée: {}", frames.len(), closed);
+
+    // Rien dans le délai : refus au bout de AUTH_TIMEOUT_SECS
+    let mut silent = server.connect();
+    let connected = Instant::now();
+    let (frames, closed) = read_frames(&mut silent, 2);
+    assert!(closed && matches!(frames[..], [NetworkMessage::AuthFailed { error: AuthError::MissingToken }]), "{} trame(s), connexion fermée: {}", frames.len(), closed);
+    assert!(connected.elapsed() >= Duration::from_secs(AUTH_TIMEOUT_SECS) - Duration::from_millis(100));
+
+    // Le client accepté reste servi
+    assert!(matches!(read_frames(&mut client, 1).0[..], [NetworkMessage::State(_) | NetworkMessage::Ping { .. }]));
+}
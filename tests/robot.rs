@@ -0,0 +1,247 @@
+// Comportement d'un robot seul, mise à jour après mise à jour, sur des cartes construites à la main
+
+use ereea::events::RobotEvent;
+use ereea::map::Map;
+use ereea::occupancy::OccupancyGrid;
+use ereea::robot::Robot;
+use ereea::station::Station;
+use ereea::types::{CollectorPolicy, GiveUpReason, RobotMode, RobotType, TileType, MAP_SIZE};
+
+// NOTE - Runs `updates` robot updates and returns the reasons of the targets it gave up, with their position
+fn give_ups(robot: &mut Robot, map: &mut Map, station: &mut Station, updates: usize) -> Vec<((usize, usize), GiveUpReason)> {
+    let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    (0..updates)
+        .flat_map(|_| robot.update(map, station, &mut occupancy))
+        .filter_map(|event| match event {
+            RobotEvent::TargetAbandoned { x, y, reason, .. } => Some(((x, y), reason)),
+            _ => None,
+        })
+        .collect()
+}
+
+// NOTE - Mineral collector at (x, y) that already knows the whole map, as the station does
+fn informed_collector(station: &mut Station, map: &Map, x: usize, y: usize) -> Robot {
+    station.reveal_map(map);
+    let mut robot = Robot::new(0, 0, RobotType::MineralCollector);
+    robot.memory = station.global_memory.clone();
+    robot.reindex_memory();
+    (robot.x, robot.y) = (x, y);
+    robot
+}
+
+#[test]
+fn a_charged_collector_gives_up_a_walled_in_resource_as_unreachable() {
+    let mut map = Map::open();
+    (map.station_x, map.station_y) = (10, 10);
+    map.wall_in(3, 3);
+    map.set_tile(3, 3, TileType::Mineral);
+    let mut station = Station::new();
+    station.collector_policy = CollectorPolicy::IMMEDIATE;
+    station.reveal_map(&map);
+    let mut robot = Robot::new_with_memory(10, 10, RobotType::MineralCollector, 1, 10, 10, station.global_memory.clone());
+
+    let give_ups = give_ups(&mut robot, &mut map, &mut station, 5);
+    assert!(give_ups.contains(&((3, 3), GiveUpReason::Unreachable)), "{:?}", give_ups);
+    assert!(!give_ups.iter().any(|&(_, reason)| reason == GiveUpReason::LowEnergy), "{:?}", give_ups);
+}
+
+#[test]
+fn a_full_hold_costs_more_energy_and_an_extra_update_per_step() {
+    let mut map = Map::open();
+    (map.station_x, map.station_y) = (0, 5);
+    let mut station = Station::new();
+    station.collector_policy = CollectorPolicy::IMMEDIATE;
+
+    let mut spent_on_one_step = |minerals: u32, updates: usize| {
+        let mut robot = Robot::new(0, 5, RobotType::MineralCollector);
+        (robot.x, robot.mode) = (8, RobotMode::ReturnToStation);
+        robot.load(TileType::Mineral, minerals);
+        let mut occupancy = OccupancyGrid::new(0, 5);
+        let before = robot.energy;
+        for _ in 0..updates {
+            robot.update(&mut map, &mut station, &mut occupancy);
+        }
+        assert_eq!(robot.x, 7);
+        before - robot.energy
+    };
+    let empty = spent_on_one_step(0, 1);
+    let full = spent_on_one_step(5, 2);
+    assert!((empty - (0.5 + 0.1)).abs() < 1e-4, "{}", empty);
+    assert!((full - (0.5 * 1.5 + 2.0 * 0.1)).abs() < 1e-4, "{}", full);
+}
+
+#[test]
+fn a_returning_collector_unloads_its_whole_mixed_cargo_at_once() {
+    let mut map = Map::open();
+    (map.station_x, map.station_y) = (0, 0);
+    let mut station = Station::new();
+    station.collector_policy = CollectorPolicy::IMMEDIATE;
+    let mut robot = Robot::new(0, 0, RobotType::MineralCollector);
+    (robot.x, robot.mode) = (1, RobotMode::ReturnToStation);
+    robot.load(TileType::Mineral, 2);
+    robot.load(TileType::Scientific, 1);
+    robot.load(TileType::Mineral, 1);
+
+    // Chargé, il lui faut deux mises à jour pour le dernier pas
+    let mut occupancy = OccupancyGrid::new(0, 0);
+    for _ in 0..2 {
+        robot.update(&mut map, &mut station, &mut occupancy);
+    }
+    assert_eq!((robot.x, robot.cargo_load()), (0, 4));
+    robot.update(&mut map, &mut station, &mut occupancy);
+
+    assert!(robot.inventory.is_empty());
+    assert_eq!((station.collected_minerals, station.collected_scientific_data), (3, 1));
+}
+
+#[test]
+fn an_oscillating_collector_gives_up_its_mineral_for_another() {
+    // Le chemin prévu fait des allers-retours entre les deux cases devant le minerai
+    let mut map = Map::open();
+    map.set_tile(7, 5, TileType::Mineral);
+    map.set_tile(5, 9, TileType::Mineral);
+    let mut station = Station::new();
+    let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    let mut robot = informed_collector(&mut station, &map, 5, 5);
+    robot.mode = RobotMode::Collecting;
+    robot.current_path = [(6, 5), (5, 5)].repeat(4).into_iter().chain([(7, 5)]).collect();
+    robot.current_target = Some((7, 5));
+
+    let mut ticks = 0;
+    while robot.minerals() == 0 {
+        robot.update(&mut map, &mut station, &mut occupancy);
+        ticks += 1;
+        assert!(ticks <= 12, "toujours bloqué en ({}, {})", robot.x, robot.y);
+    }
+    assert_eq!(map.tiles[9][5], TileType::Empty);
+    assert_eq!(map.tiles[5][7], TileType::Mineral);
+}
+
+#[test]
+fn a_collector_replans_once_its_deposit_is_taken() {
+    // Un autre robot vide le gisement visé en cours de route : le chemin périmé est abandonné
+    let mut map = Map::open();
+    map.set_tile(10, 5, TileType::Mineral);
+    map.set_tile(10, 15, TileType::Mineral);
+    let mut station = Station::new();
+    let mut occupancy = OccupancyGrid::new(map.station_x, map.station_y);
+    let mut robot = informed_collector(&mut station, &map, 4, 5);
+    robot.mode = RobotMode::Collecting;
+    robot.update(&mut map, &mut station, &mut occupancy);
+    assert_eq!(robot.current_target, Some((10, 5)));
+
+    for _ in 0..3 {
+        robot.update(&mut map, &mut station, &mut occupancy);
+    }
+    map.consume_resource(10, 5);
+
+    let mut ticks = 0;
+    while robot.minerals() == 0 {
+        robot.update(&mut map, &mut station, &mut occupancy);
+        ticks += 1;
+        assert!(ticks <= 20, "toujours en ({}, {})", robot.x, robot.y);
+    }
+    assert_eq!((robot.x, robot.y), (10, 15));
+}
+
+#[test]
+fn a_collector_turns_back_while_it_can_still_make_it_home() {
+    // Une ligne de gisements qui s'éloigne de la station : jamais besoin du sauvetage d'urgence
+    let mut map = Map::open();
+    (map.station_x, map.station_y) = (0, 10);
+    for x in 10..MAP_SIZE {
+        map.set_tile(x, 10, TileType::Mineral);
+    }
+    let mut station = Station::new();
+    station.collector_policy = CollectorPolicy::IMMEDIATE;
+    station.reveal_map(&map);
+    let mut occupancy = OccupancyGrid::new(0, 10);
+
+    let mut robot = Robot::new_with_memory(10, 10, RobotType::MineralCollector, 1, 0, 10, station.global_memory.clone());
+    (robot.energy, robot.cargo_capacity) = (10.0, 20);
+    let mut turned_back = None;
+    for _ in 0..40 {
+        station.tick();
+        robot.update(&mut map, &mut station, &mut occupancy);
+        assert!(!robot.needs_rescue());
+        if robot.mode == RobotMode::ReturnToStation && turned_back.is_none() {
+            turned_back = Some(robot.x);
+            assert!(robot.energy >= robot.energy_to_return_home(&map).unwrap());
+        }
+        if (robot.x, robot.y) == (0, 10) {
+            break;
+        }
+    }
+    assert!(turned_back.is_some_and(|x| x > 10), "{:?}", turned_back);
+    assert_eq!((robot.x, robot.y), (0, 10));
+    assert!(robot.minerals() > 0 && robot.energy > 0.0);
+}
+
+#[test]
+fn remembering_visits_spreads_a_wandering_explorer() {
+    // Seul le coin en haut à gauche reste inconnu, loin derrière un bloc de roche : l'explorateur erre
+    fn visit_variance(revisit_weight: f32, seed: u32) -> f32 {
+        let mut map = Map::open();
+        for y in 0..10 {
+            for x in 0..10 {
+                map.set_tile(x, y, TileType::Obstacle);
+            }
+        }
+        let mut station = Station::new();
+        station.reveal_map(&map);
+        station.global_memory[(0, 0)].explored = false;
+        station.reindex_memory();
+
+        let mut robot = Robot::new_with_memory(15, 15, RobotType::Explorer, 1, 15, 15, station.global_memory.clone());
+        robot.seed_rng(seed);
+        (robot.max_energy, robot.energy) = (10_000.0, 10_000.0);
+        robot.revisit_weight = revisit_weight;
+        let mut occupancy = OccupancyGrid::new(15, 15);
+        for _ in 0..600 {
+            station.tick();
+            robot.update(&mut map, &mut station, &mut occupancy);
+        }
+
+        let counts: Vec<f32> = (0..MAP_SIZE)
+            .flat_map(|y| (0..MAP_SIZE).map(move |x| (x, y)))
+            .filter(|&(x, y)| map.is_valid_position(x, y))
+            .map(|(x, y)| robot.visit_count[(x, y)] as f32)
+            .collect();
+        let mean = counts.iter().sum::<f32>() / counts.len() as f32;
+        counts.iter().map(|count| (count - mean).powi(2)).sum::<f32>() / counts.len() as f32
+    }
+
+    for seed in 1..=5 {
+        let (spread, baseline) = (visit_variance(10.0, seed), visit_variance(0.0, seed));
+        assert!(spread < baseline, "graine {} : variance {} contre {}", seed, spread, baseline);
+    }
+}
+
+#[test]
+fn a_wandering_explorer_does_not_search_its_way_home_every_update() {
+    // Batterie pleine, loin du seuil de retour : le chemin du retour n'est recalculé qu'à l'approche du seuil
+    // ou quand la carte change, pas à chaque pas
+    let mut map = Map::open();
+    for y in 0..10 {
+        for x in 0..10 {
+            map.set_tile(x, y, TileType::Obstacle);
+        }
+    }
+    let mut station = Station::new();
+    station.reveal_map(&map);
+    station.global_memory[(0, 0)].explored = false;
+    station.reindex_memory();
+
+    let mut robot = Robot::new_with_memory(15, 15, RobotType::Explorer, 1, 15, 15, station.global_memory.clone());
+    (robot.max_energy, robot.energy) = (10_000.0, 10_000.0);
+    let mut occupancy = OccupancyGrid::new(15, 15);
+    let mut away = 0;
+    for _ in 0..1000 {
+        station.tick();
+        robot.update(&mut map, &mut station, &mut occupancy);
+        away += usize::from((robot.x, robot.y) != (15, 15));
+    }
+
+    assert!(away > 900, "{} mises à jour loin de la station", away);
+    assert!(robot.path_search_count() < 100, "{} recherches A* en 1000 mises à jour", robot.path_search_count());
+}
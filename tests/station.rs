@@ -0,0 +1,183 @@
+// Coordination de plusieurs robots par la station (réservations, recharges, dépannages),
+// sur des cartes construites à la main
+
+mod common;
+
+use common::{docked_fleet, tick};
+use ereea::knowledge::sync_by_radio;
+use ereea::map::Map;
+use ereea::robot::{Malfunction, Robot};
+use ereea::station::{MalfunctionConfig, Station};
+use ereea::types::{RobotMode, RobotType, TileType, MAP_SIZE};
+use rand::rngs::mock::StepRng;
+
+#[test]
+fn collectors_leaving_together_split_the_known_minerals() {
+    let mut map = Map::open();
+    map.set_tile(6, 0, TileType::Mineral);
+    map.set_tile(12, 0, TileType::Mineral);
+    (map.station_x, map.station_y) = (0, 0);
+    let mut station = Station::new();
+    station.reveal_map(&map);
+    let mut robots = docked_fleet(&map, &mut station, &[RobotType::MineralCollector; 2]);
+
+    tick(&mut map, &mut station, &mut robots);
+    assert_eq!(station.reserved_target_of(1), Some((6, 0)));
+    assert_eq!(station.reserved_target_of(2), Some((12, 0)));
+
+    // Chacun ramasse son minerai dès la première visite, sans course inutile
+    let mut ticks = 1;
+    while map.tiles[0][6] == TileType::Mineral || map.tiles[0][12] == TileType::Mineral {
+        tick(&mut map, &mut station, &mut robots);
+        ticks += 1;
+        assert!(ticks <= 13, "minerais restants après {} cycles", ticks);
+    }
+    assert_eq!((robots[0].minerals(), robots[1].minerals()), (1, 1));
+    assert!(robots.iter().all(|robot| robot.current_target.is_none_or(|target| target == (0, 0))));
+    assert!(station.resource_reservations.is_empty());
+}
+
+#[test]
+fn dropping_cargo_off_in_passing_spares_a_round_trip() {
+    // Un gisement de chaque côté de la station, le long d'un couloir : décharger le premier au passage
+    // évite un aller-retour quand la soute (5 unités) se remplit sur le second
+    fn cycles_to_collect(drop_off_in_passing: bool) -> u32 {
+        let mut map = Map::open();
+        map.tiles = vec![vec![TileType::Obstacle; MAP_SIZE]; MAP_SIZE];
+        for x in 2..18 {
+            map.tiles[9][x] = TileType::Empty;
+            map.tiles[10][x] = TileType::Empty;
+        }
+        map.update_reachability();
+        for x in [3, 17] {
+            map.set_tile(x, 9, TileType::Mineral);
+            map.set_resource_amount(x, 9, 3);
+        }
+
+        let mut station = Station::new();
+        station.drop_off_in_passing = drop_off_in_passing;
+        station.reveal_map(&map);
+        let mut robots = [Robot::new_with_memory(10, 10, RobotType::MineralCollector, 1, 10, 10, station.global_memory.clone())];
+
+        // Aucun minerai ne se perd en route
+        let mut harvested = 0;
+        for cycle in 1..200 {
+            let left_before = map.resource_amount(3, 9) + map.resource_amount(17, 9);
+            tick(&mut map, &mut station, &mut robots);
+            harvested += left_before - (map.resource_amount(3, 9) + map.resource_amount(17, 9));
+            assert_eq!(station.collected_minerals + robots[0].minerals(), harvested);
+            if station.collected_minerals == 6 {
+                return cycle;
+            }
+        }
+        panic!("minerais jamais rapportés");
+    }
+
+    assert!(cycles_to_collect(true) + 15 < cycles_to_collect(false));
+}
+
+#[test]
+fn robots_coming_home_together_queue_for_the_charging_bays() {
+    let mut map = Map::open();
+    let mut station = Station::new();
+    station.charging_bays = 2;
+    station.energy_reserves = 1000;
+    let mut robots = docked_fleet(&map, &mut station, &[RobotType::Explorer; 3]);
+    for robot in robots.iter_mut() {
+        robot.energy = 30.0;
+        robot.mode = RobotMode::ReturnToStation;
+    }
+
+    let mut someone_waited = false;
+    for _ in 0..30 {
+        tick(&mut map, &mut station, &mut robots);
+        let charging = robots.iter().filter(|robot| robot.mode == RobotMode::Charging).count();
+        let in_bays = robots.iter().filter(|robot| station.has_charging_bay(robot.id)).count();
+        assert!(in_bays <= 2);
+        someone_waited |= charging > in_bays;
+    }
+    assert!(someone_waited);
+
+    // Énergie des robots = énergie de départ - énergie dépensée + énergie rechargée
+    let held: f32 = robots.iter().map(|robot| robot.energy + robot.total_energy_spent).sum();
+    assert_eq!(1000 - station.energy_reserves, station.charged_energy);
+    assert!((held - 3.0 * 30.0 - station.charged_energy as f32).abs() < 1e-3);
+}
+
+#[test]
+fn a_repairer_brings_a_stranded_explorer_home() {
+    let mut map = Map::open();
+    let (station_x, station_y) = (map.station_x, map.station_y);
+    let mut station = Station::new();
+    let mut explorer = Robot::new(station_x, station_y, RobotType::Explorer);
+    explorer.id = 1;
+    (explorer.x, explorer.y) = (2, 2);
+    explorer.energy = 1.0;
+    let mut repairer = Robot::new(station_x, station_y, RobotType::Repairer);
+    repairer.id = 2;
+    repairer.mode = RobotMode::Idle;
+
+    let mut robots = [explorer, repairer];
+    let (mut rescued, mut repairer_home) = (false, false);
+    for _ in 0..60 {
+        tick(&mut map, &mut station, &mut robots);
+        let [explorer, repairer] = &robots;
+        rescued |= (explorer.x, explorer.y) == (station_x, station_y);
+        repairer_home = rescued && (repairer.x, repairer.y) == (station_x, station_y);
+        if repairer_home {
+            break;
+        }
+    }
+
+    // L'explorateur est rentré sur l'énergie du réparateur, et le réparateur est revenu
+    assert!(rescued && repairer_home);
+    assert_ne!(robots[0].mode, RobotMode::Stranded);
+    assert_eq!(robots[1].rescue_cargo, 0.0);
+    assert!(station.rescue_requests.is_empty());
+}
+
+#[test]
+fn a_repairer_fixes_a_severe_breakdown_on_site() {
+    let mut map = Map::open();
+    let (station_x, station_y) = (map.station_x, map.station_y);
+    let mut station = Station::new();
+    let config = MalfunctionConfig { failure_rate: 0.001, severe_ratio: 0.5, ..MalfunctionConfig::DEFAULT };
+    let mut explorer = Robot::new(station_x, station_y, RobotType::Explorer);
+    explorer.id = 1;
+    (explorer.x, explorer.y) = (4, 4);
+    assert!(explorer.roll_malfunction(&config, &mut StepRng::new(0, 0)));
+    assert_eq!(explorer.malfunction, Some(Malfunction::Severe));
+    let mut repairer = Robot::new(station_x, station_y, RobotType::Repairer);
+    repairer.id = 2;
+    repairer.mode = RobotMode::Idle;
+
+    // En panne grave, le robot attend sur place l'arrivée du réparateur
+    let mut robots = [explorer, repairer];
+    let mut repaired = false;
+    for _ in 0..60 {
+        tick(&mut map, &mut station, &mut robots);
+        if robots[0].mode != RobotMode::Broken {
+            repaired = true;
+            break;
+        }
+        assert_eq!((robots[0].x, robots[0].y), (4, 4));
+    }
+
+    assert!(repaired, "jamais réparé");
+    assert!(robots[0].malfunction.is_none());
+    assert!(station.rescue_requests.is_empty());
+}
+
+#[test]
+fn a_radio_link_with_the_longest_latency_never_delivers() {
+    // Comme `--radio-range 6 --radio-latency 4294967295` : rien ne traverse la liaison, sans débordement
+    let mut map = Map::open();
+    let mut station = Station::new();
+    (station.radio_range, station.radio_latency, station.radio_sync_interval) = (6, u32::MAX, 1);
+    let mut robots = docked_fleet(&map, &mut station, &[RobotType::Explorer]);
+
+    for _ in 0..20 {
+        tick(&mut map, &mut station, &mut robots);
+        assert_eq!(sync_by_radio(&mut robots, &mut station, &map), 0);
+    }
+}